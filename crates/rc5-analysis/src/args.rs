@@ -0,0 +1,269 @@
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use rc5_analysis::differential::Difference;
+use rc5_analysis::linear::Mask;
+
+/// Cryptanalytic experiments on RC5 with small word sizes or few rounds.
+#[derive(Debug, Parser)]
+#[command(name = "rc5-analysis", version)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Enumerates the permutation RC5-8/r/b makes of its 2^16 blocks.
+    Permutation(PermutationArgs),
+
+    /// Differential cryptanalysis of reduced-round RC5.
+    #[command(subcommand)]
+    Differential(DifferentialCommand),
+
+    /// Linear cryptanalysis and χ² tests of reduced-round RC5.
+    #[command(subcommand)]
+    Linear(LinearCommand),
+
+    /// Measures how plaintext and key bit flips spread, half-round by half-round.
+    Avalanche(AvalancheArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct PermutationArgs {
+    /// Number of rounds.
+    #[arg(short = 'r', long, default_value_t = 12)]
+    pub rounds: u8,
+
+    /// Key length in bytes of the random keys.
+    #[arg(short = 'b', long, default_value_t = 16, conflicts_with = "key")]
+    pub key_bytes: usize,
+
+    /// A single key, in hex, to report the cycle structure of; random keys if absent.
+    #[arg(short = 'k', long, value_name = "HEX")]
+    pub key: Option<String>,
+
+    /// Number of random keys.
+    #[arg(short = 'n', long, default_value_t = 100, conflicts_with = "key")]
+    pub keys: usize,
+
+    /// Seed of the random keys.
+    #[arg(short = 's', long, default_value_t = 0)]
+    pub seed: u64,
+}
+
+/// The word sizes of the native word types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum WordSize {
+    #[value(name = "8")]
+    W8,
+    #[value(name = "16")]
+    W16,
+    #[value(name = "32")]
+    W32,
+    #[value(name = "64")]
+    W64,
+}
+
+/// Every word size RC5 is implemented for, the strange 24- and 80-bit words included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum AnyWordSize {
+    #[value(name = "8")]
+    W8,
+    #[value(name = "16")]
+    W16,
+    #[value(name = "24")]
+    W24,
+    #[value(name = "32")]
+    W32,
+    #[value(name = "64")]
+    W64,
+    #[value(name = "80")]
+    W80,
+    #[value(name = "128")]
+    W128,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum DifferentialCommand {
+    /// Estimates the probability of a characteristic, half-round by half-round.
+    Estimate(EstimateArgs),
+
+    /// Finds the most likely output difference of every low-weight input difference.
+    Search(SearchArgs),
+
+    /// Recovers the last subkey of RC5-w/r from chosen-plaintext pairs.
+    Attack(AttackArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct EstimateArgs {
+    /// Word size in bits.
+    #[arg(short = 'w', long, value_enum, default_value_t = WordSize::W32)]
+    pub word_size: WordSize,
+
+    /// The plaintext difference.
+    #[arg(short = 'i', long, value_name = "A,B")]
+    pub input: Difference,
+
+    /// The difference after each half-round from half-round 0 on, `*` for any.
+    #[arg(value_name = "A,B|*", required = true)]
+    pub trail: Vec<String>,
+
+    /// Number of random plaintext pairs.
+    #[arg(short = 'n', long, default_value_t = 1 << 20)]
+    pub pairs: usize,
+
+    /// Seed of the random keys and plaintexts.
+    #[arg(short = 's', long, default_value_t = 0)]
+    pub seed: u64,
+}
+
+#[derive(Debug, Args)]
+pub struct SearchArgs {
+    /// Word size in bits.
+    #[arg(short = 'w', long, value_enum, default_value_t = WordSize::W8)]
+    pub word_size: WordSize,
+
+    /// Number of half-rounds after half-round 0.
+    #[arg(short = 'H', long, default_value_t = 2)]
+    pub half_rounds: usize,
+
+    /// Highest weight of the input differences.
+    #[arg(short = 'm', long, default_value_t = 2)]
+    pub max_weight: u32,
+
+    /// Number of random plaintext pairs per input difference.
+    #[arg(short = 'n', long, default_value_t = 1 << 12)]
+    pub pairs: usize,
+
+    /// Number of input differences to list.
+    #[arg(short = 't', long, default_value_t = 20)]
+    pub top: usize,
+
+    /// Seed of the random keys and plaintexts.
+    #[arg(short = 's', long, default_value_t = 0)]
+    pub seed: u64,
+}
+
+#[derive(Debug, Args)]
+pub struct AttackArgs {
+    /// Word size in bits.
+    #[arg(short = 'w', long, value_enum, default_value_t = WordSize::W32)]
+    pub word_size: WordSize,
+
+    /// Number of rounds.
+    #[arg(short = 'r', long, default_value_t = 3)]
+    pub rounds: usize,
+
+    /// The key, in hex; a random 16-byte key if absent.
+    #[arg(short = 'k', long, value_name = "HEX")]
+    pub key: Option<String>,
+
+    /// Number of chosen-plaintext pairs; by default what the rounds need on RC5-32.
+    #[arg(short = 'n', long)]
+    pub pairs: Option<usize>,
+
+    /// Seed of the random key and plaintexts.
+    #[arg(short = 's', long, default_value_t = 0)]
+    pub seed: u64,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum LinearCommand {
+    /// Estimates the correlation of a linear approximation key by key; Kaliski and Yin's by default.
+    Correlation(CorrelationArgs),
+
+    /// Tests the rotation amounts after some half-rounds for uniformity with χ².
+    Rotation(RotationArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct CorrelationArgs {
+    /// Word size in bits.
+    #[arg(short = 'w', long, value_enum, default_value_t = WordSize::W16)]
+    pub word_size: WordSize,
+
+    /// Mask of the plaintext.
+    #[arg(short = 'i', long, value_name = "A,B", default_value = "0,1")]
+    pub input: Mask,
+
+    /// Mask of the state after the half-rounds.
+    #[arg(short = 'o', long, value_name = "A,B", default_value = "0,1")]
+    pub output: Mask,
+
+    /// Number of half-rounds after half-round 0.
+    #[arg(short = 'H', long, default_value_t = 2)]
+    pub half_rounds: usize,
+
+    /// Number of random keys.
+    #[arg(short = 'K', long, default_value_t = 8)]
+    pub keys: usize,
+
+    /// Number of random plaintexts per key.
+    #[arg(short = 'n', long, default_value_t = 1 << 20)]
+    pub texts: usize,
+
+    /// Seed of the random keys and plaintexts.
+    #[arg(short = 's', long, default_value_t = 0)]
+    pub seed: u64,
+}
+
+#[derive(Debug, Args)]
+pub struct RotationArgs {
+    /// Word size in bits.
+    #[arg(short = 'w', long, value_enum, default_value_t = WordSize::W32)]
+    pub word_size: WordSize,
+
+    /// Number of half-rounds after half-round 0.
+    #[arg(short = 'H', long, default_value_t = 4)]
+    pub half_rounds: usize,
+
+    /// Plaintexts with random rotation amounts, a control, rather than zero ones.
+    #[arg(long)]
+    pub random: bool,
+
+    /// Number of random keys.
+    #[arg(short = 'K', long, default_value_t = 8)]
+    pub keys: usize,
+
+    /// Number of distinct plaintexts per key.
+    #[arg(short = 'n', long, default_value_t = 1 << 18)]
+    pub texts: usize,
+
+    /// Seed of the random keys and plaintexts.
+    #[arg(short = 's', long, default_value_t = 0)]
+    pub seed: u64,
+}
+
+#[derive(Debug, Args)]
+pub struct AvalancheArgs {
+    /// Word size in bits.
+    #[arg(short = 'w', long, value_enum, default_value_t = AnyWordSize::W32)]
+    pub word_size: AnyWordSize,
+
+    /// Number of rounds.
+    #[arg(short = 'r', long, default_value_t = 4)]
+    pub rounds: usize,
+
+    /// Key length in bytes.
+    #[arg(short = 'b', long, default_value_t = 16)]
+    pub key_bytes: usize,
+
+    /// Number of random keys and plaintexts to flip the bits of.
+    #[arg(short = 'n', long, default_value_t = 1024)]
+    pub samples: usize,
+
+    /// Seed of the random keys and plaintexts.
+    #[arg(short = 's', long, default_value_t = 0)]
+    pub seed: u64,
+
+    /// Directory to write the flip matrices of each half-round to, as CSV and PPM.
+    #[arg(short = 'o', long, value_name = "DIR")]
+    pub output: Option<PathBuf>,
+
+    /// Side in pixels of each matrix entry in the PPM images.
+    #[arg(long, default_value_t = 4)]
+    pub scale: usize,
+}
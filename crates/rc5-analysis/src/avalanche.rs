@@ -0,0 +1,353 @@
+//! Avalanche: how flipping one plaintext or key bit spreads through the state half-round by half-round, for every
+//! word size, the strange 24- and 80-bit words included.
+//!
+//! Half-rounds are counted as in [`crate::differential`]. A block or state is read as the bytes of `A` then `B`;
+//! its bit `i` is bit `i % 8` of byte `i / 8`. Entry `(i, j)` of a flip matrix is the probability that flipping
+//! input bit `i` flips state bit `j`. The strict avalanche criterion (SAC) asks for every entry to be 1/2; the bit
+//! independence criterion (BIC) asks for the flips of any two state bits to be uncorrelated.
+
+use std::fmt;
+use std::io::{self, Write};
+use std::marker::PhantomData;
+
+use rand::RngCore;
+use rc5_core::algs::{encrypt_traced_slice, expand_key_slices};
+use rc5_core::std_words::{LittleEndian, StdArith, StdMagic};
+use rc5_core::strange_words::{StrangeArithmetics, StrangeByteRepr, StrangeMagic};
+use rc5_core::traits::{Arithmetics, Magic, WordByteRepr, WordBytes, WordSize};
+use typenum::Unsigned;
+
+use crate::stats::{self, par_chunks};
+
+/// RC5-w on blocks of bytes.
+pub trait Variant {
+    const BITS: u32;
+
+    type STable: Send + Sync;
+
+    fn s_table(key: &[u8], rounds: usize) -> Self::STable;
+
+    /// Encrypts `block` for `half_rounds` half-rounds, calling `trace` with the state after each, half-round 0
+    /// included.
+    fn encrypt_traced(
+        s_table: &Self::STable,
+        half_rounds: usize,
+        block: &[u8],
+        trace: impl FnMut(usize, &[u8]),
+    );
+}
+
+/// RC5 with a word type, its magic constants, byte representation and arithmetic.
+pub struct Rc5<Word, M, WBR, A>(PhantomData<(Word, M, WBR, A)>);
+
+pub type Std<Word> = Rc5<Word, StdMagic, LittleEndian, StdArith>;
+pub type Strange<Word> = Rc5<Word, StrangeMagic, StrangeByteRepr, StrangeArithmetics>;
+
+impl<Word, M, WBR, A> Variant for Rc5<Word, M, WBR, A>
+where
+    Word: WordSize + Default + Copy + Send + Sync,
+    M: Magic<Word>,
+    WBR: WordByteRepr<Word>,
+    A: Arithmetics<Word>,
+{
+    const BITS: u32 = 8 * Word::ByteLen::U32;
+
+    type STable = Vec<Word>;
+
+    fn s_table(key: &[u8], rounds: usize) -> Vec<Word> {
+        let mut l_table = vec![Word::default(); key.len().div_ceil(Word::ByteLen::USIZE).max(1)];
+        let mut s_table = vec![Word::default(); 2 * (rounds + 1)];
+        expand_key_slices::<Word, M, WBR, A>(key, &mut l_table, &mut s_table);
+        s_table
+    }
+
+    fn encrypt_traced(
+        s_table: &Vec<Word>,
+        half_rounds: usize,
+        block: &[u8],
+        mut trace: impl FnMut(usize, &[u8]),
+    ) {
+        let n = Word::ByteLen::USIZE;
+        let mut a = WBR::from_bytes(WordBytes::<Word>::from_slice(&block[..n]));
+        let mut b = WBR::from_bytes(WordBytes::<Word>::from_slice(&block[n..]));
+        let mut state = vec![0; 2 * n];
+        encrypt_traced_slice::<Word, A>(s_table, half_rounds, &mut a, &mut b, |h, a, b| {
+            state[..n].copy_from_slice(&WBR::to_bytes(a));
+            state[n..].copy_from_slice(&WBR::to_bytes(b));
+            trace(h, &state)
+        });
+    }
+}
+
+/// How often flipping each input bit flipped each state bit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlipMatrix {
+    pub inputs: usize,
+    pub outputs: usize,
+    pub samples: usize,
+    counts: Vec<u32>,
+}
+
+impl FlipMatrix {
+    fn new(rows: Vec<Vec<u32>>, outputs: usize, samples: usize) -> Self {
+        Self {
+            inputs: rows.len(),
+            outputs,
+            samples,
+            counts: rows.concat(),
+        }
+    }
+
+    pub fn probability(&self, input: usize, output: usize) -> f64 {
+        f64::from(self.counts[input * self.outputs + output]) / self.samples as f64
+    }
+
+    fn probabilities(&self) -> impl Iterator<Item = f64> + '_ {
+        self.counts
+            .iter()
+            .map(|&count| f64::from(count) / self.samples as f64)
+    }
+
+    /// The mean fraction of state bits a flip flips: 1/2 for full diffusion.
+    pub fn avalanche(&self) -> f64 {
+        self.probabilities().sum::<f64>() / self.counts.len() as f64
+    }
+
+    /// The mean of `|2p - 1|` over the entries: 0 under the SAC, 1 before any diffusion.
+    pub fn sac(&self) -> f64 {
+        self.probabilities()
+            .map(|p| (2.0 * p - 1.0).abs())
+            .sum::<f64>()
+            / self.counts.len() as f64
+    }
+
+    /// One row per input bit, one column per state bit.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        for row in self.counts.chunks(self.outputs) {
+            let row: Vec<String> = row
+                .iter()
+                .map(|&count| format!("{:.6}", f64::from(count) / self.samples as f64))
+                .collect();
+            csv += &row.join(",");
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// A binary PPM image of the matrix, input bits down and state bits across, each entry a `scale`-pixel square:
+    /// white at 1/2, shading to blue at 0 and to red at 1.
+    pub fn write_ppm(&self, out: &mut impl Write, scale: usize) -> io::Result<()> {
+        write!(
+            out,
+            "P6\n{} {}\n255\n",
+            self.outputs * scale,
+            self.inputs * scale
+        )?;
+        let mut line = Vec::with_capacity(3 * self.outputs * scale);
+        for row in self.counts.chunks(self.outputs) {
+            line.clear();
+            for &count in row {
+                let p = f64::from(count) / self.samples as f64;
+                let fade = |x: f64| (255.0 * x.clamp(0.0, 1.0)).round() as u8;
+                let pixel = if p < 0.5 {
+                    [fade(2.0 * p), fade(2.0 * p), 255]
+                } else {
+                    [255, fade(2.0 - 2.0 * p), fade(2.0 - 2.0 * p)]
+                };
+                for _ in 0..scale {
+                    line.extend_from_slice(&pixel);
+                }
+            }
+            for _ in 0..scale {
+                out.write_all(&line)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The flip matrices of plaintext and key bits after each half-round, and the BIC of the ciphertext.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Avalanche {
+    pub bits: u32,
+    pub rounds: usize,
+    pub key_len: usize,
+    pub samples: usize,
+    pub plaintext: Vec<FlipMatrix>,
+    pub key: Vec<FlipMatrix>,
+    /// The largest correlation between the flips of two ciphertext bits when a plaintext bit is flipped.
+    pub bic: f64,
+}
+
+impl Avalanche {
+    /// The SAC score of a random function measured from as many samples: `E|2p - 1|` for `p` a proportion of
+    /// fair coin flips.
+    pub fn sac_noise(&self) -> f64 {
+        (2.0 / (std::f64::consts::PI * self.samples as f64)).sqrt()
+    }
+}
+
+/// Avalanche and SAC score of plaintext and key flips per half-round, then the BIC.
+impl fmt::Display for Avalanche {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "RC5-{}/{}/{}, {} samples",
+            self.bits, self.rounds, self.key_len, self.samples
+        )?;
+        writeln!(
+            f,
+            "{:>4} {:>15} {:>10} {:>15} {:>10}",
+            "half", "plaintext flips", "SAC", "key flips", "SAC"
+        )?;
+        for (h, (plaintext, key)) in self.plaintext.iter().zip(&self.key).enumerate() {
+            writeln!(
+                f,
+                "{:>4} {:>15.4} {:>10.4} {:>15.4} {:>10.4}",
+                h,
+                plaintext.avalanche(),
+                plaintext.sac(),
+                key.avalanche(),
+                key.sac()
+            )?;
+        }
+        writeln!(f, "SAC of a random function: {:.4}", self.sac_noise())?;
+        writeln!(f, "BIC (largest correlation): {:.4}", self.bic)
+    }
+}
+
+/// One random key and plaintext, and the states they go through.
+struct Sample<S> {
+    key: Vec<u8>,
+    block: Vec<u8>,
+    s_table: S,
+    states: Vec<Vec<u8>>,
+}
+
+fn trace<V: Variant>(s_table: &V::STable, half_rounds: usize, block: &[u8]) -> Vec<Vec<u8>> {
+    let mut states = Vec::with_capacity(half_rounds + 1);
+    V::encrypt_traced(s_table, half_rounds, block, |_, state| {
+        states.push(state.to_vec())
+    });
+    states
+}
+
+/// Adds the state bits `flipped` flips to `rows`, one per half-round, and returns the flipped bits of the last state.
+fn count_flips(base: &[Vec<u8>], flipped: &[Vec<u8>], rows: &mut [Vec<u32>]) -> Vec<usize> {
+    let mut last = Vec::new();
+    for (row, (base, flipped)) in rows.iter_mut().zip(base.iter().zip(flipped)) {
+        last.clear();
+        for (byte, (x, y)) in base.iter().zip(flipped).enumerate() {
+            let mut difference = x ^ y;
+            while difference != 0 {
+                let bit = 8 * byte + difference.trailing_zeros() as usize;
+                row[bit] += 1;
+                last.push(bit);
+                difference &= difference - 1;
+            }
+        }
+    }
+    last
+}
+
+/// The largest correlation between the flips of two state bits, from the flips of each and of each pair.
+fn largest_correlation(samples: usize, flips: &[u32], pairs: &[u32]) -> f64 {
+    let n = samples as f64;
+    let outputs = flips.len();
+    let mut largest = 0.0f64;
+    for j in 0..outputs {
+        for k in j + 1..outputs {
+            let (p, q) = (f64::from(flips[j]) / n, f64::from(flips[k]) / n);
+            let variance = p * (1.0 - p) * q * (1.0 - q);
+            if variance > 0.0 {
+                let covariance = f64::from(pairs[j * outputs + k]) / n - p * q;
+                largest = largest.max(covariance.abs() / variance.sqrt());
+            }
+        }
+    }
+    largest
+}
+
+/// Measures the avalanche of RC5-w/r with `key_len`-byte keys from `samples` random keys and plaintexts, flipping
+/// every plaintext and key bit of each.
+pub fn measure<V: Variant>(rounds: usize, key_len: usize, samples: usize, seed: u64) -> Avalanche {
+    let half_rounds = 2 * rounds;
+    let outputs = 2 * V::BITS as usize;
+    let mut rng = stats::rng(seed);
+    let samples: Vec<Sample<V::STable>> = (0..samples)
+        .map(|_| {
+            let mut key = vec![0; key_len];
+            let mut block = vec![0; outputs / 8];
+            rng.fill_bytes(&mut key);
+            rng.fill_bytes(&mut block);
+            let s_table = V::s_table(&key, rounds);
+            let states = trace::<V>(&s_table, half_rounds, &block);
+            Sample {
+                key,
+                block,
+                s_table,
+                states,
+            }
+        })
+        .collect();
+
+    // plaintext bits first, then key bits, each flipped in every sample by a thread of its own
+    let inputs = outputs + 8 * key_len;
+    let results = par_chunks(seed, inputs, 1, |_, range| {
+        let input = range.start;
+        let mut rows = vec![vec![0; outputs]; half_rounds + 1];
+        let mut pairs = vec![
+            0;
+            if input < outputs {
+                outputs * outputs
+            } else {
+                0
+            }
+        ];
+        for sample in &samples {
+            let flipped = if input < outputs {
+                let mut block = sample.block.clone();
+                block[input / 8] ^= 1 << (input % 8);
+                trace::<V>(&sample.s_table, half_rounds, &block)
+            } else {
+                let bit = input - outputs;
+                let mut key = sample.key.clone();
+                key[bit / 8] ^= 1 << (bit % 8);
+                trace::<V>(&V::s_table(&key, rounds), half_rounds, &sample.block)
+            };
+            let last = count_flips(&sample.states, &flipped, &mut rows);
+            if !pairs.is_empty() {
+                for (i, &j) in last.iter().enumerate() {
+                    for &k in &last[i + 1..] {
+                        pairs[j * outputs + k] += 1;
+                    }
+                }
+            }
+        }
+        let bic = if pairs.is_empty() {
+            0.0
+        } else {
+            largest_correlation(samples.len(), &rows[half_rounds], &pairs)
+        };
+        (rows, bic)
+    });
+
+    let matrices = |inputs: &[(Vec<Vec<u32>>, f64)]| {
+        (0..=half_rounds)
+            .map(|h| {
+                let rows = inputs.iter().map(|(rows, _)| rows[h].clone()).collect();
+                FlipMatrix::new(rows, outputs, samples.len())
+            })
+            .collect()
+    };
+    Avalanche {
+        bits: V::BITS,
+        rounds,
+        key_len,
+        samples: samples.len(),
+        plaintext: matrices(&results[..outputs]),
+        key: matrices(&results[outputs..]),
+        bic: results.iter().map(|&(_, bic)| bic).fold(0.0, f64::max),
+    }
+}
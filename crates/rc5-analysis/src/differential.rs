@@ -0,0 +1,478 @@
+//! Differential cryptanalysis of reduced-round RC5 with XOR differences, after Knudsen and Meier ("Improved
+//! differential attacks on RC5", CRYPTO '96) and Biryukov and Kushilevitz ("Improved cryptanalysis of RC5",
+//! EUROCRYPT '98).
+//!
+//! Differences are counted in half-rounds, as [`rc5_core::algs::encrypt_half_rounds_slice`] does: half-round 0 is
+//! the addition of `S[0]` and `S[1]`, odd half-rounds update `A` and even ones `B`. Probabilities are estimated over
+//! random plaintext pairs, with a fresh random key for every [`PAIRS_PER_KEY`] pairs.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::stats::{par_chunks, wilson_interval};
+use crate::word::{encrypt, mask, parse_words, random_block, random_s_table, Word};
+
+/// The pairs encrypted under each random key of an estimate.
+pub const PAIRS_PER_KEY: usize = 1024;
+
+/// The XOR difference of two blocks, word by word.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Difference {
+    pub a: u64,
+    pub b: u64,
+}
+
+impl Difference {
+    pub fn new(a: u64, b: u64) -> Self {
+        Self { a, b }
+    }
+
+    pub fn of<W: Word>(x: (W, W), y: (W, W)) -> Self {
+        Self::new(x.0.to_u64() ^ y.0.to_u64(), x.1.to_u64() ^ y.1.to_u64())
+    }
+
+    pub fn weight(&self) -> u32 {
+        self.a.count_ones() + self.b.count_ones()
+    }
+
+    fn apply<W: Word>(&self, block: (W, W)) -> (W, W) {
+        (
+            W::from_u64(block.0.to_u64() ^ self.a),
+            W::from_u64(block.1.to_u64() ^ self.b),
+        )
+    }
+}
+
+/// `A,B` in hex.
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:x},{:x}", self.a, self.b)
+    }
+}
+
+impl FromStr for Difference {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (a, b) = parse_words(s)?;
+        Ok(Self::new(a, b))
+    }
+}
+
+/// An input difference and the difference expected after each half-round, `None` for any difference.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Characteristic {
+    pub input: Difference,
+    pub trail: Vec<Option<Difference>>,
+}
+
+impl Characteristic {
+    /// The number of half-rounds the characteristic covers, not counting half-round 0.
+    pub fn half_rounds(&self) -> usize {
+        self.trail.len() - 1
+    }
+
+    /// Checks the differences fit in `bits`-bit words and the trail starts at half-round 0.
+    pub fn validate(&self, bits: u32) -> Result<(), String> {
+        if self.trail.is_empty() {
+            return Err("the trail needs the difference after half-round 0".into());
+        }
+        let differences = std::iter::once(&self.input).chain(self.trail.iter().flatten());
+        if differences
+            .into_iter()
+            .any(|d| (d.a | d.b) & !mask(bits) != 0)
+        {
+            return Err(format!("a difference does not fit {}-bit words", bits));
+        }
+        Ok(())
+    }
+}
+
+/// How many pairs followed each step of a characteristic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Estimate {
+    pub characteristic: Characteristic,
+    pub pairs: usize,
+    /// The pairs matching the trail up to and including each half-round.
+    pub followed: Vec<usize>,
+}
+
+impl Estimate {
+    /// The probability of the whole characteristic.
+    pub fn probability(&self) -> f64 {
+        *self.followed.last().unwrap() as f64 / self.pairs as f64
+    }
+}
+
+/// Each half-round's expected difference, the pairs still on the trail, the probability of the step given the
+/// previous ones, and the cumulative probability with its 95% interval.
+impl fmt::Display for Estimate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "input difference {}, {} pairs",
+            self.characteristic.input, self.pairs
+        )?;
+        writeln!(
+            f,
+            "{:>4} {:>35} {:>10} {:>8} {:>8} {:>19}",
+            "half", "difference", "pairs", "p(step)", "log2 p", "95% interval"
+        )?;
+        let mut before = self.pairs;
+        for (h, (difference, &followed)) in self
+            .characteristic
+            .trail
+            .iter()
+            .zip(&self.followed)
+            .enumerate()
+        {
+            let difference = difference.map_or_else(|| "*".to_string(), |d| d.to_string());
+            let step = followed as f64 / before as f64;
+            let p = followed as f64 / self.pairs as f64;
+            let (low, high) = wilson_interval(followed, self.pairs);
+            writeln!(
+                f,
+                "{:>4} {:>35} {:>10} {:>8.4} {:>8.2} {:>9.2e}-{:<9.2e}",
+                h,
+                difference,
+                followed,
+                step,
+                p.log2(),
+                low,
+                high
+            )?;
+            before = followed;
+        }
+        Ok(())
+    }
+}
+
+/// The states of `block` after each of the first `half_rounds` half-rounds, half-round 0 included.
+fn trace<W: Word>(s_table: &[W], half_rounds: usize, block: (W, W)) -> Vec<(W, W)> {
+    let mut states = Vec::with_capacity(half_rounds + 1);
+    let (mut a, mut b) = block;
+    W::encrypt_traced(s_table, half_rounds, &mut a, &mut b, |_, a, b| {
+        states.push((*a, *b))
+    });
+    states
+}
+
+/// Estimates the probability of `characteristic` from `pairs` random plaintext pairs.
+pub fn estimate<W: Word>(characteristic: &Characteristic, pairs: usize, seed: u64) -> Estimate {
+    let half_rounds = characteristic.half_rounds();
+    let rounds = half_rounds.div_ceil(2);
+
+    let counts = par_chunks(seed, pairs, PAIRS_PER_KEY, |rng, pairs| {
+        let s_table = random_s_table::<W>(rng, rounds);
+        let mut followed = vec![0; half_rounds + 1];
+        for _ in pairs {
+            let x = random_block::<W>(rng);
+            let y = characteristic.input.apply(x);
+            let states = trace(&s_table, half_rounds, x).into_iter();
+            let differences = states
+                .zip(trace(&s_table, half_rounds, y))
+                .map(|(x, y)| Difference::of(x, y));
+            for (h, (difference, expected)) in differences.zip(&characteristic.trail).enumerate() {
+                if expected.is_some_and(|expected| expected != difference) {
+                    break;
+                }
+                followed[h] += 1;
+            }
+        }
+        followed
+    });
+
+    let mut followed = vec![0; half_rounds + 1];
+    for counts in counts {
+        for (total, count) in followed.iter_mut().zip(counts) {
+            *total += count;
+        }
+    }
+    Estimate {
+        characteristic: characteristic.clone(),
+        pairs,
+        followed,
+    }
+}
+
+/// An input difference and the output difference it most often leads to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Candidate {
+    pub input: Difference,
+    pub output: Difference,
+    /// The pairs, out of those tried, with that output difference.
+    pub count: usize,
+}
+
+/// The largest word size [`search`] takes: it enumerates the `2w`-bit input differences in a `u64`.
+pub const MAX_SEARCH_BITS: u32 = 32;
+
+/// Checks [`search`] can enumerate the differences of weight 1 to `max_weight` of two `bits`-bit words.
+pub fn validate_search(bits: u32, max_weight: u32) -> Result<(), String> {
+    if bits > MAX_SEARCH_BITS {
+        return Err(format!(
+            "the search takes words of at most {} bits",
+            MAX_SEARCH_BITS
+        ));
+    }
+    if !(1..=2 * bits).contains(&max_weight) {
+        return Err(format!(
+            "the weight of a difference of two {}-bit words is 1 to {}",
+            bits,
+            2 * bits
+        ));
+    }
+    Ok(())
+}
+
+/// The input differences of weight 1 to `max_weight` with the output difference each most often leads to after
+/// `half_rounds` half-rounds, from `pairs` random pairs each, best first.
+///
+/// There are `C(2w, max_weight)` differences of the highest weight; the search is meant for small `w`, and panics
+/// unless [`validate_search`] accepts `w` and `max_weight`.
+pub fn search<W: Word>(
+    half_rounds: usize,
+    max_weight: u32,
+    pairs: usize,
+    seed: u64,
+) -> Vec<Candidate> {
+    validate_search(W::BITS, max_weight).unwrap_or_else(|error| panic!("{}", error));
+    let bits = 2 * W::BITS;
+    let inputs: Vec<Difference> = (1..=max_weight)
+        .flat_map(|weight| combinations(bits, weight))
+        .map(|bits| Difference::new(bits & mask(W::BITS), bits >> W::BITS))
+        .collect();
+    let rounds = half_rounds.div_ceil(2);
+
+    let mut candidates = par_chunks(seed, inputs.len(), 1, |rng, range| {
+        let input = inputs[range.start];
+        let mut outputs: HashMap<Difference, usize> = HashMap::new();
+        let mut s_table = Vec::new();
+        for i in 0..pairs {
+            if i % PAIRS_PER_KEY == 0 {
+                s_table = random_s_table::<W>(rng, rounds);
+            }
+            let x = random_block::<W>(rng);
+            let y = input.apply(x);
+            let (x, y) = (
+                encrypt(&s_table, half_rounds, x),
+                encrypt(&s_table, half_rounds, y),
+            );
+            *outputs.entry(Difference::of(x, y)).or_default() += 1;
+        }
+        let (output, count) = outputs
+            .into_iter()
+            .max_by_key(|&(output, count)| (count, std::cmp::Reverse(output)))
+            .unwrap_or_default();
+        Candidate {
+            input,
+            output,
+            count,
+        }
+    });
+    candidates.sort_by_key(|c| (std::cmp::Reverse(c.count), c.input));
+    candidates
+}
+
+/// The `u64`s with `weight` of their low `bits` bits set, in increasing order; `weight` is 1 to `bits`, at most 64.
+fn combinations(bits: u32, weight: u32) -> impl Iterator<Item = u64> {
+    let first = mask(weight);
+    let last = first << (bits - weight);
+    std::iter::successors(Some(first), move |&x: &u64| {
+        // Gosper's hack: the next larger number with as many bits set
+        (x < last).then(|| {
+            let c = x & x.wrapping_neg();
+            let r = x + c;
+            (((r ^ x) >> 2) / c) | r
+        })
+    })
+}
+
+/// The chosen-plaintext difference of [`attack`] on RC5-w/r: the top bit of both words for two rounds or more, of
+/// `A` alone for one.
+///
+/// The top bit is free of carries, so `(e, e)` passes half-round 0 and loses its `A` difference in half-round 1.
+/// Right pairs, with a single-bit `A` difference and at most a single-bit `B` difference before the last
+/// half-round, then make up about `2^-2.4`, `2^-7.7`, `2^-13.3`, `2^-15.3` and `2^-22` of the pairs of RC5-32 with
+/// 2 to 6 rounds.
+pub fn attack_difference(bits: u32, rounds: usize) -> Difference {
+    let top = 1 << (bits - 1);
+    match rounds {
+        1 => Difference::new(top, 0),
+        _ => Difference::new(top, top),
+    }
+}
+
+/// The pairs [`attack`] needs on RC5-32/r to rank the subkey first for most keys: at least 150 right ones.
+/// Five rounds get 2^25 rather than 2^23, with which one key in six or seven fails.
+pub fn attack_pairs(rounds: usize) -> usize {
+    1 << match rounds {
+        0..=2 => 12,
+        3 => 17,
+        4 => 21,
+        5 => 25,
+        _ => 30,
+    }
+}
+
+/// Two ciphertexts `(A, B)` whose plaintexts differ by the attack's difference.
+pub type CiphertextPair = [(u64, u64); 2];
+
+/// The position of the `A` difference of a pair that may be right: a single bit, at least `log2 w`, so that both
+/// ciphertexts rotate `B` alike in the last half-round.
+fn position(bits: u32, pair: &CiphertextPair) -> Option<u32> {
+    let difference = pair[0].0 ^ pair[1].0;
+    let position = difference.trailing_zeros();
+    (difference.count_ones() == 1 && position >= bits.trailing_zeros()).then_some(position)
+}
+
+/// How far the low `k` bits of `subkey` make the pair right: undoing its addition must leave the `A` difference at
+/// `position` rotated in by the last half-round, and the `B` difference from before it. That is 0 for right pairs
+/// of the characteristic, scoring 2, and a single bit for many more, scoring 1.
+fn score(bits: u32, pair: &CiphertextPair, position: u32, subkey: u64, k: u32) -> usize {
+    let rotated = (position + (pair[0].0 % u64::from(bits)) as u32) % bits;
+    let difference = pair[0].1.wrapping_sub(subkey) ^ pair[1].1.wrapping_sub(subkey);
+    match ((difference ^ 1 << rotated) & mask(k)).count_ones() {
+        0 => 2,
+        1 => 1,
+        _ => 0,
+    }
+}
+
+/// Ranks the values of the last subkey, `S[2r + 1]`, by their total score over `pairs`, keeping the `beam` best
+/// after each bit from the lowest up.
+///
+/// Bit `k` of the subkey shows in the differences only through its carry into bit `k + 1`, so the top bit never
+/// does: the values are of the low `w - 1` bits.
+pub fn recover_last_subkey(bits: u32, pairs: &[CiphertextPair], beam: usize) -> Vec<(u64, usize)> {
+    let filtered: Vec<(&CiphertextPair, u32)> = pairs
+        .iter()
+        .filter_map(|pair| Some((pair, position(bits, pair)?)))
+        .collect();
+
+    let mut candidates = vec![(0, filtered.len())];
+    for k in 1..bits {
+        let mut next: Vec<(u64, usize)> = candidates
+            .iter()
+            .flat_map(|&(subkey, _)| [subkey, subkey | 1 << (k - 1)])
+            .map(|subkey| {
+                let score = filtered
+                    .iter()
+                    .map(|&(pair, position)| score(bits, pair, position, subkey, k + 1))
+                    .sum();
+                (subkey, score)
+            })
+            .collect();
+        next.sort_unstable_by_key(|&(subkey, right)| (std::cmp::Reverse(right), subkey));
+        next.truncate(beam);
+        candidates = next;
+    }
+    candidates
+}
+
+/// A run of the attack on one key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attack {
+    pub bits: u32,
+    pub rounds: usize,
+    pub input: Difference,
+    pub pairs: usize,
+    /// The pairs that passed the filter on the ciphertexts.
+    pub filtered: usize,
+    /// The filtered pairs the actual subkey scores on.
+    pub right: usize,
+    /// The low `w - 1` bits of the actual last subkey.
+    pub subkey: u64,
+    pub candidates: Vec<(u64, usize)>,
+}
+
+impl Attack {
+    /// The position of the actual subkey among the candidates.
+    pub fn rank(&self) -> Option<usize> {
+        self.candidates.iter().position(|&(s, _)| s == self.subkey)
+    }
+
+    /// Whether the actual subkey came out first, ahead of every other candidate.
+    pub fn succeeded(&self) -> bool {
+        self.rank() == Some(0)
+            && self
+                .candidates
+                .get(1)
+                .is_none_or(|c| c.1 < self.candidates[0].1)
+    }
+}
+
+impl fmt::Display for Attack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hex = (self.bits as usize - 1).div_ceil(4);
+        writeln!(
+            f,
+            "RC5-{}/{}, last subkey S[{}]",
+            self.bits,
+            self.rounds,
+            2 * self.rounds + 1
+        )?;
+        writeln!(f, "input difference: {}", self.input)?;
+        writeln!(f, "pairs: {}", self.pairs)?;
+        writeln!(f, "filtered pairs: {}", self.filtered)?;
+        writeln!(f, "right pairs: {}", self.right)?;
+        writeln!(f, "candidates (low {} bits, score):", self.bits - 1)?;
+        for &(subkey, right) in self.candidates.iter().take(5) {
+            writeln!(f, "  {:0width$x} {}", subkey, right, width = hex)?;
+        }
+        writeln!(
+            f,
+            "actual: {:0width$x}, rank {}",
+            self.subkey,
+            self.rank().map_or("-".into(), |r| (r + 1).to_string()),
+            width = hex
+        )?;
+        writeln!(
+            f,
+            "recovered: {}",
+            if self.succeeded() { "yes" } else { "no" }
+        )
+    }
+}
+
+/// Attacks RC5-w/r with `key` from `pairs` chosen-plaintext pairs with the [`attack_difference`].
+pub fn attack<W: Word>(key: &[u8], rounds: usize, pairs: usize, seed: u64) -> Attack {
+    const CHUNK: usize = 1 << 16;
+    const BEAM: usize = 256;
+
+    let bits = W::BITS;
+    let input = attack_difference(bits, rounds);
+    let s_table = W::s_table(key, rounds);
+    let ciphertexts: Vec<CiphertextPair> = par_chunks(seed, pairs, CHUNK, |rng, range| {
+        range
+            .filter_map(|_| {
+                let x = random_block::<W>(rng);
+                let (x, y) = (
+                    encrypt(&s_table, 2 * rounds, x),
+                    encrypt(&s_table, 2 * rounds, input.apply(x)),
+                );
+                let pair = [(x.0.to_u64(), x.1.to_u64()), (y.0.to_u64(), y.1.to_u64())];
+                // the attacker's filter, on the ciphertexts alone
+                position(bits, &pair).map(|_| pair)
+            })
+            .collect::<Vec<_>>()
+    })
+    .concat();
+
+    let subkey = s_table[2 * rounds + 1].to_u64() & mask(bits - 1);
+    let right = ciphertexts
+        .iter()
+        .filter(|pair| score(bits, pair, position(bits, pair).unwrap(), subkey, bits) > 0)
+        .count();
+    Attack {
+        bits,
+        rounds,
+        input,
+        pairs,
+        filtered: ciphertexts.len(),
+        right,
+        subkey,
+        candidates: recover_last_subkey(bits, &ciphertexts, BEAM),
+    }
+}
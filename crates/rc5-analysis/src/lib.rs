@@ -0,0 +1,9 @@
+//! Cryptanalytic experiments on RC5 with small word sizes or few rounds, for teaching material and as strong
+//! checks on the implementation.
+
+pub mod avalanche;
+pub mod differential;
+pub mod linear;
+pub mod permutation;
+pub mod stats;
+pub mod word;
@@ -0,0 +1,319 @@
+//! Linear cryptanalysis of reduced-round RC5, after Kaliski and Yin ("On differential and linear cryptanalysis of
+//! the RC5 encryption algorithm", CRYPTO '95), and χ² tests on its rotation amounts, after Knudsen and Meier
+//! ("Correlations in RC6 with a reduced number of rounds", FSE 2000).
+//!
+//! A linear approximation holds for a plaintext when the parity of the plaintext under its input mask equals that
+//! of the state after its half-rounds, counted as in [`crate::differential`], under its output mask. Its
+//! correlation under a key is `2p - 1`, `p` the probability that it holds. Correlations in RC5 depend on the key, in
+//! sign above all, so they are estimated key by key and averaged over keys as squares, the linear potential.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use rand::RngCore;
+
+use crate::stats::{par_chunks, wilson_interval, ChiSquare, Mean};
+use crate::word::{encrypt, mask, parse_words, random_block, random_s_table, Word};
+
+/// A mask of each word of a block.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Mask {
+    pub a: u64,
+    pub b: u64,
+}
+
+impl Mask {
+    pub fn new(a: u64, b: u64) -> Self {
+        Self { a, b }
+    }
+
+    /// The parity of the bits of `block` under the mask.
+    pub fn parity<W: Word>(&self, block: (W, W)) -> bool {
+        ((block.0.to_u64() & self.a) ^ (block.1.to_u64() & self.b)).count_ones() % 2 == 1
+    }
+}
+
+/// `A,B` in hex.
+impl fmt::Display for Mask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:x},{:x}", self.a, self.b)
+    }
+}
+
+impl FromStr for Mask {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (a, b) = parse_words(s)?;
+        Ok(Self::new(a, b))
+    }
+}
+
+/// An approximation of the parity of the state after `half_rounds` half-rounds by that of the plaintext.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Approximation {
+    pub input: Mask,
+    pub output: Mask,
+    pub half_rounds: usize,
+}
+
+impl Approximation {
+    /// Kaliski and Yin's approximation of `r` rounds: the low bit of `B` is kept, up to a key bit, by each round
+    /// whose `A` is a multiple of `w` and so rotates `B` by 0.
+    ///
+    /// Such an `A` has a low bit of 0, so the low bit of the new `B` is that of the old one plus the subkey's. Each
+    /// round then holds with probability `1/w + (1 - 1/w)/2`, a correlation of `1/w`, and the piling-up lemma
+    /// predicts `w^-r` for the whole approximation. From two rounds on other trails between the same masks add to
+    /// it: RC5-8/2 and RC5-16/2 show about 3 and 2 times that.
+    pub fn kaliski_yin(rounds: usize) -> Self {
+        Self {
+            input: Mask::new(0, 1),
+            output: Mask::new(0, 1),
+            half_rounds: 2 * rounds,
+        }
+    }
+
+    /// Checks the masks fit in `bits`-bit words.
+    pub fn validate(&self, bits: u32) -> Result<(), String> {
+        if [self.input, self.output]
+            .iter()
+            .any(|m| (m.a | m.b) & !mask(bits) != 0)
+        {
+            return Err(format!("a mask does not fit {}-bit words", bits));
+        }
+        Ok(())
+    }
+}
+
+/// How often an approximation held under one key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Correlation {
+    pub holds: usize,
+    pub texts: usize,
+}
+
+impl Correlation {
+    pub fn value(&self) -> f64 {
+        2.0 * self.holds as f64 / self.texts as f64 - 1.0
+    }
+
+    /// The 95% interval of the correlation.
+    pub fn interval(&self) -> (f64, f64) {
+        let (low, high) = wilson_interval(self.holds, self.texts);
+        (2.0 * low - 1.0, 2.0 * high - 1.0)
+    }
+}
+
+/// The correlations of an approximation under random keys.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Survey {
+    pub bits: u32,
+    pub approximation: Approximation,
+    pub correlations: Vec<Correlation>,
+}
+
+impl Survey {
+    /// The mean squared correlation, less the `1/n` that noise adds to the square of a correlation estimated from
+    /// `n` texts, and its 95% interval over the keys.
+    pub fn potential(&self) -> (f64, f64, f64) {
+        let mut potential = Mean::default();
+        for c in &self.correlations {
+            potential.add(c.value().powi(2) - 1.0 / c.texts as f64);
+        }
+        let half_width = 1.96 * potential.std_dev() / (potential.count() as f64).sqrt();
+        let mean = potential.mean();
+        (mean, mean - half_width, mean + half_width)
+    }
+}
+
+/// The correlation of each key with its 95% interval, then the linear potential over all of them.
+impl fmt::Display for Survey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let approximation = &self.approximation;
+        writeln!(
+            f,
+            "RC5-{}, {} half-rounds, input mask {}, output mask {}",
+            self.bits, approximation.half_rounds, approximation.input, approximation.output
+        )?;
+        writeln!(
+            f,
+            "{:>4} {:>10} {:>10} {:>21}",
+            "key", "texts", "c", "95% interval"
+        )?;
+        for (i, c) in self.correlations.iter().enumerate() {
+            let (low, high) = c.interval();
+            writeln!(
+                f,
+                "{:>4} {:>10} {:>10.6} {:>10.6} {:>10.6}",
+                i,
+                c.texts,
+                c.value(),
+                low,
+                high
+            )?;
+        }
+        let (potential, low, high) = self.potential();
+        write!(
+            f,
+            "linear potential: {:.3e} (95% interval {:.3e} to {:.3e})",
+            potential, low, high
+        )?;
+        if potential > 0.0 {
+            write!(f, ", |c| about 2^{:.2}", potential.log2() / 2.0)?;
+        }
+        writeln!(f)
+    }
+}
+
+/// Estimates the correlation of `approximation` under `keys` random keys from `texts` random plaintexts each.
+pub fn survey<W: Word>(
+    approximation: &Approximation,
+    keys: usize,
+    texts: usize,
+    seed: u64,
+) -> Survey {
+    let half_rounds = approximation.half_rounds;
+    let correlations = par_chunks(seed, keys, 1, |rng, _| {
+        let s_table = random_s_table::<W>(rng, half_rounds.div_ceil(2));
+        let holds = (0..texts)
+            .filter(|_| {
+                let x = random_block::<W>(rng);
+                let y = encrypt(&s_table, half_rounds, x);
+                approximation.input.parity(x) == approximation.output.parity(y)
+            })
+            .count();
+        Correlation { holds, texts }
+    });
+    Survey {
+        bits: W::BITS,
+        approximation: *approximation,
+        correlations,
+    }
+}
+
+/// The z-score of the χ² statistic above which a test rejects uniformity, at a significance of 0.001.
+pub const DISTINGUISHED: f64 = 3.09;
+
+/// χ² tests on the rotation amounts RC5 sets up after some half-rounds: `A mod w` and `B mod w`, in `w^2` cells.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RotationTest {
+    pub bits: u32,
+    pub half_rounds: usize,
+    /// Whether the plaintexts' own rotation amounts were 0 rather than random.
+    pub fixed: bool,
+    pub texts: usize,
+    /// One test per random key.
+    pub tests: Vec<ChiSquare>,
+}
+
+impl RotationTest {
+    /// The keys whose test tells the cipher from a random function.
+    pub fn distinguished(&self) -> usize {
+        self.tests
+            .iter()
+            .filter(|test| test.z_score() > DISTINGUISHED)
+            .count()
+    }
+}
+
+/// The χ² statistic and its z-score for each key, then how many keys the test distinguished.
+impl fmt::Display for RotationTest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "RC5-{}, {} half-rounds, {} plaintexts with {} rotation amounts",
+            self.bits,
+            self.half_rounds,
+            self.texts,
+            if self.fixed { "zero" } else { "random" }
+        )?;
+        writeln!(f, "{:>4} {:>12} {:>6} {:>8}", "key", "χ²", "dof", "z")?;
+        for (i, test) in self.tests.iter().enumerate() {
+            writeln!(
+                f,
+                "{:>4} {:>12.1} {:>6} {:>8.2}",
+                i,
+                test.statistic,
+                test.degrees_of_freedom,
+                test.z_score()
+            )?;
+        }
+        writeln!(
+            f,
+            "distinguished (z > {}): {} of {} keys",
+            DISTINGUISHED,
+            self.distinguished(),
+            self.tests.len()
+        )
+    }
+}
+
+/// Tests the rotation amounts after `half_rounds` half-rounds for uniformity under `keys` random keys with `texts`
+/// plaintexts each; with `fixed`, the plaintexts have `A` and `B` multiples of `w`.
+///
+/// Up to 2^64 plaintexts to draw from, they are distinct: RC5-8 has only 2^10 with zero rotation amounts, and
+/// drawing them with replacement would count the images of some twice rather than sample the cipher. RC5-64 has
+/// 2^116 or 2^128, drawn independently: any repeat among them is too unlikely to matter.
+pub fn rotation_test<W: Word>(
+    half_rounds: usize,
+    fixed: bool,
+    keys: usize,
+    texts: usize,
+    seed: u64,
+) -> RotationTest {
+    let log_w = W::BITS.trailing_zeros();
+    let low = mask(log_w);
+    let shift = if fixed { log_w } else { 0 };
+    let free_bits = W::BITS - shift;
+    let log_plaintexts = 2 * free_bits;
+    assert!(
+        log_plaintexts >= 64 || texts as u64 <= 1 << log_plaintexts,
+        "more texts than plaintexts"
+    );
+
+    let tests = par_chunks(seed, keys, 1, |rng, _| {
+        let s_table = random_s_table::<W>(rng, half_rounds.div_ceil(2));
+        let mut counts = vec![0; 1 << (2 * log_w)];
+        let mut count = |x: (W, W)| {
+            let (a, b) = encrypt(&s_table, half_rounds, x);
+            counts[((a.to_u64() & low) | (b.to_u64() & low) << log_w) as usize] += 1;
+        };
+        if log_plaintexts <= 64 {
+            for j in distinct(rng, 1 << log_plaintexts, texts) {
+                count((
+                    W::from_u64((j & mask(free_bits)) << shift),
+                    W::from_u64((j >> free_bits & mask(free_bits)) << shift),
+                ));
+            }
+        } else {
+            for _ in 0..texts {
+                count((
+                    W::from_u64(rng.next_u64() << shift),
+                    W::from_u64(rng.next_u64() << shift),
+                ));
+            }
+        }
+        ChiSquare::uniform(&counts)
+    });
+    RotationTest {
+        bits: W::BITS,
+        half_rounds,
+        fixed,
+        texts,
+        tests,
+    }
+}
+
+/// `count` distinct numbers below `n`, at most 2^64, uniformly: the first `count` steps of a Fisher–Yates shuffle of
+/// `0..n` that keeps only the entries it moved.
+fn distinct(rng: &mut impl RngCore, n: u128, count: usize) -> impl Iterator<Item = u64> + '_ {
+    let mut moved: HashMap<u64, u64> = HashMap::with_capacity(count);
+    (0..count as u64).map(move |i| {
+        let j = i + (u128::from(rng.next_u64()) % (n - u128::from(i))) as u64;
+        let at_j = *moved.get(&j).unwrap_or(&j);
+        let at_i = *moved.get(&i).unwrap_or(&i);
+        moved.insert(j, at_i);
+        at_j
+    })
+}
@@ -0,0 +1,269 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::ExitCode;
+
+use clap::Parser;
+
+mod args;
+
+use args::{
+    AnyWordSize, AttackArgs, AvalancheArgs, Cli, Command, CorrelationArgs, DifferentialCommand,
+    EstimateArgs, LinearCommand, PermutationArgs, RotationArgs, SearchArgs, WordSize,
+};
+use rc5_analysis::avalanche::{self, Avalanche, Std, Strange};
+use rc5_analysis::differential::{self, Characteristic, Difference};
+use rc5_analysis::linear::{self, Approximation};
+use rc5_analysis::permutation::{self, Aggregate, Permutation};
+use rc5_analysis::stats;
+use rc5_core::strange_words::{W24bit, W80bit};
+
+/// Runs `$body` with `$word` the native word type of `$word_size`.
+macro_rules! with_word {
+    ($word_size:expr, $word:ident => $body:expr) => {
+        match $word_size {
+            WordSize::W8 => {
+                type $word = u8;
+                $body
+            }
+            WordSize::W16 => {
+                type $word = u16;
+                $body
+            }
+            WordSize::W32 => {
+                type $word = u32;
+                $body
+            }
+            WordSize::W64 => {
+                type $word = u64;
+                $body
+            }
+        }
+    };
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(&cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("rc5-analysis: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: &Cli) -> Result<(), String> {
+    match &cli.command {
+        Command::Permutation(args) => permutation(args),
+        Command::Differential(DifferentialCommand::Estimate(args)) => estimate(args),
+        Command::Differential(DifferentialCommand::Search(args)) => search(args),
+        Command::Differential(DifferentialCommand::Attack(args)) => attack(args),
+        Command::Linear(LinearCommand::Correlation(args)) => correlation(args),
+        Command::Linear(LinearCommand::Rotation(args)) => rotation(args),
+        Command::Avalanche(args) => avalanche(args),
+    }
+}
+
+fn rc5_8(key: &[u8], rounds: u8) -> Result<Permutation, String> {
+    Permutation::new(permutation::rc5_8(key, rounds)).ok_or_else(|| {
+        format!(
+            "RC5-8/{}/{} with key {} is not a bijection",
+            rounds,
+            key.len(),
+            hex::encode(key)
+        )
+    })
+}
+
+fn permutation(args: &PermutationArgs) -> Result<(), String> {
+    if let Some(key) = &args.key {
+        let key = hex::decode(key).map_err(|e| format!("key: {}", e))?;
+        let key = key.as_slice();
+        let permutation = rc5_8(key, args.rounds)?;
+        let profile = permutation.profile();
+        let lengths: Vec<String> = permutation
+            .cycle_lengths()
+            .iter()
+            .map(usize::to_string)
+            .collect();
+
+        println!(
+            "RC5-8/{}/{}, key {}",
+            args.rounds,
+            key.len(),
+            hex::encode(key)
+        );
+        println!("bijective: yes");
+        println!("cycles: {}", profile.cycles);
+        println!("cycle lengths: {}", lengths.join(" "));
+        println!("fixed points: {}", profile.fixed_points);
+        println!("parity: {}", profile.parity);
+        return Ok(());
+    }
+
+    let mut aggregate = Aggregate::default();
+    for key in stats::keys(&mut stats::rng(args.seed), args.keys, args.key_bytes) {
+        aggregate.add(&rc5_8(&key, args.rounds)?);
+    }
+    println!(
+        "RC5-8/{}/{}, {} random keys (seed {}), all bijective",
+        args.rounds, args.key_bytes, args.keys, args.seed
+    );
+    print!("{}", aggregate);
+    Ok(())
+}
+
+fn estimate(args: &EstimateArgs) -> Result<(), String> {
+    let trail = args
+        .trail
+        .iter()
+        .map(|d| match d.as_str() {
+            "*" => Ok(None),
+            d => d.parse::<Difference>().map(Some),
+        })
+        .collect::<Result<_, _>>()?;
+    let characteristic = Characteristic {
+        input: args.input,
+        trail,
+    };
+
+    with_word!(args.word_size, W => {
+        characteristic.validate(W::BITS)?;
+        println!(
+            "RC5-{}, {} half-rounds, seed {}",
+            W::BITS,
+            characteristic.half_rounds(),
+            args.seed
+        );
+        print!("{}", differential::estimate::<W>(&characteristic, args.pairs, args.seed));
+    });
+    Ok(())
+}
+
+fn search(args: &SearchArgs) -> Result<(), String> {
+    with_word!(args.word_size, W => {
+        differential::validate_search(W::BITS, args.max_weight)?;
+        println!(
+            "RC5-{}, {} half-rounds, {} pairs per input difference, seed {}",
+            W::BITS,
+            args.half_rounds,
+            args.pairs,
+            args.seed
+        );
+        let candidates =
+            differential::search::<W>(args.half_rounds, args.max_weight, args.pairs, args.seed);
+        println!("{:>35} {:>35} {:>8} {:>8}", "input", "output", "count", "log2 p");
+        for candidate in candidates.iter().take(args.top) {
+            println!(
+                "{:>35} {:>35} {:>8} {:>8.2}",
+                candidate.input.to_string(),
+                candidate.output.to_string(),
+                candidate.count,
+                (candidate.count as f64 / args.pairs as f64).log2()
+            );
+        }
+    });
+    Ok(())
+}
+
+fn attack(args: &AttackArgs) -> Result<(), String> {
+    let key = match &args.key {
+        Some(key) => hex::decode(key).map_err(|e| format!("key: {}", e))?,
+        None => stats::keys(&mut stats::rng(args.seed), 1, 16).remove(0),
+    };
+    if args.rounds == 0 {
+        return Err("the attack needs at least one round".into());
+    }
+    let pairs = args
+        .pairs
+        .unwrap_or_else(|| differential::attack_pairs(args.rounds));
+
+    println!("key: {}", hex::encode(&key));
+    with_word!(args.word_size, W => {
+        print!("{}", differential::attack::<W>(&key, args.rounds, pairs, args.seed));
+    });
+    Ok(())
+}
+
+fn correlation(args: &CorrelationArgs) -> Result<(), String> {
+    let approximation = Approximation {
+        input: args.input,
+        output: args.output,
+        half_rounds: args.half_rounds,
+    };
+    let rounds = args.half_rounds / 2;
+
+    with_word!(args.word_size, W => {
+        approximation.validate(W::BITS)?;
+        println!("seed {}", args.seed);
+        print!("{}", linear::survey::<W>(&approximation, args.keys, args.texts, args.seed));
+        if approximation == Approximation::kaliski_yin(rounds) {
+            println!(
+                "Kaliski-Yin prediction: |c| = {}^-{} = 2^{:.2}",
+                W::BITS,
+                rounds,
+                -(rounds as f64) * f64::from(W::BITS).log2()
+            );
+        }
+    });
+    Ok(())
+}
+
+fn rotation(args: &RotationArgs) -> Result<(), String> {
+    with_word!(args.word_size, W => {
+        let free_bits = 2 * (W::BITS - if args.random { 0 } else { W::BITS.trailing_zeros() });
+        if free_bits < 64 && args.texts as u64 > 1 << free_bits {
+            return Err(format!("there are only 2^{} such plaintexts", free_bits));
+        }
+        println!("seed {}", args.seed);
+        print!(
+            "{}",
+            linear::rotation_test::<W>(args.half_rounds, !args.random, args.keys, args.texts, args.seed)
+        );
+    });
+    Ok(())
+}
+
+fn avalanche(args: &AvalancheArgs) -> Result<(), String> {
+    if args.key_bytes > 255 {
+        return Err("keys are at most 255 bytes".into());
+    }
+    let measure = match args.word_size {
+        AnyWordSize::W8 => avalanche::measure::<Std<u8>>,
+        AnyWordSize::W16 => avalanche::measure::<Std<u16>>,
+        AnyWordSize::W24 => avalanche::measure::<Strange<W24bit>>,
+        AnyWordSize::W32 => avalanche::measure::<Std<u32>>,
+        AnyWordSize::W64 => avalanche::measure::<Std<u64>>,
+        AnyWordSize::W80 => avalanche::measure::<Strange<W80bit>>,
+        AnyWordSize::W128 => avalanche::measure::<Std<u128>>,
+    };
+    let avalanche = measure(args.rounds, args.key_bytes, args.samples, args.seed);
+    println!("seed {}", args.seed);
+    print!("{}", avalanche);
+    if let Some(dir) = &args.output {
+        write_matrices(&avalanche, dir, args.scale)
+            .map_err(|e| format!("{}: {}", dir.display(), e))?;
+        println!(
+            "wrote {} matrices to {}",
+            avalanche.plaintext.len() + avalanche.key.len(),
+            dir.display()
+        );
+    }
+    Ok(())
+}
+
+fn write_matrices(avalanche: &Avalanche, dir: &Path, scale: usize) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    for (name, matrices) in [("plaintext", &avalanche.plaintext), ("key", &avalanche.key)] {
+        for (h, matrix) in matrices.iter().enumerate() {
+            let path = dir.join(format!("{}-h{:02}", name, h));
+            fs::write(path.with_extension("csv"), matrix.to_csv())?;
+            let mut ppm = io::BufWriter::new(fs::File::create(path.with_extension("ppm"))?);
+            matrix.write_ppm(&mut ppm, scale)?;
+            ppm.flush()?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,239 @@
+//! The permutation RC5-8/r/b makes of its 2^16 blocks, enumerated in full: its cycle structure, fixed points and
+//! parity, and their statistics over many keys against those of a uniformly random permutation.
+//!
+//! A block is numbered by its two bytes read little-endian, `A` being the low byte.
+//!
+//! Unlike a random permutation, RC5-8's is always even, as each of its steps is. Each changes one word as a function
+//! of the other, so it permutes the blocks as one permutation of the changed word for each value of the other:
+//!
+//! - adding a key word is the same permutation for every value, so it comes `2^w` times, an even number;
+//! - XOR with the other word is the identity or `2^(w-1)` transpositions, even for `w > 1`;
+//! - rotating by the other word is a power of the rotation by one bit, whose cycles on 8-bit words are the 36
+//!   binary necklaces of length 8: it is `256 - 36` transpositions, even. This does not hold for every `w`.
+
+use std::fmt;
+
+use rc5_core::algs::{encrypt_slice, expand_key_slices};
+use rc5_core::std_words::{LittleEndian, StdArith, StdMagic};
+
+use crate::stats::{proportion_z_score, Mean};
+
+/// The number of RC5-8 blocks.
+pub const RC5_8_BLOCKS: usize = 1 << 16;
+
+/// The Golomb–Dickman constant: the expected longest cycle of a random permutation of `n` is about `λn`.
+const GOLOMB_DICKMAN: f64 = 0.624_329_988_543_550_9;
+
+/// The image of every block under RC5-8/r with `key`, indexed by block.
+pub fn rc5_8(key: &[u8], rounds: u8) -> Vec<u32> {
+    let mut l_table = vec![0u8; key.len().max(1)];
+    let mut s_table = vec![0u8; 2 * (rounds as usize + 1)];
+    expand_key_slices::<u8, StdMagic, LittleEndian, StdArith>(key, &mut l_table, &mut s_table);
+
+    (0..RC5_8_BLOCKS as u32)
+        .map(|block| {
+            let [mut a, mut b, ..] = block.to_le_bytes();
+            encrypt_slice::<u8, StdArith>(&s_table, &mut a, &mut b);
+            u32::from(u16::from_le_bytes([a, b]))
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parity {
+    Even,
+    Odd,
+}
+
+impl fmt::Display for Parity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Even => "even",
+            Self::Odd => "odd",
+        })
+    }
+}
+
+/// A permutation of `0..n`, as the image of each element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Permutation {
+    images: Vec<u32>,
+}
+
+impl Permutation {
+    /// `None` unless `images` holds every number below its length exactly once.
+    pub fn new(images: Vec<u32>) -> Option<Self> {
+        let mut seen = vec![false; images.len()];
+        for &image in &images {
+            let seen = seen.get_mut(image as usize)?;
+            if std::mem::replace(seen, true) {
+                return None;
+            }
+        }
+        Some(Self { images })
+    }
+
+    pub fn len(&self) -> usize {
+        self.images.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.images.is_empty()
+    }
+
+    pub fn image(&self, x: u32) -> u32 {
+        self.images[x as usize]
+    }
+
+    /// The lengths of the cycles, longest first; fixed points are cycles of length 1.
+    pub fn cycle_lengths(&self) -> Vec<usize> {
+        let mut visited = vec![false; self.len()];
+        let mut lengths = Vec::new();
+        for start in 0..self.len() {
+            let mut length = 0;
+            let mut x = start;
+            while !visited[x] {
+                visited[x] = true;
+                x = self.images[x] as usize;
+                length += 1;
+            }
+            if length > 0 {
+                lengths.push(length);
+            }
+        }
+        lengths.sort_unstable_by(|a, b| b.cmp(a));
+        lengths
+    }
+
+    pub fn fixed_points(&self) -> usize {
+        self.images
+            .iter()
+            .enumerate()
+            .filter(|&(x, &image)| x == image as usize)
+            .count()
+    }
+
+    pub fn profile(&self) -> Profile {
+        let cycles = self.cycle_lengths();
+        Profile {
+            cycles: cycles.len(),
+            longest: cycles.first().copied().unwrap_or(0),
+            fixed_points: self.fixed_points(),
+            // a cycle of length k is k - 1 transpositions
+            parity: match (self.len() - cycles.len()) % 2 {
+                0 => Parity::Even,
+                _ => Parity::Odd,
+            },
+        }
+    }
+}
+
+/// The cycle statistics of one permutation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Profile {
+    pub cycles: usize,
+    pub longest: usize,
+    pub fixed_points: usize,
+    pub parity: Parity,
+}
+
+/// What a uniformly random permutation of `n` elements is expected to show.
+#[derive(Debug, Clone, Copy)]
+pub struct Expectation {
+    /// The harmonic number `H_n`.
+    pub cycles: f64,
+    pub longest: f64,
+    pub fixed_points: f64,
+    pub even: f64,
+    /// `1/e`, the limit of the probability of no fixed points.
+    pub fixed_point_free: f64,
+}
+
+impl Expectation {
+    pub fn random(n: usize) -> Self {
+        Self {
+            cycles: (1..=n).map(|k| 1.0 / k as f64).sum(),
+            longest: GOLOMB_DICKMAN * n as f64,
+            fixed_points: 1.0,
+            even: 0.5,
+            fixed_point_free: (-1.0f64).exp(),
+        }
+    }
+}
+
+/// The profiles of many permutations of the same size.
+#[derive(Debug, Clone, Default)]
+pub struct Aggregate {
+    pub len: usize,
+    pub cycles: Mean,
+    pub longest: Mean,
+    pub fixed_points: Mean,
+    pub even: usize,
+    pub fixed_point_free: usize,
+}
+
+impl Aggregate {
+    pub fn add(&mut self, permutation: &Permutation) {
+        assert!(
+            self.cycles.count() == 0 || self.len == permutation.len(),
+            "permutation size"
+        );
+        self.len = permutation.len();
+
+        let profile = permutation.profile();
+        self.cycles.add(profile.cycles as f64);
+        self.longest.add(profile.longest as f64);
+        self.fixed_points.add(profile.fixed_points as f64);
+        self.even += usize::from(profile.parity == Parity::Even);
+        self.fixed_point_free += usize::from(profile.fixed_points == 0);
+    }
+
+    pub fn count(&self) -> usize {
+        self.cycles.count()
+    }
+}
+
+/// A table of each statistic, its value if the permutations were random, and the z-score between them.
+impl fmt::Display for Aggregate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let random = Expectation::random(self.len);
+        let count = self.count();
+        writeln!(
+            f,
+            "{:<18} {:>12} {:>12} {:>8}",
+            "statistic", "observed", "random", "z"
+        )?;
+        for (name, mean, expected) in [
+            ("cycles", &self.cycles, random.cycles),
+            ("longest cycle", &self.longest, random.longest),
+            ("fixed points", &self.fixed_points, random.fixed_points),
+        ] {
+            writeln!(
+                f,
+                "{:<18} {:>12.3} {:>12.3} {:>8.2}",
+                name,
+                mean.mean(),
+                expected,
+                mean.z_score(expected)
+            )?;
+        }
+        for (name, hits, p) in [
+            ("even", self.even, random.even),
+            (
+                "fixed-point free",
+                self.fixed_point_free,
+                random.fixed_point_free,
+            ),
+        ] {
+            writeln!(
+                f,
+                "{:<18} {:>12.3} {:>12.3} {:>8.2}",
+                name,
+                hits as f64 / count as f64,
+                p,
+                proportion_z_score(hits, count, p)
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+//! Running statistics and the seeded randomness the experiments draw their keys and texts from.
+
+use std::ops::Range;
+
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+/// The mean and sample standard deviation of a stream of observations.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Mean {
+    count: usize,
+    sum: f64,
+    sum_of_squares: f64,
+}
+
+impl Mean {
+    pub fn add(&mut self, x: f64) {
+        self.count += 1;
+        self.sum += x;
+        self.sum_of_squares += x * x;
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.sum / self.count as f64
+    }
+
+    pub fn std_dev(&self) -> f64 {
+        let n = self.count as f64;
+        ((self.sum_of_squares - self.sum * self.sum / n) / (n - 1.0))
+            .max(0.0)
+            .sqrt()
+    }
+
+    /// How many standard errors the mean lies from `expected`.
+    pub fn z_score(&self, expected: f64) -> f64 {
+        (self.mean() - expected) / (self.std_dev() / (self.count as f64).sqrt())
+    }
+}
+
+/// How many standard errors the proportion `hits / trials` lies from the probability `p`.
+pub fn proportion_z_score(hits: usize, trials: usize, p: f64) -> f64 {
+    let n = trials as f64;
+    (hits as f64 / n - p) / (p * (1.0 - p) / n).sqrt()
+}
+
+/// The generator behind every random choice of an experiment, so that a seed reproduces its results.
+pub fn rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+/// The generator of range `i` of [`par_chunks`]: its own stream, unlike `rng(seed + i)`, which is range `i - 1`'s
+/// with the next seed.
+fn chunk_rng(seed: u64, i: usize) -> StdRng {
+    let mut bytes = [0; 32];
+    bytes[..8].copy_from_slice(&seed.to_le_bytes());
+    bytes[8..16].copy_from_slice(&(i as u64).to_le_bytes());
+    StdRng::from_seed(bytes)
+}
+
+/// `count` random keys of `len` bytes.
+pub fn keys(rng: &mut impl RngCore, count: usize, len: usize) -> Vec<Vec<u8>> {
+    (0..count)
+        .map(|_| {
+            let mut key = vec![0; len];
+            rng.fill_bytes(&mut key);
+            key
+        })
+        .collect()
+}
+
+/// The Wilson score interval at 95% confidence for the probability behind `hits` out of `trials`.
+pub fn wilson_interval(hits: usize, trials: usize) -> (f64, f64) {
+    const Z: f64 = 1.959_963_984_540_054;
+    let n = trials as f64;
+    let p = hits as f64 / n;
+    let centre = (p + Z * Z / (2.0 * n)) / (1.0 + Z * Z / n);
+    let half_width = Z / (1.0 + Z * Z / n) * (p * (1.0 - p) / n + Z * Z / (4.0 * n * n)).sqrt();
+    (
+        (centre - half_width).max(0.0),
+        (centre + half_width).min(1.0),
+    )
+}
+
+/// Pearson's χ² statistic of counts against the uniform distribution over their cells.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChiSquare {
+    pub statistic: f64,
+    pub degrees_of_freedom: usize,
+}
+
+impl ChiSquare {
+    pub fn uniform(counts: &[usize]) -> Self {
+        let total: usize = counts.iter().sum();
+        let expected = total as f64 / counts.len() as f64;
+        Self {
+            statistic: counts
+                .iter()
+                .map(|&count| (count as f64 - expected).powi(2) / expected)
+                .sum(),
+            degrees_of_freedom: counts.len() - 1,
+        }
+    }
+
+    /// The standard normal deviate of the statistic, by the Wilson–Hilferty cube-root approximation: above 3.09
+    /// with probability 0.001 if the counts are uniform.
+    pub fn z_score(&self) -> f64 {
+        let k = self.degrees_of_freedom as f64;
+        let v = 2.0 / (9.0 * k);
+        ((self.statistic / k).cbrt() - (1.0 - v)) / v.sqrt()
+    }
+}
+
+/// Runs `work` on the items `0..count` split into ranges of `chunk`, on every core, giving each range its own
+/// generator seeded from `seed` and the range's index: the results depend on the seed only, not on the number of
+/// threads.
+pub fn par_chunks<T: Send>(
+    seed: u64,
+    count: usize,
+    chunk: usize,
+    work: impl Fn(&mut StdRng, Range<usize>) -> T + Sync,
+) -> Vec<T> {
+    let chunks = count.div_ceil(chunk);
+    let threads = std::thread::available_parallelism()
+        .map_or(1, usize::from)
+        .min(chunks.max(1));
+    let next = std::sync::atomic::AtomicUsize::new(0);
+
+    let mut results: Vec<(usize, T)> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut results = Vec::new();
+                    loop {
+                        let i = next.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                        if i >= chunks {
+                            return results;
+                        }
+                        let mut rng = chunk_rng(seed, i);
+                        let range = i * chunk..count.min((i + 1) * chunk);
+                        results.push((i, work(&mut rng, range)));
+                    }
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    });
+    results.sort_unstable_by_key(|&(i, _)| i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
@@ -0,0 +1,112 @@
+//! The native word types the experiments run RC5 over, with values and differences handled as `u64`.
+
+use rand::RngCore;
+use rc5_core::algs::{encrypt_half_rounds_slice, encrypt_traced_slice, expand_key_slices};
+use rc5_core::std_words::{LittleEndian, StdArith, StdMagic};
+
+/// The length of the random keys.
+const KEY_LEN: usize = 16;
+
+/// A word of RC5-w with `w` at most 64.
+pub trait Word: Copy + Default + Eq + Send + Sync + 'static {
+    const BITS: u32;
+
+    /// The low [`Word::BITS`] bits of `x`.
+    fn from_u64(x: u64) -> Self;
+    fn to_u64(self) -> u64;
+
+    /// The key table of RC5-w/r for `key`.
+    fn s_table(key: &[u8], rounds: usize) -> Vec<Self>;
+
+    /// The first `half_rounds` half-rounds of encryption with `s_table`.
+    fn encrypt(s_table: &[Self], half_rounds: usize, a: &mut Self, b: &mut Self);
+
+    /// [`Word::encrypt`], calling `trace` after the key addition and each half-round.
+    fn encrypt_traced(
+        s_table: &[Self],
+        half_rounds: usize,
+        a: &mut Self,
+        b: &mut Self,
+        trace: impl FnMut(usize, &Self, &Self),
+    );
+}
+
+macro_rules! word_impl {
+    ($($word:ty),*) => {
+        $(
+            impl Word for $word {
+                const BITS: u32 = <$word>::BITS;
+
+                fn from_u64(x: u64) -> Self {
+                    x as $word
+                }
+
+                fn to_u64(self) -> u64 {
+                    self as u64
+                }
+
+                fn s_table(key: &[u8], rounds: usize) -> Vec<Self> {
+                    let mut l_table = vec![0; key.len().div_ceil(Self::BITS as usize / 8).max(1)];
+                    let mut s_table = vec![0; 2 * (rounds + 1)];
+                    expand_key_slices::<$word, StdMagic, LittleEndian, StdArith>(
+                        key,
+                        &mut l_table,
+                        &mut s_table,
+                    );
+                    s_table
+                }
+
+                fn encrypt(s_table: &[Self], half_rounds: usize, a: &mut Self, b: &mut Self) {
+                    encrypt_half_rounds_slice::<$word, StdArith>(s_table, half_rounds, a, b)
+                }
+
+                fn encrypt_traced(
+                    s_table: &[Self],
+                    half_rounds: usize,
+                    a: &mut Self,
+                    b: &mut Self,
+                    trace: impl FnMut(usize, &Self, &Self),
+                ) {
+                    encrypt_traced_slice::<$word, StdArith>(s_table, half_rounds, a, b, trace)
+                }
+            }
+        )*
+    };
+}
+
+word_impl!(u8, u16, u32, u64);
+
+/// The mask of the low `bits` bits.
+pub fn mask(bits: u32) -> u64 {
+    u64::MAX >> (64 - bits)
+}
+
+/// A uniformly random block.
+pub fn random_block<W: Word>(rng: &mut impl RngCore) -> (W, W) {
+    (W::from_u64(rng.next_u64()), W::from_u64(rng.next_u64()))
+}
+
+/// The key table of RC5-w/r for a random 16-byte key.
+pub fn random_s_table<W: Word>(rng: &mut impl RngCore, rounds: usize) -> Vec<W> {
+    let mut key = [0; KEY_LEN];
+    rng.fill_bytes(&mut key);
+    W::s_table(&key, rounds)
+}
+
+/// `block` after the first `half_rounds` half-rounds of encryption with `s_table`.
+pub fn encrypt<W: Word>(s_table: &[W], half_rounds: usize, (mut a, mut b): (W, W)) -> (W, W) {
+    W::encrypt(s_table, half_rounds, &mut a, &mut b);
+    (a, b)
+}
+
+/// Two words written `A,B` in hex, as differences and masks are.
+pub(crate) fn parse_words(s: &str) -> Result<(u64, u64), String> {
+    let word = |s: &str| {
+        u64::from_str_radix(s.trim().trim_start_matches("0x"), 16)
+            .map_err(|e| format!("{:?}: {}", s, e))
+    };
+    let (a, b) = s
+        .split_once(',')
+        .ok_or_else(|| format!("{:?}: expected A,B", s))?;
+    Ok((word(a)?, word(b)?))
+}
@@ -0,0 +1,118 @@
+mod common;
+
+use std::fs;
+
+use rc5_analysis::avalanche::{self, Std, Strange, Variant};
+use rc5_core::strange_words::{W24bit, W32bit, W80bit};
+
+use common::rc5_analysis;
+
+/// Adding `S[0]` and `S[1]` flips the flipped bit and carries only upwards, within its word.
+#[test]
+fn key_addition() {
+    let avalanche = avalanche::measure::<Std<u16>>(1, 8, 64, 0);
+    assert_eq!(avalanche.plaintext.len(), 3);
+    let matrix = &avalanche.plaintext[0];
+    assert_eq!((matrix.inputs, matrix.outputs), (32, 32));
+    for i in 0..32 {
+        for j in 0..32 {
+            let p = matrix.probability(i, j);
+            if i == j {
+                assert_eq!(p, 1.0);
+            } else if j < i || j / 16 != i / 16 {
+                assert_eq!(p, 0.0, "bit {} to bit {}", i, j);
+            }
+        }
+    }
+    assert_eq!(avalanche.key[0].inputs, 64);
+}
+
+#[test]
+fn full_diffusion() {
+    let avalanche = avalanche::measure::<Std<u16>>(5, 16, 512, 1);
+    let last = avalanche.plaintext.last().unwrap();
+    assert!((last.avalanche() - 0.5).abs() < 0.01, "{}", avalanche);
+    assert!(last.sac() < 1.2 * avalanche.sac_noise(), "{}", avalanche);
+    assert!(avalanche.plaintext[1].sac() > 0.5);
+    assert!(avalanche.bic < 0.3);
+    assert_eq!(avalanche, avalanche::measure::<Std<u16>>(5, 16, 512, 1));
+}
+
+#[test]
+fn strange_words() {
+    assert_eq!(Strange::<W24bit>::BITS, 24);
+    assert_eq!(Strange::<W80bit>::BITS, 80);
+
+    let avalanche = avalanche::measure::<Strange<W24bit>>(2, 5, 32, 0);
+    assert_eq!(avalanche.plaintext[4].outputs, 48);
+    assert_eq!(avalanche.key[4].inputs, 40);
+
+    let avalanche = avalanche::measure::<Strange<W80bit>>(1, 3, 16, 0);
+    assert_eq!(avalanche.plaintext[2].outputs, 160);
+    assert_eq!(avalanche.key[2].inputs, 24);
+
+    // W32bit keeps a 32-bit word in a u64 and must diffuse exactly as u32
+    assert_eq!(
+        avalanche::measure::<Strange<W32bit>>(2, 16, 32, 3),
+        avalanche::measure::<Std<u32>>(2, 16, 32, 3)
+    );
+}
+
+#[test]
+fn export() {
+    let matrix = &avalanche::measure::<Std<u8>>(1, 4, 16, 0).plaintext[0];
+    let csv = matrix.to_csv();
+    assert_eq!(csv.lines().count(), 16);
+    assert!(csv.starts_with("1.000000,"));
+    assert!(csv.lines().all(|line| line.split(',').count() == 16));
+
+    let mut ppm = Vec::new();
+    matrix.write_ppm(&mut ppm, 2).unwrap();
+    let header = b"P6\n32 32\n255\n";
+    assert_eq!(&ppm[..header.len()], header);
+    assert_eq!(ppm.len(), header.len() + 3 * 32 * 32);
+    // entry (0, 0) flips always: red; entry (0, 8) never: blue
+    assert_eq!(&ppm[header.len()..][..3], [255, 0, 0]);
+    assert_eq!(&ppm[header.len()..][3 * 16..][..3], [0, 0, 255]);
+}
+
+#[test]
+fn avalanche_command() {
+    let dir = std::env::temp_dir().join(format!("rc5-analysis-avalanche-{}", std::process::id()));
+    let report = rc5_analysis(&[
+        "avalanche",
+        "-w",
+        "24",
+        "-r",
+        "1",
+        "-b",
+        "4",
+        "-n",
+        "32",
+        "--scale",
+        "1",
+        "-o",
+        dir.to_str().unwrap(),
+    ]);
+    assert!(report.starts_with("seed 0\nRC5-24/1/4, 32 samples\n"));
+    assert!(report.ends_with(&format!("wrote 6 matrices to {}\n", dir.display())));
+
+    assert_eq!(
+        fs::read_to_string(dir.join("plaintext-h02.csv"))
+            .unwrap()
+            .lines()
+            .count(),
+        48
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("key-h00.csv"))
+            .unwrap()
+            .lines()
+            .count(),
+        32
+    );
+    assert!(fs::read(dir.join("key-h01.ppm"))
+        .unwrap()
+        .starts_with(b"P6\n48 32\n255\n"));
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,21 @@
+use std::process::{Command, Output};
+
+/// Runs the `rc5-analysis` binary with `args`.
+pub fn rc5_analysis_output(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_rc5-analysis"))
+        .args(args)
+        .output()
+        .expect("spawn rc5-analysis")
+}
+
+/// Runs the `rc5-analysis` binary with `args`, which must succeed, and returns what it printed.
+pub fn rc5_analysis(args: &[&str]) -> String {
+    let output = rc5_analysis_output(args);
+    assert!(
+        output.status.success(),
+        "rc5-analysis {:?}: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
@@ -0,0 +1,191 @@
+mod common;
+
+use rc5_analysis::differential::{
+    self, attack_difference, attack_pairs, recover_last_subkey, Characteristic, Difference,
+};
+use rc5_analysis::stats;
+
+use common::{rc5_analysis, rc5_analysis_output};
+
+#[test]
+fn difference_text() {
+    let d: Difference = "80000000, 0x1".parse().unwrap();
+    assert_eq!(d, Difference::new(0x8000_0000, 1));
+    assert_eq!(d.to_string(), "80000000,1");
+    assert_eq!(d.weight(), 2);
+    assert!("80000000".parse::<Difference>().is_err());
+    assert!("1,g".parse::<Difference>().is_err());
+}
+
+#[test]
+fn validate() {
+    let input = Difference::new(0x80, 0x80);
+    let characteristic = |trail| Characteristic { input, trail };
+    assert!(characteristic(vec![]).validate(8).is_err());
+    assert!(characteristic(vec![Some(Difference::new(0x100, 0))])
+        .validate(8)
+        .is_err());
+    assert!(characteristic(vec![None]).validate(8).is_ok());
+    assert_eq!(characteristic(vec![Some(input), None]).half_rounds(), 1);
+}
+
+/// The top bit passes the key additions free of carries: `(e, e)` goes to `(e, e)` and then `(0, e)`.
+#[test]
+fn top_bit_characteristic() {
+    let e = 1 << 31;
+    let characteristic = Characteristic {
+        input: Difference::new(e, e),
+        trail: vec![
+            Some(Difference::new(e, e)),
+            Some(Difference::new(0, e)),
+            None,
+        ],
+    };
+    let estimate = differential::estimate::<u32>(&characteristic, 1 << 12, 0);
+    assert_eq!(estimate.followed, [1 << 12; 3]);
+    assert_eq!(estimate.probability(), 1.0);
+    assert!(estimate.to_string().contains("\n   1 "));
+}
+
+/// A difference in the lowest bit of `A` survives the addition of `S[0]` when the low bit of `S[0]` is 0, and
+/// otherwise always carries: the probability is 1 or 0 depending on the key, 1/2 over all of them.
+#[test]
+fn low_bit_carry() {
+    let characteristic = Characteristic {
+        input: Difference::new(1, 0),
+        trail: vec![Some(Difference::new(1, 0))],
+    };
+    let estimate = differential::estimate::<u16>(&characteristic, 1 << 18, 3);
+    assert!((estimate.probability() - 0.5).abs() < 0.1, "{}", estimate);
+    assert_eq!(
+        estimate,
+        differential::estimate::<u16>(&characteristic, 1 << 18, 3)
+    );
+}
+
+#[test]
+fn search() {
+    let candidates = differential::search::<u8>(1, 2, 1 << 10, 0);
+    // 16 differences of weight 1 and 120 of weight 2
+    assert_eq!(candidates.len(), 136);
+    assert_eq!(candidates[0].input, Difference::new(0x80, 0x80));
+    assert_eq!(candidates[0].output, Difference::new(0, 0x80));
+    assert_eq!(candidates[0].count, 1 << 10);
+    assert!(candidates.windows(2).all(|w| w[0].count >= w[1].count));
+}
+
+#[test]
+fn search_limits() {
+    assert!(differential::validate_search(8, 1).is_ok());
+    assert!(differential::validate_search(8, 16).is_ok());
+    assert!(differential::validate_search(8, 0).is_err());
+    assert!(differential::validate_search(8, 17).is_err());
+    assert!(differential::validate_search(32, 64).is_ok());
+    assert!(differential::validate_search(64, 1).is_err());
+
+    // every difference of two bytes, the highest weight included
+    let candidates = differential::search::<u8>(1, 16, 4, 0);
+    assert_eq!(candidates.len(), (1 << 16) - 1);
+    assert!(candidates
+        .iter()
+        .any(|c| c.input == Difference::new(0xff, 0xff)));
+}
+
+#[test]
+#[should_panic(expected = "at most 32 bits")]
+fn search_of_64_bit_words() {
+    differential::search::<u64>(1, 1, 1, 0);
+}
+
+#[test]
+fn attack() {
+    for rounds in 1..=3 {
+        for seed in 0..2 {
+            let key = stats::keys(&mut stats::rng(seed), 1, 16).remove(0);
+            let attack = differential::attack::<u32>(&key, rounds, attack_pairs(rounds), seed);
+            assert_eq!(attack.input, attack_difference(32, rounds));
+            assert!(attack.succeeded(), "{}", attack);
+            assert_eq!(attack.subkey >> 31, 0);
+        }
+    }
+
+    let key = [0; 16];
+    let attack = differential::attack::<u16>(&key, 2, 1 << 12, 0);
+    assert!(attack.succeeded(), "{}", attack);
+    assert_eq!(attack, differential::attack::<u16>(&key, 2, 1 << 12, 0));
+}
+
+/// Four to six rounds with the default pairs: about four minutes on one core in a release build, six rounds taking
+/// over a minute a key. Run with `cargo test --release -p rc5-analysis -- --ignored`.
+#[test]
+#[ignore]
+fn attack_more_rounds() {
+    for (rounds, seeds) in [(4, 0..4), (5, 0..4), (6, 0..3)] {
+        for seed in seeds {
+            let key = stats::keys(&mut stats::rng(seed), 1, 16).remove(0);
+            let attack = differential::attack::<u32>(&key, rounds, attack_pairs(rounds), seed);
+            assert!(attack.succeeded(), "seed {}: {}", seed, attack);
+        }
+    }
+}
+
+#[test]
+fn too_few_pairs() {
+    assert_eq!(recover_last_subkey(32, &[], 4).len(), 4);
+    let attack = differential::attack::<u32>(&[1; 16], 3, 1 << 8, 0);
+    assert!(!attack.succeeded());
+}
+
+#[test]
+fn differential_commands() {
+    let report = rc5_analysis(&[
+        "differential",
+        "estimate",
+        "-w",
+        "8",
+        "-i",
+        "80,80",
+        "-n",
+        "1000",
+        "80,80",
+        "0,80",
+        "*",
+    ]);
+    assert!(
+        report.starts_with("RC5-8, 2 half-rounds, seed 0\ninput difference 80,80, 1000 pairs\n")
+    );
+
+    let report = rc5_analysis(&["differential", "search", "-H", "1", "-t", "1"]);
+    assert_eq!(report.lines().count(), 3);
+    assert!(report.contains(" 80,80 "));
+
+    let report = rc5_analysis(&[
+        "differential",
+        "attack",
+        "-r",
+        "2",
+        "-k",
+        "000102030405060708090a0b0c0d0e0f",
+    ]);
+    assert!(
+        report.starts_with("key: 000102030405060708090a0b0c0d0e0f\nRC5-32/2, last subkey S[5]\n")
+    );
+    assert!(report.ends_with("recovered: yes\n"));
+}
+
+#[test]
+fn differential_errors() {
+    let output = rc5_analysis_output(&["differential", "estimate", "-w", "8", "-i", "100,0", "*"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("does not fit 8-bit words"));
+
+    for (w, m, error) in [
+        ("64", "2", "at most 32 bits"),
+        ("8", "17", "is 1 to 16"),
+        ("8", "0", "is 1 to 16"),
+    ] {
+        let output = rc5_analysis_output(&["differential", "search", "-w", w, "-m", m]);
+        assert!(!output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr).contains(error));
+    }
+}
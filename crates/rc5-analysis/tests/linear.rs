@@ -0,0 +1,134 @@
+mod common;
+
+use rc5_analysis::linear::{self, Approximation, Correlation, Mask};
+use rc5_analysis::stats::ChiSquare;
+
+use common::{rc5_analysis, rc5_analysis_output};
+
+#[test]
+fn mask_text() {
+    let m: Mask = "0x3,80".parse().unwrap();
+    assert_eq!(m, Mask::new(3, 0x80));
+    assert_eq!(m.to_string(), "3,80");
+    assert!(m.parity((1u8, 0u8)));
+    assert!(!m.parity((3u8, 0x7fu8)));
+    assert!(m.parity((0xffu8, 0xffu8)));
+    assert!("3".parse::<Mask>().is_err());
+}
+
+#[test]
+fn validate() {
+    let mut approximation = Approximation::kaliski_yin(2);
+    assert_eq!(approximation.half_rounds, 4);
+    assert!(approximation.validate(8).is_ok());
+    approximation.output = Mask::new(0x100, 0);
+    assert!(approximation.validate(8).is_err());
+    assert!(approximation.validate(16).is_ok());
+}
+
+#[test]
+fn correlation() {
+    let c = Correlation {
+        holds: 750,
+        texts: 1000,
+    };
+    assert!((c.value() - 0.5).abs() < 1e-12);
+    let (low, high) = c.interval();
+    assert!(low < 0.5 && 0.5 < high && high - low < 0.12);
+}
+
+/// Before the first round only the addition of `S[1]` stands between the low bits of `B`: a correlation of ±1.
+#[test]
+fn kaliski_yin_whitening() {
+    let survey = linear::survey::<u16>(&Approximation::kaliski_yin(0), 8, 256, 0);
+    assert!(survey.correlations.iter().all(|c| c.value().abs() == 1.0));
+    assert_eq!(survey.potential().0, 1.0 - 1.0 / 256.0);
+}
+
+/// Over one round the approximation has the correlation `1/w` the rotations by 0 predict.
+#[test]
+fn kaliski_yin_one_round() {
+    let survey = linear::survey::<u8>(&Approximation::kaliski_yin(1), 16, 1 << 14, 1);
+    let (potential, low, high) = survey.potential();
+    assert!(low < 1.0 / 64.0 && 1.0 / 64.0 < high, "{}", survey);
+    assert!((potential * 64.0 - 1.0).abs() < 0.2);
+    assert_eq!(
+        survey,
+        linear::survey::<u8>(&Approximation::kaliski_yin(1), 16, 1 << 14, 1)
+    );
+}
+
+#[test]
+fn chi_square() {
+    let uniform = ChiSquare::uniform(&[5, 5, 5, 5]);
+    assert_eq!(uniform.statistic, 0.0);
+    assert_eq!(uniform.degrees_of_freedom, 3);
+    assert_eq!(ChiSquare::uniform(&[20, 0, 0, 0]).statistic, 60.0);
+
+    let typical = ChiSquare {
+        statistic: 1023.0,
+        degrees_of_freedom: 1023,
+    };
+    assert!(typical.z_score().abs() < 0.1);
+}
+
+#[test]
+fn rotation_amounts() {
+    // all 2^10 chosen plaintexts of RC5-8 have the rotation amounts of S[0] and S[1]
+    let test = linear::rotation_test::<u8>(0, true, 4, 1 << 10, 0);
+    assert!(test.tests.iter().all(|t| t.statistic == 1024.0 * 63.0));
+
+    let test = linear::rotation_test::<u8>(3, true, 4, 1 << 10, 0);
+    assert_eq!(test.distinguished(), 4, "{}", test);
+
+    let test = linear::rotation_test::<u32>(4, true, 4, 1 << 14, 0);
+    assert_eq!(test.distinguished(), 4, "{}", test);
+
+    let control = linear::rotation_test::<u32>(4, false, 4, 1 << 14, 0);
+    assert_eq!(control.distinguished(), 0, "{}", control);
+    assert!(control
+        .to_string()
+        .contains("with random rotation amounts\n"));
+}
+
+/// RC5-64 has more than 2^64 plaintexts, with or without zero rotation amounts.
+#[test]
+fn rotation_amounts_64() {
+    for fixed in [true, false] {
+        let test = linear::rotation_test::<u64>(0, fixed, 2, 1 << 12, 0);
+        // half-round 0 only adds S[0] and S[1]: zero rotation amounts all take theirs
+        assert_eq!(test.distinguished(), if fixed { 2 } else { 0 }, "{}", test);
+        assert_eq!(test, linear::rotation_test::<u64>(0, fixed, 2, 1 << 12, 0));
+    }
+}
+
+#[test]
+#[should_panic(expected = "more texts than plaintexts")]
+fn too_many_texts() {
+    linear::rotation_test::<u8>(0, true, 1, (1 << 10) + 1, 0);
+}
+
+#[test]
+fn linear_commands() {
+    let report = rc5_analysis(&["linear", "correlation", "-w", "8", "-K", "2", "-n", "4096"]);
+    assert!(report.starts_with("seed 0\nRC5-8, 2 half-rounds, input mask 0,1, output mask 0,1\n"));
+    assert!(report.ends_with("Kaliski-Yin prediction: |c| = 8^-1 = 2^-3.00\n"));
+
+    let report = rc5_analysis(&[
+        "linear", "rotation", "-w", "16", "-H", "2", "-K", "2", "-n", "4096",
+    ]);
+    assert!(report.ends_with("distinguished (z > 3.09): 2 of 2 keys\n"));
+
+    let output = rc5_analysis_output(&["linear", "rotation", "-w", "8", "-n", "1025"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("only 2^10 such plaintexts"));
+
+    for random in [&[][..], &["--random"]] {
+        let args = [
+            &["linear", "rotation", "-w", "64", "-K", "1", "-n", "256"],
+            random,
+        ]
+        .concat();
+        rc5_analysis(&args);
+    }
+}
@@ -0,0 +1,132 @@
+mod common;
+
+use rc5_analysis::permutation::{self, Aggregate, Expectation, Parity, Permutation, RC5_8_BLOCKS};
+use rc5_analysis::stats;
+
+use common::rc5_analysis;
+
+fn rc5_8(key: &[u8], rounds: u8) -> Permutation {
+    Permutation::new(permutation::rc5_8(key, rounds)).expect("RC5-8 is a bijection")
+}
+
+#[test]
+fn small_permutations() {
+    assert_eq!(Permutation::new(vec![0, 0]), None);
+    assert_eq!(Permutation::new(vec![0, 2]), None);
+    assert!(Permutation::new(Vec::new()).unwrap().is_empty());
+
+    let p = Permutation::new(vec![1, 2, 0, 3, 5, 4]).unwrap();
+    assert_eq!(p.cycle_lengths(), [3, 2, 1]);
+    assert_eq!(p.fixed_points(), 1);
+    // a 3-cycle is even, a transposition odd
+    assert_eq!(p.profile().parity, Parity::Odd);
+    assert_eq!(p.profile().longest, 3);
+}
+
+/// RC5-8/12/4 of draft-krovetz-rc6-rc5-vectors-00: key 00010203 takes the block 0001 to 212A.
+#[test]
+fn krovetz_vector() {
+    let p = rc5_8(&[0, 1, 2, 3], 12);
+    assert_eq!(p.len(), RC5_8_BLOCKS);
+    assert_eq!(p.image(0x0100), 0x2a21);
+}
+
+#[test]
+fn rc5_8_permutations() {
+    let keys = stats::keys(&mut stats::rng(1), 4, 16);
+    for rounds in [0, 1, 2, 12] {
+        for key in keys
+            .iter()
+            .map(Vec::as_slice)
+            .chain([&[][..], &[0xff; 255]])
+        {
+            let p = rc5_8(key, rounds);
+            let profile = p.profile();
+            assert_eq!(p.cycle_lengths().iter().sum::<usize>(), RC5_8_BLOCKS);
+            assert_eq!(profile.parity, Parity::Even, "{:?}, {} rounds", key, rounds);
+        }
+    }
+}
+
+/// The steps of an RC5-8 half-round on `A`, as permutations of the blocks: each is even on its own.
+#[test]
+fn half_round_steps_are_even() {
+    let step = |f: fn(u8, u8) -> u8| {
+        let images = (0..RC5_8_BLOCKS as u32)
+            .map(|block| {
+                let [a, b, ..] = block.to_le_bytes();
+                u32::from(u16::from_le_bytes([f(a, b), b]))
+            })
+            .collect();
+        Permutation::new(images).unwrap().profile().parity
+    };
+    assert_eq!(step(|a, b| a ^ b), Parity::Even);
+    assert_eq!(step(|a, b| a.rotate_left(u32::from(b))), Parity::Even);
+    assert_eq!(step(|a, _| a.wrapping_add(1)), Parity::Even);
+
+    // adding 1 to a byte alone is a 256-cycle, odd: the block permutation is even for being 256 copies of it
+    let add_one = Permutation::new((0..256).map(|x| (x + 1) % 256).collect()).unwrap();
+    assert_eq!(add_one.profile().parity, Parity::Odd);
+    // the rotation by one bit has the 36 binary necklaces of length 8 for cycles
+    let rotate = Permutation::new(
+        (0..256)
+            .map(|x| u32::from((x as u8).rotate_left(1)))
+            .collect(),
+    );
+    assert_eq!(rotate.unwrap().cycle_lengths().len(), 36);
+}
+
+#[test]
+fn aggregate() {
+    let mut aggregate = Aggregate::default();
+    for key in stats::keys(&mut stats::rng(0), 8, 16) {
+        aggregate.add(&rc5_8(&key, 12));
+    }
+    assert_eq!(aggregate.count(), 8);
+    assert_eq!(aggregate.even, 8);
+    assert!(
+        aggregate
+            .cycles
+            .z_score(Expectation::random(RC5_8_BLOCKS).cycles)
+            .abs()
+            < 4.0
+    );
+
+    let report = aggregate.to_string();
+    assert!(report.starts_with("statistic "));
+    assert!(report.contains("\neven                      1.000        0.500 "));
+
+    let random = Expectation::random(3);
+    assert!((random.cycles - 11.0 / 6.0).abs() < 1e-12);
+}
+
+#[test]
+fn permutation_command() {
+    let report = rc5_analysis(&[
+        "permutation",
+        "-r",
+        "4",
+        "-k",
+        "000102030405060708090a0b0c0d0e0f",
+    ]);
+    assert!(
+        report.starts_with("RC5-8/4/16, key 000102030405060708090a0b0c0d0e0f\nbijective: yes\n")
+    );
+    let lengths = report
+        .lines()
+        .find_map(|line| line.strip_prefix("cycle lengths: "))
+        .unwrap();
+    let total: usize = lengths
+        .split(' ')
+        .map(|n| n.parse::<usize>().unwrap())
+        .sum();
+    assert_eq!(total, RC5_8_BLOCKS);
+    assert!(report.ends_with("parity: even\n"));
+
+    let report = rc5_analysis(&["permutation", "-n", "3", "-b", "5", "-s", "7"]);
+    assert!(report.starts_with("RC5-8/12/5, 3 random keys (seed 7), all bijective\n"));
+    assert_eq!(
+        report,
+        rc5_analysis(&["permutation", "-n", "3", "-b", "5", "-s", "7"])
+    );
+}
@@ -0,0 +1,116 @@
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+/// Encrypts and decrypts files with RC5-w/r/b.
+#[derive(Debug, Parser)]
+#[command(name = "rc5", version, after_help = EXIT_CODES)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+const EXIT_CODES: &str = "\
+Exit codes:
+  0  success
+  1  decryption failed: wrong key, IV or associated data, or corrupted ciphertext
+  2  invalid command line or parameters
+  3  malformed input: not valid hex or base64, or too short to hold the IV
+  4  I/O error";
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Encrypts the input.
+    Encrypt(Options),
+    /// Decrypts the input.
+    Decrypt(Options),
+}
+
+#[derive(Debug, Args)]
+pub struct Options {
+    /// Word size in bits; a block is two words.
+    #[arg(short = 'w', long, value_enum, default_value = "32")]
+    pub word_size: WordSize,
+
+    /// Number of rounds.
+    #[arg(short = 'r', long, default_value_t = 12)]
+    pub rounds: u8,
+
+    /// Key length in bytes; the key is checked to be exactly this long.
+    #[arg(short = 'b', long)]
+    pub key_bytes: Option<u8>,
+
+    #[arg(short = 'm', long, value_enum, default_value = "cbc-pad")]
+    pub mode: Mode,
+
+    #[command(flatten)]
+    pub key: KeySource,
+
+    /// IV (nonce for EAX) in hex. Without it, encryption picks a random one and writes it
+    /// before the ciphertext, and decryption reads it from the start of the input.
+    #[arg(long, value_name = "HEX")]
+    pub iv: Option<String>,
+
+    /// Associated data for EAX, in hex.
+    #[arg(long, value_name = "HEX")]
+    pub aad: Option<String>,
+
+    /// Input file; standard input if absent or "-".
+    #[arg(short = 'i', long, value_name = "PATH")]
+    pub input: Option<PathBuf>,
+
+    /// Output file; standard output if absent or "-".
+    #[arg(short = 'o', long, value_name = "PATH")]
+    pub output: Option<PathBuf>,
+
+    #[arg(long, value_enum, default_value = "raw")]
+    pub input_format: Format,
+
+    #[arg(long, value_enum, default_value = "raw")]
+    pub output_format: Format,
+}
+
+#[derive(Debug, Args)]
+#[group(required = true, multiple = false)]
+pub struct KeySource {
+    /// Key in hex.
+    #[arg(short = 'k', long, value_name = "HEX")]
+    pub key: Option<String>,
+
+    /// File holding the raw key bytes.
+    #[arg(long, value_name = "PATH")]
+    pub key_file: Option<PathBuf>,
+
+    /// Environment variable holding the key in hex.
+    #[arg(long, value_name = "VAR")]
+    pub key_env: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum WordSize {
+    #[value(name = "16")]
+    W16,
+    #[value(name = "32")]
+    W32,
+    #[value(name = "64")]
+    W64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Mode {
+    /// ECB with PKCS#7 padding.
+    Ecb,
+    /// CBC with PKCS#7 padding.
+    CbcPad,
+    /// CTR with a 64-bit big-endian counter; needs a block of at least 64 bits.
+    Ctr,
+    /// EAX: the output is the ciphertext followed by a one-block tag; needs a block of at least 64 bits.
+    Eax,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Raw,
+    Hex,
+    Base64,
+}
@@ -0,0 +1,193 @@
+use std::io::{Read, Write};
+
+use aead::AeadInPlace;
+use cipher::{BlockCipher, BlockDecrypt, BlockEncrypt};
+use rand::RngCore;
+use rc5_rustcrypto_api::eax::Eax;
+use rc5_rustcrypto_api::io::{Cbc, Ctr, DecryptReader, Decryptor, Ecb, EncryptWriter, Encryptor};
+use rc5_rustcrypto_api::rc5::RC5Var;
+use typenum::Unsigned;
+
+use crate::args::{Mode, WordSize};
+use crate::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Encrypt,
+    Decrypt,
+}
+
+pub struct Params {
+    pub direction: Direction,
+    pub word_size: WordSize,
+    pub rounds: u8,
+    pub mode: Mode,
+    pub iv: Option<Vec<u8>>,
+    pub aad: Vec<u8>,
+}
+
+/// Encrypts or decrypts `input` as a whole.
+pub fn run(params: &Params, key: &[u8], input: Vec<u8>) -> Result<Vec<u8>, Error> {
+    let rounds = params.rounds;
+    let cipher = |word_size| {
+        move |_| {
+            Error::Usage(format!(
+                "RC5-{}: the key must be at most 255 bytes",
+                word_size
+            ))
+        }
+    };
+
+    match (params.word_size, params.mode) {
+        (WordSize::W16, Mode::Ecb | Mode::CbcPad) => padded(
+            params,
+            RC5Var::<u16>::new(key, rounds).map_err(cipher(16))?,
+            input,
+        ),
+        (WordSize::W32, Mode::Ecb | Mode::CbcPad) => padded(
+            params,
+            RC5Var::<u32>::new(key, rounds).map_err(cipher(32))?,
+            input,
+        ),
+        (WordSize::W64, Mode::Ecb | Mode::CbcPad) => padded(
+            params,
+            RC5Var::<u64>::new(key, rounds).map_err(cipher(64))?,
+            input,
+        ),
+
+        (WordSize::W16, Mode::Ctr | Mode::Eax) => Err(Error::Usage(
+            "CTR and EAX need a block of at least 64 bits: use -w 32 or -w 64".into(),
+        )),
+
+        (WordSize::W32, Mode::Ctr) => {
+            let cipher = RC5Var::<u32>::new(key, rounds).map_err(cipher(32))?;
+            ctr(params, 8, |iv| Ctr::new(cipher, iv.into()), input)
+        }
+        (WordSize::W64, Mode::Ctr) => {
+            let cipher = RC5Var::<u64>::new(key, rounds).map_err(cipher(64))?;
+            ctr(params, 16, |iv| Ctr::new(cipher, iv.into()), input)
+        }
+
+        (WordSize::W32, Mode::Eax) => eax(
+            params,
+            Eax::from(RC5Var::<u32>::new(key, rounds).map_err(cipher(32))?),
+            input,
+        ),
+        (WordSize::W64, Mode::Eax) => eax(
+            params,
+            Eax::from(RC5Var::<u64>::new(key, rounds).map_err(cipher(64))?),
+            input,
+        ),
+    }
+}
+
+/// ECB or CBC, with PKCS#7 padding.
+fn padded<C>(params: &Params, cipher: C, mut input: Vec<u8>) -> Result<Vec<u8>, Error>
+where
+    C: BlockCipher + BlockEncrypt + BlockDecrypt,
+{
+    let mut output = Vec::new();
+
+    if params.mode == Mode::Ecb {
+        if params.iv.is_some() {
+            return Err(Error::Usage("ECB takes no IV".into()));
+        }
+        transform(params.direction, Ecb::new(cipher), &input, output)
+    } else {
+        let iv = take_iv(params, C::block_size(), &mut input, &mut output)?;
+        let mode = Cbc::new(cipher, iv.as_slice().into());
+        transform(params.direction, mode, &input, output)
+    }
+}
+
+/// CTR; `mode` builds the concrete keystream from the IV, whose length is `iv_len`.
+fn ctr<M>(
+    params: &Params,
+    iv_len: usize,
+    mode: impl FnOnce(&[u8]) -> M,
+    mut input: Vec<u8>,
+) -> Result<Vec<u8>, Error>
+where
+    M: Encryptor + Decryptor,
+{
+    let mut output = Vec::new();
+    let iv = take_iv(params, iv_len, &mut input, &mut output)?;
+
+    transform(params.direction, mode(&iv), &input, output)
+}
+
+fn eax<A: AeadInPlace>(params: &Params, aead: A, mut input: Vec<u8>) -> Result<Vec<u8>, Error> {
+    let mut output = Vec::new();
+    let nonce = take_iv(params, A::NonceSize::USIZE, &mut input, &mut output)?;
+    let nonce = aead::Nonce::<A>::from_slice(&nonce);
+
+    match params.direction {
+        Direction::Encrypt => {
+            aead.encrypt_in_place(nonce, &params.aad, &mut input)
+                .map_err(|_| Error::InvalidInput("the input is too long".into()))?;
+        }
+        Direction::Decrypt => {
+            aead.decrypt_in_place(nonce, &params.aad, &mut input)
+                .map_err(|_| Error::DecryptionFailed("authentication tag mismatch".into()))?;
+        }
+    }
+
+    output.extend_from_slice(&input);
+    Ok(output)
+}
+
+/// The IV (or nonce) given on the command line, or else the one at the start of the input when decrypting,
+/// or else a random one, written to the start of the output, when encrypting.
+fn take_iv(
+    params: &Params,
+    len: usize,
+    input: &mut Vec<u8>,
+    output: &mut Vec<u8>,
+) -> Result<Vec<u8>, Error> {
+    match (&params.iv, params.direction) {
+        (Some(iv), _) if iv.len() == len => Ok(iv.clone()),
+        (Some(iv), _) => Err(Error::Usage(format!(
+            "the IV must be {} bytes, not {}",
+            len,
+            iv.len()
+        ))),
+        (None, Direction::Decrypt) if input.len() < len => Err(Error::InvalidInput(format!(
+            "the input is shorter than the {}-byte IV",
+            len
+        ))),
+        (None, Direction::Decrypt) => Ok(input.drain(..len).collect()),
+        (None, Direction::Encrypt) => {
+            let mut iv = vec![0; len];
+            rand::thread_rng().fill_bytes(&mut iv);
+            output.extend_from_slice(&iv);
+            Ok(iv)
+        }
+    }
+}
+
+fn transform<M>(
+    direction: Direction,
+    mode: M,
+    input: &[u8],
+    mut output: Vec<u8>,
+) -> Result<Vec<u8>, Error>
+where
+    M: Encryptor + Decryptor,
+{
+    match direction {
+        Direction::Encrypt => {
+            let mut writer = EncryptWriter::new(&mut output, mode);
+            writer
+                .write_all(input)
+                .and_then(|()| writer.finish().map(drop))
+                .map_err(|e| Error::InvalidInput(e.to_string()))?;
+        }
+        Direction::Decrypt => {
+            DecryptReader::new(input, mode)
+                .read_to_end(&mut output)
+                .map_err(|e| Error::DecryptionFailed(e.to_string()))?;
+        }
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,36 @@
+use std::path::PathBuf;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0}")]
+    Usage(String),
+
+    #[error("malformed input: {0}")]
+    InvalidInput(String),
+
+    #[error("decryption failed: {0}")]
+    DecryptionFailed(String),
+
+    #[error("{}: {source}", path.display())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+impl Error {
+    /// The process exit code, as listed in `rc5 --help`.
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Self::DecryptionFailed(_) => 1,
+            Self::Usage(_) => 2,
+            Self::InvalidInput(_) => 3,
+            Self::Io { .. } => 4,
+        }
+    }
+
+    pub fn io(path: impl Into<PathBuf>) -> impl FnOnce(std::io::Error) -> Self {
+        let path = path.into();
+        move |source| Self::Io { path, source }
+    }
+}
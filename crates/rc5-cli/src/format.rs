@@ -0,0 +1,36 @@
+use base64::Engine;
+
+use crate::args::Format;
+use crate::error::Error;
+
+/// Decodes the input; whitespace, e.g. line breaks, is ignored in hex and base64.
+pub fn decode(format: Format, input: Vec<u8>) -> Result<Vec<u8>, Error> {
+    let text = || {
+        input
+            .iter()
+            .copied()
+            .filter(|b| !b.is_ascii_whitespace())
+            .collect::<Vec<_>>()
+    };
+
+    match format {
+        Format::Raw => Ok(input),
+        Format::Hex => hex::decode(text()).map_err(|e| Error::InvalidInput(e.to_string())),
+        Format::Base64 => base64::engine::general_purpose::STANDARD
+            .decode(text())
+            .map_err(|e| Error::InvalidInput(e.to_string())),
+    }
+}
+
+/// Encodes the output; hex and base64 end with a line break.
+pub fn encode(format: Format, output: Vec<u8>) -> Vec<u8> {
+    let text = match format {
+        Format::Raw => return output,
+        Format::Hex => hex::encode(output),
+        Format::Base64 => base64::engine::general_purpose::STANDARD.encode(output),
+    };
+
+    let mut text = text.into_bytes();
+    text.push(b'\n');
+    text
+}
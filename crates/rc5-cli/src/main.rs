@@ -0,0 +1,132 @@
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::Parser;
+
+mod args;
+mod crypt;
+mod error;
+mod format;
+
+use args::{Cli, Command, KeySource, Mode, Options};
+use crypt::{Direction, Params};
+use error::Error;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("rc5: {}", error);
+            ExitCode::from(error.exit_code())
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<(), Error> {
+    let (direction, options) = match cli.command {
+        Command::Encrypt(options) => (Direction::Encrypt, options),
+        Command::Decrypt(options) => (Direction::Decrypt, options),
+    };
+
+    let key = load_key(&options.key)?;
+    if let Some(key_bytes) = options.key_bytes {
+        if key.len() != key_bytes as usize {
+            return Err(Error::Usage(format!(
+                "-b {} given, but the key is {} bytes",
+                key_bytes,
+                key.len()
+            )));
+        }
+    }
+
+    let params = params(direction, &options)?;
+
+    let input = read_input(options.input.as_deref())?;
+    let input = format::decode(options.input_format, input)?;
+
+    let output = crypt::run(&params, &key, input)?;
+
+    write_output(
+        options.output.as_deref(),
+        &format::encode(options.output_format, output),
+    )
+}
+
+fn params(direction: Direction, options: &Options) -> Result<Params, Error> {
+    if options.aad.is_some() && options.mode != Mode::Eax {
+        return Err(Error::Usage("--aad is only used with EAX".into()));
+    }
+
+    Ok(Params {
+        direction,
+        word_size: options.word_size,
+        rounds: options.rounds,
+        mode: options.mode,
+        iv: options
+            .iv
+            .as_deref()
+            .map(|iv| parse_hex("--iv", iv))
+            .transpose()?,
+        aad: options
+            .aad
+            .as_deref()
+            .map(|aad| parse_hex("--aad", aad))
+            .transpose()?
+            .unwrap_or_default(),
+    })
+}
+
+fn load_key(source: &KeySource) -> Result<Vec<u8>, Error> {
+    if let Some(key) = &source.key {
+        parse_hex("--key", key)
+    } else if let Some(path) = &source.key_file {
+        std::fs::read(path).map_err(Error::io(path))
+    } else if let Some(var) = &source.key_env {
+        let key =
+            std::env::var(var).map_err(|e| Error::Usage(format!("--key-env {}: {}", var, e)))?;
+        parse_hex(&format!("--key-env {}", var), key.trim())
+    } else {
+        unreachable!("clap requires one of the key sources")
+    }
+}
+
+fn parse_hex(what: &str, value: &str) -> Result<Vec<u8>, Error> {
+    hex::decode(value).map_err(|e| Error::Usage(format!("{}: {}", what, e)))
+}
+
+fn is_std_stream(path: &Path) -> bool {
+    path == Path::new("-")
+}
+
+fn read_input(path: Option<&Path>) -> Result<Vec<u8>, Error> {
+    let mut input = Vec::new();
+
+    match path {
+        Some(path) if !is_std_stream(path) => {
+            input = std::fs::read(path).map_err(Error::io(path))?;
+        }
+        _ => {
+            std::io::stdin()
+                .read_to_end(&mut input)
+                .map_err(Error::io("<stdin>"))?;
+        }
+    }
+
+    Ok(input)
+}
+
+fn write_output(path: Option<&Path>, output: &[u8]) -> Result<(), Error> {
+    match path {
+        Some(path) if !is_std_stream(path) => std::fs::write(path, output).map_err(Error::io(path)),
+        _ => {
+            let mut stdout = std::io::stdout().lock();
+            stdout
+                .write_all(output)
+                .and_then(|()| stdout.flush())
+                .map_err(Error::io(PathBuf::from("<stdout>")))
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+const KEY: &str = "000102030405060708090a0b0c0d0e0f";
+
+fn rc5(args: &[&str], stdin: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rc5"))
+        .args(args)
+        .env_remove("RC5_TEST_KEY")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("spawn rc5");
+    child.stdin.take().unwrap().write_all(stdin).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn ok(args: &[&str], stdin: &[u8]) -> Vec<u8> {
+    let output = rc5(args, stdin);
+    assert!(
+        output.status.success(),
+        "rc5 {:?}: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    output.stdout
+}
+
+fn exit_code(args: &[&str], stdin: &[u8]) -> i32 {
+    rc5(args, stdin).status.code().unwrap()
+}
+
+fn temp_file(name: &str, contents: &[u8]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("rc5-cli-{}-{}", std::process::id(), name));
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn ecb_known_answer() {
+    let out = ok(
+        &[
+            "encrypt",
+            "-w",
+            "32",
+            "-r",
+            "12",
+            "-m",
+            "ecb",
+            "-k",
+            KEY,
+            "--input-format",
+            "hex",
+            "--output-format",
+            "hex",
+        ],
+        b"0011223344556677\n",
+    );
+    let out = String::from_utf8(out).unwrap();
+
+    assert!(out.starts_with("2ddc149bcf088b9e"), "{}", out);
+    assert_eq!(out.trim_end().len(), 32);
+}
+
+#[test]
+fn round_trip_every_mode_and_word_size() {
+    let plaintext: Vec<u8> = (0..=200).collect();
+
+    for w in ["16", "32", "64"] {
+        for m in ["ecb", "cbc-pad", "ctr", "eax"] {
+            if w == "16" && (m == "ctr" || m == "eax") {
+                continue;
+            }
+            let args = |cmd| vec![cmd, "-w", w, "-r", "16", "-m", m, "-k", KEY];
+
+            let ciphertext = ok(&args("encrypt"), &plaintext);
+            assert_ne!(ciphertext, plaintext, "w={} m={}", w, m);
+            assert_eq!(
+                ok(&args("decrypt"), &ciphertext),
+                plaintext,
+                "w={} m={}",
+                w,
+                m
+            );
+        }
+    }
+}
+
+#[test]
+fn explicit_iv_and_aad() {
+    let enc = [
+        "encrypt",
+        "-m",
+        "eax",
+        "-k",
+        KEY,
+        "--iv",
+        "0001020304050607",
+        "--aad",
+        "abcd",
+    ];
+    let dec = [
+        "decrypt",
+        "-m",
+        "eax",
+        "-k",
+        KEY,
+        "--iv",
+        "0001020304050607",
+        "--aad",
+        "abcd",
+    ];
+
+    let ciphertext = ok(&enc, b"hello");
+    assert_eq!(ciphertext.len(), 5 + 8);
+    assert_eq!(ciphertext, ok(&enc, b"hello"));
+    assert_eq!(ok(&dec, &ciphertext), b"hello");
+}
+
+#[test]
+fn text_formats() {
+    for format in ["hex", "base64"] {
+        let enc = [
+            "encrypt",
+            "-m",
+            "ctr",
+            "-k",
+            KEY,
+            "--iv",
+            "0000000000000000",
+            "--output-format",
+            format,
+        ];
+        let dec = [
+            "decrypt",
+            "-m",
+            "ctr",
+            "-k",
+            KEY,
+            "--iv",
+            "0000000000000000",
+            "--input-format",
+            format,
+        ];
+
+        let text = ok(&enc, b"some plaintext");
+        assert!(text.ends_with(b"\n"));
+        assert_eq!(ok(&dec, &text), b"some plaintext");
+    }
+}
+
+#[test]
+fn files_and_key_sources() {
+    let key: Vec<u8> = hex::decode(KEY).unwrap();
+    let key_file = temp_file("key", &key);
+    let input = temp_file("input", b"file contents");
+    let output = std::env::temp_dir().join(format!("rc5-cli-{}-output", std::process::id()));
+
+    let by_key = ok(&["encrypt", "-m", "ecb", "-k", KEY], b"file contents");
+
+    ok(
+        &[
+            "encrypt",
+            "-m",
+            "ecb",
+            "--key-file",
+            key_file.to_str().unwrap(),
+            "-i",
+            input.to_str().unwrap(),
+            "-o",
+            output.to_str().unwrap(),
+        ],
+        b"",
+    );
+    assert_eq!(std::fs::read(&output).unwrap(), by_key);
+
+    let by_env = Command::new(env!("CARGO_BIN_EXE_rc5"))
+        .args(["encrypt", "-m", "ecb", "--key-env", "RC5_TEST_KEY", "-i"])
+        .arg(&input)
+        .env("RC5_TEST_KEY", KEY)
+        .output()
+        .unwrap();
+    assert!(by_env.status.success());
+    assert_eq!(by_env.stdout, by_key);
+
+    for path in [key_file, input, output] {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+#[test]
+fn exit_codes() {
+    let ciphertext = ok(&["encrypt", "-m", "eax", "-k", KEY], b"secret");
+
+    let mut tampered = ciphertext.clone();
+    *tampered.last_mut().unwrap() ^= 1;
+    assert_eq!(
+        exit_code(&["decrypt", "-m", "eax", "-k", KEY], &tampered),
+        1
+    );
+    assert_eq!(
+        exit_code(&["decrypt", "-m", "eax", "-k", "00"], &ciphertext),
+        1
+    );
+
+    assert_eq!(
+        exit_code(&["encrypt", "-w", "16", "-m", "ctr", "-k", KEY], b""),
+        2
+    );
+    assert_eq!(exit_code(&["encrypt", "-b", "8", "-k", KEY], b""), 2);
+    assert_eq!(exit_code(&["encrypt", "--key-env", "RC5_TEST_KEY"], b""), 2);
+    assert_eq!(exit_code(&["encrypt"], b""), 2);
+
+    assert_eq!(
+        exit_code(&["decrypt", "-k", KEY, "--input-format", "hex"], b"not hex"),
+        3
+    );
+
+    assert_eq!(
+        exit_code(&["encrypt", "-k", KEY, "-i", "/nonexistent/rc5-input"], b""),
+        4
+    );
+}
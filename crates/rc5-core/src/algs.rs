@@ -37,6 +37,35 @@ pub fn expand_key<Word, R, B, M, WBR, A>(
     key_table::s_table_mix_secret_key::<Word, B, R, A, M>(l_table, s_table);
 }
 
+/// [`expand_key`] with the key length and the number of rounds known at runtime only.
+///
+/// `l_table` must hold `max(1, ceil(b / u))` words and `s_table` `2(r + 1)` words,
+/// `b` being `key_bytes.len()` and `u` the word's length in bytes.
+pub fn expand_key_slices<Word, M, WBR, A>(
+    key_bytes: &[u8],
+    l_table: &mut [Word],
+    s_table: &mut [Word],
+) where
+    Word: WordSize + Default + Copy,
+    WBR: WordByteRepr<Word>,
+    A: Arithmetics<Word>,
+    M: Magic<Word>,
+{
+    assert_eq!(
+        l_table.len(),
+        key_bytes.len().div_ceil(Word::ByteLen::USIZE).max(1),
+        "l_table length"
+    );
+    assert!(
+        s_table.len() >= 2 && s_table.len().is_multiple_of(2),
+        "s_table length"
+    );
+
+    key_table::l_table_init_slice::<Word, WBR>(key_bytes, l_table);
+    key_table::s_table_init_slice::<Word, M, A>(s_table);
+    key_table::s_table_mix_secret_key_slices::<Word, A, M>(l_table, s_table);
+}
+
 pub fn encrypt<Word, R, A>(s_table: &KeySTable<R, Word>, reg_a: &mut Word, reg_b: &mut Word)
 where
     Sum<R, typenum::U1>: Mul<typenum::U2>,
@@ -44,10 +73,20 @@ where
     KeySTableSize<R>: ArrayLength<Word>,
     A: Arithmetics<Word>,
 {
+    encrypt_slice::<Word, A>(s_table.as_slice(), reg_a, reg_b)
+}
+
+/// [`encrypt`] with an `s_table` of `2(r + 1)` words, `r` known at runtime only.
+pub fn encrypt_slice<Word, A>(s_table: &[Word], reg_a: &mut Word, reg_b: &mut Word)
+where
+    A: Arithmetics<Word>,
+{
+    let rounds = s_table.len() / 2 - 1;
+
     *reg_a = A::add(reg_a, &s_table[0]);
     *reg_b = A::add(reg_b, &s_table[1]);
 
-    for i in 1..=R::USIZE {
+    for i in 1..=rounds {
         *reg_a = A::xor(reg_a, reg_b);
         *reg_a = A::rotl(reg_a, reg_b);
         *reg_a = A::add(reg_a, &s_table[2 * i]);
@@ -58,6 +97,55 @@ where
     }
 }
 
+/// The first `half_rounds` half-rounds of [`encrypt_slice`], for the analysis of reduced-round RC5.
+///
+/// After `S[0]` and `S[1]` are added, half-round `h` updates `A` if `h` is odd and `B` if it is even, adding
+/// `S[h + 1]`; `2r` half-rounds are [`encrypt_slice`].
+pub fn encrypt_half_rounds_slice<Word, A>(
+    s_table: &[Word],
+    half_rounds: usize,
+    reg_a: &mut Word,
+    reg_b: &mut Word,
+) where
+    A: Arithmetics<Word>,
+{
+    encrypt_traced_slice::<Word, A>(s_table, half_rounds, reg_a, reg_b, |_, _, _| {})
+}
+
+/// [`encrypt_half_rounds_slice`], calling `trace` with the registers after the key addition (half-round 0) and
+/// after each half-round.
+pub fn encrypt_traced_slice<Word, A>(
+    s_table: &[Word],
+    half_rounds: usize,
+    reg_a: &mut Word,
+    reg_b: &mut Word,
+    mut trace: impl FnMut(usize, &Word, &Word),
+) where
+    A: Arithmetics<Word>,
+{
+    assert!(
+        half_rounds + 2 <= s_table.len(),
+        "half_rounds beyond s_table"
+    );
+
+    *reg_a = A::add(reg_a, &s_table[0]);
+    *reg_b = A::add(reg_b, &s_table[1]);
+    trace(0, reg_a, reg_b);
+
+    for h in 1..=half_rounds {
+        if h % 2 == 1 {
+            *reg_a = A::xor(reg_a, reg_b);
+            *reg_a = A::rotl(reg_a, reg_b);
+            *reg_a = A::add(reg_a, &s_table[h + 1]);
+        } else {
+            *reg_b = A::xor(reg_b, reg_a);
+            *reg_b = A::rotl(reg_b, reg_a);
+            *reg_b = A::add(reg_b, &s_table[h + 1]);
+        }
+        trace(h, reg_a, reg_b);
+    }
+}
+
 pub fn decrypt<Word, R, A>(s_table: &KeySTable<R, Word>, reg_a: &mut Word, reg_b: &mut Word)
 where
     Sum<R, typenum::U1>: Mul<typenum::U2>,
@@ -65,7 +153,17 @@ where
     KeySTableSize<R>: ArrayLength<Word>,
     A: Arithmetics<Word>,
 {
-    for i in (1..=R::USIZE).rev() {
+    decrypt_slice::<Word, A>(s_table.as_slice(), reg_a, reg_b)
+}
+
+/// [`decrypt`] with an `s_table` of `2(r + 1)` words, `r` known at runtime only.
+pub fn decrypt_slice<Word, A>(s_table: &[Word], reg_a: &mut Word, reg_b: &mut Word)
+where
+    A: Arithmetics<Word>,
+{
+    let rounds = s_table.len() / 2 - 1;
+
+    for i in (1..=rounds).rev() {
         *reg_b = A::sub(reg_b, &s_table[2 * i + 1]);
         *reg_b = A::rotr(reg_b, reg_a);
         *reg_b = A::xor(reg_b, reg_a);
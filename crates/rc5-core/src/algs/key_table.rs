@@ -20,17 +20,27 @@ where
     Word: WordSize,
     WBR: WordByteRepr<Word>,
 {
-    let key_bytes = key.as_slice();
-    let key_words = l_table.as_mut_slice();
+    l_table_init_slice::<Word, WBR>(key.as_slice(), l_table.as_mut_slice())
+}
 
+/// Fills `key_words` with `key_bytes`; `key_words` holds `max(1, ceil(b / u))` words.
+///
+/// The key is zero-padded to whole words, each read with `WBR` as a block word is. With [`LittleEndian`] (and
+/// [`StrangeByteRepr`], little-endian too) the bytes of a partial last word fill its low end, as the paper's
+/// `L[i/u] = (L[i/u] <<< 8) + K[i]` has it; with [`BigEndian`] they fill its high end.
+///
+/// [`LittleEndian`]: crate::std_words::LittleEndian
+/// [`BigEndian`]: crate::std_words::BigEndian
+/// [`StrangeByteRepr`]: crate::strange_words::StrangeByteRepr
+pub fn l_table_init_slice<Word, WBR>(key_bytes: &[u8], key_words: &mut [Word])
+where
+    Word: WordSize,
+    WBR: WordByteRepr<Word>,
+{
     for (dst_word, src_bytes) in key_words.iter_mut().zip(key_bytes.chunks(Word::ByteLen::USIZE)) {
         let mut w_bytes = WordBytes::<Word>::default();
 
-        for (dst_byte, src_byte) in
-            w_bytes.as_mut_slice().iter_mut().rev().zip(src_bytes.iter().rev())
-        {
-            *dst_byte = *src_byte;
-        }
+        w_bytes.as_mut_slice()[..src_bytes.len()].copy_from_slice(src_bytes);
 
         *dst_word = WBR::from_bytes(&w_bytes);
     }
@@ -43,9 +53,18 @@ where
     KeySTableSize<R>: ArrayLength<Word>,
     Sum<R, typenum::U1>: Mul<typenum::U2>,
     R: Add<typenum::U1>,
+{
+    s_table_init_slice::<Word, M, A>(s_table.as_mut_slice())
+}
+
+/// Initialises `s_table` of any length `t = 2(r + 1)` with the magic constants.
+pub fn s_table_init_slice<Word, M, A>(s_table: &mut [Word])
+where
+    A: Arithmetics<Word>,
+    M: Magic<Word>,
 {
     s_table[0] = M::P;
-    for i in 1..KeySTableSize::<R>::USIZE {
+    for i in 1..s_table.len() {
         s_table[i] = A::add(&s_table[i - 1], &M::Q);
     }
 }
@@ -68,14 +87,24 @@ pub fn s_table_mix_secret_key<Word, B, R, A, M>(
     Word: WordSize + Default + Copy,
     A: Arithmetics<Word>,
     M: Magic<Word>,
+{
+    s_table_mix_secret_key_slices::<Word, A, M>(l_table.as_mut_slice(), s_table.as_mut_slice())
+}
+
+/// Mixes the secret key in `l_table` into `s_table`, both of any length.
+pub fn s_table_mix_secret_key_slices<Word, A, M>(l_table: &mut [Word], s_table: &mut [Word])
+where
+    Word: Default + Copy,
+    A: Arithmetics<Word>,
+    M: Magic<Word>,
 {
     let mut i: usize = 0;
     let mut j: usize = 0;
     let mut a: Word = Default::default();
     let mut b: Word = Default::default();
 
-    let t = KeySTableSize::<R>::USIZE;
-    let c = KeyLTableSize::<B, Word::ByteLen>::USIZE;
+    let t = s_table.len();
+    let c = l_table.len();
     for _ in 0..(3 * t.max(c)) {
         a = A::rotl(&A::add(&s_table[i], &A::add(&a, &b)), &M::THREE);
         s_table[i] = a;
@@ -105,6 +134,25 @@ fn test_key_bytes_to_words_01() {
     assert_eq!(key_words.as_slice(), &[0x01010101u32; 4]);
 }
 
+#[test]
+fn test_key_bytes_to_words_partial() {
+    use crate::std_words::{BigEndian, LittleEndian};
+    use crate::strange_words::{StrangeByteRepr, W24bit};
+
+    let key_bytes = &[0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+
+    let mut key_words = [0u32; 2];
+    l_table_init_slice::<u32, LittleEndian>(&key_bytes[..5], &mut key_words);
+    assert_eq!(key_words, [0x04030201, 0x00000005]);
+
+    l_table_init_slice::<u32, BigEndian>(&key_bytes[..5], &mut key_words);
+    assert_eq!(key_words, [0x01020304, 0x05000000]);
+
+    let mut key_words = [W24bit::default(); 2];
+    l_table_init_slice::<W24bit, StrangeByteRepr>(&key_bytes[..4], &mut key_words);
+    assert_eq!(key_words, [W24bit::from(0x030201), W24bit::from(0x000004)]);
+}
+
 #[test]
 fn test_s_table_init() {
     use crate::std_words::StdArith;
@@ -4,6 +4,7 @@ use crate::traits::WordByteRepr;
 use crate::traits::WordSize;
 
 pub enum LittleEndian {}
+pub enum BigEndian {}
 pub enum StdMagic {}
 
 pub enum StdArith {}
@@ -23,6 +24,15 @@ macro_rules! impl_word {
             }
         }
 
+        impl WordByteRepr<$W> for BigEndian {
+            fn from_bytes(b: &crate::traits::WordBytes<$W>) -> $W {
+                <$W>::from_be_bytes(*b.as_ref())
+            }
+            fn to_bytes(w: &$W) -> crate::traits::WordBytes<$W> {
+                w.to_be_bytes().into()
+            }
+        }
+
         impl Magic<$W> for StdMagic {
             const THREE: $W = 3;
 
@@ -2,3 +2,10 @@ mod strange_arithmetic;
 
 mod rivest97;
 mod rivest97_strange_arithmetic;
+
+mod partial_key;
+mod slices;
+
+mod reference;
+
+mod half_rounds;
@@ -0,0 +1,65 @@
+use crate::algs::{
+    encrypt_half_rounds_slice, encrypt_slice, encrypt_traced_slice, expand_key_slices,
+};
+use crate::std_words::{LittleEndian, StdArith, StdMagic};
+use std::vec::Vec;
+
+fn s_table(rounds: usize) -> Vec<u32> {
+    let key: Vec<u8> = (0..16).collect();
+    let mut l_table = [0u32; 4];
+    let mut s_table = vec![0u32; 2 * (rounds + 1)];
+    expand_key_slices::<u32, StdMagic, LittleEndian, StdArith>(&key, &mut l_table, &mut s_table);
+    s_table
+}
+
+#[test]
+fn all_half_rounds_are_encryption() {
+    for rounds in [0, 1, 12, 20] {
+        let s_table = s_table(rounds);
+        let (mut a, mut b) = (0x33221100, 0x77665544);
+        let (mut c, mut d) = (a, b);
+        encrypt_slice::<u32, StdArith>(&s_table, &mut a, &mut b);
+        encrypt_half_rounds_slice::<u32, StdArith>(&s_table, 2 * rounds, &mut c, &mut d);
+        assert_eq!((a, b), (c, d));
+    }
+}
+
+#[test]
+fn trace() {
+    let s_table = s_table(12);
+    let mut trace = Vec::new();
+    let (mut a, mut b) = (0x33221100u32, 0x77665544u32);
+    encrypt_traced_slice::<u32, StdArith>(&s_table, 7, &mut a, &mut b, |h, a, b| {
+        trace.push((h, *a, *b))
+    });
+    assert_eq!(trace.len(), 8);
+    assert_eq!(trace[7], (7, a, b));
+    assert_eq!(
+        trace[0],
+        (
+            0,
+            0x33221100u32.wrapping_add(s_table[0]),
+            0x77665544u32.wrapping_add(s_table[1])
+        )
+    );
+
+    for (h, a, b) in trace {
+        let (mut c, mut d) = (0x33221100, 0x77665544);
+        encrypt_half_rounds_slice::<u32, StdArith>(&s_table, h, &mut c, &mut d);
+        assert_eq!((a, b), (c, d), "half-round {}", h);
+        // an odd half-round leaves B alone, an even one A
+        if h > 0 {
+            let (mut e, mut f) = (0x33221100, 0x77665544);
+            encrypt_half_rounds_slice::<u32, StdArith>(&s_table, h - 1, &mut e, &mut f);
+            assert_eq!(h % 2 == 1, f == d);
+            assert_eq!(h % 2 == 0, e == c);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "half_rounds beyond s_table")]
+fn too_many_half_rounds() {
+    let (mut a, mut b) = (0, 0);
+    encrypt_half_rounds_slice::<u32, StdArith>(&s_table(2), 5, &mut a, &mut b);
+}
@@ -29,7 +29,7 @@ fn render_text(reg_a: Word, reg_b: Word) -> String {
     let mut text = [0u8; <Word as WordSize>::ByteLen::USIZE * 2];
     text[0..4].copy_from_slice(ByteRepr::to_bytes(&reg_a).as_slice());
     text[4..].copy_from_slice(ByteRepr::to_bytes(&reg_b).as_slice());
-    hex::encode(&text)
+    hex::encode(text)
 }
 
 #[test]
@@ -0,0 +1,124 @@
+//! The slice API, [`crate::algs::expand_key_slices`], [`crate::algs::encrypt_slice`] and
+//! [`crate::algs::decrypt_slice`], against Rivest's vectors and against the typed API.
+
+use core::fmt::Debug;
+use core::ops::{Add, Div, Mul, Sub};
+use generic_array::ArrayLength;
+use std::vec;
+use typenum::{Diff, Max, Quot, Sum, Unsigned};
+
+use crate::std_words::{LittleEndian, StdArith, StdMagic};
+use crate::traits::{
+    KeyBytes, KeyLTable, KeyLTableSize, KeySTable, KeySTableSize, WordByteRepr, WordBytes,
+};
+
+fn words(text: &[u8]) -> (u32, u32) {
+    (
+        LittleEndian::from_bytes(WordBytes::<u32>::from_slice(&text[..4])),
+        LittleEndian::from_bytes(WordBytes::<u32>::from_slice(&text[4..])),
+    )
+}
+
+fn run_case(key_hex: &str, plaintext_hex: &str, ciphertext_hex: &str) {
+    let key = hex::decode(key_hex).unwrap();
+    let (mut reg_a, mut reg_b) = words(&hex::decode(plaintext_hex).unwrap());
+    let ciphertext = words(&hex::decode(ciphertext_hex).unwrap());
+
+    let mut l_table = [0u32; 4];
+    let mut s_table = [0u32; 26];
+    crate::algs::expand_key_slices::<u32, StdMagic, LittleEndian, StdArith>(
+        &key,
+        &mut l_table,
+        &mut s_table,
+    );
+
+    let plaintext = (reg_a, reg_b);
+    crate::algs::encrypt_slice::<u32, StdArith>(&s_table, &mut reg_a, &mut reg_b);
+    assert_eq!((reg_a, reg_b), ciphertext);
+
+    crate::algs::decrypt_slice::<u32, StdArith>(&s_table, &mut reg_a, &mut reg_b);
+    assert_eq!((reg_a, reg_b), plaintext);
+}
+
+#[test]
+fn rivest97() {
+    run_case(
+        "00000000000000000000000000000000",
+        "0000000000000000",
+        "21a5dbee154b8f6d",
+    );
+    run_case(
+        "915f4619be41b2516355a50110a9ce91",
+        "21a5dbee154b8f6d",
+        "f7c013ac5b2b8952",
+    );
+    run_case(
+        "783348e75aeb0f2fd7b169bb8dc16787",
+        "f7c013ac5b2b8952",
+        "2f42b3b70369fc92",
+    );
+    run_case(
+        "dc49db1375a5584f6485b413b5f12baf",
+        "2f42b3b70369fc92",
+        "65c178b284d197cc",
+    );
+    run_case(
+        "5269f149d41ba0152497574d7f153125",
+        "65c178b284d197cc",
+        "eb44e415da319824",
+    );
+}
+
+/// The same S table and ciphertext as the typed API with `R` rounds and `B`-byte keys.
+fn matches_typed<Word, R, B>(key: &[u8], block: (Word, Word))
+where
+    Word: crate::traits::WordSize + Default + Copy + PartialEq + Debug,
+    LittleEndian: WordByteRepr<Word>,
+    StdArith: crate::traits::Arithmetics<Word>,
+    StdMagic: crate::traits::Magic<Word>,
+
+    B: ArrayLength<u8> + Add<Word::ByteLen>,
+    Sum<B, Word::ByteLen>: Sub<typenum::U1>,
+    Diff<Sum<B, Word::ByteLen>, typenum::U1>: Div<Word::ByteLen>,
+    Quot<Diff<Sum<B, Word::ByteLen>, typenum::U1>, Word::ByteLen>: Max<typenum::U1>,
+    KeyLTableSize<B, Word::ByteLen>: ArrayLength<Word>,
+
+    R: Add<typenum::U1> + Unsigned,
+    Sum<R, typenum::U1>: Mul<typenum::U2>,
+    KeySTableSize<R>: ArrayLength<Word>,
+{
+    let mut l_table = KeyLTable::<B, Word>::default();
+    let mut s_table = KeySTable::<R, Word>::default();
+    crate::algs::expand_key::<Word, R, B, StdMagic, LittleEndian, StdArith>(
+        KeyBytes::<B>::from_slice(key),
+        &mut l_table,
+        &mut s_table,
+    );
+
+    let mut l_slice = vec![Word::default(); KeyLTableSize::<B, Word::ByteLen>::USIZE];
+    let mut s_slice = vec![Word::default(); KeySTableSize::<R>::USIZE];
+    crate::algs::expand_key_slices::<Word, StdMagic, LittleEndian, StdArith>(
+        key,
+        &mut l_slice,
+        &mut s_slice,
+    );
+    assert_eq!(s_slice, s_table.as_slice());
+
+    let (mut typed_a, mut typed_b) = block;
+    let (mut slice_a, mut slice_b) = block;
+    crate::algs::encrypt::<Word, R, StdArith>(&s_table, &mut typed_a, &mut typed_b);
+    crate::algs::encrypt_slice::<Word, StdArith>(&s_slice, &mut slice_a, &mut slice_b);
+    assert_eq!((slice_a, slice_b), (typed_a, typed_b));
+
+    crate::algs::decrypt_slice::<Word, StdArith>(&s_slice, &mut slice_a, &mut slice_b);
+    assert_eq!((slice_a, slice_b), block);
+}
+
+#[test]
+fn typed_parameters() {
+    matches_typed::<u32, typenum::U0, typenum::U0>(&[], (0x01234567, 0x89abcdef));
+    matches_typed::<u32, typenum::U1, typenum::U8>(&[7; 8], (1, 2));
+    matches_typed::<u16, typenum::U16, typenum::U2>(&[0xfe, 0xdc], (0x1234, 0x5678));
+    matches_typed::<u64, typenum::U20, typenum::U24>(&[0x5a; 24], (u64::MAX, 0));
+    matches_typed::<u128, typenum::U28, typenum::U32>(&[0xa5; 32], (3, u128::MAX - 3));
+}
@@ -5,31 +5,31 @@ use typenum::{Max, Unsigned};
 use super::*;
 
 #[derive(Default, Clone, Copy)]
-struct W1Byte(u8);
+struct W1Byte(#[allow(dead_code)] u8);
 impl WordSize for W1Byte {
     type ByteLen = typenum::U1;
 }
 
 #[derive(Default, Clone, Copy)]
-struct W2Byte(u16);
+struct W2Byte(#[allow(dead_code)] u16);
 impl WordSize for W2Byte {
     type ByteLen = typenum::U2;
 }
 
 #[derive(Default, Clone, Copy)]
-struct W4Byte(u32);
+struct W4Byte(#[allow(dead_code)] u32);
 impl WordSize for W4Byte {
     type ByteLen = typenum::U4;
 }
 
 #[derive(Default, Clone, Copy)]
-struct W8Byte(u64);
+struct W8Byte(#[allow(dead_code)] u64);
 impl WordSize for W8Byte {
     type ByteLen = typenum::U8;
 }
 
 #[derive(Default, Clone, Copy)]
-struct W16Byte(u128);
+struct W16Byte(#[allow(dead_code)] u128);
 impl WordSize for W16Byte {
     type ByteLen = typenum::U16;
 }
@@ -0,0 +1,155 @@
+#ifndef RC5_H
+#define RC5_H
+
+/* Generated by cbindgen from src/lib.rs: edit the Rust source and run the tests with RC5_FFI_UPDATE_HEADER=1. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum Rc5Status {
+  RC5_STATUS_OK = 0,
+  /**
+   * A pointer that must not be null is.
+   */
+  RC5_STATUS_NULL_POINTER,
+  /**
+   * The word size is not 8, 16, 32, 64 or 128 bits.
+   */
+  RC5_STATUS_INVALID_WORD_SIZE,
+  /**
+   * The number of rounds is above 255.
+   */
+  RC5_STATUS_INVALID_ROUNDS,
+  /**
+   * The key is longer than 255 bytes.
+   */
+  RC5_STATUS_INVALID_KEY_LENGTH,
+  /**
+   * The length of an ECB or CBC buffer is not a multiple of the block size.
+   */
+  RC5_STATUS_INVALID_LENGTH,
+  /**
+   * The IV is not one block long.
+   */
+  RC5_STATUS_INVALID_IV_LENGTH,
+  /**
+   * CTR needs a block of at least 64 bits: a word size of 32 bits or more.
+   */
+  RC5_STATUS_UNSUPPORTED_MODE,
+} Rc5Status;
+
+/**
+ * An expanded key; opaque to C.
+ */
+typedef struct Rc5Context Rc5Context;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Creates a context for RC5 with `word_size`-bit words and `rounds` rounds, expanding the `key_len`-byte `key`.
+ *
+ * Returns null on failure; see `rc5_last_error`.
+ */
+struct Rc5Context *rc5_context_new(unsigned int word_size,
+                                   unsigned int rounds,
+                                   const uint8_t *key,
+                                   size_t key_len);
+
+/**
+ * Zeroizes and frees a context; `ctx` may be null.
+ */
+void rc5_context_free(struct Rc5Context *ctx);
+
+/**
+ * The block size of the context in bytes, twice the word size; 0 if `ctx` is null.
+ */
+size_t rc5_block_size(const struct Rc5Context *ctx);
+
+/**
+ * Encrypts one block from `input` to `output`.
+ */
+enum Rc5Status rc5_encrypt_block(const struct Rc5Context *ctx,
+                                 const uint8_t *input,
+                                 uint8_t *output);
+
+/**
+ * Decrypts one block from `input` to `output`.
+ */
+enum Rc5Status rc5_decrypt_block(const struct Rc5Context *ctx,
+                                 const uint8_t *input,
+                                 uint8_t *output);
+
+/**
+ * Encrypts `len` bytes, a multiple of the block size, in ECB mode.
+ */
+enum Rc5Status rc5_ecb_encrypt(const struct Rc5Context *ctx,
+                               const uint8_t *input,
+                               uint8_t *output,
+                               size_t len);
+
+/**
+ * Decrypts `len` bytes, a multiple of the block size, in ECB mode.
+ */
+enum Rc5Status rc5_ecb_decrypt(const struct Rc5Context *ctx,
+                               const uint8_t *input,
+                               uint8_t *output,
+                               size_t len);
+
+/**
+ * Encrypts `len` bytes, a multiple of the block size, in CBC mode with a one-block IV.
+ */
+enum Rc5Status rc5_cbc_encrypt(const struct Rc5Context *ctx,
+                               const uint8_t *iv,
+                               size_t iv_len,
+                               const uint8_t *input,
+                               uint8_t *output,
+                               size_t len);
+
+/**
+ * Decrypts `len` bytes, a multiple of the block size, in CBC mode with a one-block IV.
+ */
+enum Rc5Status rc5_cbc_decrypt(const struct Rc5Context *ctx,
+                               const uint8_t *iv,
+                               size_t iv_len,
+                               const uint8_t *input,
+                               uint8_t *output,
+                               size_t len);
+
+/**
+ * Encrypts `len` bytes in CTR mode, the IV being the first counter block; the counter is the 64-bit big-endian
+ * number in its rightmost bytes.
+ */
+enum Rc5Status rc5_ctr_encrypt(const struct Rc5Context *ctx,
+                               const uint8_t *iv,
+                               size_t iv_len,
+                               const uint8_t *input,
+                               uint8_t *output,
+                               size_t len);
+
+/**
+ * Decrypts `len` bytes in CTR mode, which is the same as encrypting them.
+ */
+enum Rc5Status rc5_ctr_decrypt(const struct Rc5Context *ctx,
+                               const uint8_t *iv,
+                               size_t iv_len,
+                               const uint8_t *input,
+                               uint8_t *output,
+                               size_t len);
+
+/**
+ * A description of the last failure on the calling thread, or null if there was none.
+ *
+ * The string is valid until the next failure on the thread.
+ */
+const char *rc5_last_error(void);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* RC5_H */
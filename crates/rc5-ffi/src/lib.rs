@@ -0,0 +1,433 @@
+//! A C ABI over RC5-w/r/b, declared in `include/rc5.h`.
+//!
+//! A context holds a key expanded for a word size of 8, 16, 32, 64 or 128 bits and 0 to 255 rounds; it encrypts
+//! and decrypts single blocks, and buffers in ECB, CBC and CTR mode. Every buffer call is a whole message: ECB and
+//! CBC take a whole number of blocks and do not pad, and CTR counts from the IV each time. The output may be the
+//! input itself.
+//!
+//! Functions report failures with an [`Rc5Status`]; [`rc5_last_error`] describes the last one of the thread.
+//! [`rc5_context_free`] overwrites the expanded key with zeroes. The key is expanded on the heap, where the context
+//! keeps it, so no other copy is left behind.
+
+#![allow(clippy::missing_safety_doc)]
+
+use std::cell::RefCell;
+use std::ffi::{c_char, c_uint, CString};
+use std::ptr;
+
+use cipher::generic_array::GenericArray;
+use cipher::{BlockDecrypt, BlockEncrypt};
+use rc5_rustcrypto_api::rc5::{RC5Var, MAX_KEY_LEN, MAX_ROUNDS};
+use zeroize::Zeroize;
+
+/// The length of the counter in the rightmost bytes of a CTR block, as in `rc5_rustcrypto_api::io::Ctr`.
+const CTR_COUNTER_LEN: usize = 8;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rc5Status {
+    Ok = 0,
+    /// A pointer that must not be null is.
+    NullPointer,
+    /// The word size is not 8, 16, 32, 64 or 128 bits.
+    InvalidWordSize,
+    /// The number of rounds is above 255.
+    InvalidRounds,
+    /// The key is longer than 255 bytes.
+    InvalidKeyLength,
+    /// The length of an ECB or CBC buffer is not a multiple of the block size.
+    InvalidLength,
+    /// The IV is not one block long.
+    InvalidIvLength,
+    /// CTR needs a block of at least 64 bits: a word size of 32 bits or more.
+    UnsupportedMode,
+}
+
+struct Error(Rc5Status, String);
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Records the error, if any, as the last one of the thread.
+fn status(result: Result<(), Error>) -> Rc5Status {
+    match result {
+        Ok(()) => Rc5Status::Ok,
+        Err(Error(status, message)) => {
+            let message = CString::new(message).expect("no NUL in error messages");
+            LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+            status
+        }
+    }
+}
+
+enum Cipher {
+    W8(Box<RC5Var<u8>>),
+    W16(Box<RC5Var<u16>>),
+    W32(Box<RC5Var<u32>>),
+    W64(Box<RC5Var<u64>>),
+    W128(Box<RC5Var<u128>>),
+}
+
+/// Dispatches on the word size of a [`Cipher`].
+macro_rules! with_cipher {
+    ($cipher:expr, $c:ident => $body:expr) => {
+        match $cipher {
+            Cipher::W8($c) => $body,
+            Cipher::W16($c) => $body,
+            Cipher::W32($c) => $body,
+            Cipher::W64($c) => $body,
+            Cipher::W128($c) => $body,
+        }
+    };
+}
+
+/// An expanded key; opaque to C.
+pub struct Rc5Context {
+    cipher: Cipher,
+}
+
+impl Rc5Context {
+    fn new(word_size: c_uint, rounds: c_uint, key: &[u8]) -> Result<Self, Error> {
+        let rounds = match u8::try_from(rounds) {
+            Ok(rounds) if rounds as usize <= MAX_ROUNDS => rounds,
+            _ => {
+                return Err(Error(
+                    Rc5Status::InvalidRounds,
+                    format!("{} rounds, at most {} are supported", rounds, MAX_ROUNDS),
+                ))
+            }
+        };
+        if key.len() > MAX_KEY_LEN {
+            return Err(Error(
+                Rc5Status::InvalidKeyLength,
+                format!(
+                    "{}-byte key, at most {} bytes are supported",
+                    key.len(),
+                    MAX_KEY_LEN
+                ),
+            ));
+        }
+
+        // boxed with the expansion of the empty key, which is no secret, then rekeyed where it stays: only the heap
+        // holds the expanded key, for `Drop` to overwrite, and `set_key` overwrites the L table it loads the key into
+        let mut cipher = match word_size {
+            8 => Cipher::W8(Box::new(RC5Var::new(&[], 0).unwrap())),
+            16 => Cipher::W16(Box::new(RC5Var::new(&[], 0).unwrap())),
+            32 => Cipher::W32(Box::new(RC5Var::new(&[], 0).unwrap())),
+            64 => Cipher::W64(Box::new(RC5Var::new(&[], 0).unwrap())),
+            128 => Cipher::W128(Box::new(RC5Var::new(&[], 0).unwrap())),
+            _ => {
+                return Err(Error(
+                    Rc5Status::InvalidWordSize,
+                    format!("{}-bit words, not 8, 16, 32, 64 or 128", word_size),
+                ))
+            }
+        };
+        with_cipher!(&mut cipher, c => c.set_key(key, rounds).unwrap());
+
+        Ok(Self { cipher })
+    }
+
+    fn block_len(&self) -> usize {
+        match self.cipher {
+            Cipher::W8(_) => 2,
+            Cipher::W16(_) => 4,
+            Cipher::W32(_) => 8,
+            Cipher::W64(_) => 16,
+            Cipher::W128(_) => 32,
+        }
+    }
+
+    fn encrypt_block(&self, block: &mut [u8]) {
+        with_cipher!(&self.cipher, c => c.encrypt_block(GenericArray::from_mut_slice(block)))
+    }
+
+    fn decrypt_block(&self, block: &mut [u8]) {
+        with_cipher!(&self.cipher, c => c.decrypt_block(GenericArray::from_mut_slice(block)))
+    }
+
+    fn check_blocks(&self, data: &[u8]) -> Result<(), Error> {
+        if !data.len().is_multiple_of(self.block_len()) {
+            return Err(Error(
+                Rc5Status::InvalidLength,
+                format!(
+                    "{} bytes is not a multiple of the {}-byte block",
+                    data.len(),
+                    self.block_len()
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_iv(&self, iv: &[u8]) -> Result<(), Error> {
+        if iv.len() != self.block_len() {
+            return Err(Error(
+                Rc5Status::InvalidIvLength,
+                format!(
+                    "{}-byte IV, the block is {} bytes",
+                    iv.len(),
+                    self.block_len()
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    fn ecb(&self, encrypt: bool, data: &mut [u8]) -> Result<(), Error> {
+        self.check_blocks(data)?;
+        for block in data.chunks_exact_mut(self.block_len()) {
+            match encrypt {
+                true => self.encrypt_block(block),
+                false => self.decrypt_block(block),
+            }
+        }
+        Ok(())
+    }
+
+    fn cbc(&self, encrypt: bool, iv: &[u8], data: &mut [u8]) -> Result<(), Error> {
+        self.check_iv(iv)?;
+        self.check_blocks(data)?;
+        let mut prev = iv.to_vec();
+        for block in data.chunks_exact_mut(self.block_len()) {
+            if encrypt {
+                xor(block, &prev);
+                self.encrypt_block(block);
+                prev.copy_from_slice(block);
+            } else {
+                let next = block.to_vec();
+                self.decrypt_block(block);
+                xor(block, &prev);
+                prev = next;
+            }
+        }
+        Ok(())
+    }
+
+    /// CTR with a 64-bit big-endian counter in the rightmost bytes of the block.
+    fn ctr(&self, iv: &[u8], data: &mut [u8]) -> Result<(), Error> {
+        if self.block_len() < CTR_COUNTER_LEN {
+            return Err(Error(
+                Rc5Status::UnsupportedMode,
+                "CTR needs a block of at least 64 bits".into(),
+            ));
+        }
+        self.check_iv(iv)?;
+
+        let mut counter = iv.to_vec();
+        let mut keystream = vec![0; self.block_len()];
+        for chunk in data.chunks_mut(self.block_len()) {
+            keystream.copy_from_slice(&counter);
+            self.encrypt_block(&mut keystream);
+            xor(chunk, &keystream);
+
+            let (_, count) = counter.split_at_mut(self.block_len() - CTR_COUNTER_LEN);
+            let next = u64::from_be_bytes(count.try_into().unwrap()).wrapping_add(1);
+            count.copy_from_slice(&next.to_be_bytes());
+        }
+        keystream.zeroize();
+        Ok(())
+    }
+}
+
+impl Drop for Rc5Context {
+    fn drop(&mut self) {
+        with_cipher!(&mut self.cipher, c => c.zeroize())
+    }
+}
+
+fn xor(data: &mut [u8], other: &[u8]) {
+    data.iter_mut().zip(other).for_each(|(a, b)| *a ^= b);
+}
+
+fn null_pointer(name: &str) -> Error {
+    Error(Rc5Status::NullPointer, format!("{} is null", name))
+}
+
+/// `len` bytes at `ptr`, which may only be null if `len` is 0.
+unsafe fn slice<'a>(name: &str, ptr: *const u8, len: usize) -> Result<&'a [u8], Error> {
+    match (ptr.is_null(), len) {
+        (_, 0) => Ok(&[]),
+        (true, _) => Err(null_pointer(name)),
+        (false, len) => Ok(std::slice::from_raw_parts(ptr, len)),
+    }
+}
+
+unsafe fn context<'a>(ctx: *const Rc5Context) -> Result<&'a Rc5Context, Error> {
+    ctx.as_ref().ok_or_else(|| null_pointer("ctx"))
+}
+
+/// Copies `len` bytes from `input` to `output`, which may overlap, and runs `f` on them.
+unsafe fn in_place(
+    input: *const u8,
+    output: *mut u8,
+    len: usize,
+    f: impl FnOnce(&mut [u8]) -> Result<(), Error>,
+) -> Result<(), Error> {
+    if len == 0 {
+        return f(&mut []);
+    }
+    if input.is_null() {
+        return Err(null_pointer("input"));
+    }
+    if output.is_null() {
+        return Err(null_pointer("output"));
+    }
+    ptr::copy(input, output, len);
+    f(std::slice::from_raw_parts_mut(output, len))
+}
+
+/// Creates a context for RC5 with `word_size`-bit words and `rounds` rounds, expanding the `key_len`-byte `key`.
+///
+/// Returns null on failure; see `rc5_last_error`.
+#[no_mangle]
+pub unsafe extern "C" fn rc5_context_new(
+    word_size: c_uint,
+    rounds: c_uint,
+    key: *const u8,
+    key_len: usize,
+) -> *mut Rc5Context {
+    let mut ctx = ptr::null_mut();
+    status(slice("key", key, key_len).and_then(|key| {
+        ctx = Box::into_raw(Box::new(Rc5Context::new(word_size, rounds, key)?));
+        Ok(())
+    }));
+    ctx
+}
+
+/// Zeroizes and frees a context; `ctx` may be null.
+#[no_mangle]
+pub unsafe extern "C" fn rc5_context_free(ctx: *mut Rc5Context) {
+    if !ctx.is_null() {
+        drop(Box::from_raw(ctx));
+    }
+}
+
+/// The block size of the context in bytes, twice the word size; 0 if `ctx` is null.
+#[no_mangle]
+pub unsafe extern "C" fn rc5_block_size(ctx: *const Rc5Context) -> usize {
+    ctx.as_ref().map_or(0, Rc5Context::block_len)
+}
+
+/// Encrypts one block from `input` to `output`.
+#[no_mangle]
+pub unsafe extern "C" fn rc5_encrypt_block(
+    ctx: *const Rc5Context,
+    input: *const u8,
+    output: *mut u8,
+) -> Rc5Status {
+    status(context(ctx).and_then(|ctx| {
+        in_place(input, output, ctx.block_len(), |block| {
+            ctx.encrypt_block(block);
+            Ok(())
+        })
+    }))
+}
+
+/// Decrypts one block from `input` to `output`.
+#[no_mangle]
+pub unsafe extern "C" fn rc5_decrypt_block(
+    ctx: *const Rc5Context,
+    input: *const u8,
+    output: *mut u8,
+) -> Rc5Status {
+    status(context(ctx).and_then(|ctx| {
+        in_place(input, output, ctx.block_len(), |block| {
+            ctx.decrypt_block(block);
+            Ok(())
+        })
+    }))
+}
+
+/// Encrypts `len` bytes, a multiple of the block size, in ECB mode.
+#[no_mangle]
+pub unsafe extern "C" fn rc5_ecb_encrypt(
+    ctx: *const Rc5Context,
+    input: *const u8,
+    output: *mut u8,
+    len: usize,
+) -> Rc5Status {
+    status(context(ctx).and_then(|ctx| in_place(input, output, len, |data| ctx.ecb(true, data))))
+}
+
+/// Decrypts `len` bytes, a multiple of the block size, in ECB mode.
+#[no_mangle]
+pub unsafe extern "C" fn rc5_ecb_decrypt(
+    ctx: *const Rc5Context,
+    input: *const u8,
+    output: *mut u8,
+    len: usize,
+) -> Rc5Status {
+    status(context(ctx).and_then(|ctx| in_place(input, output, len, |data| ctx.ecb(false, data))))
+}
+
+/// Encrypts `len` bytes, a multiple of the block size, in CBC mode with a one-block IV.
+#[no_mangle]
+pub unsafe extern "C" fn rc5_cbc_encrypt(
+    ctx: *const Rc5Context,
+    iv: *const u8,
+    iv_len: usize,
+    input: *const u8,
+    output: *mut u8,
+    len: usize,
+) -> Rc5Status {
+    status(context(ctx).and_then(|ctx| {
+        let iv = slice("iv", iv, iv_len)?;
+        in_place(input, output, len, |data| ctx.cbc(true, iv, data))
+    }))
+}
+
+/// Decrypts `len` bytes, a multiple of the block size, in CBC mode with a one-block IV.
+#[no_mangle]
+pub unsafe extern "C" fn rc5_cbc_decrypt(
+    ctx: *const Rc5Context,
+    iv: *const u8,
+    iv_len: usize,
+    input: *const u8,
+    output: *mut u8,
+    len: usize,
+) -> Rc5Status {
+    status(context(ctx).and_then(|ctx| {
+        let iv = slice("iv", iv, iv_len)?;
+        in_place(input, output, len, |data| ctx.cbc(false, iv, data))
+    }))
+}
+
+/// Encrypts `len` bytes in CTR mode, the IV being the first counter block; the counter is the 64-bit big-endian
+/// number in its rightmost bytes.
+#[no_mangle]
+pub unsafe extern "C" fn rc5_ctr_encrypt(
+    ctx: *const Rc5Context,
+    iv: *const u8,
+    iv_len: usize,
+    input: *const u8,
+    output: *mut u8,
+    len: usize,
+) -> Rc5Status {
+    status(context(ctx).and_then(|ctx| {
+        let iv = slice("iv", iv, iv_len)?;
+        in_place(input, output, len, |data| ctx.ctr(iv, data))
+    }))
+}
+
+/// Decrypts `len` bytes in CTR mode, which is the same as encrypting them.
+#[no_mangle]
+pub unsafe extern "C" fn rc5_ctr_decrypt(
+    ctx: *const Rc5Context,
+    iv: *const u8,
+    iv_len: usize,
+    input: *const u8,
+    output: *mut u8,
+    len: usize,
+) -> Rc5Status {
+    rc5_ctr_encrypt(ctx, iv, iv_len, input, output, len)
+}
+
+/// A description of the last failure on the calling thread, or null if there was none.
+///
+/// The string is valid until the next failure on the thread.
+#[no_mangle]
+pub extern "C" fn rc5_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| last.borrow().as_ref().map_or(ptr::null(), |e| e.as_ptr()))
+}
@@ -0,0 +1,164 @@
+/* Exercises the C ABI; compiled and run by tests/c_api.rs. Exits with 0 if every check passes. */
+
+#include <stdint.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "rc5.h"
+
+static int failures = 0;
+
+#define CHECK(cond)                                                            \
+    do {                                                                       \
+        if (!(cond)) {                                                         \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__,  \
+                    #cond);                                                    \
+            failures++;                                                        \
+        }                                                                      \
+    } while (0)
+
+static void parse_hex(const char *hex, uint8_t *out) {
+    for (size_t i = 0; hex[2 * i]; i++) {
+        unsigned byte;
+        sscanf(hex + 2 * i, "%2x", &byte);
+        out[i] = (uint8_t)byte;
+    }
+}
+
+/* The vectors of draft-krovetz-rc6-rc5-vectors-00 and of Rivest's RC5 paper. */
+static void known_answers(void) {
+    static const struct {
+        unsigned w, r;
+        const char *key, *plaintext, *ciphertext;
+    } vectors[] = {
+        {8, 12, "00010203", "0001", "212a"},
+        {16, 16, "0001020304050607", "00010203", "23a8d72e"},
+        {32, 12, "00000000000000000000000000000000", "0000000000000000",
+         "21a5dbee154b8f6d"},
+        {32, 20, "000102030405060708090a0b0c0d0e0f", "0001020304050607",
+         "2a0edc0e9431ff73"},
+        {64, 24, "000102030405060708090a0b0c0d0e0f1011121314151617",
+         "000102030405060708090a0b0c0d0e0f",
+         "a46772820edbce0235abea32ae7178da"},
+        {128, 28,
+         "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+         "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+         "eca5910921a4f4cfdd7ad7ad20a1fcba068ec7a7cd752d68fe914b7fe180b440"},
+    };
+
+    for (size_t i = 0; i < sizeof vectors / sizeof vectors[0]; i++) {
+        uint8_t key[32], plaintext[32], ciphertext[32], block[32];
+        size_t key_len = strlen(vectors[i].key) / 2;
+        parse_hex(vectors[i].key, key);
+        parse_hex(vectors[i].plaintext, plaintext);
+        parse_hex(vectors[i].ciphertext, ciphertext);
+
+        Rc5Context *ctx = rc5_context_new(vectors[i].w, vectors[i].r, key, key_len);
+        CHECK(ctx != NULL);
+        size_t block_len = rc5_block_size(ctx);
+        CHECK(block_len == vectors[i].w / 4);
+
+        CHECK(rc5_encrypt_block(ctx, plaintext, block) == RC5_STATUS_OK);
+        CHECK(memcmp(block, ciphertext, block_len) == 0);
+        /* in place */
+        CHECK(rc5_decrypt_block(ctx, block, block) == RC5_STATUS_OK);
+        CHECK(memcmp(block, plaintext, block_len) == 0);
+
+        rc5_context_free(ctx);
+    }
+}
+
+static void modes(void) {
+    uint8_t key[16], iv[8], data[40], ecb[40], cbc[40], ctr[37], back[40];
+    for (int i = 0; i < 16; i++) key[i] = (uint8_t)i;
+    for (int i = 0; i < 8; i++) iv[i] = (uint8_t)(0xa0 + i);
+    for (int i = 0; i < 40; i++) data[i] = (uint8_t)(3 * i);
+
+    Rc5Context *ctx = rc5_context_new(32, 12, key, sizeof key);
+    CHECK(ctx != NULL);
+
+    /* ECB is the blocks encrypted one by one */
+    CHECK(rc5_ecb_encrypt(ctx, data, ecb, sizeof data) == RC5_STATUS_OK);
+    for (int i = 0; i < 40; i += 8) {
+        uint8_t block[8];
+        CHECK(rc5_encrypt_block(ctx, data + i, block) == RC5_STATUS_OK);
+        CHECK(memcmp(block, ecb + i, 8) == 0);
+    }
+    memcpy(back, ecb, sizeof back);
+    CHECK(rc5_ecb_decrypt(ctx, back, back, sizeof back) == RC5_STATUS_OK);
+    CHECK(memcmp(back, data, sizeof data) == 0);
+
+    /* CBC chains the blocks from the IV */
+    CHECK(rc5_cbc_encrypt(ctx, iv, sizeof iv, data, cbc, sizeof data) == RC5_STATUS_OK);
+    const uint8_t *prev = iv;
+    for (int i = 0; i < 40; i += 8) {
+        uint8_t block[8];
+        for (int j = 0; j < 8; j++) block[j] = data[i + j] ^ prev[j];
+        CHECK(rc5_encrypt_block(ctx, block, block) == RC5_STATUS_OK);
+        CHECK(memcmp(block, cbc + i, 8) == 0);
+        prev = cbc + i;
+    }
+    CHECK(rc5_cbc_decrypt(ctx, iv, sizeof iv, cbc, back, sizeof cbc) == RC5_STATUS_OK);
+    CHECK(memcmp(back, data, sizeof data) == 0);
+
+    /* CTR takes any length, the keystream being the encrypted counter blocks */
+    uint8_t counter[8] = {0xa0, 0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xff};
+    CHECK(rc5_ctr_encrypt(ctx, counter, sizeof counter, data, ctr, sizeof ctr) == RC5_STATUS_OK);
+    for (int i = 0; i < 37; i += 8) {
+        uint8_t keystream[8];
+        CHECK(rc5_encrypt_block(ctx, counter, keystream) == RC5_STATUS_OK);
+        for (int j = 0; j < 8 && i + j < 37; j++) CHECK((data[i + j] ^ keystream[j]) == ctr[i + j]);
+        /* the big-endian increment carries into the next byte */
+        for (int j = 7; j >= 0 && ++counter[j] == 0; j--) {
+        }
+    }
+    CHECK(counter[6] == 0xa7 && counter[7] == 0x04);
+    counter[6] = 0xa6;
+    counter[7] = 0xff;
+    CHECK(rc5_ctr_decrypt(ctx, counter, sizeof counter, ctr, back, sizeof ctr) == RC5_STATUS_OK);
+    CHECK(memcmp(back, data, sizeof ctr) == 0);
+
+    rc5_context_free(ctx);
+}
+
+static void errors(void) {
+    uint8_t key[256] = {0}, data[16] = {0};
+
+    CHECK(rc5_context_new(24, 12, key, 16) == NULL);
+    CHECK(strstr(rc5_last_error(), "24-bit") != NULL);
+    CHECK(rc5_context_new(32, 256, key, 16) == NULL);
+    CHECK(rc5_context_new(32, 12, key, 256) == NULL);
+    CHECK(strstr(rc5_last_error(), "256-byte key") != NULL);
+    CHECK(rc5_context_new(32, 12, NULL, 16) == NULL);
+    CHECK(strstr(rc5_last_error(), "key is null") != NULL);
+
+    /* an empty key is valid */
+    Rc5Context *ctx = rc5_context_new(16, 12, NULL, 0);
+    CHECK(ctx != NULL);
+    CHECK(rc5_ctr_encrypt(ctx, data, 4, data, data, 16) == RC5_STATUS_UNSUPPORTED_MODE);
+    CHECK(rc5_ecb_encrypt(ctx, data, data, 6) == RC5_STATUS_INVALID_LENGTH);
+    CHECK(rc5_cbc_encrypt(ctx, data, 8, data, data, 16) == RC5_STATUS_INVALID_IV_LENGTH);
+    CHECK(rc5_cbc_encrypt(ctx, NULL, 4, data, data, 16) == RC5_STATUS_NULL_POINTER);
+    CHECK(rc5_ecb_encrypt(ctx, data, NULL, 16) == RC5_STATUS_NULL_POINTER);
+    CHECK(strstr(rc5_last_error(), "output is null") != NULL);
+    /* nothing to do, so nothing to point at */
+    CHECK(rc5_ecb_encrypt(ctx, NULL, NULL, 0) == RC5_STATUS_OK);
+    rc5_context_free(ctx);
+
+    CHECK(rc5_encrypt_block(NULL, data, data) == RC5_STATUS_NULL_POINTER);
+    CHECK(rc5_block_size(NULL) == 0);
+    rc5_context_free(NULL);
+}
+
+int main(void) {
+    known_answers();
+    modes();
+    errors();
+
+    if (failures) {
+        fprintf(stderr, "%d checks failed\n", failures);
+        return 1;
+    }
+    printf("all checks passed\n");
+    return 0;
+}
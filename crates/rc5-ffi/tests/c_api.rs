@@ -0,0 +1,95 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use rc5::{rc5_context_free, rc5_context_new, rc5_ctr_encrypt, Rc5Status};
+use rc5_rustcrypto_api::io::{Ctr, EncryptWriter};
+use rc5_rustcrypto_api::rc5::RC5Var;
+
+const MANIFEST_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+/// The directory of the libraries built for the tests: that of the test executable, `deps`.
+fn deps_dir() -> PathBuf {
+    let exe = std::env::current_exe().unwrap();
+    exe.parent().unwrap().to_path_buf()
+}
+
+#[test]
+fn header_is_up_to_date() {
+    let dir = Path::new(MANIFEST_DIR);
+    let config = cbindgen::Config::from_file(dir.join("cbindgen.toml")).unwrap();
+    let mut generated = Vec::new();
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(dir.join("src/lib.rs"))
+        .generate()
+        .unwrap()
+        .write(&mut generated);
+
+    let path = dir.join("include/rc5.h");
+    if std::env::var_os("RC5_FFI_UPDATE_HEADER").is_some() {
+        std::fs::write(&path, &generated).unwrap();
+    }
+    let committed = std::fs::read(&path).unwrap_or_default();
+    assert!(
+        committed == generated,
+        "include/rc5.h is stale: run the tests with RC5_FFI_UPDATE_HEADER=1"
+    );
+}
+
+#[test]
+fn c_program() {
+    let dir = Path::new(MANIFEST_DIR);
+    let exe = Path::new(env!("CARGO_TARGET_TMPDIR")).join("rc5_test");
+    let cc = std::env::var("CC").unwrap_or_else(|_| "cc".into());
+
+    let output = Command::new(&cc)
+        .args(["-std=c99", "-Wall", "-Wextra", "-Werror", "-I"])
+        .arg(dir.join("include"))
+        .arg(dir.join("tests/c/rc5_test.c"))
+        .arg(deps_dir().join("librc5.a"))
+        .args(["-lpthread", "-ldl", "-lm", "-o"])
+        .arg(&exe)
+        .output()
+        .unwrap_or_else(|e| panic!("run {}: {}", cc, e));
+    assert!(
+        output.status.success(),
+        "{} failed:\n{}",
+        cc,
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let output = Command::new(&exe).output().unwrap();
+    assert!(
+        output.status.success(),
+        "rc5_test failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn ctr_matches_io_ctr() {
+    let key = [0x5a; 10];
+    let iv = [
+        0xa0, 0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8, 0xa9, 0xaa, 0xab, 0xac, 0xad, 0xff,
+        0xfe,
+    ];
+    let data: Vec<u8> = (0..100).collect();
+
+    let mut expected = Vec::new();
+    let cipher = RC5Var::<u64>::new(&key, 16).unwrap();
+    let mut writer = EncryptWriter::new(&mut expected, Ctr::new(cipher, (&iv).into()));
+    writer.write_all(&data).unwrap();
+    writer.finish().unwrap();
+
+    let mut output = data.clone();
+    let ptr = output.as_mut_ptr();
+    unsafe {
+        let ctx = rc5_context_new(64, 16, key.as_ptr(), key.len());
+        assert!(!ctx.is_null());
+        let status = rc5_ctr_encrypt(ctx, iv.as_ptr(), iv.len(), ptr, ptr, data.len());
+        assert_eq!(status, Rc5Status::Ok);
+        rc5_context_free(ctx);
+    }
+    assert_eq!(output, expected);
+}
@@ -0,0 +1,36 @@
+use std::path::PathBuf;
+
+use clap::{Parser, ValueEnum};
+use rc5_kat::cipher::WordSize;
+
+/// Generates known-answer test vectors for RC5-w/r/b.
+#[derive(Debug, Parser)]
+#[command(name = "rc5-kat", version)]
+pub struct Cli {
+    /// Word size in bits; a block is two words.
+    #[arg(short = 'w', long, value_enum, default_value = "32")]
+    pub word_size: WordSize,
+
+    /// Number of rounds.
+    #[arg(short = 'r', long, default_value_t = 12)]
+    pub rounds: u8,
+
+    /// Key length in bytes.
+    #[arg(short = 'b', long, default_value_t = 16)]
+    pub key_bytes: u8,
+
+    #[arg(short = 'f', long, value_enum, default_value = "nessie")]
+    pub format: Format,
+
+    /// Output file; standard output if absent or "-".
+    #[arg(short = 'o', long, value_name = "PATH")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Sets 1 to 8 of the NESSIE project's block cipher vectors, iterated encryptions included.
+    Nessie,
+    /// The single vector per parameter set of draft-krovetz-rc6-rc5-vectors-00.
+    Krovetz,
+}
@@ -0,0 +1,69 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::Parser;
+use rc5_kat::cavp::{self, Mode, Options, Test};
+use rc5_kat::cipher::WordSize;
+
+/// Answers a NIST CAVP request file (.req) for RC5-w/r in a block cipher mode, writing the response (.rsp).
+#[derive(Debug, Parser)]
+#[command(name = "rc5-cavp", version)]
+struct Cli {
+    /// Word size in bits; a block is two words.
+    #[arg(short = 'w', long, value_enum, default_value = "32")]
+    word_size: WordSize,
+
+    /// Number of rounds.
+    #[arg(short = 'r', long, default_value_t = 12)]
+    rounds: u8,
+
+    #[arg(short = 'm', long, value_enum)]
+    mode: Mode,
+
+    #[arg(short = 't', long, value_enum)]
+    test: Test,
+
+    /// The request file.
+    #[arg(value_name = "REQ")]
+    request: PathBuf,
+
+    /// Output file; standard output if absent or "-".
+    #[arg(short = 'o', long, value_name = "PATH")]
+    output: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(&cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("rc5-cavp: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: &Cli) -> Result<(), String> {
+    let options = Options {
+        word_size: cli.word_size,
+        rounds: cli.rounds,
+        mode: cli.mode,
+        test: cli.test,
+    };
+
+    let request = std::fs::read_to_string(&cli.request)
+        .map_err(|e| format!("{}: {}", cli.request.display(), e))?;
+    let response = cavp::process(&request, &options)
+        .map_err(|e| format!("{}: {}", cli.request.display(), e))?;
+
+    match cli.output.as_deref() {
+        Some(path) if path != Path::new("-") => {
+            std::fs::write(path, response).map_err(|e| format!("{}: {}", path.display(), e))
+        }
+        _ => {
+            print!("{}", response);
+            Ok(())
+        }
+    }
+}
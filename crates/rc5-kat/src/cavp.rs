@@ -0,0 +1,394 @@
+//! NIST CAVP request and response files for the block cipher modes: a `.req` file's records are answered and
+//! written back as the `.rsp` file.
+//!
+//! The known-answer (KAT) and multi-block message (MMT) tests run each record's message through the mode. The
+//! Monte Carlo tests (MCT) follow the chaining of the AESAVS, section 6.4, with RC5's block and key lengths: after
+//! each 1000 blocks the key is XORed with the last key-length bytes of output. The AESAVS defines no Monte Carlo
+//! test for CTR.
+
+use std::fmt::Write;
+
+use clap::ValueEnum;
+
+use crate::cipher::{Cipher, Params, WordSize};
+
+/// The blocks encrypted or decrypted by each Monte Carlo iteration.
+const MCT_BLOCKS: usize = 1000;
+/// The Monte Carlo iterations each request record expands to.
+const MCT_ITERATIONS: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Mode {
+    Ecb,
+    Cbc,
+    /// CFB with segments of a whole block.
+    Cfb,
+    Ofb,
+    /// CTR with the IV as the initial counter block, whose last 64 bits count big-endian.
+    Ctr,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Test {
+    /// Known-answer tests.
+    Kat,
+    /// Multi-block message tests.
+    Mmt,
+    /// Monte Carlo tests.
+    Mct,
+}
+
+/// What a `.req` file runs through and how.
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    pub word_size: WordSize,
+    pub rounds: u8,
+    pub mode: Mode,
+    pub test: Test,
+}
+
+/// A malformed request or one the options cannot answer, at a line of the request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub line: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Encrypt,
+    Decrypt,
+}
+
+impl Direction {
+    /// The field holding the input, then the one holding the output.
+    fn fields(self) -> (&'static str, &'static str) {
+        match self {
+            Self::Encrypt => ("PLAINTEXT", "CIPHERTEXT"),
+            Self::Decrypt => ("CIPHERTEXT", "PLAINTEXT"),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Item<'a> {
+    Comment(&'a str),
+    Section(Direction),
+    Record(Record<'a>),
+}
+
+/// A `COUNT = n` line and the `NAME = value` lines after it.
+#[derive(Debug)]
+struct Record<'a> {
+    line: usize,
+    fields: Vec<(&'a str, &'a str)>,
+}
+
+impl Record<'_> {
+    fn error(&self, message: impl Into<String>) -> Error {
+        Error {
+            line: self.line,
+            message: message.into(),
+        }
+    }
+
+    fn bytes(&self, name: &str) -> Result<Vec<u8>, Error> {
+        let (_, value) = self
+            .fields
+            .iter()
+            .find(|(field, _)| *field == name)
+            .ok_or_else(|| self.error(format!("no {}", name)))?;
+        hex::decode(value).map_err(|e| self.error(format!("{}: {}", name, e)))
+    }
+}
+
+/// Answers the records of the request `.req`, returning the `.rsp` file.
+pub fn process(request: &str, options: &Options) -> Result<String, Error> {
+    let mut response = String::new();
+    let mut direction = None;
+    let mut comments = false;
+
+    for item in parse(request)? {
+        if comments && !matches!(item, Item::Comment(_)) {
+            response.push('\n');
+        }
+        comments = false;
+
+        match item {
+            Item::Comment(comment) => {
+                comments = true;
+                response.push_str(comment);
+                response.push('\n');
+            }
+            Item::Section(section) => {
+                direction = Some(section);
+                let name = match section {
+                    Direction::Encrypt => "ENCRYPT",
+                    Direction::Decrypt => "DECRYPT",
+                };
+                writeln!(response, "[{}]\n", name).unwrap();
+            }
+            Item::Record(record) => {
+                let direction = direction
+                    .ok_or_else(|| record.error("record before [ENCRYPT] or [DECRYPT]"))?;
+                match options.test {
+                    Test::Kat | Test::Mmt => answer(&mut response, &record, direction, options)?,
+                    Test::Mct => monte_carlo(&mut response, &record, direction, options)?,
+                }
+            }
+        }
+    }
+    if comments {
+        response.push('\n');
+    }
+
+    Ok(response)
+}
+
+fn parse(request: &str) -> Result<Vec<Item<'_>>, Error> {
+    let mut items = Vec::new();
+
+    for (i, line) in request.lines().enumerate() {
+        let error = |message: &str| Error {
+            line: i + 1,
+            message: format!("{}: {:?}", message, line),
+        };
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        } else if line.starts_with('#') {
+            items.push(Item::Comment(line));
+        } else if let Some(section) = line.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            items.push(Item::Section(match section.trim() {
+                "ENCRYPT" => Direction::Encrypt,
+                "DECRYPT" => Direction::Decrypt,
+                _ => return Err(error("unknown section")),
+            }));
+        } else if let Some((name, value)) = line.split_once('=') {
+            let (name, value) = (name.trim(), value.trim());
+            if name == "COUNT" {
+                items.push(Item::Record(Record {
+                    line: i + 1,
+                    fields: Vec::new(),
+                }));
+            }
+            match items.last_mut() {
+                Some(Item::Record(record)) => record.fields.push((name, value)),
+                _ => return Err(error("field outside a COUNT record")),
+            }
+        } else {
+            return Err(error("expected a comment, a section or NAME = value"));
+        }
+    }
+
+    Ok(items)
+}
+
+/// The KAT and MMT answer: the record with its message encrypted or decrypted.
+fn answer(
+    response: &mut String,
+    record: &Record,
+    direction: Direction,
+    options: &Options,
+) -> Result<(), Error> {
+    let (input, output) = direction.fields();
+    if record.fields.iter().any(|(name, _)| *name == output) {
+        return Err(record.error(format!("already has a {}", output)));
+    }
+
+    let mut chain = Chain::new(record, options)?;
+    let mut message = record.bytes(input)?;
+    let block_len = chain.block_len();
+    if matches!(options.mode, Mode::Ecb | Mode::Cbc) && message.len() % block_len != 0 {
+        return Err(record.error(format!(
+            "{} is not a whole number of {}-byte blocks",
+            input, block_len
+        )));
+    }
+    for block in message.chunks_mut(block_len) {
+        chain.apply(direction, block);
+    }
+
+    for (name, value) in &record.fields {
+        writeln!(response, "{} = {}", name, value).unwrap();
+    }
+    writeln!(response, "{} = {}\n", output, hex::encode(message)).unwrap();
+    Ok(())
+}
+
+/// The MCT answer: [`MCT_ITERATIONS`] records, each chaining [`MCT_BLOCKS`] blocks.
+fn monte_carlo(
+    response: &mut String,
+    record: &Record,
+    direction: Direction,
+    options: &Options,
+) -> Result<(), Error> {
+    if options.mode == Mode::Ctr {
+        return Err(record.error("the AESAVS defines no Monte Carlo test for CTR"));
+    }
+    let (input_name, output_name) = direction.fields();
+
+    let mut key = record.bytes("KEY")?;
+    let mut iv = match options.mode {
+        Mode::Ecb => Vec::new(),
+        _ => record.bytes("IV")?,
+    };
+    let mut input = record.bytes(input_name)?;
+
+    for count in 0..MCT_ITERATIONS {
+        writeln!(response, "COUNT = {}", count).unwrap();
+        writeln!(response, "KEY = {}", hex::encode(&key)).unwrap();
+        if options.mode != Mode::Ecb {
+            writeln!(response, "IV = {}", hex::encode(&iv)).unwrap();
+        }
+        writeln!(response, "{} = {}", input_name, hex::encode(&input)).unwrap();
+
+        let mut chain = Chain::with_key(record, options, &key, &iv)?;
+        if input.len() != chain.block_len() {
+            return Err(record.error(format!("{} is not one block", input_name)));
+        }
+
+        let mut outputs: Vec<Vec<u8>> = Vec::with_capacity(MCT_BLOCKS);
+        for j in 0..MCT_BLOCKS {
+            let mut block = input.clone();
+            chain.apply(direction, &mut block);
+            input = match (options.mode, j) {
+                (Mode::Ecb, _) => block.clone(),
+                (_, 0) => iv.clone(),
+                _ => outputs[j - 1].clone(),
+            };
+            outputs.push(block);
+        }
+
+        let last = &outputs[MCT_BLOCKS - 1];
+        writeln!(response, "{} = {}\n", output_name, hex::encode(last)).unwrap();
+
+        let output = outputs.concat();
+        let tail = output.len() - key.len();
+        xor(&mut key, &output[tail..]);
+        if options.mode != Mode::Ecb {
+            iv = last.clone();
+            input = outputs[MCT_BLOCKS - 2].clone();
+        }
+    }
+
+    Ok(())
+}
+
+/// A mode's state across the blocks of one message.
+struct Chain {
+    cipher: Box<dyn Cipher>,
+    mode: Mode,
+    block_len: usize,
+    /// The previous ciphertext block for CBC and CFB, the last output of the cipher for OFB, the counter for CTR.
+    register: Vec<u8>,
+}
+
+impl Chain {
+    fn new(record: &Record, options: &Options) -> Result<Self, Error> {
+        let key = record.bytes("KEY")?;
+        let iv = match options.mode {
+            Mode::Ecb => Vec::new(),
+            _ => record.bytes("IV")?,
+        };
+        Self::with_key(record, options, &key, &iv)
+    }
+
+    fn with_key(record: &Record, options: &Options, key: &[u8], iv: &[u8]) -> Result<Self, Error> {
+        let params = Params {
+            word_size: options.word_size,
+            rounds: options.rounds,
+            key_len: u8::try_from(key.len())
+                .map_err(|_| record.error(format!("{}-byte key", key.len())))?,
+        };
+        if options.mode != Mode::Ecb && iv.len() != params.block_len() {
+            return Err(record.error(format!(
+                "{}-byte IV for {}-byte blocks",
+                iv.len(),
+                params.block_len()
+            )));
+        }
+
+        Ok(Self {
+            cipher: params.cipher(key),
+            mode: options.mode,
+            block_len: params.block_len(),
+            register: iv.to_vec(),
+        })
+    }
+
+    fn block_len(&self) -> usize {
+        self.block_len
+    }
+
+    /// Encrypts or decrypts one block in place, or the final partial block of CFB, OFB and CTR.
+    fn apply(&mut self, direction: Direction, block: &mut [u8]) {
+        match (self.mode, direction) {
+            (Mode::Ecb, Direction::Encrypt) => self.cipher.encrypt(block),
+            (Mode::Ecb, Direction::Decrypt) => self.cipher.decrypt(block),
+            (Mode::Cbc, Direction::Encrypt) => {
+                xor(block, &self.register);
+                self.cipher.encrypt(block);
+                self.register.copy_from_slice(block);
+            }
+            (Mode::Cbc, Direction::Decrypt) => {
+                let ciphertext = block.to_vec();
+                self.cipher.decrypt(block);
+                xor(block, &self.register);
+                self.register = ciphertext;
+            }
+            (Mode::Cfb, _) => {
+                let mut keystream = self.register.clone();
+                self.cipher.encrypt(&mut keystream);
+                let ciphertext = match direction {
+                    Direction::Encrypt => {
+                        xor(block, &keystream);
+                        block.to_vec()
+                    }
+                    Direction::Decrypt => {
+                        let ciphertext = block.to_vec();
+                        xor(block, &keystream);
+                        ciphertext
+                    }
+                };
+                self.register[..ciphertext.len()].copy_from_slice(&ciphertext);
+            }
+            (Mode::Ofb, _) => {
+                self.cipher.encrypt(&mut self.register);
+                xor(block, &self.register);
+            }
+            (Mode::Ctr, _) => {
+                let mut keystream = self.register.clone();
+                self.cipher.encrypt(&mut keystream);
+                xor(block, &keystream);
+                increment(&mut self.register);
+            }
+        }
+    }
+}
+
+fn xor(block: &mut [u8], with: &[u8]) {
+    for (b, w) in block.iter_mut().zip(with) {
+        *b ^= w;
+    }
+}
+
+/// Adds one to the last 64 bits of `counter`, big-endian, wrapping.
+fn increment(counter: &mut [u8]) {
+    let start = counter.len().saturating_sub(8);
+    for byte in counter[start..].iter_mut().rev() {
+        *byte = byte.wrapping_add(1);
+        if *byte != 0 {
+            break;
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use cipher::{BlockDecrypt, BlockEncrypt};
+use clap::ValueEnum;
+use generic_array::GenericArray;
+use rc5_core::strange_words::{StrangeArithmetics, StrangeByteRepr, StrangeMagic, W24bit, W80bit};
+use rc5_rustcrypto_api::rc5::RC5Var;
+
+type Strange<Word> = RC5Var<Word, StrangeByteRepr, StrangeArithmetics, StrangeMagic>;
+
+/// A keyed RC5-w/r, on blocks of [`Params::block_len`] bytes.
+pub trait Cipher {
+    fn encrypt(&self, block: &mut [u8]);
+    fn decrypt(&self, block: &mut [u8]);
+}
+
+impl<C: BlockEncrypt + BlockDecrypt> Cipher for C {
+    fn encrypt(&self, block: &mut [u8]) {
+        self.encrypt_block(GenericArray::from_mut_slice(block));
+    }
+
+    fn decrypt(&self, block: &mut [u8]) {
+        self.decrypt_block(GenericArray::from_mut_slice(block));
+    }
+}
+
+/// RC5-w/r/b.
+#[derive(Debug, Clone, Copy)]
+pub struct Params {
+    pub word_size: WordSize,
+    pub rounds: u8,
+    pub key_len: u8,
+}
+
+impl Params {
+    pub fn key_len(&self) -> usize {
+        self.key_len as usize
+    }
+
+    pub fn block_len(&self) -> usize {
+        2 * self.word_size.bits() / 8
+    }
+
+    /// Expands `key`, which must be [`Params::key_len`] bytes long.
+    pub fn cipher(&self, key: &[u8]) -> Box<dyn Cipher> {
+        assert_eq!(key.len(), self.key_len(), "key length");
+
+        let rounds = self.rounds;
+        let expect = "keys of at most 255 bytes are valid";
+        match self.word_size {
+            WordSize::W8 => Box::new(RC5Var::<u8>::new(key, rounds).expect(expect)),
+            WordSize::W16 => Box::new(RC5Var::<u16>::new(key, rounds).expect(expect)),
+            WordSize::W24 => Box::new(Strange::<W24bit>::new(key, rounds).expect(expect)),
+            WordSize::W32 => Box::new(RC5Var::<u32>::new(key, rounds).expect(expect)),
+            WordSize::W64 => Box::new(RC5Var::<u64>::new(key, rounds).expect(expect)),
+            WordSize::W80 => Box::new(Strange::<W80bit>::new(key, rounds).expect(expect)),
+            WordSize::W128 => Box::new(RC5Var::<u128>::new(key, rounds).expect(expect)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum WordSize {
+    #[value(name = "8")]
+    W8,
+    #[value(name = "16")]
+    W16,
+    #[value(name = "24")]
+    W24,
+    #[value(name = "32")]
+    W32,
+    #[value(name = "64")]
+    W64,
+    #[value(name = "80")]
+    W80,
+    #[value(name = "128")]
+    W128,
+}
+
+impl WordSize {
+    pub fn bits(self) -> usize {
+        match self {
+            Self::W8 => 8,
+            Self::W16 => 16,
+            Self::W24 => 24,
+            Self::W32 => 32,
+            Self::W64 => 64,
+            Self::W80 => 80,
+            Self::W128 => 128,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//! The vectors of draft-krovetz-rc6-rc5-vectors-00: the key `00 01 02 ...` of `b` bytes, and the block
+//! `00 01 02 ...` encrypted once.
+//!
+//! For w = 24 and 80 the draft's own values are wrong, its rotations taking the amount modulo the next power of two
+//! rather than modulo w; these come from `rc5-core`, which takes it modulo w.
+
+use std::io::{self, Write};
+
+use rc5_kat::cipher::Params;
+
+pub fn write(out: &mut impl Write, params: &Params) -> io::Result<()> {
+    let key: Vec<u8> = (0..params.key_len()).map(|i| i as u8).collect();
+    let input: Vec<u8> = (0..params.block_len()).map(|i| i as u8).collect();
+    let mut output = input.clone();
+    params.cipher(&key).encrypt(&mut output);
+
+    writeln!(
+        out,
+        "RC5-{}/{}/{}",
+        params.word_size.bits(),
+        params.rounds,
+        params.key_len
+    )?;
+    writeln!(
+        out,
+        "{}",
+        format!("Key:          {}", hex::encode_upper(key)).trim_end()
+    )?;
+    writeln!(out, "Block input:  {}", hex::encode_upper(input))?;
+    writeln!(out, "Block output: {}", hex::encode_upper(output))
+}
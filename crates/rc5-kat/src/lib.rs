@@ -0,0 +1,5 @@
+//! Known-answer test vectors for RC5-w/r/b: the cipher behind the `rc5-kat` generator and the `rc5-cavp`
+//! request processor, and the processor itself.
+
+pub mod cavp;
+pub mod cipher;
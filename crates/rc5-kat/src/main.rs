@@ -0,0 +1,47 @@
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::process::ExitCode;
+
+use clap::Parser;
+
+mod args;
+mod krovetz;
+mod nessie;
+
+use args::{Cli, Format};
+use rc5_kat::cipher::Params;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(&cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("rc5-kat: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: &Cli) -> Result<(), String> {
+    let params = Params {
+        word_size: cli.word_size,
+        rounds: cli.rounds,
+        key_len: cli.key_bytes,
+    };
+
+    let mut out: Box<dyn Write> = match cli.output.as_deref() {
+        Some(path) if path != Path::new("-") => {
+            Box::new(std::fs::File::create(path).map_err(|e| format!("{}: {}", path.display(), e))?)
+        }
+        _ => Box::new(std::io::stdout().lock()),
+    };
+    let mut out = BufWriter::new(&mut out);
+
+    match cli.format {
+        Format::Nessie => nessie::write(&mut out, &params),
+        Format::Krovetz => krovetz::write(&mut out, &params),
+    }
+    .and_then(|()| out.flush())
+    .map_err(|e| format!("write: {}", e))
+}
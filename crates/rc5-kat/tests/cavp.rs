@@ -0,0 +1,301 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use cipher::{Block, BlockDecrypt, BlockEncrypt};
+use rc5_kat::cavp::{process, Mode, Options, Test};
+use rc5_kat::cipher::WordSize;
+use rc5_rustcrypto_api::io::{encrypt_to_vec, Cbc, Ctr, Decryptor, Encryptor};
+use rc5_rustcrypto_api::rivest97::RC5_32_12_16;
+
+/// The sample requests in `tests/cavp`, for RC5-32/12. Their `.rsp` files are this crate's answers.
+const REQUESTS: &[(&str, Mode, Test)] = &[
+    ("ECBKAT", Mode::Ecb, Test::Kat),
+    ("CBCMMT", Mode::Cbc, Test::Mmt),
+    ("CFBMMT", Mode::Cfb, Test::Mmt),
+    ("OFBMMT", Mode::Ofb, Test::Mmt),
+    ("CTRMMT", Mode::Ctr, Test::Mmt),
+    ("ECBMCT", Mode::Ecb, Test::Mct),
+    ("CBCMCT", Mode::Cbc, Test::Mct),
+    ("CFBMCT", Mode::Cfb, Test::Mct),
+    ("OFBMCT", Mode::Ofb, Test::Mct),
+];
+
+fn path(name: &str, extension: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/cavp")
+        .join(name)
+        .with_extension(extension)
+}
+
+fn options(mode: Mode, test: Test) -> Options {
+    Options {
+        word_size: WordSize::W32,
+        rounds: 12,
+        mode,
+        test,
+    }
+}
+
+fn respond(name: &str, mode: Mode, test: Test) -> String {
+    let request = std::fs::read_to_string(path(name, "req")).unwrap();
+    process(&request, &options(mode, test)).unwrap_or_else(|e| panic!("{}: {}", name, e))
+}
+
+/// The values of every `name` field, in file order.
+fn values<'a>(response: &'a str, name: &str) -> Vec<&'a str> {
+    response
+        .lines()
+        .filter_map(|line| line.split_once(" = "))
+        .filter(|(field, _)| *field == name)
+        .map(|(_, value)| value)
+        .collect()
+}
+
+#[test]
+fn responses_are_up_to_date() {
+    for &(name, mode, test) in REQUESTS {
+        let response = respond(name, mode, test);
+        let path = path(name, "rsp");
+        if std::env::var_os("RC5_CAVP_UPDATE_RSP").is_some() {
+            std::fs::write(&path, &response).unwrap();
+        }
+        let committed = std::fs::read_to_string(&path).unwrap_or_default();
+        assert!(
+            committed == response,
+            "{}.rsp is stale: run the tests with RC5_CAVP_UPDATE_RSP=1",
+            name
+        );
+    }
+}
+
+#[test]
+fn ecb_kat_is_rivest_examples() {
+    let response = respond("ECBKAT", Mode::Ecb, Test::Kat);
+    assert_eq!(
+        values(&response, "CIPHERTEXT"),
+        [
+            "21a5dbee154b8f6d",
+            "f7c013ac5b2b8952",
+            "2f42b3b70369fc92",
+            "65c178b284d197cc",
+            "eb44e415da319824"
+        ]
+    );
+    assert_eq!(
+        values(&response, "PLAINTEXT")[3..],
+        ["2f42b3b70369fc92", "65c178b284d197cc"]
+    );
+}
+
+#[test]
+fn mmt_matches_io_modes() {
+    let key = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+    let iv = hex::decode("a0a1a2a3a4a5a6a7").unwrap();
+    let plaintext = hex::decode("00112233445566778899aabbccddeeff").unwrap();
+    let cipher = || <RC5_32_12_16 as cipher::KeyInit>::new_from_slice(&key).unwrap();
+
+    // CBC-PAD adds a block of padding
+    let padded = encrypt_to_vec(Cbc::new(cipher(), iv.as_slice().into()), &plaintext).unwrap();
+    let response = respond("CBCMMT", Mode::Cbc, Test::Mmt);
+    assert_eq!(
+        values(&response, "CIPHERTEXT")[0],
+        hex::encode(&padded[..16])
+    );
+
+    let ctr = encrypt_to_vec(Ctr::new(cipher(), iv.as_slice().into()), &plaintext).unwrap();
+    let response = respond("CTRMMT", Mode::Ctr, Test::Mmt);
+    assert_eq!(values(&response, "CIPHERTEXT")[0], hex::encode(ctr));
+}
+
+/// With a zero plaintext OFB and CFB both encrypt the IV over and over, as NESSIE's set 1 does.
+#[test]
+fn stream_modes_iterate_the_cipher_over_zeros() {
+    let ofb = respond("OFBMMT", Mode::Ofb, Test::Mmt);
+    let cfb = respond("CFBMMT", Mode::Cfb, Test::Mmt);
+    let ofb = values(&ofb, "CIPHERTEXT")[2];
+    assert_eq!(values(&cfb, "CIPHERTEXT")[2], ofb);
+    assert!(ofb.starts_with("8f681d7f285cdc2f"), "{}", ofb);
+}
+
+#[test]
+fn mct_chaining() {
+    for &(name, mode, test) in REQUESTS.iter().filter(|r| r.2 == Test::Mct) {
+        let response = respond(name, mode, test);
+        // 100 iterations for each of the [ENCRYPT] and [DECRYPT] records
+        assert_eq!(values(&response, "COUNT").len(), 200, "{}", name);
+        assert_eq!(values(&response, "COUNT")[99], "99", "{}", name);
+        // each iteration's key is the last one XORed with its output
+        assert_ne!(
+            values(&response, "KEY")[1],
+            values(&response, "KEY")[0],
+            "{}",
+            name
+        );
+    }
+
+    // NESSIE set 1, vector 0, iterated 1000 times
+    let response = respond("ECBMCT", Mode::Ecb, Test::Mct);
+    assert_eq!(values(&response, "CIPHERTEXT")[0], "f10aed16dc85db9d");
+    assert_eq!(values(&response, "PLAINTEXT")[1], "f10aed16dc85db9d");
+
+    // 128-bit keys take the last two blocks: AESAVS's rule for 192-bit AES keys and 128-bit blocks
+    let key = hex::decode("80000000000000000000000000000000").unwrap();
+    let cipher = <RC5_32_12_16 as cipher::KeyInit>::new_from_slice(&key).unwrap();
+    let mut last = hex::decode("f10aed16dc85db9d").unwrap();
+    let mut before = last.clone();
+    cipher.decrypt_block(before.as_mut_slice().into());
+    before.append(&mut last);
+    let next: Vec<u8> = key.iter().zip(&before).map(|(k, o)| k ^ o).collect();
+    assert_eq!(values(&response, "KEY")[1], hex::encode(next));
+}
+
+/// One MCT iteration as the AESAVS pseudocode writes it: 1000 outputs of `step`, whose input is first
+/// `input`, then `iv`, then the output two blocks back.
+fn aesavs_iteration(input: &str, iv: &str, mut step: impl FnMut(&[u8]) -> Vec<u8>) -> Vec<Vec<u8>> {
+    let iv = hex::decode(iv).unwrap();
+    let mut input = hex::decode(input).unwrap();
+    let mut outputs: Vec<Vec<u8>> = Vec::new();
+    for j in 0..1000 {
+        outputs.push(step(&input));
+        input = if j == 0 {
+            iv.clone()
+        } else {
+            outputs[j - 1].clone()
+        };
+    }
+    outputs
+}
+
+/// The first CBC, CFB and OFB iterations, recomputed with `io::Cbc` and the bare cipher rather than the
+/// responder's chaining, and the key, IV and input they give the second one.
+#[test]
+fn mct_chaining_matches_aesavs() {
+    for (name, mode, direction) in [
+        ("CBCMCT", Mode::Cbc, "encrypt"),
+        ("CBCMCT", Mode::Cbc, "decrypt"),
+        ("CFBMCT", Mode::Cfb, "encrypt"),
+        ("OFBMCT", Mode::Ofb, "encrypt"),
+    ] {
+        let response = respond(name, mode, Test::Mct);
+        // the [DECRYPT] records follow the 100 [ENCRYPT] ones
+        let (record, input_name, output_name) = match direction {
+            "encrypt" => (0, "PLAINTEXT", "CIPHERTEXT"),
+            _ => (100, "CIPHERTEXT", "PLAINTEXT"),
+        };
+        let field = |field: &str, record: usize| values(&response, field)[record];
+
+        let key = hex::decode(field("KEY", record)).unwrap();
+        let iv =
+            Block::<RC5_32_12_16>::clone_from_slice(&hex::decode(field("IV", record)).unwrap());
+        let cipher = <RC5_32_12_16 as cipher::KeyInit>::new_from_slice(&key).unwrap();
+        let mut cbc = Cbc::new(cipher.clone(), &iv);
+        let mut register = iv;
+        let step = |block: &[u8]| {
+            let mut out = Vec::new();
+            match (mode, direction) {
+                (Mode::Cbc, "encrypt") => cbc.encrypt_segment(block, &mut out).unwrap(),
+                (Mode::Cbc, _) => cbc.decrypt_segment(block, &mut out).unwrap(),
+                (Mode::Cfb, _) => {
+                    cipher.encrypt_block(&mut register);
+                    out.extend(block.iter().zip(&register).map(|(b, k)| b ^ k));
+                    register.copy_from_slice(&out);
+                }
+                _ => {
+                    cipher.encrypt_block(&mut register);
+                    out.extend(block.iter().zip(&register).map(|(b, k)| b ^ k));
+                }
+            }
+            out
+        };
+        let outputs = aesavs_iteration(field(input_name, record), field("IV", record), step);
+
+        let label = format!("{} {}", name, direction);
+        assert_eq!(
+            field(output_name, record),
+            hex::encode(&outputs[999]),
+            "{}",
+            label
+        );
+        let tail = [outputs[998].as_slice(), &outputs[999]].concat();
+        let next_key: Vec<u8> = key.iter().zip(&tail).map(|(k, o)| k ^ o).collect();
+        assert_eq!(field("KEY", record + 1), hex::encode(next_key), "{}", label);
+        assert_eq!(
+            field("IV", record + 1),
+            hex::encode(&outputs[999]),
+            "{}",
+            label
+        );
+        assert_eq!(
+            field(input_name, record + 1),
+            hex::encode(&outputs[998]),
+            "{}",
+            label
+        );
+    }
+}
+
+#[test]
+fn malformed_requests() {
+    let ecb = options(Mode::Ecb, Test::Kat);
+    let cases = [
+        (
+            "COUNT = 0\nKEY = 00\nPLAINTEXT = 00\n",
+            &ecb,
+            "line 1: record before",
+        ),
+        ("[ENCRYPT]\nKEY = 00\n", &ecb, "line 2: field outside"),
+        ("[VERIFY]\n", &ecb, "line 1: unknown section"),
+        ("[ENCRYPT]\ngarbage\n", &ecb, "line 2: expected"),
+        (
+            "[ENCRYPT]\n\nCOUNT = 0\nKEY = 00\nPLAINTEXT = 0011\n",
+            &ecb,
+            "line 3: PLAINTEXT is not a whole number of 8-byte blocks",
+        ),
+        (
+            "[ENCRYPT]\nCOUNT = 0\nKEY = 00\nIV = 00\nPLAINTEXT = 00\n",
+            &options(Mode::Cbc, Test::Kat),
+            "line 2: 1-byte IV for 8-byte blocks",
+        ),
+        (
+            "[DECRYPT]\nCOUNT = 0\nKEY = 0g\nCIPHERTEXT = 00\n",
+            &ecb,
+            "line 2: KEY: ",
+        ),
+        (
+            "[ENCRYPT]\nCOUNT = 0\nKEY = 00\nPLAINTEXT = 00\nCIPHERTEXT = 00\n",
+            &ecb,
+            "line 2: already has a CIPHERTEXT",
+        ),
+        (
+            "[ENCRYPT]\nCOUNT = 0\nKEY = 00\nIV = 0000000000000000\nPLAINTEXT = 0000000000000000\n",
+            &options(Mode::Ctr, Test::Mct),
+            "line 2: the AESAVS defines no Monte Carlo test for CTR",
+        ),
+    ];
+    for (request, options, expected) in cases {
+        let error = process(request, options).unwrap_err().to_string();
+        assert!(error.starts_with(expected), "{:?}: {}", request, error);
+    }
+}
+
+#[test]
+fn rc5_cavp_binary() {
+    let output = Command::new(env!("CARGO_BIN_EXE_rc5-cavp"))
+        .args(["-w", "32", "-r", "12", "-m", "ecb", "-t", "kat"])
+        .arg(path("ECBKAT", "req"))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        std::fs::read_to_string(path("ECBKAT", "rsp")).unwrap()
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rc5-cavp"))
+        .args(["-m", "ctr", "-t", "mct"])
+        .arg(path("CBCMCT", "req"))
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("CBCMCT.req: line 7:"));
+}
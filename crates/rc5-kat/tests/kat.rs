@@ -0,0 +1,91 @@
+use std::process::Command;
+
+const NESSIE_RC5_128_64: &str =
+    include_str!("../../rc5-rustcrypto-api/src/tests/nessie/Rc5-128-64.verified.test-vectors");
+
+fn rc5_kat(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_rc5-kat"))
+        .args(args)
+        .output()
+        .expect("spawn rc5-kat");
+    assert!(
+        output.status.success(),
+        "rc5-kat {:?}: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn nessie_matches_the_published_file() {
+    let generated = rc5_kat(&["-w", "32", "-r", "12", "-b", "16", "-f", "nessie"]);
+    assert!(
+        generated == NESSIE_RC5_128_64,
+        "differs from the NESSIE file"
+    );
+}
+
+#[test]
+fn nessie_other_parameters() {
+    let generated = rc5_kat(&["-w", "64", "-r", "16", "-b", "24"]);
+    assert!(generated.contains("Primitive Name: Rc5-64/16/24\n============================\n"));
+    assert!(generated.contains("Key size: 192 bits\nBlock size: 128 bits\n"));
+    // sets 1, 2, 5 and 6 have a vector per key or block bit, 3 and 7 one per byte value
+    assert_eq!(
+        generated.matches(", vector#").count(),
+        2 * (192 + 128 + 256 + 2)
+    );
+    assert!(generated.contains(
+        "Set 4, vector#  0:\n                           key=000102030405060708090A0B0C0D0E0F1011121314151617\n\
+         \x20                        plain=00112233445566778899AABBCCDDEEFF\n"
+    ));
+
+    // no key bits, so no vectors in sets 1 and 5
+    let generated = rc5_kat(&["-w", "24", "-r", "4", "-b", "0"]);
+    assert!(generated
+        .contains("Test vectors -- set 1\n=====================\n\nTest vectors -- set 2\n"));
+    assert!(generated.contains("Set 3, vector#  0:\n                           key=\n"));
+}
+
+#[test]
+fn krovetz_vectors() {
+    let cases = [
+        (["-w", "8", "-r", "12", "-b", "4"], "RC5-8/12/4\nKey:          00010203\nBlock input:  0001\nBlock output: 212A\n"),
+        (["-w", "16", "-r", "16", "-b", "8"], "RC5-16/16/8\nKey:          0001020304050607\nBlock input:  00010203\nBlock output: 23A8D72E\n"),
+        (
+            ["-w", "32", "-r", "20", "-b", "16"],
+            "RC5-32/20/16\nKey:          000102030405060708090A0B0C0D0E0F\nBlock input:  0001020304050607\n\
+             Block output: 2A0EDC0E9431FF73\n",
+        ),
+        (
+            ["-w", "64", "-r", "24", "-b", "24"],
+            "RC5-64/24/24\nKey:          000102030405060708090A0B0C0D0E0F1011121314151617\n\
+             Block input:  000102030405060708090A0B0C0D0E0F\nBlock output: A46772820EDBCE0235ABEA32AE7178DA\n",
+        ),
+        (
+            ["-w", "128", "-r", "28", "-b", "32"],
+            "RC5-128/28/32\nKey:          000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F\n\
+             Block input:  000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F\n\
+             Block output: ECA5910921A4F4CFDD7AD7AD20A1FCBA068EC7A7CD752D68FE914B7FE180B440\n",
+        ),
+    ];
+    for (args, expected) in cases {
+        let mut args = args.to_vec();
+        args.extend(["-f", "krovetz"]);
+        assert_eq!(rc5_kat(&args), expected);
+    }
+
+    let generated = rc5_kat(&["-w", "24", "-r", "4", "-b", "0", "-f", "krovetz"]);
+    assert!(generated.starts_with("RC5-24/4/0\nKey:\nBlock input:  000102030405\n"));
+}
+
+#[test]
+fn output_file() {
+    let path = std::env::temp_dir().join(format!("rc5-kat-{}", std::process::id()));
+    let stdout = rc5_kat(&["-f", "krovetz", "-o", path.to_str().unwrap()]);
+    assert_eq!(stdout, "");
+    let written = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(written.starts_with("RC5-32/12/16\n"));
+}
@@ -0,0 +1,71 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::{ArgGroup, Parser};
+
+use rc5_keysearch::pattern::KeyPattern;
+use rc5_keysearch::search::KnownPair;
+
+/// Searches for the RC5-32/12 key of known plaintext/ciphertext pairs among keys with some bits known.
+#[derive(Debug, Parser)]
+#[command(name = "rc5-keysearch", version)]
+#[command(group(ArgGroup::new("keys").required(true).args(["pattern", "key_bytes"])))]
+pub struct Cli {
+    /// A known plaintext block and its ciphertext, in hex; the more pairs, the fewer false keys.
+    #[arg(
+        short = 'p',
+        long = "pair",
+        value_name = "PLAINTEXT:CIPHERTEXT",
+        required = true
+    )]
+    pub pairs: Vec<KnownPair>,
+
+    /// The key in hex with `?` for each unknown hex digit, or BASE/MASK with the unknown bits set in MASK.
+    #[arg(short = 'k', long)]
+    pub pattern: Option<KeyPattern>,
+
+    /// Key length in bytes, every byte unknown but those of --prefix.
+    #[arg(short = 'b', long, value_parser = clap::value_parser!(u8).range(1..=16))]
+    pub key_bytes: Option<u8>,
+
+    /// The first bytes of the key, in hex.
+    #[arg(long, value_name = "HEX", requires = "key_bytes")]
+    pub prefix: Option<String>,
+
+    /// The first key number to test, keys being numbered by their unknown bits.
+    #[arg(long, default_value_t = 0)]
+    pub from: u64,
+
+    /// The key number to stop before; all keys of the pattern if absent.
+    #[arg(long)]
+    pub to: Option<u64>,
+
+    /// Go on after a key is found, for every key that fits the pairs.
+    #[arg(long)]
+    pub all: bool,
+
+    /// Number of threads; one per core if absent.
+    #[arg(short = 't', long)]
+    pub threads: Option<usize>,
+
+    /// File to resume the search from, if it exists, and to record its progress in.
+    #[arg(short = 'c', long, value_name = "FILE")]
+    pub checkpoint: Option<PathBuf>,
+
+    /// Seconds between progress reports and checkpoints.
+    #[arg(long, value_name = "SECONDS", default_value = "10", value_parser = interval)]
+    pub interval: Duration,
+
+    /// Test at most this many keys in this run, then stop, to be resumed from the checkpoint.
+    #[arg(long, value_name = "KEYS")]
+    pub stop_after: Option<u64>,
+}
+
+/// A positive, finite number of seconds.
+fn interval(s: &str) -> Result<Duration, String> {
+    let seconds: f64 = s.parse().map_err(|e| format!("{}", e))?;
+    match Duration::try_from_secs_f64(seconds) {
+        Ok(interval) if !interval.is_zero() => Ok(interval),
+        _ => Err("expected a positive number of seconds".to_string()),
+    }
+}
@@ -0,0 +1,91 @@
+//! Checkpoint files, so that an interrupted search resumes where it stopped.
+//!
+//! A checkpoint is JSON: what is searched for and where, every key numbered below `next` tested, and the keys found
+//! so far. It is replaced whole, through a temporary file and a rename, so that a crash leaves the previous one.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::search::{Progress, Search};
+
+/// The cipher of every search, recorded for the day there is more than one.
+pub const CIPHER: &str = "RC5-32/12";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub cipher: String,
+    pub pattern: String,
+    pub pairs: Vec<String>,
+    pub all: bool,
+    /// The range of key numbers searched.
+    pub start: u64,
+    pub end: u64,
+    pub next: u64,
+    pub found: Vec<String>,
+}
+
+impl Checkpoint {
+    pub fn new(search: &Search, start: u64, end: u64) -> Self {
+        Self {
+            cipher: CIPHER.into(),
+            pattern: search.pattern.to_string(),
+            pairs: search.pairs.iter().map(ToString::to_string).collect(),
+            all: search.all,
+            start,
+            end,
+            next: start,
+            found: Vec::new(),
+        }
+    }
+
+    /// The checkpoint at `path`, `None` if there is no file.
+    pub fn load(path: &Path) -> Result<Option<Self>, String> {
+        let json = match fs::read_to_string(path) {
+            Ok(json) => json,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(format!("{}: {}", path.display(), e)),
+        };
+        serde_json::from_str(&json)
+            .map(Some)
+            .map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+        fs::write(&tmp, serde_json::to_string_pretty(self)? + "\n")?;
+        fs::rename(&tmp, path)
+    }
+
+    /// Whether the checkpoint is of the same search over the same range as `other`, whatever its progress.
+    pub fn same_search(&self, other: &Checkpoint) -> bool {
+        (
+            &self.cipher,
+            &self.pattern,
+            &self.pairs,
+            self.all,
+            self.start,
+            self.end,
+        ) == (
+            &other.cipher,
+            &other.pattern,
+            &other.pairs,
+            other.all,
+            other.start,
+            other.end,
+        )
+    }
+
+    /// Records `progress`; keys found again, above `next` when the search was interrupted, are kept once.
+    pub fn update(&mut self, progress: &Progress) {
+        self.next = progress.next;
+        for key in progress.found.iter().map(hex::encode) {
+            if !self.found.contains(&key) {
+                self.found.push(key);
+            }
+        }
+    }
+}
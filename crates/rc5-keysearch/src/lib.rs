@@ -0,0 +1,6 @@
+//! A multi-threaded known-plaintext key search of RC5-32/12 over keys of 1 to 16 bytes with some bits known,
+//! resumable from checkpoint files.
+
+pub mod checkpoint;
+pub mod pattern;
+pub mod search;
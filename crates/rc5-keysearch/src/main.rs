@@ -0,0 +1,156 @@
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use clap::Parser;
+
+mod args;
+
+use args::Cli;
+use rc5_keysearch::checkpoint::{Checkpoint, CIPHER};
+use rc5_keysearch::pattern::KeyPattern;
+use rc5_keysearch::search::{Progress, Search};
+
+/// Set by Ctrl-C: the threads finish their chunks and the checkpoint is saved.
+static STOP: AtomicBool = AtomicBool::new(false);
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(&cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("rc5-keysearch: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// `keys` per second with an SI prefix.
+fn rate(keys_per_second: f64) -> String {
+    let (value, prefix) = match keys_per_second {
+        r if r >= 1e9 => (r / 1e9, "G"),
+        r if r >= 1e6 => (r / 1e6, "M"),
+        r if r >= 1e3 => (r / 1e3, "k"),
+        r => (r, ""),
+    };
+    format!("{:.2} {}keys/s", value, prefix)
+}
+
+fn run(cli: &Cli) -> Result<(), String> {
+    let pattern = match (&cli.pattern, cli.key_bytes) {
+        (Some(pattern), _) => *pattern,
+        (None, Some(len)) => {
+            let prefix = hex::decode(cli.prefix.as_deref().unwrap_or(""))
+                .map_err(|e| format!("prefix: {}", e))?;
+            KeyPattern::prefix(&prefix, len.into())?
+        }
+        (None, None) => unreachable!("clap requires a pattern or a key length"),
+    };
+    let search = Search {
+        pattern,
+        pairs: cli.pairs.clone(),
+        all: cli.all,
+    };
+    let end = cli.to.unwrap_or(u64::MAX).min(pattern.keys());
+    if cli.from >= end {
+        return Err(format!("no keys from {} to {}", cli.from, end));
+    }
+
+    let mut checkpoint = Checkpoint::new(&search, cli.from, end);
+    if let Some(path) = &cli.checkpoint {
+        if let Some(saved) = Checkpoint::load(path)? {
+            if !saved.same_search(&checkpoint) {
+                return Err(format!("{}: checkpoint of another search", path.display()));
+            }
+            checkpoint = saved;
+        }
+    }
+    let percent = |next: u64| 100.0 * (next - cli.from) as f64 / (end - cli.from) as f64;
+
+    println!(
+        "{}, pattern {}, keys {} to {} of 2^{}, {} known pairs",
+        CIPHER,
+        pattern,
+        cli.from,
+        end,
+        pattern.free_bits(),
+        search.pairs.len()
+    );
+    if checkpoint.next > cli.from {
+        println!(
+            "resuming at key {} ({:.1}%)",
+            checkpoint.next,
+            percent(checkpoint.next)
+        );
+    }
+
+    let searched = if checkpoint.next < end && (checkpoint.found.is_empty() || cli.all) {
+        ctrlc::set_handler(|| STOP.store(true, Ordering::Relaxed))
+            .map_err(|e| format!("Ctrl-C: {}", e))?;
+        let run_end = cli
+            .stop_after
+            .map_or(end, |keys| end.min(checkpoint.next.saturating_add(keys)));
+        let threads = cli
+            .threads
+            .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, usize::from));
+
+        let progress = search.run(
+            checkpoint.next..run_end,
+            threads,
+            &STOP,
+            cli.interval,
+            |progress: &Progress| {
+                eprintln!(
+                    "{:.1}%, key {}, {}",
+                    percent(progress.next),
+                    progress.next,
+                    rate(progress.throughput())
+                );
+                if let Some(path) = &cli.checkpoint {
+                    checkpoint.update(progress);
+                    if let Err(e) = checkpoint.save(path) {
+                        eprintln!("rc5-keysearch: {}: {}", path.display(), e);
+                    }
+                }
+            },
+        );
+        checkpoint.update(&progress);
+        if let Some(path) = &cli.checkpoint {
+            checkpoint
+                .save(path)
+                .map_err(|e| format!("{}: {}", path.display(), e))?;
+        }
+        Some(progress)
+    } else {
+        None
+    };
+
+    for key in &checkpoint.found {
+        println!("found key: {}", key);
+    }
+    if let Some(progress) = &searched {
+        println!(
+            "tested {} keys in {:.2} s: {}",
+            progress.searched,
+            progress.elapsed.as_secs_f64(),
+            rate(progress.throughput())
+        );
+    }
+    if checkpoint.next >= end {
+        println!("searched every key");
+        if checkpoint.found.is_empty() {
+            println!("no key fits the pairs");
+        }
+    } else if checkpoint.found.is_empty() || cli.all {
+        println!(
+            "stopped at key {} ({:.1}%); {}",
+            checkpoint.next,
+            percent(checkpoint.next),
+            match &cli.checkpoint {
+                Some(_) => "run again to resume".to_string(),
+                None => format!("--from {} resumes", checkpoint.next),
+            }
+        );
+    }
+    Ok(())
+}
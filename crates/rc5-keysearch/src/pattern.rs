@@ -0,0 +1,156 @@
+//! The keys a search covers: a key length, the bits of the key already known, and the unknown ones, which the
+//! search enumerates.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// The longest key searched, in bytes.
+pub const MAX_KEY_LEN: usize = 16;
+
+/// The most unknown bits of a key: `2^63` keys, beyond any search anyway.
+pub const MAX_FREE_BITS: u32 = 63;
+
+/// The keys of `len` bytes that agree with `base` outside the bits of `free`.
+///
+/// Key byte `i` is byte `i` of the little-endian `base` and `free`. The search numbers the keys from 0 by the
+/// values of their unknown bits, the lowest bit of the first key byte varying fastest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyPattern {
+    len: usize,
+    base: u128,
+    free: u128,
+}
+
+impl KeyPattern {
+    /// The keys that agree with `base` outside the bits set in `mask`.
+    pub fn new(base: &[u8], mask: &[u8]) -> Result<Self, String> {
+        if base.is_empty() || base.len() > MAX_KEY_LEN {
+            return Err(format!("keys are 1 to {} bytes", MAX_KEY_LEN));
+        }
+        if mask.len() != base.len() {
+            return Err("the mask and the key differ in length".into());
+        }
+        let word = |bytes: &[u8]| {
+            let mut word = [0; MAX_KEY_LEN];
+            word[..bytes.len()].copy_from_slice(bytes);
+            u128::from_le_bytes(word)
+        };
+        let free = word(mask);
+        if free.count_ones() > MAX_FREE_BITS {
+            return Err(format!(
+                "{} unknown key bits, at most {}",
+                free.count_ones(),
+                MAX_FREE_BITS
+            ));
+        }
+        Ok(Self {
+            len: base.len(),
+            base: word(base) & !free,
+            free,
+        })
+    }
+
+    /// The keys of `len` bytes that start with `prefix`.
+    pub fn prefix(prefix: &[u8], len: usize) -> Result<Self, String> {
+        if prefix.len() > len {
+            return Err(format!(
+                "a prefix of {} bytes for {}-byte keys",
+                prefix.len(),
+                len
+            ));
+        }
+        let mut base = prefix.to_vec();
+        base.resize(len, 0);
+        let mut mask = vec![0; prefix.len()];
+        mask.resize(len, 0xff);
+        Self::new(&base, &mask)
+    }
+
+    pub fn key_len(&self) -> usize {
+        self.len
+    }
+
+    pub fn free_bits(&self) -> u32 {
+        self.free.count_ones()
+    }
+
+    /// The lowest unknown bit, counted from the lowest bit of the first key byte; 128 if every bit is known.
+    pub fn first_free_bit(&self) -> u32 {
+        self.free.trailing_zeros()
+    }
+
+    /// The number of keys.
+    pub fn keys(&self) -> u64 {
+        1 << self.free_bits()
+    }
+
+    /// The unknown bits of key `index`, the rest zero.
+    pub fn deposit(&self, index: u64) -> u128 {
+        let mut free = self.free;
+        let mut bits = 0;
+        let mut index = index;
+        while free != 0 && index != 0 {
+            let lowest = free & free.wrapping_neg();
+            if index & 1 == 1 {
+                bits |= lowest;
+            }
+            free ^= lowest;
+            index >>= 1;
+        }
+        bits
+    }
+
+    /// The unknown bits of the key after the one with `bits`.
+    pub fn next(&self, bits: u128) -> u128 {
+        (bits | !self.free).wrapping_add(1) & self.free
+    }
+
+    /// The key with the unknown bits `bits`, in the first [`KeyPattern::key_len`] bytes.
+    pub fn key(&self, bits: u128) -> [u8; MAX_KEY_LEN] {
+        (self.base | bits).to_le_bytes()
+    }
+
+    /// Key `index`.
+    pub fn key_at(&self, index: u64) -> Vec<u8> {
+        self.key(self.deposit(index))[..self.len].to_vec()
+    }
+
+    fn bytes(&self, word: u128) -> String {
+        hex::encode(&word.to_le_bytes()[..self.len])
+    }
+}
+
+/// The key in hex with `?` for each unknown hex digit, or `BASE/MASK` when some digit is only partly unknown.
+impl fmt::Display for KeyPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let base = self.bytes(self.base);
+        let free = self.bytes(self.free);
+        if free.chars().any(|c| c != '0' && c != 'f') {
+            return write!(f, "{}/{}", base, free);
+        }
+        let pattern: String = base
+            .chars()
+            .zip(free.chars())
+            .map(|(digit, free)| if free == 'f' { '?' } else { digit })
+            .collect();
+        f.write_str(&pattern)
+    }
+}
+
+/// Either form [`KeyPattern`]'s `Display` writes.
+impl FromStr for KeyPattern {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let decode = |s: &str| hex::decode(s).map_err(|e| format!("{:?}: {}", s, e));
+        if let Some((base, mask)) = s.split_once('/') {
+            return Self::new(&decode(base)?, &decode(mask)?);
+        }
+        let base: String = s.chars().map(|c| if c == '?' { '0' } else { c }).collect();
+        let mask: String = s
+            .chars()
+            .map(|c| if c == '?' { 'f' } else { '0' })
+            .collect();
+        Self::new(&decode(&base)?, &decode(&mask)?)
+    }
+}
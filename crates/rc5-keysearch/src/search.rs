@@ -0,0 +1,304 @@
+//! The search itself: RC5-32/12 rekeyed with every key of a [`KeyPattern`] in a range, on every core.
+
+use std::collections::BTreeSet;
+use std::ops::Range;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use rc5_core::algs::encrypt_slice;
+use rc5_core::std_words::{StdArith, StdMagic};
+use rc5_core::traits::Magic;
+
+use crate::pattern::{KeyPattern, MAX_KEY_LEN};
+
+/// The keys each thread takes at a time.
+pub const CHUNK: u64 = 1 << 16;
+
+/// A plaintext block and its ciphertext under the key searched for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KnownPair {
+    pub plaintext: [u8; 8],
+    pub ciphertext: [u8; 8],
+}
+
+/// `PLAINTEXT:CIPHERTEXT` in hex.
+impl FromStr for KnownPair {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let block = |s: &str| -> Result<[u8; 8], String> {
+            hex::decode(s)
+                .map_err(|e| format!("{:?}: {}", s, e))?
+                .try_into()
+                .map_err(|_| format!("{:?}: an RC5-32 block is 8 bytes", s))
+        };
+        let (plaintext, ciphertext) = s
+            .split_once(':')
+            .ok_or_else(|| format!("{:?}: expected PLAINTEXT:CIPHERTEXT", s))?;
+        Ok(Self {
+            plaintext: block(plaintext)?,
+            ciphertext: block(ciphertext)?,
+        })
+    }
+}
+
+impl std::fmt::Display for KnownPair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}",
+            hex::encode(self.plaintext),
+            hex::encode(self.ciphertext)
+        )
+    }
+}
+
+/// RC5-32/12's expanded key table: `2(r + 1)` words.
+const S_TABLE_LEN: usize = 26;
+
+/// The key schedule of RC5-32/12 for the keys of one pattern, with what they share computed once.
+///
+/// A key is its pattern's `u128` in little-endian order, as RC5-32 reads key words, so key word `k` is bits
+/// `32k..32k + 32` and is the same for every key if no unknown bit falls in it. The first steps of the mixing only
+/// read the key words before the first unknown bit, so they are the same for every key too: they are done once,
+/// and each key copies the table they leave and goes on from there. The remaining steps depend on every key word,
+/// so each key still takes them all.
+///
+/// `WORDS` is `c`, the number of key words, known at compile time so that the mixing steps need no division.
+struct Rekeying<const WORDS: usize> {
+    /// The mixing steps every key shares.
+    shared_steps: usize,
+    /// The tables and registers `A` and `B` after the shared steps.
+    s_table: [u32; S_TABLE_LEN],
+    l_table: [u32; 4],
+    a: u32,
+    b: u32,
+}
+
+impl<const WORDS: usize> Rekeying<WORDS> {
+    fn new(pattern: &KeyPattern) -> Self {
+        assert_eq!(pattern.key_len().div_ceil(4), WORDS);
+        let shared_steps = WORDS.min((pattern.first_free_bit() / 32) as usize);
+
+        let mut s_table = [<StdMagic as Magic<u32>>::P; S_TABLE_LEN];
+        for i in 1..S_TABLE_LEN {
+            s_table[i] = s_table[i - 1].wrapping_add(<StdMagic as Magic<u32>>::Q);
+        }
+        let mut l_table = key_words(pattern.key(0));
+        let (a, b) = mix::<WORDS>(&mut s_table, &mut l_table, 0..shared_steps, 0, 0);
+
+        Self {
+            shared_steps,
+            s_table,
+            l_table,
+            a,
+            b,
+        }
+    }
+
+    /// Expands the key with the unknown bits `bits` into `s_table`.
+    fn expand(&self, pattern: &KeyPattern, bits: u128, s_table: &mut [u32; S_TABLE_LEN]) {
+        let mut l_table = key_words(pattern.key(bits));
+        l_table[..self.shared_steps].copy_from_slice(&self.l_table[..self.shared_steps]);
+        s_table.copy_from_slice(&self.s_table);
+
+        let steps = 3 * S_TABLE_LEN.max(WORDS);
+        mix::<WORDS>(
+            s_table,
+            &mut l_table,
+            self.shared_steps..steps,
+            self.a,
+            self.b,
+        );
+    }
+}
+
+/// The words of a key of up to 16 bytes, zero-padded.
+fn key_words(key: [u8; MAX_KEY_LEN]) -> [u32; 4] {
+    let key = u128::from_le_bytes(key);
+    [0, 1, 2, 3].map(|k| (key >> (32 * k)) as u32)
+}
+
+/// Steps `steps` of the mixing of `WORDS` key words into the S table, from the registers `a` and `b`; returns
+/// them after the last step. It is `s_table_mix_secret_key_slices` of `rc5-core`, able to stop and resume.
+fn mix<const WORDS: usize>(
+    s_table: &mut [u32; S_TABLE_LEN],
+    l_table: &mut [u32; 4],
+    steps: Range<usize>,
+    mut a: u32,
+    mut b: u32,
+) -> (u32, u32) {
+    let (mut i, mut j) = (steps.start % S_TABLE_LEN, steps.start % WORDS);
+    for _ in steps {
+        a = s_table[i].wrapping_add(a).wrapping_add(b).rotate_left(3);
+        s_table[i] = a;
+        b = l_table[j]
+            .wrapping_add(a)
+            .wrapping_add(b)
+            .rotate_left(a.wrapping_add(b));
+        l_table[j] = b;
+
+        i = if i + 1 == S_TABLE_LEN { 0 } else { i + 1 };
+        j = if j + 1 == WORDS { 0 } else { j + 1 };
+    }
+    (a, b)
+}
+
+/// Tests the keys of `pattern` numbered `range`, pushing those that encrypt every pair right to `found`.
+type Searcher = fn(&KeyPattern, &[KnownPair], Range<u64>, &mut Vec<Vec<u8>>);
+
+fn search_range<const WORDS: usize>(
+    pattern: &KeyPattern,
+    pairs: &[KnownPair],
+    range: Range<u64>,
+    found: &mut Vec<Vec<u8>>,
+) {
+    let len = pattern.key_len();
+    let rekeying = Rekeying::<WORDS>::new(pattern);
+    let pairs: Vec<_> = pairs
+        .iter()
+        .map(|pair| (block_words(&pair.plaintext), block_words(&pair.ciphertext)))
+        .collect();
+    let encrypts = |s_table: &[u32], (plaintext, ciphertext): &([u32; 2], [u32; 2])| {
+        let [mut a, mut b] = *plaintext;
+        encrypt_slice::<u32, StdArith>(s_table, &mut a, &mut b);
+        [a, b] == *ciphertext
+    };
+
+    let mut s_table = [0; S_TABLE_LEN];
+    let mut bits = pattern.deposit(range.start);
+    for _ in range {
+        rekeying.expand(pattern, bits, &mut s_table);
+        if pairs.iter().all(|pair| encrypts(&s_table, pair)) {
+            found.push(pattern.key(bits)[..len].to_vec());
+        }
+        bits = pattern.next(bits);
+    }
+}
+
+fn searcher(key_len: usize) -> Searcher {
+    match key_len.div_ceil(4) {
+        1 => search_range::<1>,
+        2 => search_range::<2>,
+        3 => search_range::<3>,
+        4 => search_range::<4>,
+        _ => unreachable!("KeyPattern keeps keys to 1 to 16 bytes"),
+    }
+}
+
+/// The two words of an RC5-32 block, read little-endian.
+fn block_words(block: &[u8; 8]) -> [u32; 2] {
+    [0, 4].map(|i| u32::from_le_bytes(block[i..i + 4].try_into().unwrap()))
+}
+
+/// A known-plaintext search of RC5-32/12 over the keys of a pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Search {
+    pub pattern: KeyPattern,
+    pub pairs: Vec<KnownPair>,
+    /// Whether to go on after a key is found, for all the keys that fit the pairs.
+    pub all: bool,
+}
+
+/// How far a search has gone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Progress {
+    /// Every key numbered below has been tested.
+    pub next: u64,
+    /// The keys tested in this run, some maybe above `next`.
+    pub searched: u64,
+    pub elapsed: Duration,
+    /// The keys that fit every pair, in the order they were found.
+    pub found: Vec<Vec<u8>>,
+}
+
+impl Progress {
+    /// Keys tested per second.
+    pub fn throughput(&self) -> f64 {
+        self.searched as f64 / self.elapsed.as_secs_f64().max(1e-9)
+    }
+}
+
+impl Search {
+    /// Tests the keys numbered `range` on `threads` threads, [`CHUNK`] keys at a time, until all are tested, a key
+    /// is found (unless [`Search::all`]) or `stop` is set. Calls `report` about every `interval` and once at the
+    /// end, with the progress so far.
+    pub fn run(
+        &self,
+        range: Range<u64>,
+        threads: usize,
+        stop: &AtomicBool,
+        interval: Duration,
+        mut report: impl FnMut(&Progress),
+    ) -> Progress {
+        assert!(!self.pairs.is_empty(), "no known pairs");
+        let search = searcher(self.pattern.key_len());
+        let start = Instant::now();
+        let chunks = (range.end.saturating_sub(range.start)).div_ceil(CHUNK);
+        let chunk = |i: u64| range.start + i * CHUNK..range.end.min(range.start + (i + 1) * CHUNK);
+        let next_chunk = AtomicU64::new(0);
+        let mut progress = Progress {
+            next: range.start,
+            searched: 0,
+            elapsed: Duration::ZERO,
+            found: Vec::new(),
+        };
+
+        std::thread::scope(|scope| {
+            let (done, finished) = mpsc::channel();
+            for _ in 0..threads.max(1) {
+                let done = done.clone();
+                let next_chunk = &next_chunk;
+                scope.spawn(move || {
+                    while !stop.load(Ordering::Relaxed) {
+                        let i = next_chunk.fetch_add(1, Ordering::Relaxed);
+                        if i >= chunks {
+                            break;
+                        }
+                        let mut found = Vec::new();
+                        search(&self.pattern, &self.pairs, chunk(i), &mut found);
+                        if done.send((i, found)).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+            drop(done);
+
+            // chunks finish out of order; `next` only moves past a run of finished ones, so only those finished
+            // beyond it are kept: there may be up to 2^47 chunks in all
+            let mut finished_ahead = BTreeSet::new();
+            let mut frontier = 0;
+            let mut last_report = Instant::now();
+            loop {
+                match finished.recv_timeout(interval.saturating_sub(last_report.elapsed())) {
+                    Ok((i, found)) => {
+                        finished_ahead.insert(i);
+                        progress.searched += chunk(i).end - chunk(i).start;
+                        while finished_ahead.remove(&frontier) {
+                            frontier += 1;
+                        }
+                        progress.next = chunk(frontier.min(chunks)).start.min(range.end);
+                        if !found.is_empty() && !self.all {
+                            stop.store(true, Ordering::Relaxed);
+                        }
+                        progress.found.extend(found);
+                    }
+                    Err(mpsc::RecvTimeoutError::Timeout) => {}
+                    Err(mpsc::RecvTimeoutError::Disconnected) => break,
+                }
+                if last_report.elapsed() >= interval {
+                    progress.elapsed = start.elapsed();
+                    report(&progress);
+                    last_report = Instant::now();
+                }
+            }
+        });
+
+        progress.elapsed = start.elapsed();
+        report(&progress);
+        progress
+    }
+}
@@ -0,0 +1,321 @@
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+
+use cipher::{Block, BlockEncrypt};
+use rc5_keysearch::checkpoint::Checkpoint;
+use rc5_keysearch::pattern::KeyPattern;
+use rc5_keysearch::search::{KnownPair, Progress, Search};
+use rc5_rustcrypto_api::rc5::RC5Var;
+
+fn pair(key: &[u8], plaintext: [u8; 8]) -> KnownPair {
+    let cipher = RC5Var::<u32>::new(key, 12).unwrap();
+    let mut block = Block::<RC5Var<u32>>::from(plaintext);
+    cipher.encrypt_block(&mut block);
+    KnownPair {
+        plaintext,
+        ciphertext: block.into(),
+    }
+}
+
+fn run(search: &Search, range: std::ops::Range<u64>, threads: usize) -> Progress {
+    let stop = AtomicBool::new(false);
+    search.run(range, threads, &stop, Duration::from_secs(60), |_| {})
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("rc5-keysearch-{}-{}", std::process::id(), name))
+}
+
+#[test]
+fn patterns() {
+    let pattern: KeyPattern = "a0??c?".parse().unwrap();
+    assert_eq!(pattern.key_len(), 3);
+    assert_eq!(pattern.free_bits(), 12);
+    assert_eq!(pattern.keys(), 4096);
+    assert_eq!(pattern.to_string(), "a0??c?");
+    assert_eq!(pattern.key_at(0), [0xa0, 0x00, 0xc0]);
+    assert_eq!(pattern.key_at(1), [0xa0, 0x01, 0xc0]);
+    assert_eq!(pattern.key_at(0x100), [0xa0, 0x00, 0xc1]);
+    assert_eq!(pattern.key_at(0xfff), [0xa0, 0xff, 0xcf]);
+
+    let masked: KeyPattern = "0102/0180".parse().unwrap();
+    assert_eq!(masked.to_string(), "0002/0180");
+    assert_eq!(masked.keys(), 4);
+    assert_eq!(masked.key_at(3), [0x01, 0x82]);
+    assert_eq!(masked.to_string().parse::<KeyPattern>(), Ok(masked));
+
+    let prefix = KeyPattern::prefix(&[0x12, 0x34], 5).unwrap();
+    assert_eq!(prefix.to_string(), "1234??????");
+    assert_eq!(KeyPattern::prefix(&[], 1).unwrap().to_string(), "??");
+
+    assert!("".parse::<KeyPattern>().is_err());
+    assert!("abc".parse::<KeyPattern>().is_err());
+    assert!("00".repeat(17).parse::<KeyPattern>().is_err());
+    assert!("?".repeat(16).parse::<KeyPattern>().is_err());
+    assert!("00/0000".parse::<KeyPattern>().is_err());
+    assert!(KeyPattern::prefix(&[0; 3], 2).is_err());
+}
+
+/// Stepping from key to key visits the keys in the order of their numbers.
+#[test]
+fn enumeration() {
+    let pattern: KeyPattern = "00ff0000/a50f0180".parse().unwrap();
+    let mut bits = pattern.deposit(0);
+    for index in 0..pattern.keys() {
+        assert_eq!(bits, pattern.deposit(index), "key {}", index);
+        bits = pattern.next(bits);
+    }
+    assert_eq!(bits, 0);
+}
+
+#[test]
+fn known_pairs() {
+    let pair: KnownPair = "0001020304050607:08090a0b0c0d0e0f".parse().unwrap();
+    assert_eq!(pair.plaintext, [0, 1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(pair.ciphertext[7], 0x0f);
+    assert_eq!(pair.to_string(), "0001020304050607:08090a0b0c0d0e0f");
+    assert!("0001020304050607".parse::<KnownPair>().is_err());
+    assert!("00010203:08090a0b".parse::<KnownPair>().is_err());
+}
+
+/// RC5-32/12/16 of Rivest's paper: the zero key takes the zero block to 21A5DBEE154B8F6D.
+#[test]
+fn rivest_vector() {
+    let search = Search {
+        pattern: format!("{}??", "00".repeat(15)).parse().unwrap(),
+        pairs: vec!["0000000000000000:21a5dbee154b8f6d".parse().unwrap()],
+        all: false,
+    };
+    let progress = run(&search, 0..256, 2);
+    assert_eq!(progress.found, [[0; 16]]);
+}
+
+/// Every key length finds the key `RC5Var` encrypted with, whichever key word the unknown byte is in: the key
+/// schedule steps on the key words before it are shared by all the keys.
+#[test]
+fn key_lengths() {
+    for len in 1..=16 {
+        let key: Vec<u8> = (0..len as u8)
+            .map(|i| i.wrapping_mul(0x3b) ^ 0x5c)
+            .collect();
+        for unknown in [0, len / 2, len - 1] {
+            let mut mask = vec![0; len];
+            mask[unknown] = 0xff;
+            let search = Search {
+                pattern: KeyPattern::new(&key, &mask).unwrap(),
+                pairs: vec![pair(&key, *b"knownpt1"), pair(&key, *b"knownpt2")],
+                all: true,
+            };
+            let progress = run(&search, 0..256, 3);
+            assert_eq!(
+                progress.found,
+                [&key[..]],
+                "{}-byte keys, byte {}",
+                len,
+                unknown
+            );
+            assert_eq!((progress.next, progress.searched), (256, 256));
+        }
+
+        let search = Search {
+            pattern: KeyPattern::new(&key, &vec![0; len]).unwrap(),
+            pairs: vec![pair(&key, *b"knownpt1")],
+            all: true,
+        };
+        assert_eq!(run(&search, 0..1, 1).found, [&key[..]], "{}-byte key", len);
+    }
+}
+
+#[test]
+fn ranges_and_stops() {
+    let key = [0x42, 0x13, 0x37];
+    let search = Search {
+        pattern: KeyPattern::prefix(&[0x42], 3).unwrap(),
+        pairs: vec![pair(&key, [0; 8])],
+        all: false,
+    };
+    // the key is number 0x3713
+    let below = run(&search, 0..0x3713, 4);
+    assert!(below.found.is_empty());
+    assert_eq!(below.next, 0x3713);
+
+    let above = run(&search, 0x3713..0x10000, 4);
+    assert_eq!(above.found, [key.to_vec()]);
+    assert!(above.next > 0x3713);
+
+    let stop = AtomicBool::new(true);
+    let stopped = search.run(5..0x10000, 4, &stop, Duration::from_secs(60), |_| {});
+    assert_eq!((stopped.next, stopped.searched), (5, 0));
+
+    let mut reports = 0;
+    let stop = AtomicBool::new(false);
+    search.run(0..10, 1, &stop, Duration::ZERO, |_| reports += 1);
+    assert!(reports >= 1);
+}
+
+#[test]
+fn checkpoints() {
+    let search = Search {
+        pattern: "00??".parse().unwrap(),
+        pairs: vec![pair(&[0, 7], [1; 8])],
+        all: false,
+    };
+    let path = temp_path("checkpoint.json");
+    assert_eq!(Checkpoint::load(&path), Ok(None));
+
+    let mut checkpoint = Checkpoint::new(&search, 0, 256);
+    let progress = Progress {
+        next: 3,
+        searched: 10,
+        elapsed: Duration::from_secs(1),
+        found: vec![vec![0, 7]],
+    };
+    checkpoint.update(&progress);
+    checkpoint.update(&progress);
+    assert_eq!(checkpoint.found, ["0007"]);
+    checkpoint.save(&path).unwrap();
+
+    let saved = Checkpoint::load(&path).unwrap().unwrap();
+    assert_eq!(saved, checkpoint);
+    assert!(saved.same_search(&Checkpoint::new(&search, 0, 256)));
+    assert!(!saved.same_search(&Checkpoint::new(&search, 0, 255)));
+
+    std::fs::write(&path, "{").unwrap();
+    assert!(Checkpoint::load(&path).is_err());
+    std::fs::remove_file(path).unwrap();
+}
+
+fn rc5_keysearch(args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_rc5-keysearch"))
+        .args(args)
+        .output()
+        .expect("spawn rc5-keysearch")
+}
+
+/// A search stopped at 60% resumes from its checkpoint rather than from the start.
+#[test]
+fn resume() {
+    let key = [0xde, 0xad, 0x66, 0xc6];
+    let pair = pair(&key, *b"RC5-32/1").to_string();
+    let path = temp_path("resume.json");
+    let checkpoint = path.to_str().unwrap();
+    // the key is number 0xc666 of 0x10000
+    let args = ["-b", "4", "--prefix", "dead", "-p", &pair, "-c", checkpoint];
+
+    let output = rc5_keysearch(&[&args[..], &["--stop-after", "39321"]].concat());
+    assert!(output.status.success());
+    let report = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        report.starts_with("RC5-32/12, pattern dead????, keys 0 to 65536 of 2^16, 1 known pairs\n")
+    );
+    assert!(report.ends_with("stopped at key 39321 (60.0%); run again to resume\n"));
+    assert_eq!(Checkpoint::load(&path).unwrap().unwrap().next, 39321);
+
+    let output = rc5_keysearch(&args);
+    assert!(output.status.success());
+    let report = String::from_utf8(output.stdout).unwrap();
+    assert!(report.contains("\nresuming at key 39321 (60.0%)\nfound key: dead66c6\n"));
+    assert!(report.ends_with("searched every key\n"));
+    assert_eq!(
+        Checkpoint::load(&path).unwrap().unwrap().found,
+        ["dead66c6"]
+    );
+
+    let output = rc5_keysearch(&["-k", "dead????", "-p", &pair, "-c", checkpoint, "--all"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("checkpoint of another search"));
+    std::fs::remove_file(path).unwrap();
+}
+
+/// The chunks of a 56-bit search are too many to keep a flag for each: only those finished out of order are kept.
+#[test]
+fn large_search() {
+    let key = [0x34, 0x12, 0, 0, 0, 0, 0];
+    let pair = pair(&key, *b"56 bits!").to_string();
+    let output = rc5_keysearch(&["-b", "7", "-p", &pair, "-t", "4"]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let report = String::from_utf8(output.stdout).unwrap();
+    assert!(report
+        .starts_with("RC5-32/12, pattern ??????????????, keys 0 to 72057594037927936 of 2^56"));
+    assert!(
+        report.contains("\nfound key: 34120000000000\n"),
+        "{}",
+        report
+    );
+}
+
+#[test]
+fn command_line() {
+    let output = rc5_keysearch(&[
+        "-k",
+        "0102/0180",
+        "-p",
+        "0000000000000000:0000000000000000",
+        "--to",
+        "3",
+    ]);
+    assert!(output.status.success());
+    let report = String::from_utf8(output.stdout).unwrap();
+    assert!(report.starts_with("RC5-32/12, pattern 0002/0180, keys 0 to 3 of 2^2, 1 known pairs\n"));
+    assert!(report.ends_with("searched every key\nno key fits the pairs\n"));
+
+    for args in [
+        &["-b", "17", "-p", "0000000000000000:0000000000000000"][..],
+        &["-p", "0000000000000000:0000000000000000"],
+        &[
+            "-b",
+            "2",
+            "--prefix",
+            "000000",
+            "-p",
+            "0000000000000000:0000000000000000",
+        ],
+        &[
+            "-b",
+            "2",
+            "--from",
+            "65536",
+            "-p",
+            "0000000000000000:0000000000000000",
+        ],
+        &[
+            "-b",
+            "2",
+            "-p",
+            "0000000000000000:0000000000000000",
+            "--interval=-1",
+        ],
+        &[
+            "-b",
+            "2",
+            "-p",
+            "0000000000000000:0000000000000000",
+            "--interval",
+            "0",
+        ],
+        &[
+            "-b",
+            "2",
+            "-p",
+            "0000000000000000:0000000000000000",
+            "--interval",
+            "NaN",
+        ],
+        &[
+            "-b",
+            "2",
+            "-p",
+            "0000000000000000:0000000000000000",
+            "--interval",
+            "inf",
+        ],
+    ] {
+        assert!(!rc5_keysearch(args).status.success(), "{:?}", args);
+    }
+}
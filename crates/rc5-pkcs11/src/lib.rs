@@ -0,0 +1,1029 @@
+//! A PKCS#11 v2.40 software token providing the RC5 mechanisms, for testing PKCS#11 clients:
+//! `CKM_RC5_KEY_GEN`, `CKM_RC5_ECB`, `CKM_RC5_CBC`, `CKM_RC5_CBC_PAD`, `CKM_RC5_MAC` and `CKM_RC5_MAC_GENERAL`,
+//! with word sizes of 2, 4 and 8 bytes and 0 to 255 rounds.
+//!
+//! The library has one slot, [`SLOT_ID`], whose token needs no login and only holds session objects:
+//! secret keys of type `CKK_RC5`, created with `C_CreateObject` or `C_GenerateKey`.
+//! The functions of `CK_FUNCTION_LIST` the mechanisms do not need return `CKR_FUNCTION_NOT_SUPPORTED`.
+//!
+//! Every exported function is `unsafe`: the caller upholds the pointer contracts of the PKCS#11 specification.
+
+#![allow(non_snake_case, clippy::missing_safety_doc)]
+
+use std::ffi::c_void;
+use std::sync::Mutex;
+
+use rand::RngCore;
+
+pub mod mechanism;
+pub mod token;
+pub mod types;
+
+use mechanism::{Crypt, Direction, Mac, MECHANISMS};
+use token::{Key, Session, Token, MAX_KEY_LEN};
+use types::*;
+
+pub use token::SLOT_ID;
+
+pub const CRYPTOKI_VERSION: CK_VERSION = CK_VERSION {
+    major: 2,
+    minor: 40,
+};
+pub const LIBRARY_VERSION: CK_VERSION = CK_VERSION { major: 0, minor: 1 };
+
+/// `CK_UNAVAILABLE_INFORMATION`.
+const UNAVAILABLE: CK_ULONG = !0;
+
+static TOKEN: Mutex<Option<Token>> = Mutex::new(None);
+
+/// Runs `f` on the token, if the library is initialized, and turns its result into a return value.
+fn with_token(f: impl FnOnce(&mut Token) -> Result<(), CK_RV>) -> CK_RV {
+    let mut token = TOKEN
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    match token.as_mut().map(f) {
+        None => CKR_CRYPTOKI_NOT_INITIALIZED,
+        Some(Ok(())) => CKR_OK,
+        Some(Err(rv)) => rv,
+    }
+}
+
+/// `len` bytes at `ptr`, which may only be null if `len` is 0.
+unsafe fn slice<'a>(ptr: *const u8, len: CK_ULONG) -> Result<&'a [u8], CK_RV> {
+    match (ptr.is_null(), len) {
+        (_, 0) => Ok(&[]),
+        (true, _) => Err(CKR_ARGUMENTS_BAD),
+        (false, len) => Ok(std::slice::from_raw_parts(ptr, len as usize)),
+    }
+}
+
+unsafe fn out<'a, T>(ptr: *mut T) -> Result<&'a mut T, CK_RV> {
+    ptr.as_mut().ok_or(CKR_ARGUMENTS_BAD)
+}
+
+unsafe fn template<'a>(
+    ptr: *const CK_ATTRIBUTE,
+    count: CK_ULONG,
+) -> Result<Vec<(CK_ATTRIBUTE_TYPE, &'a [u8])>, CK_RV> {
+    if count == 0 {
+        return Ok(Vec::new());
+    }
+    if ptr.is_null() {
+        return Err(CKR_ARGUMENTS_BAD);
+    }
+    std::slice::from_raw_parts(ptr, count as usize)
+        .iter()
+        .map(|attribute| {
+            let value = slice(attribute.pValue as *const u8, attribute.ulValueLen)?;
+            Ok((attribute.type_, value))
+        })
+        .collect()
+}
+
+/// Hands `output` to the caller the PKCS#11 way: only its length if `buf` is null, `CKR_BUFFER_TOO_SMALL` if it
+/// does not fit. Returns whether `output` was delivered.
+unsafe fn deliver(output: &[u8], buf: *mut u8, len: *mut CK_ULONG) -> Result<bool, CK_RV> {
+    let len = out(len)?;
+    let available = *len as usize;
+    *len = output.len() as CK_ULONG;
+    if buf.is_null() {
+        return Ok(false);
+    }
+    if available < output.len() {
+        return Err(CKR_BUFFER_TOO_SMALL);
+    }
+    std::ptr::copy_nonoverlapping(output.as_ptr(), buf, output.len());
+    Ok(true)
+}
+
+/// Runs `step` on a copy of the operation in `slot` and delivers its output. A failed `step` ends the operation;
+/// so does a delivered output if `ends`. A length query, or a buffer too small, leaves the operation as it was.
+unsafe fn run<Op: Clone>(
+    slot: &mut Option<Op>,
+    ends: bool,
+    buf: *mut u8,
+    len: *mut CK_ULONG,
+    step: impl FnOnce(&mut Op) -> Result<Vec<u8>, CK_RV>,
+) -> Result<(), CK_RV> {
+    let mut op = slot.clone().ok_or(CKR_OPERATION_NOT_INITIALIZED)?;
+    let output = step(&mut op).inspect_err(|_| *slot = None)?;
+    if deliver(&output, buf, len)? {
+        *slot = if ends { None } else { Some(op) };
+    }
+    Ok(())
+}
+
+fn padded<const N: usize>(text: &str) -> [u8; N] {
+    let mut padded = [b' '; N];
+    padded[..text.len()].copy_from_slice(text.as_bytes());
+    padded
+}
+
+fn check_slot(slot: CK_SLOT_ID) -> Result<(), CK_RV> {
+    if slot != SLOT_ID {
+        return Err(CKR_SLOT_ID_INVALID);
+    }
+    Ok(())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_Initialize(_init_args: *mut c_void) -> CK_RV {
+    let mut token = TOKEN
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if token.is_some() {
+        return CKR_CRYPTOKI_ALREADY_INITIALIZED;
+    }
+    *token = Some(Token::default());
+    CKR_OK
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_Finalize(reserved: *mut c_void) -> CK_RV {
+    if !reserved.is_null() {
+        return CKR_ARGUMENTS_BAD;
+    }
+    let mut token = TOKEN
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    match token.take() {
+        Some(_) => CKR_OK,
+        None => CKR_CRYPTOKI_NOT_INITIALIZED,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_GetInfo(info: *mut CK_INFO) -> CK_RV {
+    with_token(|_| {
+        *out(info)? = CK_INFO {
+            cryptokiVersion: CRYPTOKI_VERSION,
+            manufacturerID: padded("rc5"),
+            flags: 0,
+            libraryDescription: padded("RC5 software token"),
+            libraryVersion: LIBRARY_VERSION,
+        };
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_GetFunctionList(list: *mut *const CK_FUNCTION_LIST) -> CK_RV {
+    match list.as_mut() {
+        Some(list) => {
+            *list = &FUNCTION_LIST;
+            CKR_OK
+        }
+        None => CKR_ARGUMENTS_BAD,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_GetSlotList(
+    _token_present: CK_BBOOL,
+    slots: *mut CK_SLOT_ID,
+    count: *mut CK_ULONG,
+) -> CK_RV {
+    with_token(|_| {
+        let count = out(count)?;
+        if !slots.is_null() {
+            if *count < 1 {
+                *count = 1;
+                return Err(CKR_BUFFER_TOO_SMALL);
+            }
+            *slots = SLOT_ID;
+        }
+        *count = 1;
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_GetSlotInfo(slot: CK_SLOT_ID, info: *mut CK_SLOT_INFO) -> CK_RV {
+    with_token(|_| {
+        check_slot(slot)?;
+        *out(info)? = CK_SLOT_INFO {
+            slotDescription: padded("RC5 software slot"),
+            manufacturerID: padded("rc5"),
+            flags: CKF_TOKEN_PRESENT,
+            hardwareVersion: LIBRARY_VERSION,
+            firmwareVersion: LIBRARY_VERSION,
+        };
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_GetTokenInfo(slot: CK_SLOT_ID, info: *mut CK_TOKEN_INFO) -> CK_RV {
+    with_token(|token| {
+        check_slot(slot)?;
+        let sessions = token.sessions.len() as CK_ULONG;
+        let rw_sessions = token
+            .sessions
+            .values()
+            .filter(|session| session.flags & CKF_RW_SESSION != 0)
+            .count() as CK_ULONG;
+        *out(info)? = CK_TOKEN_INFO {
+            label: padded("RC5 soft token"),
+            manufacturerID: padded("rc5"),
+            model: padded("rc5-pkcs11"),
+            serialNumber: padded("1"),
+            flags: CKF_RNG | CKF_TOKEN_INITIALIZED,
+            ulMaxSessionCount: 0,
+            ulSessionCount: sessions,
+            ulMaxRwSessionCount: 0,
+            ulRwSessionCount: rw_sessions,
+            ulMaxPinLen: 0,
+            ulMinPinLen: 0,
+            ulTotalPublicMemory: UNAVAILABLE,
+            ulFreePublicMemory: UNAVAILABLE,
+            ulTotalPrivateMemory: UNAVAILABLE,
+            ulFreePrivateMemory: UNAVAILABLE,
+            hardwareVersion: LIBRARY_VERSION,
+            firmwareVersion: LIBRARY_VERSION,
+            utcTime: padded(""),
+        };
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_GetMechanismList(
+    slot: CK_SLOT_ID,
+    mechanisms: *mut CK_MECHANISM_TYPE,
+    count: *mut CK_ULONG,
+) -> CK_RV {
+    with_token(|_| {
+        check_slot(slot)?;
+        let count = out(count)?;
+        let available = *count as usize;
+        *count = MECHANISMS.len() as CK_ULONG;
+        if !mechanisms.is_null() {
+            if available < MECHANISMS.len() {
+                return Err(CKR_BUFFER_TOO_SMALL);
+            }
+            for (i, (mechanism, _)) in MECHANISMS.iter().enumerate() {
+                *mechanisms.add(i) = *mechanism;
+            }
+        }
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_GetMechanismInfo(
+    slot: CK_SLOT_ID,
+    mechanism: CK_MECHANISM_TYPE,
+    info: *mut CK_MECHANISM_INFO,
+) -> CK_RV {
+    with_token(|_| {
+        check_slot(slot)?;
+        let (_, flags) = MECHANISMS
+            .iter()
+            .find(|(known, _)| *known == mechanism)
+            .ok_or(CKR_MECHANISM_INVALID)?;
+        *out(info)? = CK_MECHANISM_INFO {
+            ulMinKeySize: 0,
+            ulMaxKeySize: MAX_KEY_LEN as CK_ULONG,
+            flags: *flags,
+        };
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_OpenSession(
+    slot: CK_SLOT_ID,
+    flags: CK_FLAGS,
+    _application: *mut c_void,
+    _notify: CK_NOTIFY,
+    session: *mut CK_SESSION_HANDLE,
+) -> CK_RV {
+    with_token(|token| {
+        check_slot(slot)?;
+        if flags & CKF_SERIAL_SESSION == 0 {
+            return Err(CKR_SESSION_PARALLEL_NOT_SUPPORTED);
+        }
+        let session = out(session)?;
+        *session = token.next_handle();
+        token.sessions.insert(
+            *session,
+            Session {
+                flags,
+                ..Session::default()
+            },
+        );
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_CloseSession(session: CK_SESSION_HANDLE) -> CK_RV {
+    with_token(|token| {
+        token
+            .sessions
+            .remove(&session)
+            .ok_or(CKR_SESSION_HANDLE_INVALID)?;
+        token.objects.retain(|_, key| key.session != session);
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_CloseAllSessions(slot: CK_SLOT_ID) -> CK_RV {
+    with_token(|token| {
+        check_slot(slot)?;
+        token.sessions.clear();
+        token.objects.clear();
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_GetSessionInfo(
+    session: CK_SESSION_HANDLE,
+    info: *mut CK_SESSION_INFO,
+) -> CK_RV {
+    with_token(|token| {
+        let flags = token.session(session)?.flags;
+        *out(info)? = CK_SESSION_INFO {
+            slotID: SLOT_ID,
+            state: if flags & CKF_RW_SESSION != 0 {
+                CKS_RW_PUBLIC_SESSION
+            } else {
+                CKS_RO_PUBLIC_SESSION
+            },
+            flags,
+            ulDeviceError: 0,
+        };
+        Ok(())
+    })
+}
+
+unsafe fn add_key(
+    token: &mut Token,
+    session: CK_SESSION_HANDLE,
+    mut key: Key,
+    handle: *mut CK_OBJECT_HANDLE,
+) -> Result<(), CK_RV> {
+    let handle = out(handle)?;
+    key.session = session;
+    *handle = token.next_handle();
+    token.objects.insert(*handle, key);
+    Ok(())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_CreateObject(
+    session: CK_SESSION_HANDLE,
+    attributes: *mut CK_ATTRIBUTE,
+    count: CK_ULONG,
+    object: *mut CK_OBJECT_HANDLE,
+) -> CK_RV {
+    with_token(|token| {
+        token.session(session)?;
+        let key = Key::from_template(&template(attributes, count)?, None)?;
+        add_key(token, session, key, object)
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_GenerateKey(
+    session: CK_SESSION_HANDLE,
+    mechanism: *mut CK_MECHANISM,
+    attributes: *mut CK_ATTRIBUTE,
+    count: CK_ULONG,
+    key: *mut CK_OBJECT_HANDLE,
+) -> CK_RV {
+    with_token(|token| {
+        token.session(session)?;
+        if out(mechanism)?.mechanism != CKM_RC5_KEY_GEN {
+            return Err(CKR_MECHANISM_INVALID);
+        }
+        let generate = |len: usize| {
+            let mut value = vec![0; len];
+            rand::thread_rng().fill_bytes(&mut value);
+            value
+        };
+        let value = Key::from_template(&template(attributes, count)?, Some(&generate))?;
+        add_key(token, session, value, key)
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_DestroyObject(
+    session: CK_SESSION_HANDLE,
+    object: CK_OBJECT_HANDLE,
+) -> CK_RV {
+    with_token(|token| {
+        token.session(session)?;
+        token
+            .objects
+            .remove(&object)
+            .map(drop)
+            .ok_or(CKR_OBJECT_HANDLE_INVALID)
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_GetAttributeValue(
+    session: CK_SESSION_HANDLE,
+    object: CK_OBJECT_HANDLE,
+    attributes: *mut CK_ATTRIBUTE,
+    count: CK_ULONG,
+) -> CK_RV {
+    with_token(|token| {
+        token.session(session)?;
+        let key = token
+            .objects
+            .get(&object)
+            .ok_or(CKR_OBJECT_HANDLE_INVALID)?;
+        if attributes.is_null() && count > 0 {
+            return Err(CKR_ARGUMENTS_BAD);
+        }
+
+        // every attribute is processed; the last error is the one returned
+        let mut result = Ok(());
+        for i in 0..count as usize {
+            let attribute = &mut *attributes.add(i);
+            match key.attribute(attribute.type_) {
+                Ok(value) if attribute.pValue.is_null() => {
+                    attribute.ulValueLen = value.len() as CK_ULONG;
+                }
+                Ok(value) if (attribute.ulValueLen as usize) < value.len() => {
+                    attribute.ulValueLen = UNAVAILABLE;
+                    result = Err(CKR_BUFFER_TOO_SMALL);
+                }
+                Ok(value) => {
+                    let dst = attribute.pValue as *mut u8;
+                    std::ptr::copy_nonoverlapping(value.as_ptr(), dst, value.len());
+                    attribute.ulValueLen = value.len() as CK_ULONG;
+                }
+                Err(rv) => {
+                    attribute.ulValueLen = UNAVAILABLE;
+                    result = Err(rv);
+                }
+            }
+        }
+        result
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_FindObjectsInit(
+    session: CK_SESSION_HANDLE,
+    attributes: *mut CK_ATTRIBUTE,
+    count: CK_ULONG,
+) -> CK_RV {
+    with_token(|token| {
+        let template = template(attributes, count)?;
+        let mut found: Vec<_> = token
+            .objects
+            .iter()
+            .filter(|(_, key)| key.matches(&template))
+            .map(|(handle, _)| *handle)
+            .collect();
+        found.sort_unstable_by(|a, b| b.cmp(a));
+
+        let session = token.session(session)?;
+        if session.find.is_some() {
+            return Err(CKR_OPERATION_ACTIVE);
+        }
+        session.find = Some(found);
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_FindObjects(
+    session: CK_SESSION_HANDLE,
+    objects: *mut CK_OBJECT_HANDLE,
+    max_count: CK_ULONG,
+    count: *mut CK_ULONG,
+) -> CK_RV {
+    with_token(|token| {
+        let found = token
+            .session(session)?
+            .find
+            .as_mut()
+            .ok_or(CKR_OPERATION_NOT_INITIALIZED)?;
+        let count = out(count)?;
+        if objects.is_null() && max_count > 0 {
+            return Err(CKR_ARGUMENTS_BAD);
+        }
+        // `found` is in reverse order, so that handing out objects pops them
+        let n = found.len().min(max_count as usize);
+        for i in 0..n {
+            *objects.add(i) = found.pop().unwrap();
+        }
+        *count = n as CK_ULONG;
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_FindObjectsFinal(session: CK_SESSION_HANDLE) -> CK_RV {
+    with_token(|token| {
+        token
+            .session(session)?
+            .find
+            .take()
+            .map(drop)
+            .ok_or(CKR_OPERATION_NOT_INITIALIZED)
+    })
+}
+
+unsafe fn crypt_init(
+    session: CK_SESSION_HANDLE,
+    mechanism: *mut CK_MECHANISM,
+    key: CK_OBJECT_HANDLE,
+    direction: Direction,
+) -> CK_RV {
+    with_token(|token| {
+        let usage = match direction {
+            Direction::Encrypt => |key: &Key| key.encrypt,
+            Direction::Decrypt => |key: &Key| key.decrypt,
+        };
+        let (session, key) = token.session_and_key(session, key, usage)?;
+        let slot = match direction {
+            Direction::Encrypt => &mut session.encrypt,
+            Direction::Decrypt => &mut session.decrypt,
+        };
+        if slot.is_some() {
+            return Err(CKR_OPERATION_ACTIVE);
+        }
+        *slot = Some(Crypt::new(out(mechanism)?, &key, direction)?);
+        Ok(())
+    })
+}
+
+unsafe fn crypt(
+    session: CK_SESSION_HANDLE,
+    direction: Direction,
+    step: impl FnOnce(&mut Option<Crypt>) -> Result<(), CK_RV>,
+) -> CK_RV {
+    with_token(|token| {
+        let session = token.session(session)?;
+        step(match direction {
+            Direction::Encrypt => &mut session.encrypt,
+            Direction::Decrypt => &mut session.decrypt,
+        })
+    })
+}
+
+unsafe fn crypt_single(
+    session: CK_SESSION_HANDLE,
+    direction: Direction,
+    input: *mut u8,
+    input_len: CK_ULONG,
+    output: *mut u8,
+    output_len: *mut CK_ULONG,
+) -> CK_RV {
+    crypt(session, direction, |op| {
+        let input = slice(input, input_len).inspect_err(|_| *op = None)?;
+        run(op, true, output, output_len, |op| {
+            let mut output = op.update(input);
+            output.extend(op.finish()?);
+            Ok(output)
+        })
+    })
+}
+
+unsafe fn crypt_update(
+    session: CK_SESSION_HANDLE,
+    direction: Direction,
+    input: *mut u8,
+    input_len: CK_ULONG,
+    output: *mut u8,
+    output_len: *mut CK_ULONG,
+) -> CK_RV {
+    crypt(session, direction, |op| {
+        let input = slice(input, input_len).inspect_err(|_| *op = None)?;
+        run(op, false, output, output_len, |op| Ok(op.update(input)))
+    })
+}
+
+unsafe fn crypt_final(
+    session: CK_SESSION_HANDLE,
+    direction: Direction,
+    output: *mut u8,
+    output_len: *mut CK_ULONG,
+) -> CK_RV {
+    crypt(session, direction, |op| {
+        run(op, true, output, output_len, Crypt::finish)
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_EncryptInit(
+    session: CK_SESSION_HANDLE,
+    mechanism: *mut CK_MECHANISM,
+    key: CK_OBJECT_HANDLE,
+) -> CK_RV {
+    crypt_init(session, mechanism, key, Direction::Encrypt)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_Encrypt(
+    session: CK_SESSION_HANDLE,
+    data: *mut u8,
+    data_len: CK_ULONG,
+    encrypted: *mut u8,
+    encrypted_len: *mut CK_ULONG,
+) -> CK_RV {
+    crypt_single(
+        session,
+        Direction::Encrypt,
+        data,
+        data_len,
+        encrypted,
+        encrypted_len,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_EncryptUpdate(
+    session: CK_SESSION_HANDLE,
+    part: *mut u8,
+    part_len: CK_ULONG,
+    encrypted: *mut u8,
+    encrypted_len: *mut CK_ULONG,
+) -> CK_RV {
+    crypt_update(
+        session,
+        Direction::Encrypt,
+        part,
+        part_len,
+        encrypted,
+        encrypted_len,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_EncryptFinal(
+    session: CK_SESSION_HANDLE,
+    encrypted: *mut u8,
+    encrypted_len: *mut CK_ULONG,
+) -> CK_RV {
+    crypt_final(session, Direction::Encrypt, encrypted, encrypted_len)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_DecryptInit(
+    session: CK_SESSION_HANDLE,
+    mechanism: *mut CK_MECHANISM,
+    key: CK_OBJECT_HANDLE,
+) -> CK_RV {
+    crypt_init(session, mechanism, key, Direction::Decrypt)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_Decrypt(
+    session: CK_SESSION_HANDLE,
+    encrypted: *mut u8,
+    encrypted_len: CK_ULONG,
+    data: *mut u8,
+    data_len: *mut CK_ULONG,
+) -> CK_RV {
+    crypt_single(
+        session,
+        Direction::Decrypt,
+        encrypted,
+        encrypted_len,
+        data,
+        data_len,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_DecryptUpdate(
+    session: CK_SESSION_HANDLE,
+    encrypted: *mut u8,
+    encrypted_len: CK_ULONG,
+    part: *mut u8,
+    part_len: *mut CK_ULONG,
+) -> CK_RV {
+    crypt_update(
+        session,
+        Direction::Decrypt,
+        encrypted,
+        encrypted_len,
+        part,
+        part_len,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_DecryptFinal(
+    session: CK_SESSION_HANDLE,
+    part: *mut u8,
+    part_len: *mut CK_ULONG,
+) -> CK_RV {
+    crypt_final(session, Direction::Decrypt, part, part_len)
+}
+
+unsafe fn mac_init(
+    session: CK_SESSION_HANDLE,
+    mechanism: *mut CK_MECHANISM,
+    key: CK_OBJECT_HANDLE,
+    verify: bool,
+) -> CK_RV {
+    with_token(|token| {
+        let usage = match verify {
+            false => |key: &Key| key.sign,
+            true => |key: &Key| key.verify,
+        };
+        let (session, key) = token.session_and_key(session, key, usage)?;
+        let slot = match verify {
+            false => &mut session.sign,
+            true => &mut session.verify,
+        };
+        if slot.is_some() {
+            return Err(CKR_OPERATION_ACTIVE);
+        }
+        *slot = Some(Mac::new(out(mechanism)?, &key)?);
+        Ok(())
+    })
+}
+
+unsafe fn mac_update(
+    session: CK_SESSION_HANDLE,
+    part: *mut u8,
+    part_len: CK_ULONG,
+    verify: bool,
+) -> CK_RV {
+    with_token(|token| {
+        let session = token.session(session)?;
+        let slot = match verify {
+            false => &mut session.sign,
+            true => &mut session.verify,
+        };
+        let op = slot.as_mut().ok_or(CKR_OPERATION_NOT_INITIALIZED)?;
+        match slice(part, part_len) {
+            Ok(part) => {
+                op.update(part);
+                Ok(())
+            }
+            Err(rv) => {
+                *slot = None;
+                Err(rv)
+            }
+        }
+    })
+}
+
+/// Ends a verification, after feeding it `data` if any, by comparing its MAC with `signature`.
+unsafe fn verify(
+    session: CK_SESSION_HANDLE,
+    data: Option<(*mut u8, CK_ULONG)>,
+    signature: *mut u8,
+    signature_len: CK_ULONG,
+) -> CK_RV {
+    with_token(|token| {
+        let mut op = token
+            .session(session)?
+            .verify
+            .take()
+            .ok_or(CKR_OPERATION_NOT_INITIALIZED)?;
+        if let Some((data, data_len)) = data {
+            op.update(slice(data, data_len)?);
+        }
+        let signature = slice(signature, signature_len)?;
+        if signature.len() != op.mac_len() {
+            return Err(CKR_SIGNATURE_LEN_RANGE);
+        }
+        if op.finish() != signature {
+            return Err(CKR_SIGNATURE_INVALID);
+        }
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_SignInit(
+    session: CK_SESSION_HANDLE,
+    mechanism: *mut CK_MECHANISM,
+    key: CK_OBJECT_HANDLE,
+) -> CK_RV {
+    mac_init(session, mechanism, key, false)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_Sign(
+    session: CK_SESSION_HANDLE,
+    data: *mut u8,
+    data_len: CK_ULONG,
+    signature: *mut u8,
+    signature_len: *mut CK_ULONG,
+) -> CK_RV {
+    with_token(|token| {
+        let slot = &mut token.session(session)?.sign;
+        let data = slice(data, data_len).inspect_err(|_| *slot = None)?;
+        run(slot, true, signature, signature_len, |op| {
+            op.update(data);
+            Ok(op.finish())
+        })
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_SignUpdate(
+    session: CK_SESSION_HANDLE,
+    part: *mut u8,
+    part_len: CK_ULONG,
+) -> CK_RV {
+    mac_update(session, part, part_len, false)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_SignFinal(
+    session: CK_SESSION_HANDLE,
+    signature: *mut u8,
+    signature_len: *mut CK_ULONG,
+) -> CK_RV {
+    with_token(|token| {
+        let slot = &mut token.session(session)?.sign;
+        run(slot, true, signature, signature_len, |op| Ok(op.finish()))
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_VerifyInit(
+    session: CK_SESSION_HANDLE,
+    mechanism: *mut CK_MECHANISM,
+    key: CK_OBJECT_HANDLE,
+) -> CK_RV {
+    mac_init(session, mechanism, key, true)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_Verify(
+    session: CK_SESSION_HANDLE,
+    data: *mut u8,
+    data_len: CK_ULONG,
+    signature: *mut u8,
+    signature_len: CK_ULONG,
+) -> CK_RV {
+    verify(session, Some((data, data_len)), signature, signature_len)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_VerifyUpdate(
+    session: CK_SESSION_HANDLE,
+    part: *mut u8,
+    part_len: CK_ULONG,
+) -> CK_RV {
+    mac_update(session, part, part_len, true)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_VerifyFinal(
+    session: CK_SESSION_HANDLE,
+    signature: *mut u8,
+    signature_len: CK_ULONG,
+) -> CK_RV {
+    verify(session, None, signature, signature_len)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn C_GenerateRandom(
+    session: CK_SESSION_HANDLE,
+    random: *mut u8,
+    len: CK_ULONG,
+) -> CK_RV {
+    with_token(|token| {
+        token.session(session)?;
+        if len > 0 {
+            if random.is_null() {
+                return Err(CKR_ARGUMENTS_BAD);
+            }
+            rand::thread_rng().fill_bytes(std::slice::from_raw_parts_mut(random, len as usize));
+        }
+        Ok(())
+    })
+}
+
+/// Defines functions with the given signatures that return `CKR_FUNCTION_NOT_SUPPORTED`.
+macro_rules! not_supported {
+    ($($name:ident($($arg:ty),*);)*) => {$(
+        #[no_mangle]
+        pub unsafe extern "C" fn $name($(_: $arg),*) -> CK_RV {
+            CKR_FUNCTION_NOT_SUPPORTED
+        }
+    )*};
+}
+
+not_supported! {
+    C_InitToken(CK_SLOT_ID, *mut u8, CK_ULONG, *mut u8);
+    C_InitPIN(CK_SESSION_HANDLE, *mut u8, CK_ULONG);
+    C_SetPIN(CK_SESSION_HANDLE, *mut u8, CK_ULONG, *mut u8, CK_ULONG);
+    C_GetOperationState(CK_SESSION_HANDLE, *mut u8, *mut CK_ULONG);
+    C_SetOperationState(CK_SESSION_HANDLE, *mut u8, CK_ULONG, CK_OBJECT_HANDLE, CK_OBJECT_HANDLE);
+    C_Login(CK_SESSION_HANDLE, CK_USER_TYPE, *mut u8, CK_ULONG);
+    C_Logout(CK_SESSION_HANDLE);
+    C_CopyObject(CK_SESSION_HANDLE, CK_OBJECT_HANDLE, *mut CK_ATTRIBUTE, CK_ULONG, *mut CK_OBJECT_HANDLE);
+    C_GetObjectSize(CK_SESSION_HANDLE, CK_OBJECT_HANDLE, *mut CK_ULONG);
+    C_SetAttributeValue(CK_SESSION_HANDLE, CK_OBJECT_HANDLE, *mut CK_ATTRIBUTE, CK_ULONG);
+    C_DigestInit(CK_SESSION_HANDLE, *mut CK_MECHANISM);
+    C_Digest(CK_SESSION_HANDLE, *mut u8, CK_ULONG, *mut u8, *mut CK_ULONG);
+    C_DigestUpdate(CK_SESSION_HANDLE, *mut u8, CK_ULONG);
+    C_DigestKey(CK_SESSION_HANDLE, CK_OBJECT_HANDLE);
+    C_DigestFinal(CK_SESSION_HANDLE, *mut u8, *mut CK_ULONG);
+    C_SignRecoverInit(CK_SESSION_HANDLE, *mut CK_MECHANISM, CK_OBJECT_HANDLE);
+    C_SignRecover(CK_SESSION_HANDLE, *mut u8, CK_ULONG, *mut u8, *mut CK_ULONG);
+    C_VerifyRecoverInit(CK_SESSION_HANDLE, *mut CK_MECHANISM, CK_OBJECT_HANDLE);
+    C_VerifyRecover(CK_SESSION_HANDLE, *mut u8, CK_ULONG, *mut u8, *mut CK_ULONG);
+    C_DigestEncryptUpdate(CK_SESSION_HANDLE, *mut u8, CK_ULONG, *mut u8, *mut CK_ULONG);
+    C_DecryptDigestUpdate(CK_SESSION_HANDLE, *mut u8, CK_ULONG, *mut u8, *mut CK_ULONG);
+    C_SignEncryptUpdate(CK_SESSION_HANDLE, *mut u8, CK_ULONG, *mut u8, *mut CK_ULONG);
+    C_DecryptVerifyUpdate(CK_SESSION_HANDLE, *mut u8, CK_ULONG, *mut u8, *mut CK_ULONG);
+    C_GenerateKeyPair(
+        CK_SESSION_HANDLE, *mut CK_MECHANISM, *mut CK_ATTRIBUTE, CK_ULONG, *mut CK_ATTRIBUTE, CK_ULONG,
+        *mut CK_OBJECT_HANDLE, *mut CK_OBJECT_HANDLE
+    );
+    C_WrapKey(
+        CK_SESSION_HANDLE, *mut CK_MECHANISM, CK_OBJECT_HANDLE, CK_OBJECT_HANDLE, *mut u8, *mut CK_ULONG
+    );
+    C_UnwrapKey(
+        CK_SESSION_HANDLE, *mut CK_MECHANISM, CK_OBJECT_HANDLE, *mut u8, CK_ULONG, *mut CK_ATTRIBUTE, CK_ULONG,
+        *mut CK_OBJECT_HANDLE
+    );
+    C_DeriveKey(
+        CK_SESSION_HANDLE, *mut CK_MECHANISM, CK_OBJECT_HANDLE, *mut CK_ATTRIBUTE, CK_ULONG, *mut CK_OBJECT_HANDLE
+    );
+    C_SeedRandom(CK_SESSION_HANDLE, *mut u8, CK_ULONG);
+    C_GetFunctionStatus(CK_SESSION_HANDLE);
+    C_CancelFunction(CK_SESSION_HANDLE);
+    C_WaitForSlotEvent(CK_FLAGS, *mut CK_SLOT_ID, *mut c_void);
+}
+
+static FUNCTION_LIST: CK_FUNCTION_LIST = CK_FUNCTION_LIST {
+    version: CRYPTOKI_VERSION,
+    C_Initialize: Some(C_Initialize),
+    C_Finalize: Some(C_Finalize),
+    C_GetInfo: Some(C_GetInfo),
+    C_GetFunctionList: Some(C_GetFunctionList),
+    C_GetSlotList: Some(C_GetSlotList),
+    C_GetSlotInfo: Some(C_GetSlotInfo),
+    C_GetTokenInfo: Some(C_GetTokenInfo),
+    C_GetMechanismList: Some(C_GetMechanismList),
+    C_GetMechanismInfo: Some(C_GetMechanismInfo),
+    C_InitToken: Some(C_InitToken),
+    C_InitPIN: Some(C_InitPIN),
+    C_SetPIN: Some(C_SetPIN),
+    C_OpenSession: Some(C_OpenSession),
+    C_CloseSession: Some(C_CloseSession),
+    C_CloseAllSessions: Some(C_CloseAllSessions),
+    C_GetSessionInfo: Some(C_GetSessionInfo),
+    C_GetOperationState: Some(C_GetOperationState),
+    C_SetOperationState: Some(C_SetOperationState),
+    C_Login: Some(C_Login),
+    C_Logout: Some(C_Logout),
+    C_CreateObject: Some(C_CreateObject),
+    C_CopyObject: Some(C_CopyObject),
+    C_DestroyObject: Some(C_DestroyObject),
+    C_GetObjectSize: Some(C_GetObjectSize),
+    C_GetAttributeValue: Some(C_GetAttributeValue),
+    C_SetAttributeValue: Some(C_SetAttributeValue),
+    C_FindObjectsInit: Some(C_FindObjectsInit),
+    C_FindObjects: Some(C_FindObjects),
+    C_FindObjectsFinal: Some(C_FindObjectsFinal),
+    C_EncryptInit: Some(C_EncryptInit),
+    C_Encrypt: Some(C_Encrypt),
+    C_EncryptUpdate: Some(C_EncryptUpdate),
+    C_EncryptFinal: Some(C_EncryptFinal),
+    C_DecryptInit: Some(C_DecryptInit),
+    C_Decrypt: Some(C_Decrypt),
+    C_DecryptUpdate: Some(C_DecryptUpdate),
+    C_DecryptFinal: Some(C_DecryptFinal),
+    C_DigestInit: Some(C_DigestInit),
+    C_Digest: Some(C_Digest),
+    C_DigestUpdate: Some(C_DigestUpdate),
+    C_DigestKey: Some(C_DigestKey),
+    C_DigestFinal: Some(C_DigestFinal),
+    C_SignInit: Some(C_SignInit),
+    C_Sign: Some(C_Sign),
+    C_SignUpdate: Some(C_SignUpdate),
+    C_SignFinal: Some(C_SignFinal),
+    C_SignRecoverInit: Some(C_SignRecoverInit),
+    C_SignRecover: Some(C_SignRecover),
+    C_VerifyInit: Some(C_VerifyInit),
+    C_Verify: Some(C_Verify),
+    C_VerifyUpdate: Some(C_VerifyUpdate),
+    C_VerifyFinal: Some(C_VerifyFinal),
+    C_VerifyRecoverInit: Some(C_VerifyRecoverInit),
+    C_VerifyRecover: Some(C_VerifyRecover),
+    C_DigestEncryptUpdate: Some(C_DigestEncryptUpdate),
+    C_DecryptDigestUpdate: Some(C_DecryptDigestUpdate),
+    C_SignEncryptUpdate: Some(C_SignEncryptUpdate),
+    C_DecryptVerifyUpdate: Some(C_DecryptVerifyUpdate),
+    C_GenerateKey: Some(C_GenerateKey),
+    C_GenerateKeyPair: Some(C_GenerateKeyPair),
+    C_WrapKey: Some(C_WrapKey),
+    C_UnwrapKey: Some(C_UnwrapKey),
+    C_DeriveKey: Some(C_DeriveKey),
+    C_SeedRandom: Some(C_SeedRandom),
+    C_GenerateRandom: Some(C_GenerateRandom),
+    C_GetFunctionStatus: Some(C_GetFunctionStatus),
+    C_CancelFunction: Some(C_CancelFunction),
+    C_WaitForSlotEvent: Some(C_WaitForSlotEvent),
+};
@@ -0,0 +1,283 @@
+//! The RC5 mechanisms: parameter parsing and the state of multi-part operations.
+
+use cipher::generic_array::GenericArray;
+use cipher::{BlockDecrypt, BlockEncrypt};
+use rc5_rustcrypto_api::rc5::{RC5Var, MAX_ROUNDS};
+
+use crate::types::*;
+
+/// The word sizes, in bytes, of the ciphers the token provides.
+pub const WORD_SIZES: [CK_ULONG; 3] = [2, 4, 8];
+
+/// The mechanisms of the token, with the flags of their `CK_MECHANISM_INFO`.
+pub const MECHANISMS: [(CK_MECHANISM_TYPE, CK_FLAGS); 6] = [
+    (CKM_RC5_KEY_GEN, CKF_GENERATE),
+    (CKM_RC5_ECB, CKF_ENCRYPT | CKF_DECRYPT),
+    (CKM_RC5_CBC, CKF_ENCRYPT | CKF_DECRYPT),
+    (CKM_RC5_CBC_PAD, CKF_ENCRYPT | CKF_DECRYPT),
+    (CKM_RC5_MAC, CKF_SIGN | CKF_VERIFY),
+    (CKM_RC5_MAC_GENERAL, CKF_SIGN | CKF_VERIFY),
+];
+
+/// RC5 with a word size chosen at runtime; the key tables are sized for the most rounds, hence the boxes.
+#[derive(Clone)]
+pub enum Cipher {
+    W16(Box<RC5Var<u16>>),
+    W32(Box<RC5Var<u32>>),
+    W64(Box<RC5Var<u64>>),
+}
+
+impl Cipher {
+    pub fn new(word_size: CK_ULONG, rounds: CK_ULONG, key: &[u8]) -> Result<Self, CK_RV> {
+        let rounds = match u8::try_from(rounds) {
+            Ok(rounds) if rounds as usize <= MAX_ROUNDS => rounds,
+            _ => return Err(CKR_MECHANISM_PARAM_INVALID),
+        };
+        let cipher = match word_size {
+            2 => RC5Var::new(key, rounds).map(|c| Self::W16(Box::new(c))),
+            4 => RC5Var::new(key, rounds).map(|c| Self::W32(Box::new(c))),
+            8 => RC5Var::new(key, rounds).map(|c| Self::W64(Box::new(c))),
+            _ => return Err(CKR_MECHANISM_PARAM_INVALID),
+        };
+        cipher.map_err(|_| CKR_KEY_SIZE_RANGE)
+    }
+
+    pub fn block_len(&self) -> usize {
+        match self {
+            Self::W16(_) => 4,
+            Self::W32(_) => 8,
+            Self::W64(_) => 16,
+        }
+    }
+
+    fn encrypt_block(&self, block: &mut [u8]) {
+        match self {
+            Self::W16(cipher) => cipher.encrypt_block(GenericArray::from_mut_slice(block)),
+            Self::W32(cipher) => cipher.encrypt_block(GenericArray::from_mut_slice(block)),
+            Self::W64(cipher) => cipher.encrypt_block(GenericArray::from_mut_slice(block)),
+        }
+    }
+
+    fn decrypt_block(&self, block: &mut [u8]) {
+        match self {
+            Self::W16(cipher) => cipher.decrypt_block(GenericArray::from_mut_slice(block)),
+            Self::W32(cipher) => cipher.decrypt_block(GenericArray::from_mut_slice(block)),
+            Self::W64(cipher) => cipher.decrypt_block(GenericArray::from_mut_slice(block)),
+        }
+    }
+}
+
+/// Reads the parameters of `mechanism` as a `T`, checking their length.
+///
+/// # Safety
+/// `mechanism.pParameter` must point to `mechanism.ulParameterLen` readable bytes.
+unsafe fn params<T: Copy>(mechanism: &CK_MECHANISM) -> Result<T, CK_RV> {
+    if mechanism.pParameter.is_null()
+        || mechanism.ulParameterLen as usize != std::mem::size_of::<T>()
+    {
+        return Err(CKR_MECHANISM_PARAM_INVALID);
+    }
+    Ok(std::ptr::read_unaligned(mechanism.pParameter as *const T))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Encrypt,
+    Decrypt,
+}
+
+#[derive(Clone)]
+enum Mode {
+    Ecb,
+    Cbc { iv: Vec<u8>, pad: bool },
+}
+
+/// A multi-part `CKM_RC5_ECB`, `CKM_RC5_CBC` or `CKM_RC5_CBC_PAD` encryption or decryption.
+#[derive(Clone)]
+pub struct Crypt {
+    cipher: Cipher,
+    mode: Mode,
+    direction: Direction,
+    /// Input not processed yet: a partial block, or the last full block when decrypting with padding.
+    buffer: Vec<u8>,
+}
+
+impl Crypt {
+    /// # Safety
+    /// The parameters of `mechanism` must be valid for reads, see [`params`].
+    pub unsafe fn new(
+        mechanism: &CK_MECHANISM,
+        key: &[u8],
+        direction: Direction,
+    ) -> Result<Self, CK_RV> {
+        let (cipher, mode) = match mechanism.mechanism {
+            CKM_RC5_ECB => {
+                let params = params::<CK_RC5_PARAMS>(mechanism)?;
+                (
+                    Cipher::new(params.ulWordsize, params.ulRounds, key)?,
+                    Mode::Ecb,
+                )
+            }
+            CKM_RC5_CBC | CKM_RC5_CBC_PAD => {
+                let params = params::<CK_RC5_CBC_PARAMS>(mechanism)?;
+                let cipher = Cipher::new(params.ulWordsize, params.ulRounds, key)?;
+                if params.pIv.is_null() || params.ulIvLen as usize != cipher.block_len() {
+                    return Err(CKR_MECHANISM_PARAM_INVALID);
+                }
+                let iv = std::slice::from_raw_parts(params.pIv, cipher.block_len()).to_vec();
+                let pad = mechanism.mechanism == CKM_RC5_CBC_PAD;
+                (cipher, Mode::Cbc { iv, pad })
+            }
+            _ => return Err(CKR_MECHANISM_INVALID),
+        };
+
+        Ok(Self {
+            cipher,
+            mode,
+            direction,
+            buffer: Vec::new(),
+        })
+    }
+
+    fn pads(&self) -> bool {
+        matches!(self.mode, Mode::Cbc { pad: true, .. })
+    }
+
+    /// Processes the complete blocks of the buffered input and `input`.
+    pub fn update(&mut self, input: &[u8]) -> Vec<u8> {
+        let block_len = self.cipher.block_len();
+        self.buffer.extend_from_slice(input);
+
+        let mut ready = self.buffer.len() / block_len * block_len;
+        // the last block may hold the padding, which only the final part can tell
+        if self.direction == Direction::Decrypt && self.pads() && ready == self.buffer.len() {
+            ready = ready.saturating_sub(block_len);
+        }
+        let rest = self.buffer.split_off(ready);
+        let mut output = std::mem::replace(&mut self.buffer, rest);
+        self.process(&mut output);
+
+        output
+    }
+
+    /// Processes the buffered input; the operation ends with it.
+    pub fn finish(&mut self) -> Result<Vec<u8>, CK_RV> {
+        let block_len = self.cipher.block_len();
+        let mut output = std::mem::take(&mut self.buffer);
+
+        match self.direction {
+            Direction::Encrypt if self.pads() => {
+                let pad = block_len - output.len();
+                output.resize(block_len, pad as u8);
+            }
+            Direction::Encrypt if !output.is_empty() => return Err(CKR_DATA_LEN_RANGE),
+            Direction::Decrypt if output.len() != block_len * self.pads() as usize => {
+                return Err(CKR_ENCRYPTED_DATA_LEN_RANGE);
+            }
+            _ => {}
+        }
+        self.process(&mut output);
+
+        if self.direction == Direction::Decrypt && self.pads() {
+            let pad = output[block_len - 1] as usize;
+            if pad == 0
+                || pad > block_len
+                || output[block_len - pad..].iter().any(|&b| b as usize != pad)
+            {
+                return Err(CKR_ENCRYPTED_DATA_INVALID);
+            }
+            output.truncate(block_len - pad);
+        }
+
+        Ok(output)
+    }
+
+    fn process(&mut self, data: &mut [u8]) {
+        let block_len = self.cipher.block_len();
+        for block in data.chunks_exact_mut(block_len) {
+            match (&mut self.mode, self.direction) {
+                (Mode::Ecb, Direction::Encrypt) => self.cipher.encrypt_block(block),
+                (Mode::Ecb, Direction::Decrypt) => self.cipher.decrypt_block(block),
+                (Mode::Cbc { iv, .. }, Direction::Encrypt) => {
+                    block.iter_mut().zip(iv.iter()).for_each(|(b, v)| *b ^= v);
+                    self.cipher.encrypt_block(block);
+                    iv.copy_from_slice(block);
+                }
+                (Mode::Cbc { iv, .. }, Direction::Decrypt) => {
+                    let next = block.to_vec();
+                    self.cipher.decrypt_block(block);
+                    block.iter_mut().zip(iv.iter()).for_each(|(b, v)| *b ^= v);
+                    iv.copy_from_slice(&next);
+                }
+            }
+        }
+    }
+}
+
+/// A multi-part `CKM_RC5_MAC` or `CKM_RC5_MAC_GENERAL` signature or verification: CBC-MAC with a zero IV,
+/// the last block padded with zeroes, truncated to the MAC length.
+#[derive(Clone)]
+pub struct Mac {
+    cipher: Cipher,
+    mac_len: usize,
+    state: Vec<u8>,
+    buffer: Vec<u8>,
+}
+
+impl Mac {
+    /// # Safety
+    /// The parameters of `mechanism` must be valid for reads, see [`params`].
+    pub unsafe fn new(mechanism: &CK_MECHANISM, key: &[u8]) -> Result<Self, CK_RV> {
+        let (cipher, mac_len) = match mechanism.mechanism {
+            CKM_RC5_MAC => {
+                let params = params::<CK_RC5_PARAMS>(mechanism)?;
+                let cipher = Cipher::new(params.ulWordsize, params.ulRounds, key)?;
+                let mac_len = cipher.block_len() / 2;
+                (cipher, mac_len)
+            }
+            CKM_RC5_MAC_GENERAL => {
+                let params = params::<CK_RC5_MAC_GENERAL_PARAMS>(mechanism)?;
+                let cipher = Cipher::new(params.ulWordsize, params.ulRounds, key)?;
+                if params.ulMacLength as usize > cipher.block_len() {
+                    return Err(CKR_MECHANISM_PARAM_INVALID);
+                }
+                (cipher, params.ulMacLength as usize)
+            }
+            _ => return Err(CKR_MECHANISM_INVALID),
+        };
+
+        Ok(Self {
+            state: vec![0; cipher.block_len()],
+            cipher,
+            mac_len,
+            buffer: Vec::new(),
+        })
+    }
+
+    pub fn mac_len(&self) -> usize {
+        self.mac_len
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        let block_len = self.cipher.block_len();
+        self.buffer.extend_from_slice(data);
+
+        let ready = self.buffer.len() / block_len * block_len;
+        let rest = self.buffer.split_off(ready);
+        for block in self.buffer.chunks_exact(block_len) {
+            self.state.iter_mut().zip(block).for_each(|(s, b)| *s ^= b);
+            self.cipher.encrypt_block(&mut self.state);
+        }
+        self.buffer = rest;
+    }
+
+    /// The MAC of the data; the operation ends with it.
+    pub fn finish(&mut self) -> Vec<u8> {
+        if !self.buffer.is_empty() {
+            let mut last = std::mem::take(&mut self.buffer);
+            last.resize(self.cipher.block_len(), 0);
+            self.update(&last);
+        }
+        self.state[..self.mac_len].to_vec()
+    }
+}
@@ -0,0 +1,178 @@
+//! The state of the library: one slot with one token holding session RC5 keys.
+
+use std::collections::HashMap;
+
+use crate::mechanism::{Crypt, Mac};
+use crate::types::*;
+
+pub const SLOT_ID: CK_SLOT_ID = 1;
+pub const MAX_KEY_LEN: usize = rc5_rustcrypto_api::rc5::MAX_KEY_LEN;
+
+/// A secret key object of type `CKK_RC5`.
+#[derive(Clone)]
+pub struct Key {
+    /// The session that created the key, which destroys it when closed.
+    pub session: CK_SESSION_HANDLE,
+    pub value: Vec<u8>,
+    pub label: Vec<u8>,
+    pub encrypt: bool,
+    pub decrypt: bool,
+    pub sign: bool,
+    pub verify: bool,
+    pub sensitive: bool,
+    pub extractable: bool,
+}
+
+impl Key {
+    /// Builds a key from a `C_CreateObject` or `C_GenerateKey` template; `value` is given by `CKA_VALUE`
+    /// when creating, and generated from `CKA_VALUE_LEN` when generating.
+    pub fn from_template(
+        template: &[(CK_ATTRIBUTE_TYPE, &[u8])],
+        generate: Option<&dyn Fn(usize) -> Vec<u8>>,
+    ) -> Result<Self, CK_RV> {
+        let mut key = Key {
+            session: CK_INVALID_HANDLE,
+            value: Vec::new(),
+            label: Vec::new(),
+            encrypt: true,
+            decrypt: true,
+            sign: true,
+            verify: true,
+            sensitive: false,
+            extractable: true,
+        };
+        let mut value = None;
+        let mut value_len = None;
+
+        for &(type_, bytes) in template {
+            match type_ {
+                CKA_CLASS if ulong(bytes)? != CKO_SECRET_KEY => {
+                    return Err(CKR_TEMPLATE_INCONSISTENT)
+                }
+                CKA_KEY_TYPE if ulong(bytes)? != CKK_RC5 => return Err(CKR_TEMPLATE_INCONSISTENT),
+                CKA_CLASS | CKA_KEY_TYPE => {}
+                // session objects only, readable without login
+                CKA_TOKEN | CKA_PRIVATE if bool(bytes)? => return Err(CKR_TEMPLATE_INCONSISTENT),
+                CKA_TOKEN | CKA_PRIVATE => {}
+                CKA_LABEL => key.label = bytes.to_vec(),
+                CKA_VALUE if generate.is_none() => value = Some(bytes.to_vec()),
+                CKA_VALUE_LEN if generate.is_some() => value_len = Some(ulong(bytes)? as usize),
+                CKA_ENCRYPT => key.encrypt = bool(bytes)?,
+                CKA_DECRYPT => key.decrypt = bool(bytes)?,
+                CKA_SIGN => key.sign = bool(bytes)?,
+                CKA_VERIFY => key.verify = bool(bytes)?,
+                CKA_SENSITIVE => key.sensitive = bool(bytes)?,
+                CKA_EXTRACTABLE => key.extractable = bool(bytes)?,
+                CKA_VALUE | CKA_VALUE_LEN => return Err(CKR_TEMPLATE_INCONSISTENT),
+                _ => return Err(CKR_ATTRIBUTE_TYPE_INVALID),
+            }
+        }
+
+        key.value = match (generate, value, value_len) {
+            (None, Some(value), _) => value,
+            (Some(generate), _, Some(len)) => generate(len),
+            _ => return Err(CKR_TEMPLATE_INCOMPLETE),
+        };
+        if key.value.len() > MAX_KEY_LEN {
+            return Err(if generate.is_some() {
+                CKR_KEY_SIZE_RANGE
+            } else {
+                CKR_ATTRIBUTE_VALUE_INVALID
+            });
+        }
+
+        Ok(key)
+    }
+
+    /// The value of an attribute as `C_GetAttributeValue` returns it.
+    pub fn attribute(&self, type_: CK_ATTRIBUTE_TYPE) -> Result<Vec<u8>, CK_RV> {
+        let flag = |value: bool| vec![value as CK_BBOOL];
+        Ok(match type_ {
+            CKA_CLASS => CKO_SECRET_KEY.to_ne_bytes().to_vec(),
+            CKA_KEY_TYPE => CKK_RC5.to_ne_bytes().to_vec(),
+            CKA_TOKEN | CKA_PRIVATE => flag(false),
+            CKA_LABEL => self.label.clone(),
+            CKA_VALUE if self.sensitive || !self.extractable => {
+                return Err(CKR_ATTRIBUTE_SENSITIVE)
+            }
+            CKA_VALUE => self.value.clone(),
+            CKA_VALUE_LEN => (self.value.len() as CK_ULONG).to_ne_bytes().to_vec(),
+            CKA_ENCRYPT => flag(self.encrypt),
+            CKA_DECRYPT => flag(self.decrypt),
+            CKA_SIGN => flag(self.sign),
+            CKA_VERIFY => flag(self.verify),
+            CKA_SENSITIVE => flag(self.sensitive),
+            CKA_EXTRACTABLE => flag(self.extractable),
+            _ => return Err(CKR_ATTRIBUTE_TYPE_INVALID),
+        })
+    }
+
+    /// Whether the key has all the attributes of a `C_FindObjectsInit` template.
+    pub fn matches(&self, template: &[(CK_ATTRIBUTE_TYPE, &[u8])]) -> bool {
+        template
+            .iter()
+            .all(|&(type_, value)| self.attribute(type_).is_ok_and(|own| own == value))
+    }
+}
+
+fn ulong(bytes: &[u8]) -> Result<CK_ULONG, CK_RV> {
+    bytes
+        .try_into()
+        .map(CK_ULONG::from_ne_bytes)
+        .map_err(|_| CKR_ATTRIBUTE_VALUE_INVALID)
+}
+
+fn bool(bytes: &[u8]) -> Result<bool, CK_RV> {
+    match bytes {
+        [CK_FALSE] => Ok(false),
+        [CK_TRUE] => Ok(true),
+        _ => Err(CKR_ATTRIBUTE_VALUE_INVALID),
+    }
+}
+
+#[derive(Default)]
+pub struct Session {
+    pub flags: CK_FLAGS,
+    pub encrypt: Option<Crypt>,
+    pub decrypt: Option<Crypt>,
+    pub sign: Option<Mac>,
+    pub verify: Option<Mac>,
+    pub find: Option<Vec<CK_OBJECT_HANDLE>>,
+}
+
+#[derive(Default)]
+pub struct Token {
+    pub sessions: HashMap<CK_SESSION_HANDLE, Session>,
+    pub objects: HashMap<CK_OBJECT_HANDLE, Key>,
+    /// The last handle given out, to sessions and objects alike.
+    last_handle: CK_ULONG,
+}
+
+impl Token {
+    pub fn next_handle(&mut self) -> CK_ULONG {
+        self.last_handle += 1;
+        self.last_handle
+    }
+
+    pub fn session(&mut self, handle: CK_SESSION_HANDLE) -> Result<&mut Session, CK_RV> {
+        self.sessions
+            .get_mut(&handle)
+            .ok_or(CKR_SESSION_HANDLE_INVALID)
+    }
+
+    /// The session and the key of an operation, checking the key permits `usage`.
+    pub fn session_and_key(
+        &mut self,
+        session: CK_SESSION_HANDLE,
+        key: CK_OBJECT_HANDLE,
+        usage: fn(&Key) -> bool,
+    ) -> Result<(&mut Session, Vec<u8>), CK_RV> {
+        self.session(session)?;
+        let key = self.objects.get(&key).ok_or(CKR_KEY_HANDLE_INVALID)?;
+        if !usage(key) {
+            return Err(CKR_KEY_FUNCTION_NOT_PERMITTED);
+        }
+        let value = key.value.clone();
+        Ok((self.session(session)?, value))
+    }
+}
@@ -0,0 +1,394 @@
+//! The subset of the PKCS#11 v2.40 C types and constants the token uses, named as in `pkcs11t.h`.
+//!
+//! Structures are laid out as on Unix: the headers only pack them to 1 byte on Windows.
+
+#![allow(non_camel_case_types, non_snake_case)]
+
+use std::ffi::c_void;
+use std::os::raw::c_ulong;
+
+pub type CK_BYTE = u8;
+pub type CK_BBOOL = u8;
+pub type CK_ULONG = c_ulong;
+pub type CK_FLAGS = CK_ULONG;
+pub type CK_RV = CK_ULONG;
+pub type CK_SLOT_ID = CK_ULONG;
+pub type CK_SESSION_HANDLE = CK_ULONG;
+pub type CK_OBJECT_HANDLE = CK_ULONG;
+pub type CK_OBJECT_CLASS = CK_ULONG;
+pub type CK_KEY_TYPE = CK_ULONG;
+pub type CK_ATTRIBUTE_TYPE = CK_ULONG;
+pub type CK_MECHANISM_TYPE = CK_ULONG;
+pub type CK_USER_TYPE = CK_ULONG;
+pub type CK_STATE = CK_ULONG;
+pub type CK_NOTIFY =
+    Option<unsafe extern "C" fn(CK_SESSION_HANDLE, CK_ULONG, *mut c_void) -> CK_RV>;
+
+pub const CK_TRUE: CK_BBOOL = 1;
+pub const CK_FALSE: CK_BBOOL = 0;
+pub const CK_INVALID_HANDLE: CK_ULONG = 0;
+
+pub const CKR_OK: CK_RV = 0x000;
+pub const CKR_HOST_MEMORY: CK_RV = 0x002;
+pub const CKR_SLOT_ID_INVALID: CK_RV = 0x003;
+pub const CKR_GENERAL_ERROR: CK_RV = 0x005;
+pub const CKR_ARGUMENTS_BAD: CK_RV = 0x007;
+pub const CKR_ATTRIBUTE_READ_ONLY: CK_RV = 0x010;
+pub const CKR_ATTRIBUTE_SENSITIVE: CK_RV = 0x011;
+pub const CKR_ATTRIBUTE_TYPE_INVALID: CK_RV = 0x012;
+pub const CKR_ATTRIBUTE_VALUE_INVALID: CK_RV = 0x013;
+pub const CKR_DATA_LEN_RANGE: CK_RV = 0x021;
+pub const CKR_ENCRYPTED_DATA_INVALID: CK_RV = 0x040;
+pub const CKR_ENCRYPTED_DATA_LEN_RANGE: CK_RV = 0x041;
+pub const CKR_FUNCTION_NOT_SUPPORTED: CK_RV = 0x054;
+pub const CKR_KEY_HANDLE_INVALID: CK_RV = 0x060;
+pub const CKR_KEY_SIZE_RANGE: CK_RV = 0x062;
+pub const CKR_KEY_TYPE_INCONSISTENT: CK_RV = 0x063;
+pub const CKR_KEY_FUNCTION_NOT_PERMITTED: CK_RV = 0x068;
+pub const CKR_MECHANISM_INVALID: CK_RV = 0x070;
+pub const CKR_MECHANISM_PARAM_INVALID: CK_RV = 0x071;
+pub const CKR_OBJECT_HANDLE_INVALID: CK_RV = 0x082;
+pub const CKR_OPERATION_ACTIVE: CK_RV = 0x090;
+pub const CKR_OPERATION_NOT_INITIALIZED: CK_RV = 0x091;
+pub const CKR_SESSION_HANDLE_INVALID: CK_RV = 0x0B3;
+pub const CKR_SESSION_PARALLEL_NOT_SUPPORTED: CK_RV = 0x0B4;
+pub const CKR_SIGNATURE_INVALID: CK_RV = 0x0C0;
+pub const CKR_SIGNATURE_LEN_RANGE: CK_RV = 0x0C1;
+pub const CKR_TEMPLATE_INCOMPLETE: CK_RV = 0x0D0;
+pub const CKR_TEMPLATE_INCONSISTENT: CK_RV = 0x0D1;
+pub const CKR_BUFFER_TOO_SMALL: CK_RV = 0x150;
+pub const CKR_CRYPTOKI_NOT_INITIALIZED: CK_RV = 0x190;
+pub const CKR_CRYPTOKI_ALREADY_INITIALIZED: CK_RV = 0x191;
+
+pub const CKF_TOKEN_PRESENT: CK_FLAGS = 0x0001;
+pub const CKF_RNG: CK_FLAGS = 0x0001;
+pub const CKF_TOKEN_INITIALIZED: CK_FLAGS = 0x0400;
+pub const CKF_RW_SESSION: CK_FLAGS = 0x0002;
+pub const CKF_SERIAL_SESSION: CK_FLAGS = 0x0004;
+pub const CKF_ENCRYPT: CK_FLAGS = 0x0100;
+pub const CKF_DECRYPT: CK_FLAGS = 0x0200;
+pub const CKF_SIGN: CK_FLAGS = 0x0800;
+pub const CKF_VERIFY: CK_FLAGS = 0x2000;
+pub const CKF_GENERATE: CK_FLAGS = 0x8000;
+
+pub const CKS_RW_PUBLIC_SESSION: CK_STATE = 2;
+pub const CKS_RO_PUBLIC_SESSION: CK_STATE = 0;
+
+pub const CKO_SECRET_KEY: CK_OBJECT_CLASS = 4;
+pub const CKK_RC5: CK_KEY_TYPE = 0x19;
+
+pub const CKA_CLASS: CK_ATTRIBUTE_TYPE = 0x000;
+pub const CKA_TOKEN: CK_ATTRIBUTE_TYPE = 0x001;
+pub const CKA_PRIVATE: CK_ATTRIBUTE_TYPE = 0x002;
+pub const CKA_LABEL: CK_ATTRIBUTE_TYPE = 0x003;
+pub const CKA_VALUE: CK_ATTRIBUTE_TYPE = 0x011;
+pub const CKA_KEY_TYPE: CK_ATTRIBUTE_TYPE = 0x100;
+pub const CKA_SENSITIVE: CK_ATTRIBUTE_TYPE = 0x103;
+pub const CKA_ENCRYPT: CK_ATTRIBUTE_TYPE = 0x104;
+pub const CKA_DECRYPT: CK_ATTRIBUTE_TYPE = 0x105;
+pub const CKA_SIGN: CK_ATTRIBUTE_TYPE = 0x108;
+pub const CKA_VERIFY: CK_ATTRIBUTE_TYPE = 0x10A;
+pub const CKA_VALUE_LEN: CK_ATTRIBUTE_TYPE = 0x161;
+pub const CKA_EXTRACTABLE: CK_ATTRIBUTE_TYPE = 0x162;
+
+pub const CKM_RC5_KEY_GEN: CK_MECHANISM_TYPE = 0x330;
+pub const CKM_RC5_ECB: CK_MECHANISM_TYPE = 0x331;
+pub const CKM_RC5_CBC: CK_MECHANISM_TYPE = 0x332;
+pub const CKM_RC5_MAC: CK_MECHANISM_TYPE = 0x333;
+pub const CKM_RC5_MAC_GENERAL: CK_MECHANISM_TYPE = 0x334;
+pub const CKM_RC5_CBC_PAD: CK_MECHANISM_TYPE = 0x335;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CK_VERSION {
+    pub major: CK_BYTE,
+    pub minor: CK_BYTE,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CK_INFO {
+    pub cryptokiVersion: CK_VERSION,
+    pub manufacturerID: [u8; 32],
+    pub flags: CK_FLAGS,
+    pub libraryDescription: [u8; 32],
+    pub libraryVersion: CK_VERSION,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CK_SLOT_INFO {
+    pub slotDescription: [u8; 64],
+    pub manufacturerID: [u8; 32],
+    pub flags: CK_FLAGS,
+    pub hardwareVersion: CK_VERSION,
+    pub firmwareVersion: CK_VERSION,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CK_TOKEN_INFO {
+    pub label: [u8; 32],
+    pub manufacturerID: [u8; 32],
+    pub model: [u8; 16],
+    pub serialNumber: [u8; 16],
+    pub flags: CK_FLAGS,
+    pub ulMaxSessionCount: CK_ULONG,
+    pub ulSessionCount: CK_ULONG,
+    pub ulMaxRwSessionCount: CK_ULONG,
+    pub ulRwSessionCount: CK_ULONG,
+    pub ulMaxPinLen: CK_ULONG,
+    pub ulMinPinLen: CK_ULONG,
+    pub ulTotalPublicMemory: CK_ULONG,
+    pub ulFreePublicMemory: CK_ULONG,
+    pub ulTotalPrivateMemory: CK_ULONG,
+    pub ulFreePrivateMemory: CK_ULONG,
+    pub hardwareVersion: CK_VERSION,
+    pub firmwareVersion: CK_VERSION,
+    pub utcTime: [u8; 16],
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CK_SESSION_INFO {
+    pub slotID: CK_SLOT_ID,
+    pub state: CK_STATE,
+    pub flags: CK_FLAGS,
+    pub ulDeviceError: CK_ULONG,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CK_MECHANISM_INFO {
+    pub ulMinKeySize: CK_ULONG,
+    pub ulMaxKeySize: CK_ULONG,
+    pub flags: CK_FLAGS,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CK_MECHANISM {
+    pub mechanism: CK_MECHANISM_TYPE,
+    pub pParameter: *mut c_void,
+    pub ulParameterLen: CK_ULONG,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CK_ATTRIBUTE {
+    pub type_: CK_ATTRIBUTE_TYPE,
+    pub pValue: *mut c_void,
+    pub ulValueLen: CK_ULONG,
+}
+
+/// The parameters of `CKM_RC5_ECB` and `CKM_RC5_MAC`; the word size is in bytes.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CK_RC5_PARAMS {
+    pub ulWordsize: CK_ULONG,
+    pub ulRounds: CK_ULONG,
+}
+
+/// The parameters of `CKM_RC5_CBC` and `CKM_RC5_CBC_PAD`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CK_RC5_CBC_PARAMS {
+    pub ulWordsize: CK_ULONG,
+    pub ulRounds: CK_ULONG,
+    pub pIv: *mut CK_BYTE,
+    pub ulIvLen: CK_ULONG,
+}
+
+/// The parameters of `CKM_RC5_MAC_GENERAL`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CK_RC5_MAC_GENERAL_PARAMS {
+    pub ulWordsize: CK_ULONG,
+    pub ulRounds: CK_ULONG,
+    pub ulMacLength: CK_ULONG,
+}
+
+type FnPtr = Option<unsafe extern "C" fn(*mut c_void) -> CK_RV>;
+type FnSession = Option<unsafe extern "C" fn(CK_SESSION_HANDLE) -> CK_RV>;
+type FnInit =
+    Option<unsafe extern "C" fn(CK_SESSION_HANDLE, *mut CK_MECHANISM, CK_OBJECT_HANDLE) -> CK_RV>;
+type FnSingle = Option<
+    unsafe extern "C" fn(CK_SESSION_HANDLE, *mut u8, CK_ULONG, *mut u8, *mut CK_ULONG) -> CK_RV,
+>;
+type FnUpdate = Option<unsafe extern "C" fn(CK_SESSION_HANDLE, *mut u8, CK_ULONG) -> CK_RV>;
+type FnFinal = Option<unsafe extern "C" fn(CK_SESSION_HANDLE, *mut u8, *mut CK_ULONG) -> CK_RV>;
+type FnVerify =
+    Option<unsafe extern "C" fn(CK_SESSION_HANDLE, *mut u8, CK_ULONG, *mut u8, CK_ULONG) -> CK_RV>;
+type FnVerifyFinal = Option<unsafe extern "C" fn(CK_SESSION_HANDLE, *mut u8, CK_ULONG) -> CK_RV>;
+type FnAttributes = Option<
+    unsafe extern "C" fn(CK_SESSION_HANDLE, CK_OBJECT_HANDLE, *mut CK_ATTRIBUTE, CK_ULONG) -> CK_RV,
+>;
+
+/// `CK_FUNCTION_LIST`: the version, then the functions in the order of `pkcs11f.h`.
+#[repr(C)]
+pub struct CK_FUNCTION_LIST {
+    pub version: CK_VERSION,
+    pub C_Initialize: FnPtr,
+    pub C_Finalize: FnPtr,
+    pub C_GetInfo: Option<unsafe extern "C" fn(*mut CK_INFO) -> CK_RV>,
+    pub C_GetFunctionList: Option<unsafe extern "C" fn(*mut *const CK_FUNCTION_LIST) -> CK_RV>,
+    pub C_GetSlotList:
+        Option<unsafe extern "C" fn(CK_BBOOL, *mut CK_SLOT_ID, *mut CK_ULONG) -> CK_RV>,
+    pub C_GetSlotInfo: Option<unsafe extern "C" fn(CK_SLOT_ID, *mut CK_SLOT_INFO) -> CK_RV>,
+    pub C_GetTokenInfo: Option<unsafe extern "C" fn(CK_SLOT_ID, *mut CK_TOKEN_INFO) -> CK_RV>,
+    pub C_GetMechanismList:
+        Option<unsafe extern "C" fn(CK_SLOT_ID, *mut CK_MECHANISM_TYPE, *mut CK_ULONG) -> CK_RV>,
+    pub C_GetMechanismInfo: Option<
+        unsafe extern "C" fn(CK_SLOT_ID, CK_MECHANISM_TYPE, *mut CK_MECHANISM_INFO) -> CK_RV,
+    >,
+    pub C_InitToken: Option<unsafe extern "C" fn(CK_SLOT_ID, *mut u8, CK_ULONG, *mut u8) -> CK_RV>,
+    pub C_InitPIN: FnUpdate,
+    pub C_SetPIN: FnVerify,
+    pub C_OpenSession: Option<
+        unsafe extern "C" fn(
+            CK_SLOT_ID,
+            CK_FLAGS,
+            *mut c_void,
+            CK_NOTIFY,
+            *mut CK_SESSION_HANDLE,
+        ) -> CK_RV,
+    >,
+    pub C_CloseSession: FnSession,
+    pub C_CloseAllSessions: Option<unsafe extern "C" fn(CK_SLOT_ID) -> CK_RV>,
+    pub C_GetSessionInfo:
+        Option<unsafe extern "C" fn(CK_SESSION_HANDLE, *mut CK_SESSION_INFO) -> CK_RV>,
+    pub C_GetOperationState: FnFinal,
+    pub C_SetOperationState: Option<
+        unsafe extern "C" fn(
+            CK_SESSION_HANDLE,
+            *mut u8,
+            CK_ULONG,
+            CK_OBJECT_HANDLE,
+            CK_OBJECT_HANDLE,
+        ) -> CK_RV,
+    >,
+    pub C_Login:
+        Option<unsafe extern "C" fn(CK_SESSION_HANDLE, CK_USER_TYPE, *mut u8, CK_ULONG) -> CK_RV>,
+    pub C_Logout: FnSession,
+    pub C_CreateObject: Option<
+        unsafe extern "C" fn(
+            CK_SESSION_HANDLE,
+            *mut CK_ATTRIBUTE,
+            CK_ULONG,
+            *mut CK_OBJECT_HANDLE,
+        ) -> CK_RV,
+    >,
+    pub C_CopyObject: Option<
+        unsafe extern "C" fn(
+            CK_SESSION_HANDLE,
+            CK_OBJECT_HANDLE,
+            *mut CK_ATTRIBUTE,
+            CK_ULONG,
+            *mut CK_OBJECT_HANDLE,
+        ) -> CK_RV,
+    >,
+    pub C_DestroyObject: Option<unsafe extern "C" fn(CK_SESSION_HANDLE, CK_OBJECT_HANDLE) -> CK_RV>,
+    pub C_GetObjectSize:
+        Option<unsafe extern "C" fn(CK_SESSION_HANDLE, CK_OBJECT_HANDLE, *mut CK_ULONG) -> CK_RV>,
+    pub C_GetAttributeValue: FnAttributes,
+    pub C_SetAttributeValue: FnAttributes,
+    pub C_FindObjectsInit:
+        Option<unsafe extern "C" fn(CK_SESSION_HANDLE, *mut CK_ATTRIBUTE, CK_ULONG) -> CK_RV>,
+    pub C_FindObjects: Option<
+        unsafe extern "C" fn(
+            CK_SESSION_HANDLE,
+            *mut CK_OBJECT_HANDLE,
+            CK_ULONG,
+            *mut CK_ULONG,
+        ) -> CK_RV,
+    >,
+    pub C_FindObjectsFinal: FnSession,
+    pub C_EncryptInit: FnInit,
+    pub C_Encrypt: FnSingle,
+    pub C_EncryptUpdate: FnSingle,
+    pub C_EncryptFinal: FnFinal,
+    pub C_DecryptInit: FnInit,
+    pub C_Decrypt: FnSingle,
+    pub C_DecryptUpdate: FnSingle,
+    pub C_DecryptFinal: FnFinal,
+    pub C_DigestInit: Option<unsafe extern "C" fn(CK_SESSION_HANDLE, *mut CK_MECHANISM) -> CK_RV>,
+    pub C_Digest: FnSingle,
+    pub C_DigestUpdate: FnUpdate,
+    pub C_DigestKey: Option<unsafe extern "C" fn(CK_SESSION_HANDLE, CK_OBJECT_HANDLE) -> CK_RV>,
+    pub C_DigestFinal: FnFinal,
+    pub C_SignInit: FnInit,
+    pub C_Sign: FnSingle,
+    pub C_SignUpdate: FnUpdate,
+    pub C_SignFinal: FnFinal,
+    pub C_SignRecoverInit: FnInit,
+    pub C_SignRecover: FnSingle,
+    pub C_VerifyInit: FnInit,
+    pub C_Verify: FnVerify,
+    pub C_VerifyUpdate: FnUpdate,
+    pub C_VerifyFinal: FnVerifyFinal,
+    pub C_VerifyRecoverInit: FnInit,
+    pub C_VerifyRecover: FnSingle,
+    pub C_DigestEncryptUpdate: FnSingle,
+    pub C_DecryptDigestUpdate: FnSingle,
+    pub C_SignEncryptUpdate: FnSingle,
+    pub C_DecryptVerifyUpdate: FnSingle,
+    pub C_GenerateKey: Option<
+        unsafe extern "C" fn(
+            CK_SESSION_HANDLE,
+            *mut CK_MECHANISM,
+            *mut CK_ATTRIBUTE,
+            CK_ULONG,
+            *mut CK_OBJECT_HANDLE,
+        ) -> CK_RV,
+    >,
+    pub C_GenerateKeyPair: Option<
+        unsafe extern "C" fn(
+            CK_SESSION_HANDLE,
+            *mut CK_MECHANISM,
+            *mut CK_ATTRIBUTE,
+            CK_ULONG,
+            *mut CK_ATTRIBUTE,
+            CK_ULONG,
+            *mut CK_OBJECT_HANDLE,
+            *mut CK_OBJECT_HANDLE,
+        ) -> CK_RV,
+    >,
+    pub C_WrapKey: Option<
+        unsafe extern "C" fn(
+            CK_SESSION_HANDLE,
+            *mut CK_MECHANISM,
+            CK_OBJECT_HANDLE,
+            CK_OBJECT_HANDLE,
+            *mut u8,
+            *mut CK_ULONG,
+        ) -> CK_RV,
+    >,
+    pub C_UnwrapKey: Option<
+        unsafe extern "C" fn(
+            CK_SESSION_HANDLE,
+            *mut CK_MECHANISM,
+            CK_OBJECT_HANDLE,
+            *mut u8,
+            CK_ULONG,
+            *mut CK_ATTRIBUTE,
+            CK_ULONG,
+            *mut CK_OBJECT_HANDLE,
+        ) -> CK_RV,
+    >,
+    pub C_DeriveKey: Option<
+        unsafe extern "C" fn(
+            CK_SESSION_HANDLE,
+            *mut CK_MECHANISM,
+            CK_OBJECT_HANDLE,
+            *mut CK_ATTRIBUTE,
+            CK_ULONG,
+            *mut CK_OBJECT_HANDLE,
+        ) -> CK_RV,
+    >,
+    pub C_SeedRandom: FnUpdate,
+    pub C_GenerateRandom: FnUpdate,
+    pub C_GetFunctionStatus: FnSession,
+    pub C_CancelFunction: FnSession,
+    pub C_WaitForSlotEvent:
+        Option<unsafe extern "C" fn(CK_FLAGS, *mut CK_SLOT_ID, *mut c_void) -> CK_RV>,
+}
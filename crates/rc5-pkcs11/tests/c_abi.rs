@@ -0,0 +1,736 @@
+//! Loads the library with `dlopen` and drives it through `C_GetFunctionList`, as a PKCS#11 client would.
+
+use std::ffi::{c_void, CString};
+use std::path::PathBuf;
+use std::ptr::{null, null_mut};
+use std::sync::{Mutex, MutexGuard};
+
+use cipher::generic_array::GenericArray;
+use cipher::BlockEncrypt;
+use rc5_pkcs11::types::*;
+use rc5_pkcs11::SLOT_ID;
+use rc5_rustcrypto_api::rc5::RC5Var;
+
+const KEY: [u8; 16] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+];
+const IV: [u8; 8] = [0xa0, 0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7];
+
+/// The library is one global token, so the tests take turns.
+static LOCK: Mutex<()> = Mutex::new(());
+
+/// `librc5_pkcs11.so`, built for the tests next to the test executable, in `deps`.
+fn library_path() -> PathBuf {
+    let exe = std::env::current_exe().unwrap();
+    exe.parent().unwrap().join(format!(
+        "{}rc5_pkcs11{}",
+        std::env::consts::DLL_PREFIX,
+        std::env::consts::DLL_SUFFIX
+    ))
+}
+
+/// An initialized library, finalized on drop.
+struct Module {
+    f: &'static CK_FUNCTION_LIST,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl Module {
+    fn load() -> Self {
+        let lock = LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let path = CString::new(library_path().into_os_string().into_encoded_bytes()).unwrap();
+        let f = unsafe {
+            let handle = libc::dlopen(path.as_ptr(), libc::RTLD_NOW);
+            assert!(!handle.is_null(), "dlopen {:?}", path);
+            let symbol = libc::dlsym(handle, c"C_GetFunctionList".as_ptr());
+            assert!(!symbol.is_null());
+            let get_function_list: unsafe extern "C" fn(*mut *const CK_FUNCTION_LIST) -> CK_RV =
+                std::mem::transmute(symbol);
+            let mut list = null();
+            assert_eq!(get_function_list(&mut list), CKR_OK);
+            &*list
+        };
+        // a test that failed may have left it initialized
+        unsafe { (f.C_Finalize.unwrap())(null_mut()) };
+        assert_eq!(unsafe { (f.C_Initialize.unwrap())(null_mut()) }, CKR_OK);
+
+        Self { f, _lock: lock }
+    }
+
+    fn open_session(&self) -> CK_SESSION_HANDLE {
+        let mut session = 0;
+        let flags = CKF_SERIAL_SESSION | CKF_RW_SESSION;
+        let rv = unsafe {
+            (self.f.C_OpenSession.unwrap())(SLOT_ID, flags, null_mut(), None, &mut session)
+        };
+        assert_eq!(rv, CKR_OK);
+        session
+    }
+
+    fn create_key(
+        &self,
+        session: CK_SESSION_HANDLE,
+        value: &[u8],
+        extra: &[CK_ATTRIBUTE],
+    ) -> CK_OBJECT_HANDLE {
+        let mut template = vec![
+            attribute(CKA_CLASS, &CKO_SECRET_KEY),
+            attribute(CKA_KEY_TYPE, &CKK_RC5),
+            bytes_attribute(CKA_VALUE, value),
+        ];
+        template.extend_from_slice(extra);
+        let mut key = 0;
+        let rv = unsafe {
+            (self.f.C_CreateObject.unwrap())(
+                session,
+                template.as_mut_ptr(),
+                template.len() as CK_ULONG,
+                &mut key,
+            )
+        };
+        assert_eq!(rv, CKR_OK);
+        key
+    }
+
+    /// Runs `C_Encrypt` or `C_Decrypt` with a length query first.
+    fn single(
+        &self,
+        single: FnSingle,
+        session: CK_SESSION_HANDLE,
+        input: &[u8],
+    ) -> Result<Vec<u8>, CK_RV> {
+        let single = single.unwrap();
+        let mut input = input.to_vec();
+        let mut len = 0;
+        let rv = unsafe {
+            single(
+                session,
+                input.as_mut_ptr(),
+                input.len() as CK_ULONG,
+                null_mut(),
+                &mut len,
+            )
+        };
+        if rv != CKR_OK {
+            return Err(rv);
+        }
+        let mut output = vec![0; len as usize];
+        let rv = unsafe {
+            single(
+                session,
+                input.as_mut_ptr(),
+                input.len() as CK_ULONG,
+                output.as_mut_ptr(),
+                &mut len,
+            )
+        };
+        if rv != CKR_OK {
+            return Err(rv);
+        }
+        output.truncate(len as usize);
+        Ok(output)
+    }
+}
+
+impl Drop for Module {
+    fn drop(&mut self) {
+        unsafe { (self.f.C_Finalize.unwrap())(null_mut()) };
+    }
+}
+
+type FnSingle = Option<
+    unsafe extern "C" fn(CK_SESSION_HANDLE, *mut u8, CK_ULONG, *mut u8, *mut CK_ULONG) -> CK_RV,
+>;
+
+fn attribute<T>(type_: CK_ATTRIBUTE_TYPE, value: &T) -> CK_ATTRIBUTE {
+    CK_ATTRIBUTE {
+        type_,
+        pValue: value as *const T as *mut c_void,
+        ulValueLen: std::mem::size_of::<T>() as CK_ULONG,
+    }
+}
+
+fn bytes_attribute(type_: CK_ATTRIBUTE_TYPE, value: &[u8]) -> CK_ATTRIBUTE {
+    CK_ATTRIBUTE {
+        type_,
+        pValue: value.as_ptr() as *mut c_void,
+        ulValueLen: value.len() as CK_ULONG,
+    }
+}
+
+fn mechanism<T>(mechanism: CK_MECHANISM_TYPE, params: &mut T) -> CK_MECHANISM {
+    CK_MECHANISM {
+        mechanism,
+        pParameter: params as *mut T as *mut c_void,
+        ulParameterLen: std::mem::size_of::<T>() as CK_ULONG,
+    }
+}
+
+fn cbc_params(iv: &mut [u8]) -> CK_RC5_CBC_PARAMS {
+    CK_RC5_CBC_PARAMS {
+        ulWordsize: 4,
+        ulRounds: 12,
+        pIv: iv.as_mut_ptr(),
+        ulIvLen: iv.len() as CK_ULONG,
+    }
+}
+
+/// RC5-32/12-CBC of `data`, a whole number of blocks, computed directly.
+fn cbc_reference(data: &[u8]) -> Vec<u8> {
+    let cipher = RC5Var::<u32>::new(&KEY, 12).unwrap();
+    let mut prev = IV;
+    let mut output = Vec::new();
+    for chunk in data.chunks(8) {
+        let mut block = GenericArray::clone_from_slice(chunk);
+        block.iter_mut().zip(prev).for_each(|(b, p)| *b ^= p);
+        cipher.encrypt_block(&mut block);
+        prev.copy_from_slice(&block);
+        output.extend_from_slice(&block);
+    }
+    output
+}
+
+#[test]
+fn info_slots_and_mechanisms() {
+    let m = Module::load();
+    let f = m.f;
+    unsafe {
+        assert_eq!(
+            (f.C_Initialize.unwrap())(null_mut()),
+            CKR_CRYPTOKI_ALREADY_INITIALIZED
+        );
+
+        let mut info = std::mem::zeroed::<CK_INFO>();
+        assert_eq!((f.C_GetInfo.unwrap())(&mut info), CKR_OK);
+        assert_eq!(
+            info.cryptokiVersion,
+            CK_VERSION {
+                major: 2,
+                minor: 40
+            }
+        );
+
+        let mut count = 0;
+        assert_eq!(
+            (f.C_GetSlotList.unwrap())(CK_TRUE, null_mut(), &mut count),
+            CKR_OK
+        );
+        assert_eq!(count, 1);
+        let mut slot = 0;
+        assert_eq!(
+            (f.C_GetSlotList.unwrap())(CK_TRUE, &mut slot, &mut count),
+            CKR_OK
+        );
+        assert_eq!(slot, SLOT_ID);
+        let mut slot_info = std::mem::zeroed::<CK_SLOT_INFO>();
+        assert_eq!(
+            (f.C_GetSlotInfo.unwrap())(SLOT_ID + 1, &mut slot_info),
+            CKR_SLOT_ID_INVALID
+        );
+
+        let mut count = 0;
+        assert_eq!(
+            (f.C_GetMechanismList.unwrap())(SLOT_ID, null_mut(), &mut count),
+            CKR_OK
+        );
+        assert_eq!(count, 6);
+        let mut mechanisms = [0; 6];
+        count = 2;
+        assert_eq!(
+            (f.C_GetMechanismList.unwrap())(SLOT_ID, mechanisms.as_mut_ptr(), &mut count),
+            CKR_BUFFER_TOO_SMALL
+        );
+        assert_eq!(count, 6);
+        assert_eq!(
+            (f.C_GetMechanismList.unwrap())(SLOT_ID, mechanisms.as_mut_ptr(), &mut count),
+            CKR_OK
+        );
+        assert!(mechanisms.contains(&CKM_RC5_CBC_PAD));
+
+        let mut info = CK_MECHANISM_INFO::default();
+        assert_eq!(
+            (f.C_GetMechanismInfo.unwrap())(SLOT_ID, CKM_RC5_MAC, &mut info),
+            CKR_OK
+        );
+        assert_eq!(info.flags, CKF_SIGN | CKF_VERIFY);
+        assert_eq!(
+            (f.C_GetMechanismInfo.unwrap())(SLOT_ID, 0x1081, &mut info),
+            CKR_MECHANISM_INVALID
+        );
+
+        assert_eq!(
+            (f.C_Login.unwrap())(0, 0, null_mut(), 0),
+            CKR_FUNCTION_NOT_SUPPORTED
+        );
+    }
+    drop(m);
+
+    // once finalized, the library refuses everything but C_Initialize and C_GetFunctionList
+    let m = Module::load();
+    unsafe {
+        assert_eq!((m.f.C_Finalize.unwrap())(null_mut()), CKR_OK);
+        let mut count = 0;
+        assert_eq!(
+            (m.f.C_GetSlotList.unwrap())(CK_TRUE, null_mut(), &mut count),
+            CKR_CRYPTOKI_NOT_INITIALIZED
+        );
+    }
+}
+
+#[test]
+fn cbc_matches_reference() {
+    let m = Module::load();
+    let session = m.open_session();
+    let key = m.create_key(session, &KEY, &[]);
+    let data: Vec<u8> = (0..40).collect();
+
+    let mut iv = IV;
+    let mut params = cbc_params(&mut iv);
+    let mut mech = mechanism(CKM_RC5_CBC, &mut params);
+    unsafe {
+        assert_eq!(
+            (m.f.C_EncryptInit.unwrap())(session, &mut mech, key),
+            CKR_OK
+        );
+        assert_eq!(
+            (m.f.C_EncryptInit.unwrap())(session, &mut mech, key),
+            CKR_OPERATION_ACTIVE
+        );
+    }
+    let ciphertext = m.single(m.f.C_Encrypt, session, &data).unwrap();
+    assert_eq!(ciphertext, cbc_reference(&data));
+
+    unsafe {
+        assert_eq!(
+            (m.f.C_DecryptInit.unwrap())(session, &mut mech, key),
+            CKR_OK
+        )
+    };
+    assert_eq!(m.single(m.f.C_Decrypt, session, &ciphertext).unwrap(), data);
+
+    // CBC without padding takes whole blocks only, and the failure ends the operation
+    unsafe {
+        assert_eq!(
+            (m.f.C_EncryptInit.unwrap())(session, &mut mech, key),
+            CKR_OK
+        )
+    };
+    assert_eq!(
+        m.single(m.f.C_Encrypt, session, &data[..13]),
+        Err(CKR_DATA_LEN_RANGE)
+    );
+    assert_eq!(
+        m.single(m.f.C_Encrypt, session, &data),
+        Err(CKR_OPERATION_NOT_INITIALIZED)
+    );
+}
+
+#[test]
+fn cbc_pad_multi_part() {
+    let m = Module::load();
+    let session = m.open_session();
+    let key = m.create_key(session, &KEY, &[]);
+    let data: Vec<u8> = (0..21).collect();
+
+    let mut iv = IV;
+    let mut params = cbc_params(&mut iv);
+    let mut mech = mechanism(CKM_RC5_CBC_PAD, &mut params);
+
+    let mut padded = data.clone();
+    padded.extend([3; 3]);
+    let expected = cbc_reference(&padded);
+
+    unsafe {
+        assert_eq!(
+            (m.f.C_EncryptInit.unwrap())(session, &mut mech, key),
+            CKR_OK
+        );
+        let mut ciphertext = Vec::new();
+        for part in data.chunks(5) {
+            let mut part = part.to_vec();
+            let mut out = [0; 16];
+            let mut len = out.len() as CK_ULONG;
+            let rv = (m.f.C_EncryptUpdate.unwrap())(
+                session,
+                part.as_mut_ptr(),
+                part.len() as CK_ULONG,
+                out.as_mut_ptr(),
+                &mut len,
+            );
+            assert_eq!(rv, CKR_OK);
+            ciphertext.extend_from_slice(&out[..len as usize]);
+        }
+        // a buffer too small keeps the operation going
+        let mut out = [0; 8];
+        let mut len = 4;
+        assert_eq!(
+            (m.f.C_EncryptFinal.unwrap())(session, out.as_mut_ptr(), &mut len),
+            CKR_BUFFER_TOO_SMALL
+        );
+        assert_eq!(len, 8);
+        assert_eq!(
+            (m.f.C_EncryptFinal.unwrap())(session, out.as_mut_ptr(), &mut len),
+            CKR_OK
+        );
+        ciphertext.extend_from_slice(&out[..len as usize]);
+        assert_eq!(ciphertext, expected);
+
+        // decrypts block by block: the last block stays buffered until the final part
+        assert_eq!(
+            (m.f.C_DecryptInit.unwrap())(session, &mut mech, key),
+            CKR_OK
+        );
+        let mut plaintext = Vec::new();
+        for part in ciphertext.chunks(8) {
+            let mut part = part.to_vec();
+            let mut out = [0; 16];
+            let mut len = out.len() as CK_ULONG;
+            let rv = (m.f.C_DecryptUpdate.unwrap())(
+                session,
+                part.as_mut_ptr(),
+                part.len() as CK_ULONG,
+                out.as_mut_ptr(),
+                &mut len,
+            );
+            assert_eq!(rv, CKR_OK);
+            plaintext.extend_from_slice(&out[..len as usize]);
+        }
+        assert_eq!(plaintext.len(), 16);
+        let mut len = out.len() as CK_ULONG;
+        assert_eq!(
+            (m.f.C_DecryptFinal.unwrap())(session, out.as_mut_ptr(), &mut len),
+            CKR_OK
+        );
+        plaintext.extend_from_slice(&out[..len as usize]);
+        assert_eq!(plaintext, data);
+    }
+
+    // a wrong key garbles the padding
+    let other = m.create_key(session, b"another key", &[]);
+    unsafe {
+        assert_eq!(
+            (m.f.C_DecryptInit.unwrap())(session, &mut mech, other),
+            CKR_OK
+        )
+    };
+    assert_eq!(
+        m.single(m.f.C_Decrypt, session, &expected),
+        Err(CKR_ENCRYPTED_DATA_INVALID)
+    );
+    unsafe {
+        assert_eq!(
+            (m.f.C_DecryptInit.unwrap())(session, &mut mech, key),
+            CKR_OK
+        )
+    };
+    assert_eq!(
+        m.single(m.f.C_Decrypt, session, &expected[..20]),
+        Err(CKR_ENCRYPTED_DATA_LEN_RANGE)
+    );
+}
+
+#[test]
+fn ecb_word_sizes() {
+    let m = Module::load();
+    let session = m.open_session();
+    let key = m.create_key(session, &KEY, &[]);
+
+    for (word_size, block_len) in [(2, 4), (4, 8), (8, 16)] {
+        let mut params = CK_RC5_PARAMS {
+            ulWordsize: word_size,
+            ulRounds: 16,
+        };
+        let mut mech = mechanism(CKM_RC5_ECB, &mut params);
+        let data: Vec<u8> = (0..2 * block_len as u8).collect();
+        unsafe {
+            assert_eq!(
+                (m.f.C_EncryptInit.unwrap())(session, &mut mech, key),
+                CKR_OK
+            )
+        };
+        let ciphertext = m.single(m.f.C_Encrypt, session, &data).unwrap();
+        assert_eq!(ciphertext.len(), data.len());
+        assert_ne!(ciphertext[..block_len], ciphertext[block_len..]);
+        unsafe {
+            assert_eq!(
+                (m.f.C_DecryptInit.unwrap())(session, &mut mech, key),
+                CKR_OK
+            )
+        };
+        assert_eq!(m.single(m.f.C_Decrypt, session, &ciphertext).unwrap(), data);
+    }
+
+    let cipher = RC5Var::<u32>::new(&KEY, 16).unwrap();
+    let mut block = GenericArray::clone_from_slice(&IV);
+    cipher.encrypt_block(&mut block);
+    let mut params = CK_RC5_PARAMS {
+        ulWordsize: 4,
+        ulRounds: 16,
+    };
+    let mut mech = mechanism(CKM_RC5_ECB, &mut params);
+    unsafe {
+        assert_eq!(
+            (m.f.C_EncryptInit.unwrap())(session, &mut mech, key),
+            CKR_OK
+        )
+    };
+    assert_eq!(
+        m.single(m.f.C_Encrypt, session, &IV).unwrap(),
+        block.as_slice()
+    );
+
+    let mut params = CK_RC5_PARAMS {
+        ulWordsize: 3,
+        ulRounds: 16,
+    };
+    let mut mech = mechanism(CKM_RC5_ECB, &mut params);
+    unsafe {
+        assert_eq!(
+            (m.f.C_EncryptInit.unwrap())(session, &mut mech, key),
+            CKR_MECHANISM_PARAM_INVALID
+        );
+        let mut mech = CK_MECHANISM {
+            mechanism: CKM_RC5_ECB,
+            pParameter: null_mut(),
+            ulParameterLen: 0,
+        };
+        assert_eq!(
+            (m.f.C_EncryptInit.unwrap())(session, &mut mech, key),
+            CKR_MECHANISM_PARAM_INVALID
+        );
+    }
+}
+
+#[test]
+fn mac_sign_and_verify() {
+    let m = Module::load();
+    let session = m.open_session();
+    let key = m.create_key(session, &KEY, &[]);
+    let mut data: Vec<u8> = (0..20).collect();
+
+    // CBC-MAC with a zero IV over the data padded with zeroes
+    let cipher = RC5Var::<u32>::new(&KEY, 12).unwrap();
+    let mut state = GenericArray::default();
+    let mut padded = data.clone();
+    padded.resize(24, 0);
+    for chunk in padded.chunks(8) {
+        state.iter_mut().zip(chunk).for_each(|(s, b)| *s ^= b);
+        cipher.encrypt_block(&mut state);
+    }
+
+    let mut params = CK_RC5_PARAMS {
+        ulWordsize: 4,
+        ulRounds: 12,
+    };
+    let mut mech = mechanism(CKM_RC5_MAC, &mut params);
+    unsafe { assert_eq!((m.f.C_SignInit.unwrap())(session, &mut mech, key), CKR_OK) };
+    let mac = m.single(m.f.C_Sign, session, &data).unwrap();
+    assert_eq!(mac, state[..4]);
+
+    let mut general = CK_RC5_MAC_GENERAL_PARAMS {
+        ulWordsize: 4,
+        ulRounds: 12,
+        ulMacLength: 7,
+    };
+    let mut mech_general = mechanism(CKM_RC5_MAC_GENERAL, &mut general);
+    unsafe {
+        assert_eq!(
+            (m.f.C_SignInit.unwrap())(session, &mut mech_general, key),
+            CKR_OK
+        );
+        for part in data.chunks_mut(3) {
+            assert_eq!(
+                (m.f.C_SignUpdate.unwrap())(session, part.as_mut_ptr(), part.len() as CK_ULONG),
+                CKR_OK
+            );
+        }
+        let mut mac = [0; 8];
+        let mut len = mac.len() as CK_ULONG;
+        assert_eq!(
+            (m.f.C_SignFinal.unwrap())(session, mac.as_mut_ptr(), &mut len),
+            CKR_OK
+        );
+        assert_eq!(mac[..len as usize], state[..7]);
+
+        let mut signature = state[..4].to_vec();
+        let verify = m.f.C_Verify.unwrap();
+        let data_len = data.len() as CK_ULONG;
+        assert_eq!((m.f.C_VerifyInit.unwrap())(session, &mut mech, key), CKR_OK);
+        assert_eq!(
+            verify(
+                session,
+                data.as_mut_ptr(),
+                data_len,
+                signature.as_mut_ptr(),
+                4
+            ),
+            CKR_OK
+        );
+
+        signature[0] ^= 1;
+        assert_eq!((m.f.C_VerifyInit.unwrap())(session, &mut mech, key), CKR_OK);
+        assert_eq!(
+            verify(
+                session,
+                data.as_mut_ptr(),
+                data_len,
+                signature.as_mut_ptr(),
+                4
+            ),
+            CKR_SIGNATURE_INVALID
+        );
+        assert_eq!((m.f.C_VerifyInit.unwrap())(session, &mut mech, key), CKR_OK);
+        assert_eq!(
+            verify(
+                session,
+                data.as_mut_ptr(),
+                data_len,
+                signature.as_mut_ptr(),
+                3
+            ),
+            CKR_SIGNATURE_LEN_RANGE
+        );
+        assert_eq!(
+            verify(
+                session,
+                data.as_mut_ptr(),
+                data_len,
+                signature.as_mut_ptr(),
+                4
+            ),
+            CKR_OPERATION_NOT_INITIALIZED
+        );
+    }
+}
+
+#[test]
+fn generated_keys_and_attributes() {
+    let m = Module::load();
+    let session = m.open_session();
+    let f = m.f;
+
+    let value_len: CK_ULONG = 20;
+    let no = CK_FALSE;
+    let label = b"generated";
+    let mut template = [
+        attribute(CKA_VALUE_LEN, &value_len),
+        attribute(CKA_SENSITIVE, &no),
+        bytes_attribute(CKA_LABEL, label),
+    ];
+    let mut mech = CK_MECHANISM {
+        mechanism: CKM_RC5_KEY_GEN,
+        pParameter: null_mut(),
+        ulParameterLen: 0,
+    };
+    let mut key = 0;
+    unsafe {
+        let rv = (f.C_GenerateKey.unwrap())(session, &mut mech, template.as_mut_ptr(), 3, &mut key);
+        assert_eq!(rv, CKR_OK);
+
+        let mut value = [0; 32];
+        let mut attributes = [
+            CK_ATTRIBUTE {
+                type_: CKA_VALUE,
+                pValue: null_mut(),
+                ulValueLen: 0,
+            },
+            CK_ATTRIBUTE {
+                type_: CKA_KEY_TYPE,
+                pValue: null_mut(),
+                ulValueLen: 0,
+            },
+        ];
+        assert_eq!(
+            (f.C_GetAttributeValue.unwrap())(session, key, attributes.as_mut_ptr(), 2),
+            CKR_OK
+        );
+        assert_eq!(attributes[0].ulValueLen, value_len);
+        attributes[0].pValue = value.as_mut_ptr() as *mut c_void;
+        attributes[0].ulValueLen = value.len() as CK_ULONG;
+        let mut key_type: CK_KEY_TYPE = 0;
+        attributes[1].pValue = &mut key_type as *mut CK_KEY_TYPE as *mut c_void;
+        assert_eq!(
+            (f.C_GetAttributeValue.unwrap())(session, key, attributes.as_mut_ptr(), 2),
+            CKR_OK
+        );
+        assert_eq!(key_type, CKK_RC5);
+
+        // the generated value works like a created key with the same value
+        let copy = m.create_key(session, &value[..value_len as usize], &[]);
+        let mut params = CK_RC5_PARAMS {
+            ulWordsize: 4,
+            ulRounds: 12,
+        };
+        let mut ecb = mechanism(CKM_RC5_ECB, &mut params);
+        let mut encrypt = |key| {
+            assert_eq!((f.C_EncryptInit.unwrap())(session, &mut ecb, key), CKR_OK);
+            m.single(f.C_Encrypt, session, &IV).unwrap()
+        };
+        assert_eq!(encrypt(key), encrypt(copy));
+
+        // sensitive and restricted keys
+        let yes = CK_TRUE;
+        let sensitive = m.create_key(
+            session,
+            &KEY,
+            &[attribute(CKA_SENSITIVE, &yes), attribute(CKA_ENCRYPT, &no)],
+        );
+        let mut value_attribute = [bytes_attribute(CKA_VALUE, &value)];
+        assert_eq!(
+            (f.C_GetAttributeValue.unwrap())(session, sensitive, value_attribute.as_mut_ptr(), 1),
+            CKR_ATTRIBUTE_SENSITIVE
+        );
+        assert_eq!(value_attribute[0].ulValueLen, !0);
+        assert_eq!(
+            (f.C_EncryptInit.unwrap())(session, &mut ecb, sensitive),
+            CKR_KEY_FUNCTION_NOT_PERMITTED
+        );
+
+        // finds the keys by label
+        let mut find = [bytes_attribute(CKA_LABEL, label)];
+        assert_eq!(
+            (f.C_FindObjectsInit.unwrap())(session, find.as_mut_ptr(), 1),
+            CKR_OK
+        );
+        let mut found = [0; 4];
+        let mut count = 0;
+        assert_eq!(
+            (f.C_FindObjects.unwrap())(session, found.as_mut_ptr(), 4, &mut count),
+            CKR_OK
+        );
+        assert_eq!(found[..count as usize], [key]);
+        assert_eq!((f.C_FindObjectsFinal.unwrap())(session), CKR_OK);
+
+        // a mechanism that is not the key generation one, and inconsistent templates
+        mech.mechanism = CKM_RC5_ECB;
+        assert_eq!(
+            (f.C_GenerateKey.unwrap())(session, &mut mech, template.as_mut_ptr(), 3, &mut key),
+            CKR_MECHANISM_INVALID
+        );
+        let aes: CK_KEY_TYPE = 0x1f;
+        let mut template = [
+            attribute(CKA_KEY_TYPE, &aes),
+            bytes_attribute(CKA_VALUE, &KEY),
+        ];
+        assert_eq!(
+            (f.C_CreateObject.unwrap())(session, template.as_mut_ptr(), 2, &mut key),
+            CKR_TEMPLATE_INCONSISTENT
+        );
+        assert_eq!(
+            (f.C_CreateObject.unwrap())(session, template.as_mut_ptr().add(1), 0, &mut key),
+            CKR_TEMPLATE_INCOMPLETE
+        );
+
+        // closing the session destroys its keys
+        assert_eq!((f.C_CloseSession.unwrap())(session), CKR_OK);
+        assert_eq!(
+            (f.C_CloseSession.unwrap())(session),
+            CKR_SESSION_HANDLE_INVALID
+        );
+        let session = m.open_session();
+        assert_eq!(
+            (f.C_EncryptInit.unwrap())(session, &mut ecb, copy),
+            CKR_KEY_HANDLE_INVALID
+        );
+    }
+}
@@ -0,0 +1,14 @@
+//! Compiles the C reference once per word size.
+
+fn main() {
+    let files = ["c/rc5ref16.c", "c/rc5ref32.c", "c/rc5ref64.c"];
+    cc::Build::new()
+        .files(files)
+        .std("c99")
+        .warnings(true)
+        .extra_warnings(true)
+        .warnings_into_errors(true)
+        .compile("rc5ref");
+
+    println!("cargo:rerun-if-changed=c");
+}
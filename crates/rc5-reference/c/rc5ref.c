@@ -0,0 +1,76 @@
+/* The C reference implementation of RC5 from Rivest's paper "The RC5 Encryption Algorithm" (1997), with the word
+ * size w given by the W macro and r and b given at run time. It departs from the paper only where its code relies
+ * on WORD being exactly 32 bits:
+ *
+ * - WORD is an exact-width type, and the rotations cast their operand back to WORD so that 16-bit words are not
+ *   rotated as promoted ints;
+ * - the rotations do not shift by w when the amount is 0, which is undefined;
+ * - the key loading loop counts with a signed index, since `i != -1` never holds for a promoted 16-bit i;
+ * - L is cleared as a whole: the paper only clears its last word, relying on the other words shifting out
+ *   whatever they held, which they only do if WORD is exactly w bits;
+ * - the key mixing runs 3*max(t, c) times, as the paper's description of the algorithm has it: its code runs it 3*t
+ *   times, which is the same for its r = 12 and b = 16 but not for keys of more than t words.
+ *
+ * Each of rc5ref16.c, rc5ref32.c and rc5ref64.c defines W and includes this file. */
+
+#include <stdint.h>
+#include <string.h>
+
+#define CAT_(a, b) a##_##b
+#define CAT(a, b) CAT_(a, b)
+#define FN(name) CAT(name, W)
+
+#if W == 16
+typedef uint16_t WORD;
+static const WORD P = 0xb7e1, Q = 0x9e37;
+#elif W == 32
+typedef uint32_t WORD;
+static const WORD P = 0xb7e15163, Q = 0x9e3779b9;
+#elif W == 64
+typedef uint64_t WORD;
+static const WORD P = 0xb7e151628aed2a6b, Q = 0x9e3779b97f4a7c15;
+#else
+#error "W must be 16, 32 or 64"
+#endif
+
+#define w W
+#define ROTL(x, y) ((WORD)(((WORD)(x) << ((y) & (w - 1))) | ((WORD)(x) >> ((w - ((y) & (w - 1))) & (w - 1)))))
+#define ROTR(x, y) ((WORD)(((WORD)(x) >> ((y) & (w - 1))) | ((WORD)(x) << ((w - ((y) & (w - 1))) & (w - 1)))))
+
+/* 2 WORD input pt/output ct */
+void FN(rc5ref_encrypt)(const WORD *S, unsigned r, const WORD *pt, WORD *ct) {
+    WORD i, A = pt[0] + S[0], B = pt[1] + S[1];
+    for (i = 1; i <= r; i++) {
+        A = ROTL(A ^ B, B) + S[2 * i];
+        B = ROTL(B ^ A, A) + S[2 * i + 1];
+    }
+    ct[0] = A;
+    ct[1] = B;
+}
+
+/* 2 WORD input ct/output pt */
+void FN(rc5ref_decrypt)(const WORD *S, unsigned r, const WORD *ct, WORD *pt) {
+    WORD i, B = ct[1], A = ct[0];
+    for (i = r; i > 0; i--) {
+        B = ROTR(B - S[2 * i + 1], A) ^ A;
+        A = ROTR(A - S[2 * i], B) ^ B;
+    }
+    pt[1] = B - S[1];
+    pt[0] = A - S[0];
+}
+
+/* secret input key K[0...b-1]; S has room for t = 2*(r+1) words */
+void FN(rc5ref_setup)(const unsigned char *K, unsigned b, unsigned r, WORD *S) {
+    unsigned u = w / 8, c = b ? (b + u - 1) / u : 1, t = 2 * (r + 1), i, j, k;
+    long n;
+    WORD A, B, L[256];
+
+    /* Initialize L, then S, then mix key into S */
+    memset(L, 0, sizeof L);
+    for (n = (long)b - 1; n != -1; n--) L[n / u] = (WORD)(L[n / u] << 8) + K[n];
+    for (S[0] = P, i = 1; i < t; i++) S[i] = S[i - 1] + Q;
+    for (A = B = i = j = k = 0; k < 3 * (t > c ? t : c); k++, i = (i + 1) % t, j = (j + 1) % c) {
+        A = S[i] = ROTL(S[i] + (WORD)(A + B), 3);
+        B = L[j] = ROTL(L[j] + (WORD)(A + B), (WORD)(A + B));
+    }
+}
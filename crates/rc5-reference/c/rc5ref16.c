@@ -0,0 +1,2 @@
+#define W 16
+#include "rc5ref.c"
@@ -0,0 +1,2 @@
+#define W 32
+#include "rc5ref.c"
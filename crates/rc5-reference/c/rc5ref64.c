@@ -0,0 +1,2 @@
+#define W 64
+#include "rc5ref.c"
@@ -0,0 +1,244 @@
+//! DER encoding of the RC5-CBC-Pad algorithm ([RFC 2040](https://www.rfc-editor.org/rfc/rfc2040) §6,
+//! [RFC 8018](https://www.rfc-editor.org/rfc/rfc8018) §B.2.4):
+//!
+//! ```text
+//! rc5-CBC-PAD OBJECT IDENTIFIER ::= { iso(1) member-body(2) us(840) rsadsi(113549) encryptionAlgorithm(3) 9 }
+//!
+//! RC5-CBC-Parameters ::= SEQUENCE {
+//!     version INTEGER {v1-0(16)} (v1-0),
+//!     rounds INTEGER (8..127),
+//!     blockSizeInBits INTEGER (64 | 128),
+//!     iv OCTET STRING OPTIONAL }
+//! ```
+//!
+//! An absent IV stands for the all-zero one.
+
+use core::ops::{Add, Div, Mul, Sub};
+
+use cipher::{BlockSizeUser, KeyInit};
+use der::asn1::OctetStringRef;
+use der::oid::{AssociatedOid, ObjectIdentifier};
+use der::{
+    Decode, DecodeValue, Encode, EncodeValue, ErrorKind, Header, Length, Reader, Sequence, Tag,
+    Writer,
+};
+use generic_array::ArrayLength;
+use rc5_core::traits::{Arithmetics, KeyLTableSize, KeySTableSize, Magic, WordByteRepr, WordSize};
+use spki::{AlgorithmIdentifier, AlgorithmIdentifierRef};
+use typenum::{Diff, Max, Prod, Quot, Sum, Unsigned};
+
+use crate::rc5::{RC5Var, RC5};
+
+pub const RC5_CBC_PAD: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.3.9");
+
+/// The only `version` defined: v1-0.
+pub const VERSION_1_0: u8 = 16;
+
+pub const MIN_ROUNDS: u8 = 8;
+pub const MAX_ROUNDS: u8 = 127;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The cipher type has a different block size or number of rounds than the parameters.
+    ParamsMismatch,
+    /// The key does not fit the cipher type.
+    InvalidKeyLength,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ParamsMismatch => write!(f, "cipher does not match the RC5-CBC parameters"),
+            Self::InvalidKeyLength => write!(f, "invalid key length"),
+        }
+    }
+}
+
+/// `RC5-CBC-Parameters`, checked against the constraints of the ASN.1 module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rc5CbcParameters<'a> {
+    rounds: u8,
+    block_size_in_bits: u16,
+    iv: Option<&'a [u8]>,
+}
+
+impl<'a> Rc5CbcParameters<'a> {
+    /// Fails unless `rounds` is in `8..=127`, the block is 64 or 128 bits, and the IV, if any, is one block long.
+    pub fn new(rounds: u8, block_size_in_bits: u16, iv: Option<&'a [u8]>) -> der::Result<Self> {
+        if !(MIN_ROUNDS..=MAX_ROUNDS).contains(&rounds) || !matches!(block_size_in_bits, 64 | 128) {
+            return Err(ErrorKind::Value { tag: Tag::Integer }.into());
+        }
+        if iv.is_some_and(|iv| iv.len() * 8 != block_size_in_bits as usize) {
+            return Err(ErrorKind::Value {
+                tag: Tag::OctetString,
+            }
+            .into());
+        }
+
+        Ok(Self {
+            rounds,
+            block_size_in_bits,
+            iv,
+        })
+    }
+
+    pub fn rounds(&self) -> u8 {
+        self.rounds
+    }
+
+    pub fn block_size_in_bits(&self) -> u16 {
+        self.block_size_in_bits
+    }
+
+    /// The IV as encoded; see [`Rc5CbcParameters::iv_or_zero`].
+    pub fn iv(&self) -> Option<&'a [u8]> {
+        self.iv
+    }
+
+    /// Copies the IV, or zeroes if there is none, into `block`, which must be one block long.
+    pub fn iv_or_zero(&self, block: &mut [u8]) {
+        match self.iv {
+            Some(iv) => block.copy_from_slice(iv),
+            None => block.fill(0),
+        }
+    }
+
+    pub fn algorithm_identifier(&self) -> AlgorithmIdentifier<Self> {
+        AlgorithmIdentifier {
+            oid: RC5_CBC_PAD,
+            parameters: Some(*self),
+        }
+    }
+
+    /// Decodes the parameters of an `rc5-CBC-PAD` algorithm identifier.
+    pub fn from_algorithm_identifier(algorithm: &AlgorithmIdentifierRef<'a>) -> der::Result<Self> {
+        if algorithm.oid != RC5_CBC_PAD {
+            return Err(ErrorKind::OidUnknown { oid: algorithm.oid }.into());
+        }
+        algorithm
+            .parameters
+            .ok_or(ErrorKind::Length { tag: Tag::Sequence })?
+            .decode_as()
+    }
+
+    /// The cipher these parameters describe, keyed with `key`.
+    pub fn cipher<C: FromParameters>(&self, key: &[u8]) -> Result<C, Error> {
+        C::from_parameters(self, key)
+    }
+
+    /// CBC-Pad with the cipher these parameters describe, keyed with `key`, and their IV.
+    #[cfg(feature = "std")]
+    pub fn cbc_pad<C>(&self, key: &[u8]) -> Result<crate::io::Cbc<C>, Error>
+    where
+        C: FromParameters + cipher::BlockCipher,
+    {
+        let cipher = self.cipher::<C>(key)?;
+        let mut iv = cipher::Block::<C>::default();
+        self.iv_or_zero(&mut iv);
+
+        Ok(crate::io::Cbc::new(cipher, &iv))
+    }
+
+    fn iv_octets(&self) -> der::Result<Option<OctetStringRef<'a>>> {
+        self.iv.map(OctetStringRef::new).transpose()
+    }
+}
+
+impl AssociatedOid for Rc5CbcParameters<'_> {
+    const OID: ObjectIdentifier = RC5_CBC_PAD;
+}
+
+impl<'a> DecodeValue<'a> for Rc5CbcParameters<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
+        reader.read_nested(header.length, |reader| {
+            let version = u8::decode(reader)?;
+            if version != VERSION_1_0 {
+                return Err(ErrorKind::Value { tag: Tag::Integer }.into());
+            }
+            let rounds = u8::decode(reader)?;
+            let block_size_in_bits = u16::decode(reader)?;
+            let iv = Option::<OctetStringRef<'a>>::decode(reader)?;
+
+            Self::new(rounds, block_size_in_bits, iv.map(|iv| iv.as_bytes()))
+        })
+    }
+}
+
+impl EncodeValue for Rc5CbcParameters<'_> {
+    fn value_len(&self) -> der::Result<Length> {
+        VERSION_1_0.encoded_len()?
+            + self.rounds.encoded_len()?
+            + self.block_size_in_bits.encoded_len()?
+            + self.iv_octets()?.encoded_len()?
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
+        VERSION_1_0.encode(writer)?;
+        self.rounds.encode(writer)?;
+        self.block_size_in_bits.encode(writer)?;
+        self.iv_octets()?.encode(writer)
+    }
+}
+
+impl<'a> Sequence<'a> for Rc5CbcParameters<'a> {}
+
+/// RC5 ciphers that can be built from [`Rc5CbcParameters`].
+pub trait FromParameters: BlockSizeUser + Sized {
+    fn from_parameters(params: &Rc5CbcParameters, key: &[u8]) -> Result<Self, Error>;
+}
+
+fn check_block_size<C: BlockSizeUser>(params: &Rc5CbcParameters) -> Result<(), Error> {
+    if C::BlockSize::USIZE * 8 != params.block_size_in_bits as usize {
+        return Err(Error::ParamsMismatch);
+    }
+    Ok(())
+}
+
+impl<Word, BR, A, M> FromParameters for RC5Var<Word, BR, A, M>
+where
+    Word: WordSize + Default + Copy,
+    Word::ByteLen: Mul<typenum::U2>,
+    Prod<Word::ByteLen, typenum::U2>: ArrayLength<u8>,
+    BR: WordByteRepr<Word>,
+    A: Arithmetics<Word>,
+    M: Magic<Word>,
+{
+    fn from_parameters(params: &Rc5CbcParameters, key: &[u8]) -> Result<Self, Error> {
+        check_block_size::<Self>(params)?;
+        Self::new(key, params.rounds).map_err(|_| Error::InvalidKeyLength)
+    }
+}
+
+impl<Word, R, B, BR, A, M> FromParameters for RC5<Word, R, B, BR, A, M>
+where
+    KeyLTableSize<B, Word::ByteLen>: ArrayLength<Word>,
+    B: ArrayLength<u8>,
+    Word: WordSize + Default + Copy,
+
+    B: Add<Word::ByteLen>,
+    Sum<B, Word::ByteLen>: Sub<typenum::U1>,
+    Diff<Sum<B, Word::ByteLen>, typenum::U1>: Div<Word::ByteLen>,
+    Quot<Diff<Sum<B, Word::ByteLen>, typenum::U1>, Word::ByteLen>: Max<typenum::U1>,
+
+    KeySTableSize<R>: ArrayLength<Word>,
+    Sum<R, typenum::U1>: Mul<typenum::U2>,
+    R: Add<typenum::U1>,
+
+    Word::ByteLen: Mul<typenum::U2>,
+    Prod<Word::ByteLen, typenum::U2>: ArrayLength<u8>,
+
+    R: Unsigned,
+    BR: WordByteRepr<Word>,
+    A: Arithmetics<Word>,
+    M: Magic<Word>,
+
+    Word: core::fmt::Debug,
+{
+    fn from_parameters(params: &Rc5CbcParameters, key: &[u8]) -> Result<Self, Error> {
+        check_block_size::<Self>(params)?;
+        if R::USIZE != params.rounds as usize {
+            return Err(Error::ParamsMismatch);
+        }
+        <Self as KeyInit>::new_from_slice(key).map_err(|_| Error::InvalidKeyLength)
+    }
+}
@@ -0,0 +1,282 @@
+//! CCM ([RFC 3610](https://www.rfc-editor.org/rfc/rfc3610)): CBC-MAC for authentication, CTR for
+//! confidentiality, with the length field size `L` and the tag size `M` as type parameters.
+//!
+//! For 128-bit blocks (e.g. RC5-64) this is RFC 3610 as written: `M` is one of 4, 6, ..., 16,
+//! `L` is one of 2, ..., 8 and the nonce is `15 - L` bytes.
+//!
+//! For 64-bit blocks (e.g. RC5-32) the formatting is the same, scaled down to the block:
+//! the flags byte, the nonce and the length field (or the counter) still fill exactly one block,
+//! so the nonce is `7 - L` bytes. `M` is one of 4, 6, 8; `L` is one of 2, 3, 4,
+//! i.e. at most half the block, as in the RFC.
+//! The associated data length is encoded exactly as in RFC 3610 section 2.2.
+
+use core::marker::PhantomData;
+
+use aead::{AeadCore, AeadInPlace, Error, Nonce, Tag};
+use cipher::{Block, BlockCipher, BlockEncrypt, Key, KeyInit, KeySizeUser};
+use generic_array::ArrayLength;
+use subtle::ConstantTimeEq;
+use typenum::{Unsigned, U10, U11, U12, U13, U14, U16, U2, U3, U4, U5, U6, U7, U8, U9};
+
+/// A tag size `M` allowed for a cipher with `BlockSize`.
+pub trait TagSize<BlockSize>: ArrayLength<u8> + private::Sealed {}
+
+/// A length field size `L` allowed for a cipher with `BlockSize`, determining the nonce size.
+pub trait LengthSize<BlockSize>: Unsigned + private::Sealed {
+    type NonceSize: ArrayLength<u8>;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_tag_sizes {
+    ($BlockSize: ty, $($M: ty),+) => {
+        $(impl TagSize<$BlockSize> for $M {})+
+    };
+}
+
+macro_rules! impl_length_sizes {
+    ($BlockSize: ty, $($L: ty => $N: ty),+) => {
+        $(impl LengthSize<$BlockSize> for $L {
+            type NonceSize = $N;
+        })+
+    };
+}
+
+impl private::Sealed for U2 {}
+impl private::Sealed for U3 {}
+impl private::Sealed for U4 {}
+impl private::Sealed for U5 {}
+impl private::Sealed for U6 {}
+impl private::Sealed for U7 {}
+impl private::Sealed for U8 {}
+impl private::Sealed for U10 {}
+impl private::Sealed for U12 {}
+impl private::Sealed for U14 {}
+impl private::Sealed for U16 {}
+
+impl_tag_sizes!(U16, U4, U6, U8, U10, U12, U14, U16);
+impl_length_sizes!(U16, U2 => U13, U3 => U12, U4 => U11, U5 => U10, U6 => U9, U7 => U8, U8 => U7);
+
+impl_tag_sizes!(U8, U4, U6, U8);
+impl_length_sizes!(U8, U2 => U5, U3 => U4, U4 => U3);
+
+/// CCM with an `M`-byte tag and an `L`-byte length field.
+#[derive(Clone)]
+pub struct Ccm<C, M, L> {
+    cipher: C,
+    _pd: PhantomData<(M, L)>,
+}
+
+impl<C, M, L> From<C> for Ccm<C, M, L> {
+    fn from(cipher: C) -> Self {
+        Self {
+            cipher,
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<C: KeySizeUser, M, L> KeySizeUser for Ccm<C, M, L> {
+    type KeySize = C::KeySize;
+}
+
+impl<C: KeyInit, M, L> KeyInit for Ccm<C, M, L> {
+    fn new(key: &Key<Self>) -> Self {
+        C::new(key).into()
+    }
+}
+
+impl<C, M, L> AeadCore for Ccm<C, M, L>
+where
+    C: BlockCipher,
+    M: TagSize<C::BlockSize>,
+    L: LengthSize<C::BlockSize>,
+{
+    type NonceSize = L::NonceSize;
+    type TagSize = M;
+    type CiphertextOverhead = typenum::U0;
+}
+
+impl<C, M, L> AeadInPlace for Ccm<C, M, L>
+where
+    C: BlockCipher + BlockEncrypt,
+    M: TagSize<C::BlockSize>,
+    L: LengthSize<C::BlockSize>,
+{
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self>, Error> {
+        let mac = self.cbc_mac(nonce, associated_data, buffer)?;
+        self.apply_keystream(nonce, buffer)?;
+
+        self.encrypt_tag(nonce, mac)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self>,
+    ) -> Result<(), Error> {
+        // checked before decrypting, so that no error leaves unauthenticated plaintext in the buffer
+        if !fits_length::<L>(buffer.len()) {
+            return Err(Error);
+        }
+
+        self.apply_keystream(nonce, buffer)?;
+        let authentic = self
+            .cbc_mac(nonce, associated_data, buffer)
+            .and_then(|mac| self.encrypt_tag(nonce, mac))
+            .is_ok_and(|expected| bool::from(expected.ct_eq(tag)));
+
+        if authentic {
+            Ok(())
+        } else {
+            self.apply_keystream(nonce, buffer)?;
+            Err(Error)
+        }
+    }
+}
+
+impl<C, M, L> Ccm<C, M, L>
+where
+    C: BlockCipher + BlockEncrypt,
+    M: TagSize<C::BlockSize>,
+    L: LengthSize<C::BlockSize>,
+{
+    /// CBC-MAC over `B_0`, the encoded associated data and the message (RFC 3610 section 2.2).
+    fn cbc_mac(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        message: &[u8],
+    ) -> Result<Block<C>, Error> {
+        let mut b0 = Block::<C>::default();
+        let adata = if associated_data.is_empty() { 0 } else { 0x40 };
+        b0[0] = adata | (((M::U8 - 2) / 2) << 3) | (L::U8 - 1);
+        b0[1..=nonce.len()].copy_from_slice(nonce);
+        encode_length::<L>(message.len(), &mut b0[1 + nonce.len()..])?;
+
+        let mut mac = CbcMac::new(&self.cipher, b0);
+
+        if !associated_data.is_empty() {
+            let len = associated_data.len() as u64;
+            if len < 0xFF00 {
+                mac.update(&(len as u16).to_be_bytes());
+            } else if len <= u32::MAX as u64 {
+                mac.update(&[0xFF, 0xFE]);
+                mac.update(&(len as u32).to_be_bytes());
+            } else {
+                mac.update(&[0xFF, 0xFF]);
+                mac.update(&len.to_be_bytes());
+            }
+            mac.update(associated_data);
+            mac.pad();
+        }
+
+        mac.update(message);
+        mac.pad();
+
+        Ok(mac.state)
+    }
+
+    /// `A_i`: the flags, the nonce and the counter `i` in the `L`-byte length field.
+    fn counter_block(nonce: &Nonce<Self>, i: usize) -> Result<Block<C>, Error> {
+        let mut a = Block::<C>::default();
+        a[0] = L::U8 - 1;
+        a[1..=nonce.len()].copy_from_slice(nonce);
+        encode_length::<L>(i, &mut a[1 + nonce.len()..])?;
+        Ok(a)
+    }
+
+    /// XORs `buffer` with the key stream, leaving it untouched if its counters overflow the length field.
+    fn apply_keystream(&self, nonce: &Nonce<Self>, buffer: &mut [u8]) -> Result<(), Error> {
+        if !fits_length::<L>(buffer.len().div_ceil(C::BlockSize::USIZE)) {
+            return Err(Error);
+        }
+
+        for (i, chunk) in buffer.chunks_mut(C::BlockSize::USIZE).enumerate() {
+            let mut s = Self::counter_block(nonce, i + 1)?;
+            self.cipher.encrypt_block(&mut s);
+            chunk.iter_mut().zip(s.iter()).for_each(|(b, s)| *b ^= s);
+        }
+
+        Ok(())
+    }
+
+    fn encrypt_tag(&self, nonce: &Nonce<Self>, mac: Block<C>) -> Result<Tag<Self>, Error> {
+        let mut s0 = Self::counter_block(nonce, 0)?;
+        self.cipher.encrypt_block(&mut s0);
+
+        Ok(Tag::<Self>::from_exact_iter(
+            mac.iter().zip(s0.iter()).map(|(t, s)| t ^ s).take(M::USIZE),
+        )
+        .expect("the tag is not longer than a block"))
+    }
+}
+
+/// Whether `value` fits an `L`-byte length field.
+fn fits_length<L: Unsigned>(value: usize) -> bool {
+    L::USIZE >= 8 || (value as u64) >> (8 * L::USIZE) == 0
+}
+
+/// Writes `value` big-endian into `field`, failing if it does not fit.
+fn encode_length<L: Unsigned>(value: usize, field: &mut [u8]) -> Result<(), Error> {
+    if !fits_length::<L>(value) {
+        return Err(Error);
+    }
+
+    let bytes = (value as u64).to_be_bytes();
+    field.copy_from_slice(&bytes[8 - L::USIZE..]);
+
+    Ok(())
+}
+
+/// CBC-MAC with a zero IV, fed with arbitrary slices and padded with zeroes on request.
+struct CbcMac<'c, C: BlockCipher> {
+    cipher: &'c C,
+    state: Block<C>,
+    pos: usize,
+}
+
+impl<'c, C: BlockCipher + BlockEncrypt> CbcMac<'c, C> {
+    fn new(cipher: &'c C, mut b0: Block<C>) -> Self {
+        cipher.encrypt_block(&mut b0);
+        Self {
+            cipher,
+            state: b0,
+            pos: 0,
+        }
+    }
+
+    fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let take = (C::BlockSize::USIZE - self.pos).min(data.len());
+            self.state[self.pos..self.pos + take]
+                .iter_mut()
+                .zip(&data[..take])
+                .for_each(|(s, d)| *s ^= d);
+
+            self.pos += take;
+            data = &data[take..];
+
+            if self.pos == C::BlockSize::USIZE {
+                self.cipher.encrypt_block(&mut self.state);
+                self.pos = 0;
+            }
+        }
+    }
+
+    fn pad(&mut self) {
+        if self.pos != 0 {
+            self.cipher.encrypt_block(&mut self.state);
+            self.pos = 0;
+        }
+    }
+}
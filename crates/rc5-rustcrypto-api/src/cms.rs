@@ -0,0 +1,550 @@
+//! CMS ([RFC 5652](https://www.rfc-editor.org/rfc/rfc5652)) content encryption with `rc5-CBC-PAD`:
+//!
+//! ```text
+//! ContentInfo ::= SEQUENCE {
+//!     contentType ContentType,
+//!     content [0] EXPLICIT ANY DEFINED BY contentType }
+//!
+//! EncryptedData ::= SEQUENCE {
+//!     version CMSVersion,
+//!     encryptedContentInfo EncryptedContentInfo,
+//!     unprotectedAttrs [1] IMPLICIT UnprotectedAttributes OPTIONAL }
+//!
+//! EnvelopedData ::= SEQUENCE {
+//!     version CMSVersion,
+//!     originatorInfo [0] IMPLICIT OriginatorInfo OPTIONAL,
+//!     recipientInfos RecipientInfos,
+//!     encryptedContentInfo EncryptedContentInfo,
+//!     unprotectedAttrs [1] IMPLICIT UnprotectedAttributes OPTIONAL }
+//!
+//! EncryptedContentInfo ::= SEQUENCE {
+//!     contentType ContentType,
+//!     contentEncryptionAlgorithm ContentEncryptionAlgorithmIdentifier,
+//!     encryptedContent [0] IMPLICIT EncryptedContent OPTIONAL }
+//! ```
+//!
+//! The decoders read DER. CMS from streaming encoders is BER, with indefinite lengths and the encrypted content
+//! split over constructed octet strings: [`ber_to_der`] re-encodes it first.
+//! The content-encryption key comes from the caller; `RecipientInfos` are kept undecoded for it.
+
+use std::borrow::Cow;
+use std::vec::Vec;
+
+use der::asn1::{AnyRef, ContextSpecific, ContextSpecificRef, ObjectIdentifier, OctetStringRef};
+use der::{
+    Decode, DecodeValue, Encode, EncodeValue, ErrorKind, FixedTag, Header, Length, Reader,
+    Sequence, Tag, TagMode, TagNumber, Tagged, Writer,
+};
+use spki::AlgorithmIdentifierRef;
+
+use crate::asn1::Rc5CbcParameters;
+use crate::io::{decrypt_to_vec, encrypt_to_vec};
+use crate::rc5::RC5Var;
+
+pub const ID_DATA: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.7.1");
+pub const ID_ENVELOPED_DATA: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.7.3");
+pub const ID_ENCRYPTED_DATA: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.7.6");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The DER is malformed, or the content-encryption algorithm is not `rc5-CBC-PAD`.
+    Asn1(der::Error),
+    /// The `encryptedContent` is absent (detached content).
+    MissingContent,
+    /// The content-encryption key is longer than RC5 accepts.
+    InvalidKeyLength,
+    /// The encrypted content ends in the middle of a block.
+    Truncated,
+    /// The padding of the last block is malformed: wrong key or corrupted data.
+    InvalidPadding,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Asn1(error) => write!(f, "{}", error),
+            Self::MissingContent => write!(f, "encrypted content is detached"),
+            Self::InvalidKeyLength => write!(f, "invalid content-encryption key length"),
+            Self::Truncated => write!(f, "encrypted content is truncated"),
+            Self::InvalidPadding => write!(f, "invalid padding"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<der::Error> for Error {
+    fn from(error: der::Error) -> Self {
+        Self::Asn1(error)
+    }
+}
+
+impl From<crate::io::Error> for Error {
+    fn from(error: crate::io::Error) -> Self {
+        match error {
+            crate::io::Error::InvalidPadding => Self::InvalidPadding,
+            _ => Self::Truncated,
+        }
+    }
+}
+
+/// `EncryptedContentInfo` with `rc5-CBC-PAD` as the content-encryption algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncryptedContentInfo<'a> {
+    pub content_type: ObjectIdentifier,
+    pub content_encryption_algorithm: Rc5CbcParameters<'a>,
+    pub encrypted_content: Option<&'a [u8]>,
+}
+
+impl<'a> EncryptedContentInfo<'a> {
+    /// Decrypts the content with the content-encryption key `key`.
+    pub fn decrypt(&self, key: &[u8]) -> Result<Vec<u8>, Error> {
+        let ciphertext = self.encrypted_content.ok_or(Error::MissingContent)?;
+        let params = &self.content_encryption_algorithm;
+        let plaintext = match params.block_size_in_bits() {
+            64 => decrypt_to_vec(cbc_pad::<RC5Var<u32>>(params, key)?, ciphertext),
+            _ => decrypt_to_vec(cbc_pad::<RC5Var<u64>>(params, key)?, ciphertext),
+        };
+
+        Ok(plaintext?)
+    }
+
+    /// Encrypts `content` with the content-encryption key `key`.
+    pub fn encrypt(
+        params: &Rc5CbcParameters,
+        key: &[u8],
+        content: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        let ciphertext = match params.block_size_in_bits() {
+            64 => encrypt_to_vec(cbc_pad::<RC5Var<u32>>(params, key)?, content),
+            _ => encrypt_to_vec(cbc_pad::<RC5Var<u64>>(params, key)?, content),
+        };
+
+        Ok(ciphertext?)
+    }
+
+    fn encrypted_content_field(&self) -> der::Result<Option<OctetStringRef<'a>>> {
+        self.encrypted_content.map(OctetStringRef::new).transpose()
+    }
+}
+
+fn cbc_pad<C>(params: &Rc5CbcParameters, key: &[u8]) -> Result<crate::io::Cbc<C>, Error>
+where
+    C: crate::asn1::FromParameters + cipher::BlockCipher,
+{
+    params
+        .cbc_pad::<C>(key)
+        .map_err(|_| Error::InvalidKeyLength)
+}
+
+impl<'a> DecodeValue<'a> for EncryptedContentInfo<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
+        reader.read_nested(header.length, |reader| {
+            let content_type = ObjectIdentifier::decode(reader)?;
+            let algorithm = AlgorithmIdentifierRef::decode(reader)?;
+            let encrypted_content =
+                ContextSpecific::<OctetStringRef<'a>>::decode_implicit(reader, TagNumber::N0)?;
+
+            Ok(Self {
+                content_type,
+                content_encryption_algorithm: Rc5CbcParameters::from_algorithm_identifier(
+                    &algorithm,
+                )?,
+                encrypted_content: encrypted_content.map(|field| field.value.as_bytes()),
+            })
+        })
+    }
+}
+
+impl EncodeValue for EncryptedContentInfo<'_> {
+    fn value_len(&self) -> der::Result<Length> {
+        let content = self.encrypted_content_field()?;
+        self.content_type.encoded_len()?
+            + self
+                .content_encryption_algorithm
+                .algorithm_identifier()
+                .encoded_len()?
+            + content.as_ref().map(implicit).encoded_len()?
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
+        let content = self.encrypted_content_field()?;
+        self.content_type.encode(writer)?;
+        self.content_encryption_algorithm
+            .algorithm_identifier()
+            .encode(writer)?;
+        content.as_ref().map(implicit).encode(writer)
+    }
+}
+
+impl<'a> Sequence<'a> for EncryptedContentInfo<'a> {}
+
+fn implicit<T>(value: &T) -> ContextSpecificRef<'_, T> {
+    ContextSpecificRef {
+        tag_number: TagNumber::N0,
+        tag_mode: TagMode::Implicit,
+        value,
+    }
+}
+
+/// Reads the `[tag_number] IMPLICIT` field at the reader's position, if any, without decoding it.
+fn optional_field<'a, R: Reader<'a>>(
+    reader: &mut R,
+    tag_number: TagNumber,
+) -> der::Result<Option<AnyRef<'a>>> {
+    match reader.peek_tag() {
+        Ok(Tag::ContextSpecific { number, .. }) if number == tag_number => {
+            Ok(Some(AnyRef::decode(reader)?))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// `EncryptedData`: content encrypted with a key the parties already share.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncryptedData<'a> {
+    pub encrypted_content_info: EncryptedContentInfo<'a>,
+    /// The `[1] IMPLICIT` field as encoded.
+    pub unprotected_attrs: Option<AnyRef<'a>>,
+}
+
+impl<'a> EncryptedData<'a> {
+    /// Version 0, or 2 with unprotected attributes.
+    pub fn version(&self) -> u8 {
+        if self.unprotected_attrs.is_some() {
+            2
+        } else {
+            0
+        }
+    }
+
+    pub fn decrypt(&self, key: &[u8]) -> Result<Vec<u8>, Error> {
+        self.encrypted_content_info.decrypt(key)
+    }
+
+    /// Encrypts `content` of type `content_type` and returns the DER of the `ContentInfo` holding the `EncryptedData`.
+    pub fn encrypt(
+        content_type: ObjectIdentifier,
+        params: &Rc5CbcParameters,
+        key: &[u8],
+        content: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        let encrypted_content = EncryptedContentInfo::encrypt(params, key, content)?;
+        let data = EncryptedData {
+            encrypted_content_info: EncryptedContentInfo {
+                content_type,
+                content_encryption_algorithm: *params,
+                encrypted_content: Some(&encrypted_content),
+            },
+            unprotected_attrs: None,
+        };
+
+        Ok(data.to_content_info()?)
+    }
+
+    /// Decodes the `EncryptedData` of a `ContentInfo`.
+    pub fn from_content_info(der: &'a [u8]) -> der::Result<Self> {
+        from_content_info(der, ID_ENCRYPTED_DATA)
+    }
+
+    pub fn to_content_info(&self) -> der::Result<Vec<u8>> {
+        to_content_info(ID_ENCRYPTED_DATA, self)
+    }
+}
+
+impl<'a> DecodeValue<'a> for EncryptedData<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
+        reader.read_nested(header.length, |reader| {
+            let version = u8::decode(reader)?;
+            let data = Self {
+                encrypted_content_info: EncryptedContentInfo::decode(reader)?,
+                unprotected_attrs: optional_field(reader, TagNumber::N1)?,
+            };
+            if version != data.version() {
+                return Err(ErrorKind::Value { tag: Tag::Integer }.into());
+            }
+
+            Ok(data)
+        })
+    }
+}
+
+impl EncodeValue for EncryptedData<'_> {
+    fn value_len(&self) -> der::Result<Length> {
+        self.version().encoded_len()?
+            + self.encrypted_content_info.encoded_len()?
+            + self.unprotected_attrs.encoded_len()?
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
+        self.version().encode(writer)?;
+        self.encrypted_content_info.encode(writer)?;
+        self.unprotected_attrs.encode(writer)
+    }
+}
+
+impl<'a> Sequence<'a> for EncryptedData<'a> {}
+
+/// `EnvelopedData`, decoded up to its `EncryptedContentInfo`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnvelopedData<'a> {
+    pub version: u8,
+    /// The `[0] IMPLICIT` field as encoded.
+    pub originator_info: Option<AnyRef<'a>>,
+    /// The `SET OF RecipientInfo` as encoded, for the caller to recover the content-encryption key from.
+    pub recipient_infos: AnyRef<'a>,
+    pub encrypted_content_info: EncryptedContentInfo<'a>,
+    /// The `[1] IMPLICIT` field as encoded.
+    pub unprotected_attrs: Option<AnyRef<'a>>,
+}
+
+impl<'a> EnvelopedData<'a> {
+    pub fn decrypt(&self, key: &[u8]) -> Result<Vec<u8>, Error> {
+        self.encrypted_content_info.decrypt(key)
+    }
+
+    /// Decodes the `EnvelopedData` of a `ContentInfo`.
+    pub fn from_content_info(der: &'a [u8]) -> der::Result<Self> {
+        from_content_info(der, ID_ENVELOPED_DATA)
+    }
+}
+
+impl<'a> DecodeValue<'a> for EnvelopedData<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
+        reader.read_nested(header.length, |reader| {
+            let version = u8::decode(reader)?;
+            let originator_info = optional_field(reader, TagNumber::N0)?;
+            let recipient_infos = AnyRef::decode(reader)?;
+            recipient_infos.tag().assert_eq(Tag::Set)?;
+
+            Ok(Self {
+                version,
+                originator_info,
+                recipient_infos,
+                encrypted_content_info: EncryptedContentInfo::decode(reader)?,
+                unprotected_attrs: optional_field(reader, TagNumber::N1)?,
+            })
+        })
+    }
+}
+
+impl FixedTag for EnvelopedData<'_> {
+    const TAG: Tag = Tag::Sequence;
+}
+
+fn from_content_info<'a, T: Decode<'a>>(
+    der: &'a [u8],
+    content_type: ObjectIdentifier,
+) -> der::Result<T> {
+    let mut reader = der::SliceReader::new(der)?;
+    let content = reader.sequence(|reader| {
+        let oid = ObjectIdentifier::decode(reader)?;
+        if oid != content_type {
+            return Err(ErrorKind::OidUnknown { oid }.into());
+        }
+        ContextSpecific::<T>::decode_explicit(reader, TagNumber::N0)?.ok_or_else(|| {
+            Tag::ContextSpecific {
+                constructed: true,
+                number: TagNumber::N0,
+            }
+            .value_error()
+        })
+    })?;
+    reader.finish(content.value)
+}
+
+fn to_content_info<T: EncodeValue + Tagged>(
+    content_type: ObjectIdentifier,
+    content: &T,
+) -> der::Result<Vec<u8>> {
+    let content = ContextSpecificRef {
+        tag_number: TagNumber::N0,
+        tag_mode: TagMode::Explicit,
+        value: content,
+    };
+    let mut der = Vec::new();
+    Header::new(
+        Tag::Sequence,
+        (content_type.encoded_len()? + content.encoded_len()?)?,
+    )?
+    .encode_to_vec(&mut der)?;
+    content_type.encode_to_vec(&mut der)?;
+    content.encode_to_vec(&mut der)?;
+
+    Ok(der)
+}
+
+/// How deep BER elements may nest, so that hostile input cannot exhaust the stack.
+const MAX_BER_DEPTH: usize = 32;
+
+/// Re-encodes a BER `ContentInfo` holding `EncryptedData` or `EnvelopedData` as DER, for `from_content_info`:
+/// indefinite lengths become definite, and constructed octet strings, the `[0] IMPLICIT` encrypted content
+/// included, primitive. The rest is copied as it is, so a BER-only form elsewhere (e.g. an unsorted `SET OF`)
+/// is still rejected when decoding.
+pub fn ber_to_der(ber: &[u8]) -> der::Result<Vec<u8>> {
+    let mut input = ber;
+    let mut content_info = BerElement::parse(&mut input, 0)?;
+    if !input.is_empty() {
+        return Err(ErrorKind::TrailingData {
+            decoded: Length::try_from(ber.len() - input.len())?,
+            remaining: Length::try_from(input.len())?,
+        }
+        .into());
+    }
+
+    // ContentInfo, [0] EXPLICIT content, EncryptedData or EnvelopedData, EncryptedContentInfo: the first
+    // SEQUENCE in both, then its [0] IMPLICIT OCTET STRING
+    let encrypted_content = content_info
+        .elements_mut()
+        .get_mut(1)
+        .and_then(|content| content.elements_mut().first_mut())
+        .and_then(|data| {
+            data.elements_mut()
+                .iter_mut()
+                .find(|field| field.identifier == [0x30])
+        })
+        .and_then(|info| {
+            info.elements_mut()
+                .iter_mut()
+                .find(|field| field.identifier == [0xa0])
+        });
+    if let Some(field) = encrypted_content {
+        *field = BerElement {
+            identifier: &[0x80],
+            value: BerValue::Primitive(Cow::Owned(field.octets()?)),
+        };
+    }
+
+    let mut der = Vec::new();
+    content_info.encode(&mut der)?;
+    Ok(der)
+}
+
+/// A BER element: its identifier octets, then its contents if primitive, or its elements if constructed.
+struct BerElement<'a> {
+    identifier: &'a [u8],
+    value: BerValue<'a>,
+}
+
+enum BerValue<'a> {
+    Primitive(Cow<'a, [u8]>),
+    Constructed(Vec<BerElement<'a>>),
+}
+
+impl<'a> BerElement<'a> {
+    fn parse(input: &mut &'a [u8], depth: usize) -> der::Result<Self> {
+        if depth > MAX_BER_DEPTH {
+            return Err(ErrorKind::Overlength.into());
+        }
+
+        let start = *input;
+        let mut identifier_len = 1;
+        if take(input, 1)?[0] & 0x1f == 0x1f {
+            while take(input, 1)?[0] & 0x80 != 0 {
+                identifier_len += 1;
+            }
+            identifier_len += 1;
+        }
+        let identifier = &start[..identifier_len];
+        let constructed = identifier[0] & 0x20 != 0;
+
+        let value = match (ber_length(input)?, constructed) {
+            (Some(len), false) => BerValue::Primitive(Cow::Borrowed(take(input, len)?)),
+            (Some(len), true) => {
+                let mut contents = take(input, len)?;
+                let mut elements = Vec::new();
+                while !contents.is_empty() {
+                    elements.push(Self::parse(&mut contents, depth + 1)?);
+                }
+                BerValue::Constructed(elements)
+            }
+            (None, true) => {
+                let mut elements = Vec::new();
+                while !input.starts_with(&[0, 0]) {
+                    elements.push(Self::parse(input, depth + 1)?);
+                }
+                take(input, 2)?;
+                BerValue::Constructed(elements)
+            }
+            (None, false) => return Err(ErrorKind::IndefiniteLength.into()),
+        };
+
+        Ok(Self { identifier, value })
+    }
+
+    fn elements_mut(&mut self) -> &mut [BerElement<'a>] {
+        match &mut self.value {
+            BerValue::Primitive(_) => &mut [],
+            BerValue::Constructed(elements) => elements,
+        }
+    }
+
+    /// The contents of an octet string, joining the segments of a constructed one.
+    fn octets(&self) -> der::Result<Vec<u8>> {
+        match &self.value {
+            BerValue::Primitive(contents) => Ok(contents.to_vec()),
+            BerValue::Constructed(segments) => {
+                let mut octets = Vec::new();
+                for segment in segments {
+                    if segment.identifier != [0x04] && segment.identifier != [0x24] {
+                        return Err(Tag::OctetString.value_error());
+                    }
+                    octets.extend(segment.octets()?);
+                }
+                Ok(octets)
+            }
+        }
+    }
+
+    fn encode(&self, der: &mut Vec<u8>) -> der::Result<()> {
+        let (identifier, contents) = match &self.value {
+            BerValue::Primitive(contents) => (self.identifier, Cow::Borrowed(contents.as_ref())),
+            BerValue::Constructed(_) if self.identifier == [0x24] => {
+                (&[0x04][..], Cow::Owned(self.octets()?))
+            }
+            BerValue::Constructed(elements) => {
+                let mut contents = Vec::new();
+                for element in elements {
+                    element.encode(&mut contents)?;
+                }
+                (self.identifier, Cow::Owned(contents))
+            }
+        };
+
+        der.extend_from_slice(identifier);
+        Length::try_from(contents.len())?.encode_to_vec(der)?;
+        der.extend_from_slice(&contents);
+        Ok(())
+    }
+}
+
+/// A BER length: `None` if indefinite.
+fn ber_length(input: &mut &[u8]) -> der::Result<Option<usize>> {
+    match take(input, 1)?[0] {
+        0x80 => Ok(None),
+        len if len < 0x80 => Ok(Some(len.into())),
+        0xff => Err(ErrorKind::Overlength.into()),
+        first => take(input, (first & 0x7f).into())?
+            .iter()
+            .try_fold(0usize, |len, &byte| {
+                len.checked_mul(0x100).map(|len| len | usize::from(byte))
+            })
+            .map(Some)
+            .ok_or_else(|| ErrorKind::Overlength.into()),
+    }
+}
+
+fn take<'a>(input: &mut &'a [u8], len: usize) -> der::Result<&'a [u8]> {
+    if input.len() < len {
+        return Err(ErrorKind::Incomplete {
+            expected_len: Length::try_from(len)?,
+            actual_len: Length::try_from(input.len())?,
+        }
+        .into());
+    }
+    let (taken, rest) = input.split_at(len);
+    *input = rest;
+    Ok(taken)
+}
@@ -0,0 +1,566 @@
+//! A self-describing container for encrypted files: the parameters needed to decrypt it, except the key,
+//! are in its header.
+//!
+//! ```text
+//! header:  magic "\x89RC5\r\n\x1a\n" | version | word size in bits | rounds | key length | mode
+//!          | byte representation | chunk length (u32) | nonce length | nonce | key id length | key id
+//! chunks:  chunk 0 | tag 0 | chunk 1 | tag 1 | ... | last chunk | last tag
+//! trailer: number of chunks (u32) | plaintext length (u64) | "RC5E"
+//! ```
+//!
+//! Integers are big-endian. Every chunk but the last holds exactly `chunk length` plaintext bytes;
+//! the last one holds `1..=chunk length` bytes, or none if the plaintext is empty.
+//!
+//! The chunks are sealed independently with the STREAM construction (chunk number and last-chunk flag in the nonce),
+//! so any chunk can be decrypted on its own, while reordered, dropped or truncated chunks are detected.
+//! EAX takes [`EaxNonceSize`]-byte nonces rather than one-block ones, so that even with RC5-32's 8-byte block
+//! the random per-file nonce left after STREAM's 5 bytes is long enough not to collide.
+//! Every chunk authenticates the header as associated data; the last one authenticates the trailer too.
+
+use std::boxed::Box;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::vec::Vec;
+
+use aead::stream::{NewStream, StreamBE32, StreamPrimitive};
+use aead::AeadInPlace;
+use core::ops::Sub;
+use generic_array::{ArrayLength, GenericArray};
+use rc5_core::std_words::{BigEndian, LittleEndian};
+use typenum::{Diff, Unsigned, U16, U5};
+
+use crate::eax::Eax;
+use crate::rc5::RC5Var;
+
+/// The length of the EAX nonce under STREAM, whatever the block size.
+pub type EaxNonceSize = U16;
+
+pub const MAGIC: [u8; 8] = *b"\x89RC5\r\n\x1a\n";
+pub const VERSION: u8 = 1;
+
+const TRAILER_MAGIC: [u8; 4] = *b"RC5E";
+const TRAILER_LEN: usize = 16;
+
+/// The length of the header up to the nonce.
+const FIXED_HEADER_LEN: usize = 19;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The data does not start with [`MAGIC`].
+    BadMagic,
+    /// The container was written by a newer version of the format.
+    UnsupportedVersion(u8),
+    /// The header names a word size, mode or byte representation this version does not know.
+    UnsupportedParams,
+    /// The header is inconsistent: a zero chunk length, or a nonce or key id of the wrong length.
+    InvalidHeader,
+    /// The key is not as long as the header says.
+    InvalidKeyLength,
+    /// The container ends before its trailer, or its trailer disagrees with its length.
+    Truncated,
+    /// A chunk failed authentication: wrong key, or tampered, reordered or truncated data.
+    TagMismatch,
+    /// The plaintext is too long for the chunk counter.
+    TooLong,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::BadMagic => write!(f, "not an RC5 container"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported container version {}", version)
+            }
+            Self::UnsupportedParams => write!(f, "unsupported container parameters"),
+            Self::InvalidHeader => write!(f, "invalid container header"),
+            Self::InvalidKeyLength => write!(f, "invalid key length"),
+            Self::Truncated => write!(f, "container is truncated"),
+            Self::TagMismatch => write!(f, "authentication tag mismatch"),
+            Self::TooLong => write!(f, "container is too long"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Error> for io::Error {
+    fn from(error: Error) -> Self {
+        let kind = match error {
+            Error::Truncated => io::ErrorKind::UnexpectedEof,
+            _ => io::ErrorKind::InvalidData,
+        };
+        io::Error::new(kind, error)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordSize {
+    W32,
+    W64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// [`Eax`] under STREAM.
+    Eax,
+}
+
+/// How the cipher maps bytes to words: RC5 as specified is little-endian.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteRepr {
+    LittleEndian,
+    BigEndian,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Header {
+    pub word_size: WordSize,
+    pub rounds: u8,
+    pub key_len: u8,
+    pub mode: Mode,
+    pub byte_repr: ByteRepr,
+    /// The number of plaintext bytes in each chunk but the last.
+    pub chunk_len: u32,
+    /// Must be [`Header::nonce_len`] bytes long, and never reused with the same key.
+    pub nonce: Vec<u8>,
+    /// Names the key for the reader; it is authenticated, not encrypted.
+    pub key_id: Vec<u8>,
+}
+
+impl Header {
+    /// The length of the nonce: the AEAD's nonce less the 5 bytes STREAM takes for the chunk number and flag.
+    pub fn nonce_len(mode: Mode) -> usize {
+        match mode {
+            Mode::Eax => EaxNonceSize::USIZE - 5,
+        }
+    }
+
+    /// The length of the tag following each chunk.
+    pub fn tag_len(&self) -> usize {
+        match self.mode {
+            Mode::Eax => block_len(self.word_size),
+        }
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        self.check()?;
+
+        let mut bytes =
+            Vec::with_capacity(FIXED_HEADER_LEN + self.nonce.len() + 1 + self.key_id.len());
+        bytes.extend_from_slice(&MAGIC);
+        bytes.push(VERSION);
+        bytes.push(match self.word_size {
+            WordSize::W32 => 32,
+            WordSize::W64 => 64,
+        });
+        bytes.push(self.rounds);
+        bytes.push(self.key_len);
+        bytes.push(match self.mode {
+            Mode::Eax => 1,
+        });
+        bytes.push(match self.byte_repr {
+            ByteRepr::LittleEndian => 0,
+            ByteRepr::BigEndian => 1,
+        });
+        bytes.extend_from_slice(&self.chunk_len.to_be_bytes());
+        bytes.push(self.nonce.len() as u8);
+        bytes.extend_from_slice(&self.nonce);
+        bytes.push(self.key_id.len() as u8);
+        bytes.extend_from_slice(&self.key_id);
+
+        Ok(bytes)
+    }
+
+    /// Reads a header, leaving `reader` at the first chunk. Nothing is authenticated yet.
+    pub fn read_from<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut fixed = [0u8; FIXED_HEADER_LEN];
+        read_exact(reader, &mut fixed)?;
+
+        if fixed[..8] != MAGIC {
+            return Err(Error::BadMagic.into());
+        }
+        if fixed[8] != VERSION {
+            return Err(Error::UnsupportedVersion(fixed[8]).into());
+        }
+        let word_size = match fixed[9] {
+            32 => WordSize::W32,
+            64 => WordSize::W64,
+            _ => return Err(Error::UnsupportedParams.into()),
+        };
+        let mode = match fixed[12] {
+            1 => Mode::Eax,
+            _ => return Err(Error::UnsupportedParams.into()),
+        };
+        let byte_repr = match fixed[13] {
+            0 => ByteRepr::LittleEndian,
+            1 => ByteRepr::BigEndian,
+            _ => return Err(Error::UnsupportedParams.into()),
+        };
+
+        let mut nonce = vec![0; fixed[18] as usize];
+        read_exact(reader, &mut nonce)?;
+        let mut key_id_len = [0u8];
+        read_exact(reader, &mut key_id_len)?;
+        let mut key_id = vec![0; key_id_len[0] as usize];
+        read_exact(reader, &mut key_id)?;
+
+        let header = Self {
+            word_size,
+            rounds: fixed[10],
+            key_len: fixed[11],
+            mode,
+            byte_repr,
+            chunk_len: u32::from_be_bytes(fixed[14..18].try_into().unwrap()),
+            nonce,
+            key_id,
+        };
+        header.check()?;
+
+        Ok(header)
+    }
+
+    fn check(&self) -> Result<(), Error> {
+        if self.chunk_len == 0
+            || self.nonce.len() != Self::nonce_len(self.mode)
+            || self.key_id.len() > u8::MAX as usize
+        {
+            return Err(Error::InvalidHeader);
+        }
+        Ok(())
+    }
+}
+
+fn block_len(word_size: WordSize) -> usize {
+    match word_size {
+        WordSize::W32 => 8,
+        WordSize::W64 => 16,
+    }
+}
+
+fn read_exact<R: Read>(reader: &mut R, buf: &mut [u8]) -> io::Result<()> {
+    reader.read_exact(buf).map_err(|err| match err.kind() {
+        io::ErrorKind::UnexpectedEof => Error::Truncated.into(),
+        _ => err,
+    })
+}
+
+/// STREAM over the AEAD the header names, with the nonce prefix already bound.
+trait ChunkAead {
+    fn seal(
+        &self,
+        position: u32,
+        last: bool,
+        aad: &[u8],
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), Error>;
+    fn open(
+        &self,
+        position: u32,
+        last: bool,
+        aad: &[u8],
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), Error>;
+}
+
+impl<A> ChunkAead for StreamBE32<A>
+where
+    A: AeadInPlace,
+    A::NonceSize: Sub<U5>,
+    Diff<A::NonceSize, U5>: ArrayLength<u8>,
+{
+    fn seal(
+        &self,
+        position: u32,
+        last: bool,
+        aad: &[u8],
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), Error> {
+        self.encrypt_in_place(position, last, aad, buffer)
+            .map_err(|_| Error::TooLong)
+    }
+
+    fn open(
+        &self,
+        position: u32,
+        last: bool,
+        aad: &[u8],
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), Error> {
+        self.decrypt_in_place(position, last, aad, buffer)
+            .map_err(|_| Error::TagMismatch)
+    }
+}
+
+fn chunk_aead(header: &Header, key: &[u8]) -> Result<Box<dyn ChunkAead>, Error> {
+    header.check()?;
+    if key.len() != header.key_len as usize {
+        return Err(Error::InvalidKeyLength);
+    }
+
+    macro_rules! stream {
+        ($word: ty, $repr: ty) => {{
+            let cipher = RC5Var::<$word, $repr>::new(key, header.rounds)
+                .map_err(|_| Error::InvalidKeyLength)?;
+            let nonce = GenericArray::from_slice(&header.nonce);
+            match header.mode {
+                Mode::Eax => Box::new(StreamBE32::from_aead(
+                    Eax::<_, EaxNonceSize>::with_nonce_size(cipher),
+                    nonce,
+                )),
+            }
+        }};
+    }
+
+    Ok(match (header.word_size, header.byte_repr) {
+        (WordSize::W32, ByteRepr::LittleEndian) => stream!(u32, LittleEndian),
+        (WordSize::W32, ByteRepr::BigEndian) => stream!(u32, BigEndian),
+        (WordSize::W64, ByteRepr::LittleEndian) => stream!(u64, LittleEndian),
+        (WordSize::W64, ByteRepr::BigEndian) => stream!(u64, BigEndian),
+    })
+}
+
+fn trailer(chunk_count: u32, len: u64) -> [u8; TRAILER_LEN] {
+    let mut trailer = [0u8; TRAILER_LEN];
+    trailer[..4].copy_from_slice(&chunk_count.to_be_bytes());
+    trailer[4..12].copy_from_slice(&len.to_be_bytes());
+    trailer[12..].copy_from_slice(&TRAILER_MAGIC);
+    trailer
+}
+
+/// The number of chunks holding `len` plaintext bytes: there is always at least one.
+fn chunks_for(len: u64, chunk_len: u32) -> Option<u32> {
+    len.div_ceil(chunk_len as u64).max(1).try_into().ok()
+}
+
+/// Writes a container: the header on creation, then the chunks as they fill up.
+///
+/// [`Writer::finish`] must be called at the end: it writes the last chunk and the trailer.
+pub struct Writer<W> {
+    inner: W,
+    header: Vec<u8>,
+    aead: Box<dyn ChunkAead>,
+    chunk_len: usize,
+    position: u32,
+    len: u64,
+    pending: Vec<u8>,
+}
+
+impl<W: Write> Writer<W> {
+    /// Writes the header to `inner`; the key must be `header.key_len` bytes long.
+    pub fn new(mut inner: W, header: &Header, key: &[u8]) -> io::Result<Self> {
+        let aead = chunk_aead(header, key)?;
+        let bytes = header.to_bytes()?;
+        inner.write_all(&bytes)?;
+
+        Ok(Self {
+            inner,
+            header: bytes,
+            aead,
+            chunk_len: header.chunk_len as usize,
+            position: 0,
+            len: 0,
+            pending: Vec::new(),
+        })
+    }
+
+    /// Writes the last chunk and the trailer, and returns the inner writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        let chunk_count = self.position.checked_add(1).ok_or(Error::TooLong)?;
+        let trailer = trailer(chunk_count, self.len);
+
+        let mut aad = self.header.clone();
+        aad.extend_from_slice(&trailer);
+        let mut chunk = core::mem::take(&mut self.pending);
+        self.aead.seal(self.position, true, &aad, &mut chunk)?;
+
+        self.inner.write_all(&chunk)?;
+        self.inner.write_all(&trailer)?;
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for Writer<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.pending.extend_from_slice(buf);
+        self.len += buf.len() as u64;
+
+        // a full chunk is held back until more data arrives: only `finish` knows which chunk is the last
+        while self.pending.len() > self.chunk_len {
+            let rest = self.pending.split_off(self.chunk_len);
+            let mut chunk = core::mem::replace(&mut self.pending, rest);
+            self.aead
+                .seal(self.position, false, &self.header, &mut chunk)?;
+            self.position = self.position.checked_add(1).ok_or(Error::TooLong)?;
+            self.inner.write_all(&chunk)?;
+        }
+
+        Ok(buf.len())
+    }
+
+    /// Flushes the inner writer. The bytes of an incomplete chunk stay buffered until [`Writer::finish`].
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Reads the plaintext of a container, seeking to and decrypting only the chunks it needs.
+///
+/// No plaintext of a chunk is returned before its tag is checked.
+pub struct Reader<R> {
+    inner: R,
+    header: Header,
+    header_bytes: Vec<u8>,
+    trailer: [u8; TRAILER_LEN],
+    aead: Box<dyn ChunkAead>,
+    chunk_count: u32,
+    len: u64,
+    pos: u64,
+    chunk: Option<(u32, Vec<u8>)>,
+}
+
+impl<R: Read + Seek> Reader<R> {
+    /// Opens the container at the start of `inner`: reads its header and trailer, and checks the last chunk,
+    /// which authenticates both.
+    pub fn open(mut inner: R, key: &[u8]) -> io::Result<Self> {
+        inner.seek(SeekFrom::Start(0))?;
+        let header = Header::read_from(&mut inner)?;
+        let header_bytes = header.to_bytes()?;
+        let aead = chunk_aead(&header, key)?;
+
+        let end = inner.seek(SeekFrom::End(0))?;
+        if end < (header_bytes.len() + TRAILER_LEN) as u64 {
+            return Err(Error::Truncated.into());
+        }
+        let mut trailer = [0u8; TRAILER_LEN];
+        inner.seek(SeekFrom::End(-(TRAILER_LEN as i64)))?;
+        read_exact(&mut inner, &mut trailer)?;
+
+        let chunk_count = u32::from_be_bytes(trailer[..4].try_into().unwrap());
+        let len = u64::from_be_bytes(trailer[4..12].try_into().unwrap());
+        let expected_end = chunks_for(len, header.chunk_len)
+            .filter(|&count| count == chunk_count)
+            .and_then(|_| {
+                let tags = chunk_count as u64 * header.tag_len() as u64;
+                len.checked_add(tags)?
+                    .checked_add((header_bytes.len() + TRAILER_LEN) as u64)
+            });
+        if trailer[12..] != TRAILER_MAGIC || expected_end != Some(end) {
+            return Err(Error::Truncated.into());
+        }
+
+        let mut reader = Self {
+            inner,
+            header,
+            header_bytes,
+            trailer,
+            aead,
+            chunk_count,
+            len,
+            pos: 0,
+            chunk: None,
+        };
+        reader.load(chunk_count - 1)?;
+
+        Ok(reader)
+    }
+
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// The length of the plaintext.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn chunk_count(&self) -> u32 {
+        self.chunk_count
+    }
+
+    /// Decrypts the chunk `index`.
+    pub fn read_chunk(&mut self, index: u32) -> io::Result<Vec<u8>> {
+        self.load(index)?;
+        Ok(self.chunk.as_ref().unwrap().1.clone())
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Makes the chunk `index` the cached one, reading and decrypting it unless it already is.
+    fn load(&mut self, index: u32) -> io::Result<()> {
+        if matches!(&self.chunk, Some((cached, _)) if *cached == index) {
+            return Ok(());
+        }
+        if index >= self.chunk_count {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "chunk index out of range",
+            ));
+        }
+
+        let chunk_len = self.header.chunk_len as u64;
+        let tag_len = self.header.tag_len() as u64;
+        let last = index == self.chunk_count - 1;
+        let plaintext_len = if last {
+            self.len - index as u64 * chunk_len
+        } else {
+            chunk_len
+        };
+
+        let offset = self.header_bytes.len() as u64 + index as u64 * (chunk_len + tag_len);
+        self.inner.seek(SeekFrom::Start(offset))?;
+        let mut chunk = vec![0; (plaintext_len + tag_len) as usize];
+        read_exact(&mut self.inner, &mut chunk)?;
+
+        let mut aad = self.header_bytes.clone();
+        if last {
+            aad.extend_from_slice(&self.trailer);
+        }
+        self.chunk = None;
+        self.aead.open(index, last, &aad, &mut chunk)?;
+        self.chunk = Some((index, chunk));
+
+        Ok(())
+    }
+}
+
+impl<R: Read + Seek> Read for Reader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.pos >= self.len || buf.is_empty() {
+            return Ok(0);
+        }
+
+        let chunk_len = self.header.chunk_len as u64;
+        let index = (self.pos / chunk_len) as u32;
+        self.load(index)?;
+
+        let chunk = &self.chunk.as_ref().unwrap().1;
+        let start = (self.pos % chunk_len) as usize;
+        let len = buf.len().min(chunk.len() - start);
+        buf[..len].copy_from_slice(&chunk[start..start + len]);
+        self.pos += len as u64;
+
+        Ok(len)
+    }
+}
+
+/// Seeks within the plaintext.
+impl<R: Read + Seek> Seek for Reader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(pos) => Some(pos),
+            SeekFrom::End(offset) => self.len.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.pos.checked_add_signed(offset),
+        };
+        self.pos = pos
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "seek before the start"))?;
+
+        Ok(self.pos)
+    }
+}
@@ -0,0 +1,156 @@
+//! EAX ([Bellare, Rogaway, Wagner](https://web.cs.ucdavis.edu/~rogaway/papers/eax.pdf)): CTR for
+//! confidentiality, OMAC (CMAC) over the nonce, the associated data and the ciphertext for authentication.
+//!
+//! EAX is defined for any block size; here it works with every block size CMAC supports,
+//! i.e. 64-bit (RC5-32), 128-bit (RC5-64) and 256-bit (RC5-128) blocks. The tag is one block long;
+//! the nonce is too by default, but since OMAC takes it whatever its length, `Eax<C, N>` takes `N`-byte nonces.
+
+use core::marker::PhantomData;
+
+use aead::{AeadCore, AeadInPlace, Error, Nonce, Tag};
+use cipher::{Block, BlockCipher, BlockEncrypt, BlockSizeUser, Key, KeyInit, KeySizeUser};
+use cmac::digest::crypto_common::InnerInit;
+use cmac::{Cmac, CmacCore, Mac};
+use dbl::Dbl;
+use generic_array::{ArrayLength, GenericArray};
+use subtle::ConstantTimeEq;
+use typenum::{IsLess, Le, NonZero, Unsigned, U256};
+
+/// The OMAC tweaks of the three EAX components.
+const NONCE: u8 = 0;
+const HEADER: u8 = 1;
+const CIPHERTEXT: u8 = 2;
+
+/// EAX over the block cipher `C`, with `N`-byte nonces.
+#[derive(Clone)]
+pub struct Eax<C: BlockSizeUser, N = <C as BlockSizeUser>::BlockSize> {
+    cipher: C,
+    nonce_size: PhantomData<N>,
+}
+
+impl<C: BlockSizeUser, N> Eax<C, N> {
+    /// EAX with `N`-byte nonces; [`From`] gives the usual one-block nonces.
+    pub fn with_nonce_size(cipher: C) -> Self {
+        Self {
+            cipher,
+            nonce_size: PhantomData,
+        }
+    }
+}
+
+impl<C: BlockSizeUser> From<C> for Eax<C> {
+    fn from(cipher: C) -> Self {
+        Self::with_nonce_size(cipher)
+    }
+}
+
+impl<C: BlockSizeUser + KeySizeUser, N> KeySizeUser for Eax<C, N> {
+    type KeySize = C::KeySize;
+}
+
+impl<C: BlockSizeUser + KeyInit, N> KeyInit for Eax<C, N> {
+    fn new(key: &Key<Self>) -> Self {
+        Self::with_nonce_size(C::new(key))
+    }
+}
+
+impl<C: BlockCipher, N: ArrayLength<u8>> AeadCore for Eax<C, N> {
+    type NonceSize = N;
+    type TagSize = C::BlockSize;
+    type CiphertextOverhead = typenum::U0;
+}
+
+impl<C, N> AeadInPlace for Eax<C, N>
+where
+    C: BlockCipher + BlockEncrypt + Clone,
+    N: ArrayLength<u8>,
+    Block<C>: Dbl,
+    C::BlockSize: IsLess<U256>,
+    Le<C::BlockSize, U256>: NonZero,
+{
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self>, Error> {
+        let n = self.omac(NONCE, nonce);
+        let h = self.omac(HEADER, associated_data);
+
+        self.apply_keystream(&n, buffer);
+        let c = self.omac(CIPHERTEXT, buffer);
+
+        Ok(xor3(n, &c, &h))
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self>,
+    ) -> Result<(), Error> {
+        let n = self.omac(NONCE, nonce);
+        let h = self.omac(HEADER, associated_data);
+        let c = self.omac(CIPHERTEXT, buffer);
+
+        if bool::from(xor3(n.clone(), &c, &h).ct_eq(tag)) {
+            self.apply_keystream(&n, buffer);
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+}
+
+impl<C, N> Eax<C, N>
+where
+    C: BlockCipher + BlockEncrypt + Clone,
+    Block<C>: Dbl,
+    C::BlockSize: IsLess<U256>,
+    Le<C::BlockSize, U256>: NonZero,
+{
+    /// `OMAC^t(data)`: CMAC over the block holding `t` in its last byte, followed by `data`.
+    fn omac(&self, t: u8, data: &[u8]) -> Block<C> {
+        let mut mac = Cmac::<C>::from_core(CmacCore::inner_init(self.cipher.clone()));
+
+        let mut tweak = Block::<C>::default();
+        tweak[C::BlockSize::USIZE - 1] = t;
+        mac.update(&tweak);
+        mac.update(data);
+
+        mac.finalize().into_bytes()
+    }
+
+    /// CTR with the whole block as a big-endian counter, starting at `n`.
+    fn apply_keystream(&self, n: &Block<C>, buffer: &mut [u8]) {
+        let mut counter = n.clone();
+
+        for chunk in buffer.chunks_mut(C::BlockSize::USIZE) {
+            let mut keystream = counter.clone();
+            self.cipher.encrypt_block(&mut keystream);
+            chunk
+                .iter_mut()
+                .zip(keystream.iter())
+                .for_each(|(b, k)| *b ^= k);
+
+            for byte in counter.iter_mut().rev() {
+                *byte = byte.wrapping_add(1);
+                if *byte != 0 {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+fn xor3<N: ArrayLength<u8>>(
+    mut a: GenericArray<u8, N>,
+    b: &GenericArray<u8, N>,
+    c: &GenericArray<u8, N>,
+) -> GenericArray<u8, N> {
+    a.iter_mut()
+        .zip(b.iter().zip(c))
+        .for_each(|(a, (b, c))| *a ^= b ^ c);
+    a
+}
@@ -0,0 +1,250 @@
+//! The ESP RC5-CBC transform ([RFC 2451](https://www.rfc-editor.org/rfc/rfc2451)) with the ESP packet format
+//! of [RFC 4303](https://www.rfc-editor.org/rfc/rfc4303) §2:
+//!
+//! ```text
+//! SPI (4) | Sequence Number (4) | IV (8) | encrypted [ Payload | Padding | Pad Length (1) | Next Header (1) ] | ICV
+//! ```
+//!
+//! RC5-32/16 in CBC mode with a 5 to 255 byte key and an explicit IV. The padding is the monotonic `1, 2, 3, ...`
+//! of RFC 4303 §2.4 and is checked on decryption. The ICV, if the security association has one, is a MAC over
+//! everything before it, verified before anything is decrypted; see [`Icv`].
+//!
+//! The packets are ESP headers and what follows them: the IP header, and anything anti-replay, is the caller's.
+
+use std::vec::Vec;
+
+use cipher::{Block, BlockDecrypt, BlockEncrypt};
+use core::marker::PhantomData;
+use hmac::Mac;
+use subtle::ConstantTimeEq;
+use typenum::Unsigned;
+
+use crate::rc5::RC5Var;
+
+pub type Cipher = RC5Var<u32>;
+
+pub const ROUNDS: u8 = 16;
+pub const MIN_KEY_LEN: usize = 5;
+pub const MAX_KEY_LEN: usize = 255;
+/// The key length implementations must support.
+pub const DEFAULT_KEY_LEN: usize = 16;
+
+pub const BLOCK_LEN: usize = 8;
+pub const IV_LEN: usize = BLOCK_LEN;
+/// The SPI and the sequence number.
+pub const HEADER_LEN: usize = 8;
+/// The pad length and next header bytes.
+const TRAILER_LEN: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The key is not 5 to 255 bytes long.
+    InvalidKeyLength,
+    /// The packet is shorter than its header, IV, one block and ICV, or its ciphertext ends in the middle of a block.
+    Truncated,
+    /// The packet belongs to another security association.
+    SpiMismatch,
+    /// The ICV does not match: wrong key or corrupted packet.
+    IcvMismatch,
+    /// The padding is malformed: wrong key or corrupted packet.
+    InvalidPadding,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidKeyLength => write!(f, "invalid key length"),
+            Self::Truncated => write!(f, "packet is truncated"),
+            Self::SpiMismatch => write!(f, "SPI mismatch"),
+            Self::IcvMismatch => write!(f, "ICV mismatch"),
+            Self::InvalidPadding => write!(f, "invalid padding"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The integrity check of a security association.
+pub trait Icv {
+    /// The length of the ICV in bytes.
+    fn icv_len(&self) -> usize;
+
+    /// Computes the ICV of `data` (header, IV and ciphertext) into `icv`, which is [`Icv::icv_len`] bytes long.
+    fn compute(&self, data: &[u8], icv: &mut [u8]);
+}
+
+/// No ICV: the confidentiality-only ESP of legacy deployments.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoIcv;
+
+impl Icv for NoIcv {
+    fn icv_len(&self) -> usize {
+        0
+    }
+
+    fn compute(&self, _data: &[u8], _icv: &mut [u8]) {}
+}
+
+/// A MAC truncated to its first `L` bytes, e.g. `TruncatedMac<Hmac<Sha1>, U12>` for HMAC-SHA1-96
+/// ([RFC 2404](https://www.rfc-editor.org/rfc/rfc2404)).
+#[derive(Clone)]
+pub struct TruncatedMac<M, L> {
+    mac: M,
+    _pd: PhantomData<L>,
+}
+
+impl<M: Mac, L: Unsigned> TruncatedMac<M, L> {
+    pub fn new(mac: M) -> Self {
+        assert!(L::USIZE <= M::output_size(), "ICV longer than the MAC");
+        Self {
+            mac,
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<M: Mac + Clone, L: Unsigned> Icv for TruncatedMac<M, L> {
+    fn icv_len(&self) -> usize {
+        L::USIZE
+    }
+
+    fn compute(&self, data: &[u8], icv: &mut [u8]) {
+        let mut mac = self.mac.clone();
+        mac.update(data);
+        icv.copy_from_slice(&mac.finalize().into_bytes()[..L::USIZE]);
+    }
+}
+
+/// A decrypted packet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packet {
+    pub spi: u32,
+    pub sequence_number: u32,
+    pub next_header: u8,
+    pub payload: Vec<u8>,
+}
+
+/// The RC5-CBC transform of one security association.
+pub struct SecurityAssociation<I = NoIcv> {
+    spi: u32,
+    cipher: Cipher,
+    icv: I,
+}
+
+impl SecurityAssociation {
+    pub fn new(spi: u32, key: &[u8]) -> Result<Self, Error> {
+        Self::with_icv(spi, key, NoIcv)
+    }
+}
+
+impl<I: Icv> SecurityAssociation<I> {
+    pub fn with_icv(spi: u32, key: &[u8], icv: I) -> Result<Self, Error> {
+        if !(MIN_KEY_LEN..=MAX_KEY_LEN).contains(&key.len()) {
+            return Err(Error::InvalidKeyLength);
+        }
+        let cipher = Cipher::new(key, ROUNDS).map_err(|_| Error::InvalidKeyLength)?;
+
+        Ok(Self { spi, cipher, icv })
+    }
+
+    pub fn spi(&self) -> u32 {
+        self.spi
+    }
+
+    /// Builds the packet carrying `payload`, padded to the block size with the minimal padding.
+    pub fn encrypt(
+        &self,
+        sequence_number: u32,
+        iv: &[u8; IV_LEN],
+        next_header: u8,
+        payload: &[u8],
+    ) -> Vec<u8> {
+        let pad_len = (BLOCK_LEN - (payload.len() + TRAILER_LEN) % BLOCK_LEN) % BLOCK_LEN;
+        let ciphertext_len = payload.len() + pad_len + TRAILER_LEN;
+
+        let mut packet =
+            Vec::with_capacity(HEADER_LEN + IV_LEN + ciphertext_len + self.icv.icv_len());
+        packet.extend_from_slice(&self.spi.to_be_bytes());
+        packet.extend_from_slice(&sequence_number.to_be_bytes());
+        packet.extend_from_slice(iv);
+        packet.extend_from_slice(payload);
+        packet.extend(1..=pad_len as u8);
+        packet.extend_from_slice(&[pad_len as u8, next_header]);
+
+        let mut prev = Block::<Cipher>::clone_from_slice(iv);
+        for block in packet[HEADER_LEN + IV_LEN..].chunks_exact_mut(BLOCK_LEN) {
+            let block = Block::<Cipher>::from_mut_slice(block);
+            xor(block, &prev);
+            self.cipher.encrypt_block(block);
+            prev = *block;
+        }
+
+        let icv_start = packet.len();
+        packet.resize(icv_start + self.icv.icv_len(), 0);
+        let (data, icv) = packet.split_at_mut(icv_start);
+        self.icv.compute(data, icv);
+
+        packet
+    }
+
+    /// Verifies the ICV, decrypts the packet and strips the padding and trailer.
+    pub fn decrypt(&self, packet: &[u8]) -> Result<Packet, Error> {
+        let icv_len = self.icv.icv_len();
+        if packet.len() < HEADER_LEN + IV_LEN + BLOCK_LEN + icv_len {
+            return Err(Error::Truncated);
+        }
+        let (data, icv) = packet.split_at(packet.len() - icv_len);
+        let (header, rest) = data.split_at(HEADER_LEN);
+        let (iv, ciphertext) = rest.split_at(IV_LEN);
+        if !ciphertext.len().is_multiple_of(BLOCK_LEN) {
+            return Err(Error::Truncated);
+        }
+
+        let spi = u32::from_be_bytes(header[..4].try_into().unwrap());
+        if spi != self.spi {
+            return Err(Error::SpiMismatch);
+        }
+
+        let mut expected = vec![0; icv_len];
+        self.icv.compute(data, &mut expected);
+        if !bool::from(expected.ct_eq(icv)) {
+            return Err(Error::IcvMismatch);
+        }
+
+        let mut plaintext = ciphertext.to_vec();
+        let mut prev = Block::<Cipher>::clone_from_slice(iv);
+        for block in plaintext.chunks_exact_mut(BLOCK_LEN) {
+            let block = Block::<Cipher>::from_mut_slice(block);
+            let next = *block;
+            self.cipher.decrypt_block(block);
+            xor(block, &prev);
+            prev = next;
+        }
+
+        let next_header = plaintext.pop().unwrap();
+        let pad_len = plaintext.pop().unwrap() as usize;
+        if pad_len > plaintext.len() {
+            return Err(Error::InvalidPadding);
+        }
+        let payload_len = plaintext.len() - pad_len;
+        if !plaintext[payload_len..]
+            .iter()
+            .zip(1..)
+            .all(|(&byte, i)| byte == i)
+        {
+            return Err(Error::InvalidPadding);
+        }
+        plaintext.truncate(payload_len);
+
+        Ok(Packet {
+            spi,
+            sequence_number: u32::from_be_bytes(header[4..].try_into().unwrap()),
+            next_header,
+            payload: plaintext,
+        })
+    }
+}
+
+fn xor(block: &mut Block<Cipher>, other: &Block<Cipher>) {
+    block.iter_mut().zip(other).for_each(|(a, b)| *a ^= b);
+}
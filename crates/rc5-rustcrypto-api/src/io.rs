@@ -0,0 +1,642 @@
+//! Streaming encryption over [`std::io`]: [`EncryptWriter`] encrypts everything written to it,
+//! [`DecryptReader`] decrypts everything read through it.
+//!
+//! The adapters buffer partial segments internally, so the data may be written or read in chunks of any size.
+//! The modes available are:
+//! - [`Ecb`] and [`Cbc`]: ECB and CBC with PKCS#7 padding;
+//! - [`Ctr`]: CTR with a 64-bit big-endian counter; it has no integrity protection and cannot detect truncation;
+//! - [`Stream`]: an AEAD split into segments with the STREAM construction (counter and "last segment" flag
+//!   in the nonce), which detects reordered, truncated and tampered streams.
+
+use std::io::{self, Read, Write};
+use std::vec::Vec;
+
+use aead::stream::{NewStream, Nonce as StreamNonce, StreamBE32, StreamPrimitive};
+use aead::AeadInPlace;
+use cipher::{
+    Block, BlockCipher, BlockDecrypt, BlockEncrypt, InnerIvInit, StreamCipher,
+    StreamCipherCoreWrapper,
+};
+use core::ops::Sub;
+use ctr::flavors::{Ctr64BE, CtrFlavor};
+use ctr::CtrCore;
+use generic_array::ArrayLength;
+use typenum::{Diff, IsLess, Le, NonZero, Unsigned, U256, U5};
+
+/// The size of the reads [`DecryptReader`] issues to the underlying reader.
+const READ_CHUNK: usize = 8 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The stream ended in the middle of a block or before its last segment.
+    Truncated,
+    /// The padding of the last block is malformed: wrong key or corrupted data.
+    InvalidPadding,
+    /// A segment failed authentication: wrong key, corrupted, reordered or truncated data.
+    TagMismatch,
+    /// The stream is too long for the segment counter.
+    TooLong,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Truncated => write!(f, "stream is truncated"),
+            Self::InvalidPadding => write!(f, "invalid padding"),
+            Self::TagMismatch => write!(f, "authentication tag mismatch"),
+            Self::TooLong => write!(f, "stream is too long"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Error> for io::Error {
+    fn from(error: Error) -> Self {
+        let kind = match error {
+            Error::Truncated => io::ErrorKind::UnexpectedEof,
+            Error::InvalidPadding | Error::TagMismatch | Error::TooLong => {
+                io::ErrorKind::InvalidData
+            }
+        };
+        io::Error::new(kind, error)
+    }
+}
+
+/// A mode encrypting a stream one segment at a time.
+pub trait Encryptor {
+    /// The number of plaintext bytes [`Encryptor::encrypt_segment`] takes.
+    fn segment_len(&self) -> usize;
+
+    /// Encrypts a segment of exactly [`Encryptor::segment_len`] bytes, which is not the last one.
+    fn encrypt_segment(&mut self, segment: &[u8], out: &mut Vec<u8>) -> Result<(), Error>;
+
+    /// Encrypts the remaining `0..=segment_len` bytes and finalises the stream.
+    fn encrypt_last(self, rest: &[u8], out: &mut Vec<u8>) -> Result<(), Error>;
+}
+
+/// A mode decrypting a stream one segment at a time.
+pub trait Decryptor {
+    /// The number of ciphertext bytes [`Decryptor::decrypt_segment`] takes.
+    fn segment_len(&self) -> usize;
+
+    /// Decrypts a segment of exactly [`Decryptor::segment_len`] bytes, which is not the last one.
+    fn decrypt_segment(&mut self, segment: &[u8], out: &mut Vec<u8>) -> Result<(), Error>;
+
+    /// Decrypts the remaining `0..=segment_len` bytes, checking the padding or the tag.
+    fn decrypt_last(self, rest: &[u8], out: &mut Vec<u8>) -> Result<(), Error>;
+}
+
+/// Encrypts the data written to it and writes the ciphertext to `W`.
+///
+/// [`EncryptWriter::finish`] must be called at the end of the stream: it writes the last segment.
+///
+/// A write that fails because `W` does may be retried: the ciphertext not yet taken by `W` is kept and goes out
+/// first. A write that fails because the mode does, e.g. [`Error::TooLong`], fails every later call too.
+pub struct EncryptWriter<W, E> {
+    inner: W,
+    mode: E,
+    failed: Option<Error>,
+    pending: Vec<u8>,
+    out: Unwritten,
+}
+
+/// Ciphertext on its way to the inner writer, and how much of it the writer has taken.
+#[derive(Default)]
+struct Unwritten {
+    bytes: Vec<u8>,
+    pos: usize,
+}
+
+impl Unwritten {
+    /// Writes the bytes not yet taken to `inner`, keeping track of them should it fail.
+    fn write_to<W: Write>(&mut self, inner: &mut W) -> io::Result<()> {
+        while self.pos < self.bytes.len() {
+            match inner.write(&self.bytes[self.pos..]) {
+                Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+                Ok(written) => self.pos += written,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+        self.bytes.clear();
+        self.pos = 0;
+
+        Ok(())
+    }
+}
+
+impl<W: Write, E: Encryptor> EncryptWriter<W, E> {
+    pub fn new(inner: W, mode: E) -> Self {
+        Self {
+            inner,
+            mode,
+            failed: None,
+            pending: Vec::new(),
+            out: Unwritten::default(),
+        }
+    }
+
+    /// Encrypts the buffered remainder, writes out the end of the stream and returns the inner writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        if let Some(error) = self.failed {
+            return Err(error.into());
+        }
+        self.out.write_to(&mut self.inner)?;
+        self.mode.encrypt_last(&self.pending, &mut self.out.bytes)?;
+        self.out.write_to(&mut self.inner)?;
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write, E: Encryptor> Write for EncryptWriter<W, E> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let Some(error) = self.failed {
+            return Err(error.into());
+        }
+        // nothing of `buf` is taken until the ciphertext of the earlier writes is out
+        self.out.write_to(&mut self.inner)?;
+
+        self.pending.extend_from_slice(buf);
+
+        // the last segment is always held back: only `finish` knows it is the last one
+        let segment_len = self.mode.segment_len();
+        let mut consumed = 0;
+        while self.pending.len() - consumed > segment_len {
+            let segment = &self.pending[consumed..consumed + segment_len];
+            if let Err(error) = self.mode.encrypt_segment(segment, &mut self.out.bytes) {
+                self.failed = Some(error);
+                return Err(error.into());
+            }
+            consumed += segment_len;
+        }
+        self.pending.drain(..consumed);
+
+        // `buf` is taken now: should `W` fail, the next call retries and reports it
+        let _ = self.out.write_to(&mut self.inner);
+
+        Ok(buf.len())
+    }
+
+    /// Writes out the ciphertext so far and flushes the inner writer. The bytes of an incomplete segment stay
+    /// buffered until [`EncryptWriter::finish`].
+    fn flush(&mut self) -> io::Result<()> {
+        self.out.write_to(&mut self.inner)?;
+        self.inner.flush()
+    }
+}
+
+/// Reads the ciphertext from `R` and yields the plaintext.
+///
+/// The end of the stream is checked when `R` reaches EOF: a truncated or tampered stream yields an error
+/// instead of EOF. With an authenticating mode, no plaintext of a segment is returned before its tag is checked.
+pub struct DecryptReader<R, D> {
+    inner: R,
+    mode: Option<D>,
+    failed: Option<Error>,
+    pending: Vec<u8>,
+    out: Vec<u8>,
+    out_pos: usize,
+}
+
+impl<R: Read, D: Decryptor> DecryptReader<R, D> {
+    pub fn new(inner: R, mode: D) -> Self {
+        Self {
+            inner,
+            mode: Some(mode),
+            failed: None,
+            pending: Vec::new(),
+            out: Vec::new(),
+            out_pos: 0,
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads from `R` until some plaintext is available or the stream is over.
+    ///
+    /// A decryption error is reported once the plaintext preceding the bad segment has been read.
+    fn fill_out(&mut self) -> io::Result<()> {
+        self.out.clear();
+        self.out_pos = 0;
+
+        while self.out.is_empty() {
+            if let Some(error) = self.failed {
+                return Err(error.into());
+            }
+            let Some(mode) = self.mode.as_mut() else {
+                return Ok(());
+            };
+
+            let len = self.pending.len();
+            self.pending.resize(len + READ_CHUNK, 0);
+            let read = match self.inner.read(&mut self.pending[len..]) {
+                Ok(read) => read,
+                Err(err) => {
+                    self.pending.truncate(len);
+                    return Err(err);
+                }
+            };
+            self.pending.truncate(len + read);
+
+            let result = if read == 0 {
+                let mode = self.mode.take().expect("checked above");
+                let result = mode.decrypt_last(&self.pending, &mut self.out);
+                self.pending.clear();
+                result
+            } else {
+                let segment_len = mode.segment_len();
+                let mut consumed = 0;
+                let mut result = Ok(());
+                while result.is_ok() && self.pending.len() - consumed > segment_len {
+                    result = mode.decrypt_segment(
+                        &self.pending[consumed..consumed + segment_len],
+                        &mut self.out,
+                    );
+                    consumed += segment_len;
+                }
+                self.pending.drain(..consumed);
+                result
+            };
+
+            if let Err(error) = result {
+                self.failed = Some(error);
+                self.mode = None;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: Read, D: Decryptor> Read for DecryptReader<R, D> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.out_pos == self.out.len() {
+            self.fill_out()?;
+        }
+
+        let available = &self.out[self.out_pos..];
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.out_pos += n;
+
+        Ok(n)
+    }
+}
+
+/// Encrypts `plaintext` as a whole: the same as writing it to an [`EncryptWriter`] over a `Vec`.
+pub fn encrypt_to_vec<E: Encryptor>(mut mode: E, plaintext: &[u8]) -> Result<Vec<u8>, Error> {
+    let segment_len = mode.segment_len();
+    let mut out = Vec::with_capacity(plaintext.len() + segment_len);
+
+    let mut rest = plaintext;
+    while rest.len() > segment_len {
+        let (segment, tail) = rest.split_at(segment_len);
+        mode.encrypt_segment(segment, &mut out)?;
+        rest = tail;
+    }
+    mode.encrypt_last(rest, &mut out)?;
+
+    Ok(out)
+}
+
+/// Decrypts `ciphertext` as a whole: the same as reading a [`DecryptReader`] over it to the end.
+pub fn decrypt_to_vec<D: Decryptor>(mut mode: D, ciphertext: &[u8]) -> Result<Vec<u8>, Error> {
+    let segment_len = mode.segment_len();
+    let mut out = Vec::with_capacity(ciphertext.len());
+
+    let mut rest = ciphertext;
+    while rest.len() > segment_len {
+        let (segment, tail) = rest.split_at(segment_len);
+        mode.decrypt_segment(segment, &mut out)?;
+        rest = tail;
+    }
+    mode.decrypt_last(rest, &mut out)?;
+
+    Ok(out)
+}
+
+/// ECB with PKCS#7 padding: every block is encrypted on its own, so equal blocks give equal ciphertext.
+pub struct Ecb<C> {
+    cipher: C,
+}
+
+impl<C: BlockCipher> Ecb<C> {
+    pub fn new(cipher: C) -> Self {
+        Self { cipher }
+    }
+}
+
+impl<C: BlockCipher + BlockEncrypt> Encryptor for Ecb<C> {
+    fn segment_len(&self) -> usize {
+        C::BlockSize::USIZE
+    }
+
+    fn encrypt_segment(&mut self, segment: &[u8], out: &mut Vec<u8>) -> Result<(), Error> {
+        let mut block = Block::<C>::clone_from_slice(segment);
+        self.cipher.encrypt_block(&mut block);
+        out.extend_from_slice(&block);
+
+        Ok(())
+    }
+
+    fn encrypt_last(mut self, rest: &[u8], out: &mut Vec<u8>) -> Result<(), Error> {
+        encrypt_padded::<C>(rest, |block| self.encrypt_segment(block, out))
+    }
+}
+
+impl<C: BlockCipher + BlockDecrypt> Decryptor for Ecb<C> {
+    fn segment_len(&self) -> usize {
+        C::BlockSize::USIZE
+    }
+
+    fn decrypt_segment(&mut self, segment: &[u8], out: &mut Vec<u8>) -> Result<(), Error> {
+        let mut block = Block::<C>::clone_from_slice(segment);
+        self.cipher.decrypt_block(&mut block);
+        out.extend_from_slice(&block);
+
+        Ok(())
+    }
+
+    fn decrypt_last(mut self, rest: &[u8], out: &mut Vec<u8>) -> Result<(), Error> {
+        decrypt_padded::<C>(rest, out, |block, out| self.decrypt_segment(block, out))
+    }
+}
+
+/// CBC with PKCS#7 padding.
+pub struct Cbc<C: BlockCipher> {
+    cipher: C,
+    chain: Block<C>,
+}
+
+impl<C: BlockCipher> Cbc<C> {
+    pub fn new(cipher: C, iv: &Block<C>) -> Self {
+        Self {
+            cipher,
+            chain: iv.clone(),
+        }
+    }
+}
+
+impl<C: BlockCipher + BlockEncrypt> Encryptor for Cbc<C> {
+    fn segment_len(&self) -> usize {
+        C::BlockSize::USIZE
+    }
+
+    fn encrypt_segment(&mut self, segment: &[u8], out: &mut Vec<u8>) -> Result<(), Error> {
+        self.chain
+            .iter_mut()
+            .zip(segment)
+            .for_each(|(c, p)| *c ^= p);
+        self.cipher.encrypt_block(&mut self.chain);
+        out.extend_from_slice(&self.chain);
+
+        Ok(())
+    }
+
+    fn encrypt_last(mut self, rest: &[u8], out: &mut Vec<u8>) -> Result<(), Error> {
+        encrypt_padded::<C>(rest, |block| self.encrypt_segment(block, out))
+    }
+}
+
+impl<C: BlockCipher + BlockDecrypt> Decryptor for Cbc<C> {
+    fn segment_len(&self) -> usize {
+        C::BlockSize::USIZE
+    }
+
+    fn decrypt_segment(&mut self, segment: &[u8], out: &mut Vec<u8>) -> Result<(), Error> {
+        let mut block = Block::<C>::clone_from_slice(segment);
+        self.cipher.decrypt_block(&mut block);
+        block
+            .iter_mut()
+            .zip(self.chain.iter())
+            .for_each(|(p, c)| *p ^= c);
+        self.chain.copy_from_slice(segment);
+        out.extend_from_slice(&block);
+
+        Ok(())
+    }
+
+    fn decrypt_last(mut self, rest: &[u8], out: &mut Vec<u8>) -> Result<(), Error> {
+        decrypt_padded::<C>(rest, out, |block, out| self.decrypt_segment(block, out))
+    }
+}
+
+/// Passes the last `0..=block_len` bytes of a stream to `encrypt` as PKCS#7-padded blocks.
+fn encrypt_padded<C: BlockCipher>(
+    rest: &[u8],
+    mut encrypt: impl FnMut(&[u8]) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let block_len = C::BlockSize::USIZE;
+    let (full, rest) = rest.split_at(rest.len() / block_len * block_len);
+    if !full.is_empty() {
+        encrypt(full)?;
+    }
+
+    let mut last = Block::<C>::default();
+    last.fill((block_len - rest.len()) as u8);
+    last[..rest.len()].copy_from_slice(rest);
+
+    encrypt(&last)
+}
+
+/// Decrypts the last block of a stream with `decrypt` and strips its PKCS#7 padding.
+fn decrypt_padded<C: BlockCipher>(
+    rest: &[u8],
+    out: &mut Vec<u8>,
+    decrypt: impl FnOnce(&[u8], &mut Vec<u8>) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let block_len = C::BlockSize::USIZE;
+    if rest.len() != block_len {
+        return Err(Error::Truncated);
+    }
+
+    let start = out.len();
+    decrypt(rest, out)?;
+
+    let pad = out[out.len() - 1];
+    let padding_ok = (1..=block_len).contains(&(pad as usize))
+        && out[out.len() - pad as usize..].iter().all(|b| *b == pad);
+    if !padding_ok {
+        out.truncate(start);
+        return Err(Error::InvalidPadding);
+    }
+    out.truncate(out.len() - pad as usize);
+
+    Ok(())
+}
+
+/// CTR with a 64-bit big-endian counter in the rightmost bytes of the block.
+pub struct Ctr<C>
+where
+    C: BlockCipher + BlockEncrypt,
+    Ctr64BE: CtrFlavor<C::BlockSize>,
+    C::BlockSize: IsLess<U256>,
+    Le<C::BlockSize, U256>: NonZero,
+{
+    keystream: StreamCipherCoreWrapper<CtrCore<C, Ctr64BE>>,
+}
+
+impl<C> Ctr<C>
+where
+    C: BlockCipher + BlockEncrypt,
+    Ctr64BE: CtrFlavor<C::BlockSize>,
+    C::BlockSize: IsLess<U256>,
+    Le<C::BlockSize, U256>: NonZero,
+{
+    pub fn new(cipher: C, iv: &Block<C>) -> Self {
+        Self {
+            keystream: StreamCipherCoreWrapper::from_core(CtrCore::inner_iv_init(cipher, iv)),
+        }
+    }
+
+    fn apply(&mut self, data: &[u8], out: &mut Vec<u8>) -> Result<(), Error> {
+        let start = out.len();
+        out.extend_from_slice(data);
+        self.keystream
+            .try_apply_keystream(&mut out[start..])
+            .map_err(|_| Error::TooLong)
+    }
+}
+
+impl<C> Encryptor for Ctr<C>
+where
+    C: BlockCipher + BlockEncrypt,
+    Ctr64BE: CtrFlavor<C::BlockSize>,
+    C::BlockSize: IsLess<U256>,
+    Le<C::BlockSize, U256>: NonZero,
+{
+    fn segment_len(&self) -> usize {
+        C::BlockSize::USIZE
+    }
+
+    fn encrypt_segment(&mut self, segment: &[u8], out: &mut Vec<u8>) -> Result<(), Error> {
+        self.apply(segment, out)
+    }
+
+    fn encrypt_last(mut self, rest: &[u8], out: &mut Vec<u8>) -> Result<(), Error> {
+        self.apply(rest, out)
+    }
+}
+
+impl<C> Decryptor for Ctr<C>
+where
+    C: BlockCipher + BlockEncrypt,
+    Ctr64BE: CtrFlavor<C::BlockSize>,
+    C::BlockSize: IsLess<U256>,
+    Le<C::BlockSize, U256>: NonZero,
+{
+    fn segment_len(&self) -> usize {
+        C::BlockSize::USIZE
+    }
+
+    fn decrypt_segment(&mut self, segment: &[u8], out: &mut Vec<u8>) -> Result<(), Error> {
+        self.apply(segment, out)
+    }
+
+    fn decrypt_last(mut self, rest: &[u8], out: &mut Vec<u8>) -> Result<(), Error> {
+        self.apply(rest, out)
+    }
+}
+
+/// An AEAD over segments of `segment_len` plaintext bytes, each followed by its tag.
+///
+/// The nonce of each segment is the 5-byte-shorter nonce given to [`Stream::new`], the segment's number
+/// (32-bit big-endian) and a byte flagging the last segment.
+pub struct Stream<A>
+where
+    A: AeadInPlace,
+    A::NonceSize: Sub<U5>,
+    Diff<A::NonceSize, U5>: ArrayLength<u8>,
+{
+    stream: StreamBE32<A>,
+    segment_len: usize,
+    position: u32,
+}
+
+impl<A> Stream<A>
+where
+    A: AeadInPlace,
+    A::NonceSize: Sub<U5>,
+    Diff<A::NonceSize, U5>: ArrayLength<u8>,
+{
+    pub fn new(aead: A, nonce: &StreamNonce<A, StreamBE32<A>>, segment_len: usize) -> Self {
+        assert!(segment_len > 0, "segment_len must be positive");
+
+        Self {
+            stream: StreamBE32::from_aead(aead, nonce),
+            segment_len,
+            position: 0,
+        }
+    }
+
+    fn seal(&mut self, last: bool, segment: &[u8], out: &mut Vec<u8>) -> Result<(), Error> {
+        let mut buffer = Vec::from(segment);
+        self.stream
+            .encrypt_in_place(self.position, last, &[], &mut buffer)
+            .map_err(|_| Error::TooLong)?;
+        out.extend_from_slice(&buffer);
+
+        self.advance()
+    }
+
+    fn open(&mut self, last: bool, segment: &[u8], out: &mut Vec<u8>) -> Result<(), Error> {
+        let mut buffer = Vec::from(segment);
+        self.stream
+            .decrypt_in_place(self.position, last, &[], &mut buffer)
+            .map_err(|_| Error::TagMismatch)?;
+        out.extend_from_slice(&buffer);
+
+        self.advance()
+    }
+
+    fn advance(&mut self) -> Result<(), Error> {
+        self.position = self.position.checked_add(1).ok_or(Error::TooLong)?;
+        Ok(())
+    }
+}
+
+impl<A> Encryptor for Stream<A>
+where
+    A: AeadInPlace,
+    A::NonceSize: Sub<U5>,
+    Diff<A::NonceSize, U5>: ArrayLength<u8>,
+{
+    fn segment_len(&self) -> usize {
+        self.segment_len
+    }
+
+    fn encrypt_segment(&mut self, segment: &[u8], out: &mut Vec<u8>) -> Result<(), Error> {
+        self.seal(false, segment, out)
+    }
+
+    fn encrypt_last(mut self, rest: &[u8], out: &mut Vec<u8>) -> Result<(), Error> {
+        self.seal(true, rest, out)
+    }
+}
+
+impl<A> Decryptor for Stream<A>
+where
+    A: AeadInPlace,
+    A::NonceSize: Sub<U5>,
+    Diff<A::NonceSize, U5>: ArrayLength<u8>,
+{
+    fn segment_len(&self) -> usize {
+        self.segment_len + A::TagSize::USIZE
+    }
+
+    fn decrypt_segment(&mut self, segment: &[u8], out: &mut Vec<u8>) -> Result<(), Error> {
+        self.open(false, segment, out)
+    }
+
+    fn decrypt_last(mut self, rest: &[u8], out: &mut Vec<u8>) -> Result<(), Error> {
+        if rest.len() < A::TagSize::USIZE {
+            return Err(Error::Truncated);
+        }
+        self.open(true, rest, out)
+    }
+}
@@ -0,0 +1,246 @@
+//! Key wrapping: [RFC 3394](https://www.rfc-editor.org/rfc/rfc3394) (KW) and
+//! [RFC 5649](https://www.rfc-editor.org/rfc/rfc5649) (KWP).
+//!
+//! The RFCs are written for 128-bit blocks, i.e. 64-bit semiblocks, which is what RC5-64 provides.
+//! KW also works with any other even block size: the semiblock is half a block, the default IV is `0xA6`
+//! repeated over one semiblock and the step counter is XOR-ed into its rightmost bytes.
+//! With RC5-32 this gives KW over 32-bit semiblocks. KWP needs a 64-bit semiblock to hold its
+//! alternative IV, so it is only available for 128-bit blocks.
+
+use cipher::{Block, BlockCipher, BlockDecrypt, BlockEncrypt, Key, KeyInit, KeySizeUser};
+use subtle::ConstantTimeEq;
+use typenum::Unsigned;
+
+/// The byte the RFC 3394 default IV is made of.
+pub const IV_BYTE: u8 = 0xA6;
+
+/// The constant half of the RFC 5649 alternative IV; the other half is the message length.
+pub const KWP_AIV_PREFIX: [u8; 4] = [0xA6, 0x59, 0x59, 0xA6];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The input is not a valid length for this operation.
+    InvalidInputLength,
+    /// The output buffer is too short for the result.
+    InvalidOutputLength,
+    /// The unwrapped IV or padding does not match: wrong key or corrupted data.
+    IntegrityCheckFailed,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidInputLength => write!(f, "invalid input length"),
+            Self::InvalidOutputLength => write!(f, "invalid output length"),
+            Self::IntegrityCheckFailed => write!(f, "integrity check failed"),
+        }
+    }
+}
+
+/// A key-encryption key.
+#[derive(Clone)]
+pub struct Kek<C>(C);
+
+impl<C> From<C> for Kek<C> {
+    fn from(cipher: C) -> Self {
+        Self(cipher)
+    }
+}
+
+impl<C: KeySizeUser> KeySizeUser for Kek<C> {
+    type KeySize = C::KeySize;
+}
+
+impl<C: KeyInit> KeyInit for Kek<C> {
+    fn new(key: &Key<Self>) -> Self {
+        Self(C::new(key))
+    }
+}
+
+impl<C> Kek<C>
+where
+    C: BlockCipher + BlockEncrypt + BlockDecrypt,
+{
+    const SEMIBLOCK: usize = C::BlockSize::USIZE / 2;
+
+    /// Wraps `data` (at least two semiblocks, a whole number of them) into `out`.
+    ///
+    /// Returns the part of `out` holding the result, which is one semiblock longer than `data`.
+    pub fn wrap<'o>(&self, data: &[u8], out: &'o mut [u8]) -> Result<&'o [u8], Error> {
+        if !data.len().is_multiple_of(Self::SEMIBLOCK)
+            || data.len() < 2 * Self::SEMIBLOCK
+            || !Self::steps_fit(data.len() / Self::SEMIBLOCK)
+        {
+            return Err(Error::InvalidInputLength);
+        }
+
+        let out = out
+            .get_mut(..data.len() + Self::SEMIBLOCK)
+            .ok_or(Error::InvalidOutputLength)?;
+        out[..Self::SEMIBLOCK].fill(IV_BYTE);
+        out[Self::SEMIBLOCK..].copy_from_slice(data);
+
+        self.w(out);
+
+        Ok(out)
+    }
+
+    /// Unwraps `data` into `out`, checking the integrity of the result.
+    ///
+    /// Returns the part of `out` holding the key, which is one semiblock shorter than `data`.
+    pub fn unwrap<'o>(&self, data: &[u8], out: &'o mut [u8]) -> Result<&'o [u8], Error> {
+        if !data.len().is_multiple_of(Self::SEMIBLOCK)
+            || data.len() < 3 * Self::SEMIBLOCK
+            || !Self::steps_fit(data.len() / Self::SEMIBLOCK - 1)
+        {
+            return Err(Error::InvalidInputLength);
+        }
+
+        let out = out
+            .get_mut(..data.len() - Self::SEMIBLOCK)
+            .ok_or(Error::InvalidOutputLength)?;
+
+        let mut a = Block::<C>::default();
+        self.w_inverse(data, &mut a[..Self::SEMIBLOCK], out);
+
+        let mut iv = Block::<C>::default();
+        iv.fill(IV_BYTE);
+
+        if bool::from(a[..Self::SEMIBLOCK].ct_eq(&iv[..Self::SEMIBLOCK])) {
+            Ok(out)
+        } else {
+            out.fill(0);
+            Err(Error::IntegrityCheckFailed)
+        }
+    }
+
+    /// Whether the last step counter, `6n`, fits into a semiblock.
+    fn steps_fit(n: usize) -> bool {
+        let bits = (8 * Self::SEMIBLOCK).min(64) as u32;
+        (6 * n as u64).checked_shr(bits).unwrap_or(0) == 0
+    }
+
+    /// The wrapping process `W` of RFC 3394 (section 2.2.1), applied to `A || R[1] || ... || R[n]` in place.
+    fn w(&self, buf: &mut [u8]) {
+        let n = buf.len() / Self::SEMIBLOCK - 1;
+        let mut block = Block::<C>::default();
+
+        for j in 0..6 {
+            for i in 1..=n {
+                let r = i * Self::SEMIBLOCK;
+                block[..Self::SEMIBLOCK].copy_from_slice(&buf[..Self::SEMIBLOCK]);
+                block[Self::SEMIBLOCK..].copy_from_slice(&buf[r..r + Self::SEMIBLOCK]);
+
+                self.0.encrypt_block(&mut block);
+
+                buf[..Self::SEMIBLOCK].copy_from_slice(&block[..Self::SEMIBLOCK]);
+                xor_step(&mut buf[..Self::SEMIBLOCK], n * j + i);
+                buf[r..r + Self::SEMIBLOCK].copy_from_slice(&block[Self::SEMIBLOCK..]);
+            }
+        }
+    }
+
+    /// The unwrapping process `W^-1` of RFC 3394 (section 2.2.2): splits `data` into `a` and `r`.
+    fn w_inverse(&self, data: &[u8], a: &mut [u8], r: &mut [u8]) {
+        let n = r.len() / Self::SEMIBLOCK;
+        let mut block = Block::<C>::default();
+
+        a.copy_from_slice(&data[..Self::SEMIBLOCK]);
+        r.copy_from_slice(&data[Self::SEMIBLOCK..]);
+
+        for j in (0..6).rev() {
+            for i in (1..=n).rev() {
+                let ri = (i - 1) * Self::SEMIBLOCK;
+                xor_step(a, n * j + i);
+                block[..Self::SEMIBLOCK].copy_from_slice(a);
+                block[Self::SEMIBLOCK..].copy_from_slice(&r[ri..ri + Self::SEMIBLOCK]);
+
+                self.0.decrypt_block(&mut block);
+
+                a.copy_from_slice(&block[..Self::SEMIBLOCK]);
+                r[ri..ri + Self::SEMIBLOCK].copy_from_slice(&block[Self::SEMIBLOCK..]);
+            }
+        }
+    }
+}
+
+impl<C> Kek<C>
+where
+    C: BlockCipher<BlockSize = typenum::U16> + BlockEncrypt + BlockDecrypt,
+{
+    /// Wraps a key of any length from 1 to 2^32 - 1 bytes.
+    ///
+    /// Returns the part of `out` holding the result: the key padded to a multiple of 8 bytes, plus 8 bytes.
+    pub fn wrap_with_padding<'o>(&self, data: &[u8], out: &'o mut [u8]) -> Result<&'o [u8], Error> {
+        let mli = u32::try_from(data.len())
+            .ok()
+            .filter(|mli| *mli > 0)
+            .ok_or(Error::InvalidInputLength)?;
+
+        let padded_len = data.len().div_ceil(8) * 8;
+        let out = out
+            .get_mut(..padded_len + 8)
+            .ok_or(Error::InvalidOutputLength)?;
+
+        out[..4].copy_from_slice(&KWP_AIV_PREFIX);
+        out[4..8].copy_from_slice(&mli.to_be_bytes());
+        out[8..8 + data.len()].copy_from_slice(data);
+        out[8 + data.len()..].fill(0);
+
+        if padded_len == 8 {
+            self.0.encrypt_block(Block::<C>::from_mut_slice(out));
+        } else {
+            self.w(out);
+        }
+
+        Ok(out)
+    }
+
+    /// Unwraps a key wrapped with [`Kek::wrap_with_padding`], checking the IV, the length and the padding.
+    ///
+    /// `out` must hold at least `data.len() - 8` bytes; the returned slice is the key itself.
+    pub fn unwrap_with_padding<'o>(
+        &self,
+        data: &[u8],
+        out: &'o mut [u8],
+    ) -> Result<&'o [u8], Error> {
+        if !data.len().is_multiple_of(8) || data.len() < 16 {
+            return Err(Error::InvalidInputLength);
+        }
+
+        let out = out
+            .get_mut(..data.len() - 8)
+            .ok_or(Error::InvalidOutputLength)?;
+
+        let mut a = [0u8; 8];
+        if data.len() == 16 {
+            let mut block = Block::<C>::clone_from_slice(data);
+            self.0.decrypt_block(&mut block);
+            a.copy_from_slice(&block[..8]);
+            out.copy_from_slice(&block[8..]);
+        } else {
+            self.w_inverse(data, &mut a, out);
+        }
+
+        let mli = u32::from_be_bytes(a[4..].try_into().expect("4 bytes")) as usize;
+        let padding_ok = mli <= out.len()
+            && mli + 8 > out.len()
+            && out[mli..].iter().fold(0, |acc, b| acc | b) == 0;
+
+        if bool::from(a[..4].ct_eq(&KWP_AIV_PREFIX)) && padding_ok {
+            Ok(&out[..mli])
+        } else {
+            out.fill(0);
+            Err(Error::IntegrityCheckFailed)
+        }
+    }
+}
+
+/// XORs the step counter `t`, big-endian, into the rightmost bytes of the semiblock `a`.
+fn xor_step(a: &mut [u8], t: usize) {
+    let t = (t as u64).to_be_bytes();
+    a.iter_mut()
+        .rev()
+        .zip(t.iter().rev())
+        .for_each(|(a, t)| *a ^= t);
+}
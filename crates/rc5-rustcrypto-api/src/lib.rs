@@ -1,6 +1,24 @@
 #![no_std]
 
+pub mod asn1;
+pub mod ccm;
+#[cfg(feature = "std")]
+pub mod cms;
+#[cfg(feature = "std")]
+pub mod container;
+pub mod eax;
+#[cfg(feature = "std")]
+pub mod esp;
+#[cfg(feature = "std")]
+pub mod io;
+pub mod kw;
+#[cfg(feature = "std")]
+pub mod openssl;
+#[cfg(feature = "std")]
+pub mod pbes2;
 pub mod rc5;
+pub mod siv;
+pub mod xts;
 
 pub mod rivest97 {
     type RC5_32_12<B> = crate::rc5::RC5<u32, typenum::U12, B>;
@@ -28,7 +46,7 @@ pub mod krovetz18 {
     pub type RC5_64_20_32 = RC5_64_20<typenum::U32>;
 }
 
-#[cfg(test)]
+#[cfg(any(feature = "std", test))]
 #[macro_use]
 extern crate std;
 
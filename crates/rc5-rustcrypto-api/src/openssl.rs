@@ -0,0 +1,170 @@
+//! The format of `openssl enc -rc5-cbc`: `Salted__`, an 8-byte salt, then RC5-32/12/16-CBC with PKCS#7 padding.
+//!
+//! The key and IV are derived from the password and salt with either
+//! - [`Kdf::BytesToKey`]: `EVP_BytesToKey` with one iteration, what `openssl enc` does by default
+//!   (the digest is set with `-md`: MD5 before OpenSSL 1.1.0, SHA-256 since), or
+//! - [`Kdf::Pbkdf2`]: PBKDF2-HMAC, what `openssl enc -pbkdf2 [-iter N]` does (10000 iterations by default).
+
+use std::vec::Vec;
+
+use cipher::{Block, Key, KeyInit, KeySizeUser};
+use md5::Md5;
+use sha1::Sha1;
+use sha2::digest::{self, Output};
+use sha2::Sha256;
+use typenum::Unsigned;
+
+use crate::io::{decrypt_to_vec, encrypt_to_vec, Cbc};
+use crate::rivest97::RC5_32_12_16;
+
+pub type Cipher = RC5_32_12_16;
+
+pub const MAGIC: [u8; 8] = *b"Salted__";
+pub const SALT_LEN: usize = 8;
+
+/// The number of PBKDF2 iterations `openssl enc -pbkdf2` uses unless given `-iter`.
+pub const DEFAULT_PBKDF2_ITERATIONS: u32 = 10_000;
+
+const BLOCK_LEN: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The data does not start with `Salted__`.
+    NotSalted,
+    /// The data ends before the salt, or in the middle of a block.
+    Truncated,
+    /// The padding of the last block is malformed: wrong password or corrupted data.
+    InvalidPadding,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotSalted => write!(f, "missing Salted__ header"),
+            Self::Truncated => write!(f, "data is truncated"),
+            Self::InvalidPadding => write!(f, "invalid padding"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<crate::io::Error> for Error {
+    fn from(error: crate::io::Error) -> Self {
+        match error {
+            crate::io::Error::InvalidPadding => Self::InvalidPadding,
+            _ => Self::Truncated,
+        }
+    }
+}
+
+/// The digest of `openssl enc -md`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Digest {
+    Md5,
+    Sha1,
+    Sha256,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kdf {
+    BytesToKey(Digest),
+    Pbkdf2 { digest: Digest, iterations: u32 },
+}
+
+/// `EVP_BytesToKey`: fills `out` with `D_1 || D_2 || ...`, where `D_i` is `D_{i-1} || password || salt`
+/// hashed `count` times.
+pub fn evp_bytes_to_key<D: digest::Digest>(
+    password: &[u8],
+    salt: &[u8],
+    count: usize,
+    out: &mut [u8],
+) {
+    let mut prev: Option<Output<D>> = None;
+
+    for chunk in out.chunks_mut(<D as digest::Digest>::output_size()) {
+        let mut hasher = D::new();
+        if let Some(prev) = &prev {
+            hasher.update(prev);
+        }
+        hasher.update(password);
+        hasher.update(salt);
+        let mut digest = hasher.finalize();
+        for _ in 1..count {
+            digest = D::digest(&digest);
+        }
+
+        chunk.copy_from_slice(&digest[..chunk.len()]);
+        prev = Some(digest);
+    }
+}
+
+/// Derives the key and IV the way `openssl enc` does.
+pub fn derive(kdf: Kdf, password: &[u8], salt: &[u8; SALT_LEN]) -> (Key<Cipher>, Block<Cipher>) {
+    let key_len = <Cipher as KeySizeUser>::KeySize::USIZE;
+    let mut material = [0u8; 16 + BLOCK_LEN];
+
+    match kdf {
+        Kdf::BytesToKey(Digest::Md5) => evp_bytes_to_key::<Md5>(password, salt, 1, &mut material),
+        Kdf::BytesToKey(Digest::Sha1) => evp_bytes_to_key::<Sha1>(password, salt, 1, &mut material),
+        Kdf::BytesToKey(Digest::Sha256) => {
+            evp_bytes_to_key::<Sha256>(password, salt, 1, &mut material)
+        }
+        Kdf::Pbkdf2 { digest, iterations } => {
+            let pbkdf2 = match digest {
+                Digest::Md5 => pbkdf2::pbkdf2_hmac::<Md5>,
+                Digest::Sha1 => pbkdf2::pbkdf2_hmac::<Sha1>,
+                Digest::Sha256 => pbkdf2::pbkdf2_hmac::<Sha256>,
+            };
+            pbkdf2(password, salt, iterations, &mut material);
+        }
+    }
+
+    let (key, iv) = material.split_at(key_len);
+    (
+        Key::<Cipher>::clone_from_slice(key),
+        Block::<Cipher>::clone_from_slice(iv),
+    )
+}
+
+/// Encrypts `plaintext` into what `openssl enc -rc5-cbc -S <salt>` would write.
+pub fn encrypt(kdf: Kdf, password: &[u8], salt: &[u8; SALT_LEN], plaintext: &[u8]) -> Vec<u8> {
+    let (key, iv) = derive(kdf, password, salt);
+    let ciphertext = encrypt_to_vec(Cbc::new(Cipher::new(&key), &iv), plaintext)
+        .expect("CBC encryption never fails");
+
+    let mut out = Vec::with_capacity(MAGIC.len() + SALT_LEN + ciphertext.len());
+    out.extend_from_slice(&MAGIC);
+    out.extend_from_slice(salt);
+    out.extend_from_slice(&ciphertext);
+
+    out
+}
+
+/// Decrypts the output of `openssl enc -rc5-cbc`.
+pub fn decrypt(kdf: Kdf, password: &[u8], data: &[u8]) -> Result<Vec<u8>, Error> {
+    let salt = salt(data)?;
+    let ciphertext = &data[MAGIC.len() + SALT_LEN..];
+    if ciphertext.is_empty() || !ciphertext.len().is_multiple_of(BLOCK_LEN) {
+        return Err(Error::Truncated);
+    }
+
+    let (key, iv) = derive(kdf, password, salt);
+    decrypt_to_vec(Cbc::new(Cipher::new(&key), &iv), ciphertext).map_err(Error::from)
+}
+
+/// The salt of the `Salted__` header.
+pub fn salt(data: &[u8]) -> Result<&[u8; SALT_LEN], Error> {
+    if data.len() < MAGIC.len() || data[..MAGIC.len()] != MAGIC {
+        return Err(if MAGIC.starts_with(data) {
+            Error::Truncated
+        } else {
+            Error::NotSalted
+        });
+    }
+
+    data[MAGIC.len()..]
+        .get(..SALT_LEN)
+        .map(|salt| salt.try_into().unwrap())
+        .ok_or(Error::Truncated)
+}
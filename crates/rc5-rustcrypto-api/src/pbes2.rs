@@ -0,0 +1,266 @@
+//! PBES2 ([RFC 8018](https://www.rfc-editor.org/rfc/rfc8018) §6.2) with PBKDF2 and `rc5-CBC-PAD`,
+//! and PKCS#8 `EncryptedPrivateKeyInfo` ([RFC 5958](https://www.rfc-editor.org/rfc/rfc5958) §3) encrypted with it.
+//!
+//! ```text
+//! PBES2-params ::= SEQUENCE {
+//!     keyDerivationFunc AlgorithmIdentifier {{PBES2-KDFs}},        -- PBKDF2
+//!     encryptionScheme AlgorithmIdentifier {{PBES2-Encs}} }        -- rc5-CBC-PAD
+//!
+//! EncryptedPrivateKeyInfo ::= SEQUENCE {
+//!     encryptionAlgorithm AlgorithmIdentifier {{KeyEncryptionAlgorithms}},  -- PBES2
+//!     encryptedData OCTET STRING }
+//! ```
+//!
+//! RC5 takes keys of any length, so the PBKDF2 parameters must carry `keyLength`.
+
+use std::vec::Vec;
+
+use der::asn1::OctetStringRef;
+use der::{
+    Decode, DecodeValue, Encode, EncodeValue, ErrorKind, Header, Length, Reader, Sequence, Tag,
+    Writer,
+};
+use pkcs5::pbes2::{Pbkdf2Params, Pbkdf2Prf, PBES2_OID, PBKDF2_OID};
+use sha1::Sha1;
+use sha2::{Sha224, Sha256, Sha384, Sha512};
+use spki::{AlgorithmIdentifier, AlgorithmIdentifierRef};
+
+use crate::asn1::Rc5CbcParameters;
+use crate::io::{decrypt_to_vec, encrypt_to_vec};
+use crate::rc5::{RC5Var, MAX_KEY_LEN};
+
+/// The largest PBKDF2 iteration count taken, well above current recommendations: the count comes with the
+/// ciphertext, and an untrusted one could otherwise keep [`Parameters::derive_key`] busy for hours.
+pub const MAX_ITERATION_COUNT: u32 = 10_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The DER is malformed, or names algorithms other than PBES2, PBKDF2 and `rc5-CBC-PAD`.
+    Asn1(der::Error),
+    /// The PBKDF2 pseudo-random function is not an HMAC with SHA-1 or SHA-2.
+    UnsupportedPrf,
+    /// The PBKDF2 parameters carry no `keyLength`, or one longer than RC5 accepts.
+    InvalidKeyLength,
+    /// The PBKDF2 iteration count is 0 or above [`MAX_ITERATION_COUNT`].
+    InvalidIterationCount,
+    /// The ciphertext ends in the middle of a block.
+    Truncated,
+    /// The padding of the last block is malformed: wrong password or corrupted data.
+    InvalidPadding,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Asn1(error) => write!(f, "{}", error),
+            Self::UnsupportedPrf => write!(f, "unsupported PBKDF2 pseudo-random function"),
+            Self::InvalidKeyLength => write!(f, "invalid PBKDF2 key length"),
+            Self::InvalidIterationCount => write!(f, "invalid PBKDF2 iteration count"),
+            Self::Truncated => write!(f, "ciphertext is truncated"),
+            Self::InvalidPadding => write!(f, "invalid padding"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<der::Error> for Error {
+    fn from(error: der::Error) -> Self {
+        Self::Asn1(error)
+    }
+}
+
+impl From<crate::io::Error> for Error {
+    fn from(error: crate::io::Error) -> Self {
+        match error {
+            crate::io::Error::InvalidPadding => Self::InvalidPadding,
+            _ => Self::Truncated,
+        }
+    }
+}
+
+/// `PBES2-params` with PBKDF2 as the key derivation function and `rc5-CBC-PAD` as the encryption scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Parameters<'a> {
+    pub kdf: Pbkdf2Params<'a>,
+    pub encryption: Rc5CbcParameters<'a>,
+}
+
+impl<'a> Parameters<'a> {
+    pub fn algorithm_identifier(&self) -> AlgorithmIdentifier<Self> {
+        AlgorithmIdentifier {
+            oid: PBES2_OID,
+            parameters: Some(*self),
+        }
+    }
+
+    /// Decodes the parameters of a PBES2 algorithm identifier.
+    pub fn from_algorithm_identifier(algorithm: &AlgorithmIdentifierRef<'a>) -> der::Result<Self> {
+        if algorithm.oid != PBES2_OID {
+            return Err(ErrorKind::OidUnknown { oid: algorithm.oid }.into());
+        }
+        algorithm
+            .parameters
+            .ok_or(ErrorKind::Length { tag: Tag::Sequence })?
+            .decode_as()
+    }
+
+    /// Derives the RC5 key from `password` with PBKDF2.
+    pub fn derive_key(&self, password: &[u8]) -> Result<Vec<u8>, Error> {
+        let key_len = match self.kdf.key_length {
+            Some(len) if (1..=MAX_KEY_LEN).contains(&(len as usize)) => len as usize,
+            _ => return Err(Error::InvalidKeyLength),
+        };
+        if !(1..=MAX_ITERATION_COUNT).contains(&self.kdf.iteration_count) {
+            return Err(Error::InvalidIterationCount);
+        }
+
+        let pbkdf2 = match self.kdf.prf {
+            Pbkdf2Prf::HmacWithSha1 => pbkdf2::pbkdf2_hmac::<Sha1>,
+            Pbkdf2Prf::HmacWithSha224 => pbkdf2::pbkdf2_hmac::<Sha224>,
+            Pbkdf2Prf::HmacWithSha256 => pbkdf2::pbkdf2_hmac::<Sha256>,
+            Pbkdf2Prf::HmacWithSha384 => pbkdf2::pbkdf2_hmac::<Sha384>,
+            Pbkdf2Prf::HmacWithSha512 => pbkdf2::pbkdf2_hmac::<Sha512>,
+            _ => return Err(Error::UnsupportedPrf),
+        };
+
+        let mut key = vec![0; key_len];
+        pbkdf2(password, self.kdf.salt, self.kdf.iteration_count, &mut key);
+
+        Ok(key)
+    }
+
+    /// Encrypts with RC5-CBC-Pad at the block size, rounds and IV of [`Parameters::encryption`].
+    pub fn encrypt(&self, password: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, Error> {
+        let key = self.derive_key(password)?;
+        let ciphertext = match self.encryption.block_size_in_bits() {
+            64 => encrypt_to_vec(self.cbc_pad::<RC5Var<u32>>(&key)?, plaintext),
+            _ => encrypt_to_vec(self.cbc_pad::<RC5Var<u64>>(&key)?, plaintext),
+        };
+
+        Ok(ciphertext?)
+    }
+
+    pub fn decrypt(&self, password: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, Error> {
+        let key = self.derive_key(password)?;
+        let plaintext = match self.encryption.block_size_in_bits() {
+            64 => decrypt_to_vec(self.cbc_pad::<RC5Var<u32>>(&key)?, ciphertext),
+            _ => decrypt_to_vec(self.cbc_pad::<RC5Var<u64>>(&key)?, ciphertext),
+        };
+
+        Ok(plaintext?)
+    }
+
+    fn cbc_pad<C>(&self, key: &[u8]) -> Result<crate::io::Cbc<C>, Error>
+    where
+        C: crate::asn1::FromParameters + cipher::BlockCipher,
+    {
+        self.encryption
+            .cbc_pad::<C>(key)
+            .map_err(|_| Error::InvalidKeyLength)
+    }
+
+    fn kdf_algorithm(&self) -> AlgorithmIdentifier<Pbkdf2Params<'a>> {
+        AlgorithmIdentifier {
+            oid: PBKDF2_OID,
+            parameters: Some(self.kdf),
+        }
+    }
+}
+
+impl<'a> DecodeValue<'a> for Parameters<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
+        reader.read_nested(header.length, |reader| {
+            let kdf = AlgorithmIdentifierRef::decode(reader)?;
+            if kdf.oid != PBKDF2_OID {
+                return Err(ErrorKind::OidUnknown { oid: kdf.oid }.into());
+            }
+            let kdf: Pbkdf2Params = kdf
+                .parameters
+                .ok_or(ErrorKind::Length { tag: Tag::Sequence })?
+                .decode_as()?;
+            if !(1..=MAX_ITERATION_COUNT).contains(&kdf.iteration_count) {
+                return Err(ErrorKind::Value { tag: Tag::Integer }.into());
+            }
+
+            let encryption = AlgorithmIdentifierRef::decode(reader)?;
+            let encryption = Rc5CbcParameters::from_algorithm_identifier(&encryption)?;
+
+            Ok(Self { kdf, encryption })
+        })
+    }
+}
+
+impl EncodeValue for Parameters<'_> {
+    fn value_len(&self) -> der::Result<Length> {
+        self.kdf_algorithm().encoded_len()?
+            + self.encryption.algorithm_identifier().encoded_len()?
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
+        self.kdf_algorithm().encode(writer)?;
+        self.encryption.algorithm_identifier().encode(writer)
+    }
+}
+
+impl<'a> Sequence<'a> for Parameters<'a> {}
+
+/// A PKCS#8 `EncryptedPrivateKeyInfo` encrypted with PBES2 and `rc5-CBC-PAD`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncryptedPrivateKeyInfo<'a> {
+    pub encryption_algorithm: Parameters<'a>,
+    pub encrypted_data: &'a [u8],
+}
+
+impl EncryptedPrivateKeyInfo<'_> {
+    /// Decrypts the DER of the `PrivateKeyInfo` (`OneAsymmetricKey`).
+    pub fn decrypt(&self, password: &[u8]) -> Result<Vec<u8>, Error> {
+        self.encryption_algorithm
+            .decrypt(password, self.encrypted_data)
+    }
+
+    /// Encrypts the DER of a `PrivateKeyInfo` and returns the DER of the `EncryptedPrivateKeyInfo`.
+    pub fn encrypt(
+        params: Parameters,
+        password: &[u8],
+        private_key_info: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        let encrypted_data = params.encrypt(password, private_key_info)?;
+        let info = EncryptedPrivateKeyInfo {
+            encryption_algorithm: params,
+            encrypted_data: &encrypted_data,
+        };
+
+        Ok(info.to_der()?)
+    }
+}
+
+impl<'a> DecodeValue<'a> for EncryptedPrivateKeyInfo<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
+        reader.read_nested(header.length, |reader| {
+            let algorithm = AlgorithmIdentifierRef::decode(reader)?;
+            Ok(Self {
+                encryption_algorithm: Parameters::from_algorithm_identifier(&algorithm)?,
+                encrypted_data: OctetStringRef::decode(reader)?.as_bytes(),
+            })
+        })
+    }
+}
+
+impl EncodeValue for EncryptedPrivateKeyInfo<'_> {
+    fn value_len(&self) -> der::Result<Length> {
+        self.encryption_algorithm
+            .algorithm_identifier()
+            .encoded_len()?
+            + OctetStringRef::new(self.encrypted_data)?.encoded_len()?
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
+        self.encryption_algorithm
+            .algorithm_identifier()
+            .encode(writer)?;
+        OctetStringRef::new(self.encrypted_data)?.encode(writer)
+    }
+}
+
+impl<'a> Sequence<'a> for EncryptedPrivateKeyInfo<'a> {}
@@ -7,6 +7,9 @@ use rc5_core::traits::{KeyLTableSize, KeySTable, KeySTableSize, WordSize};
 use typenum::{Diff, Max, Quot, Sum};
 
 mod cipher_traits;
+mod var;
+
+pub use var::{RC5Var, MAX_KEY_LEN, MAX_ROUNDS};
 
 pub struct RC5<Word, R, B, ByteRepr = LittleEndian, Arith = StdArith, Magic = StdMagic>
 where
@@ -26,3 +29,26 @@ where
     s_table: KeySTable<R, Word>,
     _pd: PhantomData<(B, ByteRepr, Arith, Magic)>,
 }
+
+impl<Word, R, B, ByteRepr, Arith, Magic> Clone for RC5<Word, R, B, ByteRepr, Arith, Magic>
+where
+    KeyLTableSize<B, Word::ByteLen>: ArrayLength<Word>,
+    B: ArrayLength<u8>,
+    Word: WordSize + Clone,
+
+    B: Add<Word::ByteLen>,
+    Sum<B, Word::ByteLen>: Sub<typenum::U1>,
+    Diff<Sum<B, Word::ByteLen>, typenum::U1>: Div<Word::ByteLen>,
+    Quot<Diff<Sum<B, Word::ByteLen>, typenum::U1>, Word::ByteLen>: Max<typenum::U1>,
+
+    KeySTableSize<R>: ArrayLength<Word>,
+    Sum<R, typenum::U1>: Mul<typenum::U2>,
+    R: Add<typenum::U1>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            s_table: self.s_table.clone(),
+            _pd: Default::default(),
+        }
+    }
+}
@@ -165,7 +165,7 @@ where
 
 struct Backend<T, F>(T, F);
 
-impl<'a, Word, R, B, BR, A, M, F> BlockSizeUser for Backend<&'a RC5<Word, R, B, BR, A, M>, F>
+impl<Word, R, B, BR, A, M, F> BlockSizeUser for Backend<&RC5<Word, R, B, BR, A, M>, F>
 where
     KeyLTableSize<B, Word::ByteLen>: ArrayLength<Word>,
     B: ArrayLength<u8>,
@@ -186,7 +186,7 @@ where
     type BlockSize = <RC5<Word, R, B> as BlockSizeUser>::BlockSize;
 }
 
-impl<'a, Word, R, B, BR, A, M, F> ParBlocksSizeUser for Backend<&'a RC5<Word, R, B, BR, A, M>, F>
+impl<Word, R, B, BR, A, M, F> ParBlocksSizeUser for Backend<&RC5<Word, R, B, BR, A, M>, F>
 where
     KeyLTableSize<B, Word::ByteLen>: ArrayLength<Word>,
     B: ArrayLength<u8>,
@@ -207,7 +207,7 @@ where
     type ParBlocksSize = typenum::U1;
 }
 
-impl<'a, Word, R, B, BR, A, M, F> BlockBackend for Backend<&'a RC5<Word, R, B, BR, A, M>, F>
+impl<Word, R, B, BR, A, M, F> BlockBackend for Backend<&RC5<Word, R, B, BR, A, M>, F>
 where
     KeyLTableSize<B, Word::ByteLen>: ArrayLength<Word>,
     B: ArrayLength<u8>,
@@ -0,0 +1,215 @@
+use core::marker::PhantomData;
+use core::ops::Mul;
+
+use cipher::{
+    BlockBackend, BlockCipher, BlockDecrypt, BlockEncrypt, BlockSizeUser, InvalidLength,
+    ParBlocksSizeUser,
+};
+use generic_array::ArrayLength;
+use rc5_core::std_words::{LittleEndian, StdArith, StdMagic};
+use rc5_core::traits::{Arithmetics, BlockSize, Magic, WordByteRepr, WordBytes, WordSize};
+use typenum::{Prod, Unsigned};
+
+/// The largest key RC5 accepts, in bytes.
+pub const MAX_KEY_LEN: usize = 255;
+
+/// The largest number of rounds RC5 accepts.
+pub const MAX_ROUNDS: usize = 255;
+
+const MAX_S_TABLE_LEN: usize = 2 * (MAX_ROUNDS + 1);
+const MAX_L_TABLE_LEN: usize = MAX_KEY_LEN;
+
+/// RC5 with the number of rounds and the key length chosen at runtime, e.g. from a command line.
+///
+/// Only the word size is a type parameter; [`RC5`](super::RC5) should be preferred when `r` and `b` are known.
+pub struct RC5Var<Word, ByteRepr = LittleEndian, Arith = StdArith, Magic = StdMagic> {
+    s_table: [Word; MAX_S_TABLE_LEN],
+    rounds: usize,
+    _pd: PhantomData<(ByteRepr, Arith, Magic)>,
+}
+
+impl<Word: Copy, BR, A, M> Clone for RC5Var<Word, BR, A, M> {
+    fn clone(&self) -> Self {
+        Self {
+            s_table: self.s_table,
+            rounds: self.rounds,
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<Word, BR, A, M> RC5Var<Word, BR, A, M>
+where
+    Word: WordSize + Default + Copy,
+    BR: WordByteRepr<Word>,
+    A: Arithmetics<Word>,
+    M: Magic<Word>,
+{
+    /// Expands a key of up to [`MAX_KEY_LEN`] bytes for `rounds` rounds.
+    ///
+    /// The cipher is returned by value, so moving it, e.g. into a `Box`, may leave copies of the expanded key
+    /// behind: see [`RC5Var::set_key`].
+    pub fn new(key: &[u8], rounds: u8) -> Result<Self, InvalidLength> {
+        let mut cipher = Self {
+            s_table: [Word::default(); MAX_S_TABLE_LEN],
+            rounds: 0,
+            _pd: PhantomData,
+        };
+        cipher.set_key(key, rounds)?;
+
+        Ok(cipher)
+    }
+
+    /// Expands a key of up to [`MAX_KEY_LEN`] bytes for `rounds` rounds in place of the current one, so that a
+    /// cipher that stays put, e.g. in a `Box`, holds the only copy of the expanded key.
+    ///
+    /// With the `zeroize` feature, the L table the key is loaded into is overwritten with zeroes before returning.
+    pub fn set_key(&mut self, key: &[u8], rounds: u8) -> Result<(), InvalidLength> {
+        if key.len() > MAX_KEY_LEN {
+            return Err(InvalidLength);
+        }
+
+        let rounds = rounds as usize;
+        let mut l_table = [Word::default(); MAX_L_TABLE_LEN];
+        let l_table_len = key.len().div_ceil(Word::ByteLen::USIZE).max(1);
+        let (s_table, unused) = self.s_table.split_at_mut(2 * (rounds + 1));
+
+        rc5_core::algs::expand_key_slices::<Word, M, BR, A>(
+            key,
+            &mut l_table[..l_table_len],
+            s_table,
+        );
+        unused.fill(Word::default());
+        self.rounds = rounds;
+
+        #[cfg(feature = "zeroize")]
+        wipe(&mut l_table[..l_table_len]);
+
+        Ok(())
+    }
+}
+
+/// Overwrites `words` with their default, zero, in a way the compiler cannot remove as dead stores, as
+/// `zeroize` does for the words that implement it.
+#[cfg(feature = "zeroize")]
+fn wipe<Word: Default + Copy>(words: &mut [Word]) {
+    for word in words {
+        // SAFETY: `word` is a valid, aligned and exclusive reference, and a `Copy` value needs no drop.
+        unsafe { core::ptr::write_volatile(word, Word::default()) };
+    }
+    core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+}
+
+impl<Word, BR, A, M> RC5Var<Word, BR, A, M> {
+    pub fn rounds(&self) -> usize {
+        self.rounds
+    }
+
+    fn s_table(&self) -> &[Word] {
+        &self.s_table[..2 * (self.rounds + 1)]
+    }
+}
+
+/// Overwrites the expanded key with zeroes.
+#[cfg(feature = "zeroize")]
+impl<Word: zeroize::Zeroize, BR, A, M> zeroize::Zeroize for RC5Var<Word, BR, A, M> {
+    fn zeroize(&mut self) {
+        self.s_table.zeroize();
+    }
+}
+
+impl<Word, BR, A, M> BlockSizeUser for RC5Var<Word, BR, A, M>
+where
+    Word: WordSize,
+    Word::ByteLen: Mul<typenum::U2>,
+    Prod<Word::ByteLen, typenum::U2>: ArrayLength<u8>,
+{
+    type BlockSize = BlockSize<Word>;
+}
+
+impl<Word, BR, A, M> BlockCipher for RC5Var<Word, BR, A, M>
+where
+    Word: WordSize,
+    Word::ByteLen: Mul<typenum::U2>,
+    Prod<Word::ByteLen, typenum::U2>: ArrayLength<u8>,
+{
+}
+
+impl<Word, BR, A, M> BlockEncrypt for RC5Var<Word, BR, A, M>
+where
+    Word: WordSize,
+    Word::ByteLen: Mul<typenum::U2>,
+    Prod<Word::ByteLen, typenum::U2>: ArrayLength<u8>,
+    BR: WordByteRepr<Word>,
+    A: Arithmetics<Word>,
+{
+    fn encrypt_with_backend(&self, f: impl cipher::BlockClosure<BlockSize = Self::BlockSize>) {
+        f.call(&mut Backend::<_, BR, _>(
+            self.s_table(),
+            rc5_core::algs::encrypt_slice::<Word, A>,
+            PhantomData,
+        ))
+    }
+}
+
+impl<Word, BR, A, M> BlockDecrypt for RC5Var<Word, BR, A, M>
+where
+    Word: WordSize,
+    Word::ByteLen: Mul<typenum::U2>,
+    Prod<Word::ByteLen, typenum::U2>: ArrayLength<u8>,
+    BR: WordByteRepr<Word>,
+    A: Arithmetics<Word>,
+{
+    fn decrypt_with_backend(&self, f: impl cipher::BlockClosure<BlockSize = Self::BlockSize>) {
+        f.call(&mut Backend::<_, BR, _>(
+            self.s_table(),
+            rc5_core::algs::decrypt_slice::<Word, A>,
+            PhantomData,
+        ))
+    }
+}
+
+struct Backend<'s, Word, BR, F>(&'s [Word], F, PhantomData<BR>);
+
+impl<Word, BR, F> BlockSizeUser for Backend<'_, Word, BR, F>
+where
+    Word: WordSize,
+    Word::ByteLen: Mul<typenum::U2>,
+    Prod<Word::ByteLen, typenum::U2>: ArrayLength<u8>,
+{
+    type BlockSize = BlockSize<Word>;
+}
+
+impl<Word, BR, F> ParBlocksSizeUser for Backend<'_, Word, BR, F>
+where
+    Word: WordSize,
+    Word::ByteLen: Mul<typenum::U2>,
+    Prod<Word::ByteLen, typenum::U2>: ArrayLength<u8>,
+{
+    type ParBlocksSize = typenum::U1;
+}
+
+impl<Word, BR, F> BlockBackend for Backend<'_, Word, BR, F>
+where
+    Word: WordSize,
+    Word::ByteLen: Mul<typenum::U2>,
+    Prod<Word::ByteLen, typenum::U2>: ArrayLength<u8>,
+    BR: WordByteRepr<Word>,
+    F: Fn(&[Word], &mut Word, &mut Word),
+{
+    fn proc_block(&mut self, mut io: cipher::inout::InOut<'_, '_, cipher::Block<Self>>) {
+        let block = io.get_in();
+        let mut reg_a = BR::from_bytes(WordBytes::<Word>::from_slice(
+            &block[..Word::ByteLen::USIZE],
+        ));
+        let mut reg_b = BR::from_bytes(WordBytes::<Word>::from_slice(
+            &block[Word::ByteLen::USIZE..],
+        ));
+
+        (self.1)(self.0, &mut reg_a, &mut reg_b);
+
+        let block = io.get_out();
+        block[..Word::ByteLen::USIZE].copy_from_slice(BR::to_bytes(&reg_a).as_ref());
+        block[Word::ByteLen::USIZE..].copy_from_slice(BR::to_bytes(&reg_b).as_ref());
+    }
+}
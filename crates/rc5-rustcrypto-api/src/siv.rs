@@ -0,0 +1,287 @@
+//! SIV mode ([RFC 5297](https://www.rfc-editor.org/rfc/rfc5297)): S2V over CMAC for the synthetic IV,
+//! CTR for the payload.
+//!
+//! The RFC only defines SIV for 128-bit blocks. For 64-bit blocks (e.g. RC5-32) the same construction is
+//! used with CMAC-64, and the counter is the whole block with bits 31 and 63 cleared,
+//! just as the RFC clears them in the rightmost 64 bits of a 128-bit block.
+
+use core::marker::PhantomData;
+use core::ops::Mul;
+
+use aead::{AeadCore, AeadInPlace, Buffer, Error, Nonce, Tag};
+use cipher::{
+    Block, BlockCipher, BlockEncrypt, BlockSizeUser, InnerIvInit, Key, KeyInit, KeySizeUser,
+    StreamCipher,
+};
+use cmac::digest::crypto_common::InnerInit;
+use cmac::{Cmac, CmacCore, Mac};
+use ctr::flavors::{Ctr64BE, CtrFlavor};
+use ctr::CtrCore;
+use dbl::Dbl;
+use generic_array::{ArrayLength, GenericArray};
+use subtle::ConstantTimeEq;
+use typenum::{IsLess, Le, NonZero, Prod, Unsigned, U256};
+
+type Ctr<C> = cipher::StreamCipherCoreWrapper<CtrCore<C, Ctr64BE>>;
+
+/// Deterministic authenticated encryption with any number of associated-data components.
+///
+/// The key is twice the size of the cipher's key: the left half keys S2V, the right half keys CTR.
+pub struct Siv<C> {
+    mac: C,
+    ctr: C,
+}
+
+/// [`Siv`] exposed through [`aead`]: the nonce is passed to S2V as the last associated-data component.
+///
+/// Repeating a nonce only reveals whether the same (associated data, plaintext) pair was encrypted twice.
+pub struct SivAead<C, NonceSize = typenum::U16> {
+    siv: Siv<C>,
+    _pd: PhantomData<NonceSize>,
+}
+
+impl<C> KeySizeUser for Siv<C>
+where
+    C: KeySizeUser,
+    C::KeySize: Mul<typenum::U2>,
+    Prod<C::KeySize, typenum::U2>: ArrayLength<u8>,
+{
+    type KeySize = Prod<C::KeySize, typenum::U2>;
+}
+
+impl<C> KeyInit for Siv<C>
+where
+    C: KeyInit,
+    C::KeySize: Mul<typenum::U2>,
+    Prod<C::KeySize, typenum::U2>: ArrayLength<u8>,
+{
+    fn new(key: &Key<Self>) -> Self {
+        let (mac_key, ctr_key) = key.split_at(C::KeySize::USIZE);
+        Self {
+            mac: C::new(Key::<C>::from_slice(mac_key)),
+            ctr: C::new(Key::<C>::from_slice(ctr_key)),
+        }
+    }
+}
+
+impl<C> Siv<C>
+where
+    C: BlockCipher + BlockEncrypt + Clone,
+    Block<C>: Dbl,
+    Ctr64BE: CtrFlavor<C::BlockSize>,
+    C::BlockSize: IsLess<U256>,
+    Le<C::BlockSize, U256>: NonZero,
+{
+    /// Encrypts `buffer` in place and returns the synthetic IV, which doubles as the tag.
+    pub fn encrypt_in_place_detached<I, T>(
+        &self,
+        headers: I,
+        buffer: &mut [u8],
+    ) -> Result<Block<C>, Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<[u8]>,
+    {
+        let siv = self.s2v(headers, buffer)?;
+        self.apply_keystream(&siv, buffer);
+        Ok(siv)
+    }
+
+    /// Decrypts `buffer` in place. On a tag mismatch, or more than [`Self::MAX_HEADERS`] headers, the buffer is
+    /// re-encrypted and an error is returned.
+    pub fn decrypt_in_place_detached<I, T>(
+        &self,
+        headers: I,
+        buffer: &mut [u8],
+        siv: &Block<C>,
+    ) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<[u8]>,
+    {
+        self.apply_keystream(siv, buffer);
+        let authentic = self
+            .s2v(headers, buffer)
+            .is_ok_and(|expected| bool::from(expected.ct_eq(siv)));
+
+        if authentic {
+            Ok(())
+        } else {
+            self.apply_keystream(siv, buffer);
+            Err(Error)
+        }
+    }
+
+    /// Encrypts the buffer in place, producing `SIV || ciphertext`.
+    pub fn encrypt_in_place<I, T>(&self, headers: I, buffer: &mut dyn Buffer) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<[u8]>,
+    {
+        let len = buffer.len();
+        let siv = self.encrypt_in_place_detached(headers, buffer.as_mut())?;
+
+        buffer.extend_from_slice(siv.as_slice())?;
+        buffer.as_mut().copy_within(..len, C::BlockSize::USIZE);
+        buffer.as_mut()[..C::BlockSize::USIZE].copy_from_slice(siv.as_slice());
+
+        Ok(())
+    }
+
+    /// Decrypts `SIV || ciphertext` in place, leaving only the plaintext in the buffer.
+    pub fn decrypt_in_place<I, T>(&self, headers: I, buffer: &mut dyn Buffer) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<[u8]>,
+    {
+        if buffer.len() < C::BlockSize::USIZE {
+            return Err(Error);
+        }
+
+        let (siv, payload) = buffer.as_mut().split_at_mut(C::BlockSize::USIZE);
+        let siv = Block::<C>::clone_from_slice(siv);
+        self.decrypt_in_place_detached(headers, payload, &siv)?;
+
+        let len = buffer.len() - C::BlockSize::USIZE;
+        buffer.as_mut().copy_within(C::BlockSize::USIZE.., 0);
+        buffer.truncate(len);
+
+        Ok(())
+    }
+
+    fn s2v<I, T>(&self, headers: I, message: &[u8]) -> Result<Block<C>, Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<[u8]>,
+    {
+        let mut mac = Cmac::<C>::from_core(CmacCore::inner_init(self.mac.clone()));
+
+        mac.update(&Block::<C>::default());
+        let mut d = mac.finalize_reset().into_bytes();
+
+        for (idx, header) in headers.into_iter().enumerate() {
+            if idx >= Self::MAX_HEADERS {
+                return Err(Error);
+            }
+
+            mac.update(header.as_ref());
+            d = xor(d.dbl(), &mac.finalize_reset().into_bytes());
+        }
+
+        if let Some(split_at) = message.len().checked_sub(C::BlockSize::USIZE) {
+            let (head, tail) = message.split_at(split_at);
+            mac.update(head);
+            mac.update(&xor(d, Block::<C>::from_slice(tail)));
+        } else {
+            let mut padded = Block::<C>::default();
+            padded[..message.len()].copy_from_slice(message);
+            padded[message.len()] = 0x80;
+            mac.update(&xor(d.dbl(), &padded));
+        }
+
+        Ok(mac.finalize().into_bytes())
+    }
+
+    fn apply_keystream(&self, siv: &Block<C>, buffer: &mut [u8]) {
+        let mut iv = siv.clone();
+        let len = iv.len();
+        iv[len - 8] &= 0x7f;
+        iv[len - 4] &= 0x7f;
+
+        Ctr::<C>::from_core(CtrCore::inner_iv_init(self.ctr.clone(), &iv)).apply_keystream(buffer);
+    }
+}
+
+impl<C: BlockSizeUser> Siv<C> {
+    /// The most associated-data components S2V takes: with the plaintext, one fewer than the bits in a block,
+    /// i.e. the 126 of RFC 5297 for 128-bit blocks and 62 for 64-bit ones.
+    pub const MAX_HEADERS: usize = 8 * C::BlockSize::USIZE - 2;
+}
+
+impl<C, NonceSize> KeySizeUser for SivAead<C, NonceSize>
+where
+    Siv<C>: KeySizeUser,
+{
+    type KeySize = <Siv<C> as KeySizeUser>::KeySize;
+}
+
+impl<C, NonceSize> KeyInit for SivAead<C, NonceSize>
+where
+    Siv<C>: KeyInit,
+{
+    fn new(key: &Key<Self>) -> Self {
+        Self {
+            siv: Siv::new(key),
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<C, NonceSize> AeadCore for SivAead<C, NonceSize>
+where
+    C: BlockSizeUser,
+    NonceSize: ArrayLength<u8>,
+{
+    type NonceSize = NonceSize;
+    type TagSize = C::BlockSize;
+    type CiphertextOverhead = typenum::U0;
+}
+
+impl<C, NonceSize> AeadInPlace for SivAead<C, NonceSize>
+where
+    C: BlockCipher + BlockEncrypt + Clone,
+    Block<C>: Dbl,
+    Ctr64BE: CtrFlavor<C::BlockSize>,
+    C::BlockSize: IsLess<U256>,
+    Le<C::BlockSize, U256>: NonZero,
+    NonceSize: ArrayLength<u8>,
+{
+    fn encrypt_in_place(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        self.siv
+            .encrypt_in_place([associated_data, nonce.as_slice()], buffer)
+    }
+
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self>, Error> {
+        self.siv
+            .encrypt_in_place_detached([associated_data, nonce.as_slice()], buffer)
+    }
+
+    fn decrypt_in_place(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<(), Error> {
+        self.siv
+            .decrypt_in_place([associated_data, nonce.as_slice()], buffer)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self>,
+    ) -> Result<(), Error> {
+        self.siv
+            .decrypt_in_place_detached([associated_data, nonce.as_slice()], buffer, tag)
+    }
+}
+
+fn xor<N: ArrayLength<u8>>(
+    mut l: GenericArray<u8, N>,
+    r: &GenericArray<u8, N>,
+) -> GenericArray<u8, N> {
+    l.iter_mut().zip(r).for_each(|(l, r)| *l ^= r);
+    l
+}
@@ -1,4 +1,25 @@
+mod asn1;
+mod ccm;
+#[cfg(feature = "std")]
+mod cms;
 mod common;
+#[cfg(feature = "std")]
+mod container;
+mod eax;
+#[cfg(feature = "std")]
+mod esp;
+#[cfg(feature = "std")]
+mod io;
 mod krovetz18;
+mod kw;
 mod nessie;
+#[cfg(feature = "std")]
+mod openssl;
+#[cfg(feature = "std")]
+mod pbes2;
 mod rivest97;
+mod siv;
+mod var;
+#[cfg(feature = "std")]
+mod wycheproof;
+mod xts;
@@ -0,0 +1,188 @@
+use cipher::{BlockEncrypt, KeyInit};
+use der::{Decode, Encode};
+use generic_array::GenericArray;
+use spki::AlgorithmIdentifierRef;
+
+use crate::asn1::{Error, Rc5CbcParameters, RC5_CBC_PAD};
+use crate::rc5::RC5Var;
+use crate::rivest97::{RC5_32_12_16, RC5_64_16_16};
+
+const IV: [u8; 8] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+
+fn der(params: &Rc5CbcParameters) -> std::vec::Vec<u8> {
+    let mut buf = [0u8; 64];
+    params.encode_to_slice(&mut buf).unwrap().to_vec()
+}
+
+#[test]
+fn encoding() {
+    let params = Rc5CbcParameters::new(12, 64, Some(&IV)).unwrap();
+    assert_eq!(
+        hex::encode(der(&params)),
+        "301302011002010c0201400408 0102030405060708".replace(' ', "")
+    );
+
+    let params = Rc5CbcParameters::new(16, 128, None).unwrap();
+    assert_eq!(hex::encode(der(&params)), "300a02011002011002020080");
+
+    let mut buf = [0u8; 64];
+    let algorithm = Rc5CbcParameters::new(12, 64, Some(&IV))
+        .unwrap()
+        .algorithm_identifier();
+    assert_eq!(
+        hex::encode(algorithm.encode_to_slice(&mut buf).unwrap()),
+        "301f06082a864886f70d0309301302011002010c0201400408 0102030405060708".replace(' ', "")
+    );
+}
+
+#[test]
+fn round_trip() {
+    let iv16 = [0xa5; 16];
+    for params in [
+        Rc5CbcParameters::new(8, 64, None).unwrap(),
+        Rc5CbcParameters::new(12, 64, Some(&IV)).unwrap(),
+        Rc5CbcParameters::new(127, 128, Some(&iv16)).unwrap(),
+    ] {
+        let bytes = der(&params);
+        assert_eq!(Rc5CbcParameters::from_der(&bytes).unwrap(), params);
+
+        let mut buf = [0u8; 64];
+        let algorithm = params.algorithm_identifier();
+        let encoded = algorithm.encode_to_slice(&mut buf).unwrap();
+        let decoded = AlgorithmIdentifierRef::from_der(encoded).unwrap();
+        assert_eq!(decoded.oid, RC5_CBC_PAD);
+        assert_eq!(
+            Rc5CbcParameters::from_algorithm_identifier(&decoded).unwrap(),
+            params
+        );
+    }
+}
+
+#[test]
+fn out_of_range() {
+    assert!(Rc5CbcParameters::new(7, 64, None).is_err());
+    assert!(Rc5CbcParameters::new(128, 64, None).is_err());
+    assert!(Rc5CbcParameters::new(12, 32, None).is_err());
+    assert!(Rc5CbcParameters::new(12, 256, None).is_err());
+    assert!(Rc5CbcParameters::new(12, 128, Some(&IV)).is_err());
+}
+
+#[test]
+fn malformed() {
+    // SEQUENCE bodies: version, rounds, blockSizeInBits, iv
+    for (what, body) in [
+        ("version 0", "020100 020110 020140"),
+        ("version 17", "020111 020110 020140"),
+        ("7 rounds", "020110 020107 020140"),
+        ("128 rounds", "020110 02020080 020140"),
+        ("negative rounds", "020110 0201f0 020140"),
+        ("32-bit blocks", "020110 020110 020120"),
+        ("96-bit blocks", "020110 020110 020160"),
+        ("short IV", "020110 020110 020140 0407 01020304050607"),
+        (
+            "IV of a 128-bit block",
+            "020110 020110 020140 0410 000102030405060708090a0b0c0d0e0f",
+        ),
+        (
+            "IV not an OCTET STRING",
+            "020110 020110 020140 0308 0001020304050607",
+        ),
+        ("missing block size", "020110 020110"),
+        (
+            "trailing field",
+            "020110 020110 020140 0408 0102030405060708 020100",
+        ),
+    ] {
+        let body = hex::decode(body.replace(' ', "")).unwrap();
+        let mut bytes = vec![0x30, body.len() as u8];
+        bytes.extend_from_slice(&body);
+        assert!(Rc5CbcParameters::from_der(&bytes).is_err(), "{}", what);
+    }
+
+    let valid = hex::decode("3009020110020110020140").unwrap();
+    assert!(Rc5CbcParameters::from_der(&valid).is_ok());
+    assert!(
+        Rc5CbcParameters::from_der(&valid[..10]).is_err(),
+        "truncated"
+    );
+    let mut set = valid.clone();
+    set[0] = 0x31;
+    assert!(Rc5CbcParameters::from_der(&set).is_err(), "not a SEQUENCE");
+
+    // a DES-CBC algorithm identifier
+    let des = hex::decode("301106052b0e0302070408 0102030405060708".replace(' ', "")).unwrap();
+    let des = AlgorithmIdentifierRef::from_der(&des).unwrap();
+    assert!(Rc5CbcParameters::from_algorithm_identifier(&des).is_err());
+}
+
+#[test]
+fn ciphers() {
+    let key = [0x42; 16];
+    let params = Rc5CbcParameters::new(12, 64, Some(&IV)).unwrap();
+
+    let mut expected = GenericArray::clone_from_slice(&IV);
+    RC5_32_12_16::new(&key.into()).encrypt_block(&mut expected);
+
+    let mut block = GenericArray::clone_from_slice(&IV);
+    params
+        .cipher::<RC5_32_12_16>(&key)
+        .unwrap()
+        .encrypt_block(&mut block);
+    assert_eq!(block, expected);
+
+    let mut block = GenericArray::clone_from_slice(&IV);
+    params
+        .cipher::<RC5Var<u32>>(&key)
+        .unwrap()
+        .encrypt_block(&mut block);
+    assert_eq!(block, expected);
+
+    assert_eq!(
+        params.cipher::<RC5_64_16_16>(&key).err(),
+        Some(Error::ParamsMismatch)
+    );
+    assert_eq!(
+        params.cipher::<RC5Var<u64>>(&key).err(),
+        Some(Error::ParamsMismatch)
+    );
+    assert_eq!(
+        Rc5CbcParameters::new(16, 64, None)
+            .unwrap()
+            .cipher::<RC5_32_12_16>(&key)
+            .err(),
+        Some(Error::ParamsMismatch)
+    );
+    assert_eq!(
+        params.cipher::<RC5_32_12_16>(&key[..8]).err(),
+        Some(Error::InvalidKeyLength)
+    );
+    assert!(params.cipher::<RC5Var<u32>>(&key[..8]).is_ok());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn cbc_pad() {
+    use crate::io::{Cbc, EncryptWriter, Encryptor};
+    use std::io::Write;
+
+    let key = [0x42; 16];
+    fn encrypt<E: Encryptor>(mode: E) -> std::vec::Vec<u8> {
+        let mut writer = EncryptWriter::new(std::vec::Vec::new(), mode);
+        writer.write_all(b"RC5-CBC-Pad").unwrap();
+        writer.finish().unwrap()
+    }
+
+    let params = Rc5CbcParameters::new(12, 64, Some(&IV)).unwrap();
+    let expected = encrypt(Cbc::new(RC5_32_12_16::new(&key.into()), &IV.into()));
+    assert_eq!(
+        encrypt(params.cbc_pad::<RC5_32_12_16>(&key).unwrap()),
+        expected
+    );
+
+    let params = Rc5CbcParameters::new(12, 64, None).unwrap();
+    let expected = encrypt(Cbc::new(RC5_32_12_16::new(&key.into()), &[0; 8].into()));
+    assert_eq!(
+        encrypt(params.cbc_pad::<RC5Var<u32>>(&key).unwrap()),
+        expected
+    );
+}
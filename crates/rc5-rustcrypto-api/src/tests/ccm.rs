@@ -0,0 +1,249 @@
+use aead::{Aead, AeadCore, AeadInPlace, KeyInit, Payload};
+use generic_array::GenericArray;
+use std::vec::Vec;
+use typenum::{Unsigned, U10, U11, U12, U13, U14, U16, U2, U3, U4, U5, U6, U7, U8, U9};
+
+use crate::ccm::Ccm;
+use crate::rivest97::{RC5_32_12_16, RC5_64_16_16};
+
+fn run_rfc3610_case(nonce_hex: &str, packet_hex: &str, header_len: usize, output_hex: &str) {
+    let key = hex::decode("C0C1C2C3C4C5C6C7C8C9CACBCCCDCECF").unwrap();
+    let ccm = Ccm::<aes::Aes128, typenum::U8, typenum::U2>::new_from_slice(&key).unwrap();
+    let nonce = hex::decode(nonce_hex).unwrap();
+    let packet = hex::decode(packet_hex).unwrap();
+
+    let (header, plaintext) = packet.split_at(header_len);
+    let payload = Payload {
+        msg: plaintext,
+        aad: header,
+    };
+    let ciphertext = ccm
+        .encrypt(GenericArray::from_slice(&nonce), payload)
+        .unwrap();
+    assert_eq!(hex::encode_upper(&ciphertext), output_hex);
+
+    let payload = Payload {
+        msg: &ciphertext,
+        aad: header,
+    };
+    assert_eq!(
+        ccm.decrypt(GenericArray::from_slice(&nonce), payload)
+            .unwrap(),
+        plaintext
+    );
+}
+
+#[test]
+fn rfc3610_packet_vector_1() {
+    run_rfc3610_case(
+        "00000003020100A0A1A2A3A4A5",
+        "000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E",
+        8,
+        "588C979A61C663D2F066D0C2C0F989806D5F6B61DAC38417E8D12CFDF926E0",
+    );
+}
+
+#[test]
+fn rfc3610_packet_vector_2() {
+    run_rfc3610_case(
+        "00000004030201A0A1A2A3A4A5",
+        "000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F",
+        8,
+        "72C91A36E135F8CF291CA894085C87E3CC15C439C9E43A3BA091D56E10400916",
+    );
+}
+
+const LENGTHS: &[usize] = &[0, 1, 7, 8, 9, 15, 16, 17, 33, 300];
+
+/// Round trip and tamper checks for a CCM instance over any cipher.
+fn exercise<A>(aead: &A)
+where
+    A: AeadInPlace,
+{
+    let nonce = GenericArray::from_iter((0..).take(A::NonceSize::USIZE));
+
+    for &msg_len in LENGTHS {
+        for &ad_len in LENGTHS {
+            let plaintext = (0..msg_len).map(|i| i as u8).collect::<Vec<_>>();
+            let ad = (0..ad_len).map(|i| !i as u8).collect::<Vec<_>>();
+
+            let mut buffer = plaintext.clone();
+            let tag = aead
+                .encrypt_in_place_detached(&nonce, &ad, &mut buffer)
+                .unwrap();
+            assert_eq!(tag.len(), A::TagSize::USIZE);
+
+            let mut tampered_tag = tag.clone();
+            tampered_tag[A::TagSize::USIZE - 1] ^= 1;
+            let mut copy = buffer.clone();
+            assert!(aead
+                .decrypt_in_place_detached(&nonce, &ad, &mut copy, &tampered_tag)
+                .is_err());
+            assert_eq!(copy, buffer);
+
+            if msg_len > 0 {
+                let mut tampered = buffer.clone();
+                tampered[msg_len / 2] ^= 0x80;
+                assert!(aead
+                    .decrypt_in_place_detached(&nonce, &ad, &mut tampered, &tag)
+                    .is_err());
+            }
+            if ad_len > 0 {
+                let mut tampered_ad = ad.clone();
+                tampered_ad[0] ^= 1;
+                let mut copy = buffer.clone();
+                assert!(aead
+                    .decrypt_in_place_detached(&nonce, &tampered_ad, &mut copy, &tag)
+                    .is_err());
+            }
+
+            let mut other_nonce = nonce.clone();
+            other_nonce[0] ^= 1;
+            let mut copy = buffer.clone();
+            assert!(aead
+                .decrypt_in_place_detached(&other_nonce, &ad, &mut copy, &tag)
+                .is_err());
+
+            aead.decrypt_in_place_detached(&nonce, &ad, &mut buffer, &tag)
+                .unwrap();
+            assert_eq!(buffer, plaintext);
+        }
+    }
+}
+
+/// Messages must fit into the `L`-byte length field.
+fn check_length_limit<A: AeadInPlace>(aead: &A, l: usize) {
+    if l > 2 {
+        return;
+    }
+
+    let nonce = Default::default();
+    let mut at_limit = vec![0u8; (1 << (8 * l)) - 1];
+    assert!(aead
+        .encrypt_in_place_detached(&nonce, b"", &mut at_limit)
+        .is_ok());
+
+    let mut over_limit = vec![0u8; 1 << (8 * l)];
+    assert!(aead
+        .encrypt_in_place_detached(&nonce, b"", &mut over_limit)
+        .is_err());
+
+    // long enough for the counter of its last block to overflow the length field too
+    for len in [1 << (8 * l), 16 << (8 * l)] {
+        let mut over_limit = vec![0x5a; len];
+        assert!(aead
+            .decrypt_in_place_detached(&nonce, b"", &mut over_limit, &Default::default())
+            .is_err());
+        assert!(over_limit.iter().all(|&b| b == 0x5a), "{} bytes", len);
+    }
+}
+
+macro_rules! ccm_case {
+    ($test_name: ident, $Cipher: ty, $M: ty, $L: ty, $nonce_size: literal) => {
+        #[test]
+        fn $test_name() {
+            type A = Ccm<$Cipher, $M, $L>;
+            assert_eq!(<A as AeadCore>::NonceSize::USIZE, $nonce_size);
+
+            let aead = A::new(&(0..16).collect());
+            exercise(&aead);
+            check_length_limit(&aead, <$L>::USIZE);
+        }
+    };
+}
+
+/// Cross-checks `Ccm` against the `ccm` crate, which implements RFC 3610 for AES.
+macro_rules! ccm_aes_case {
+    ($test_name: ident, $M: ty, $L: ty, $N: ty) => {
+        #[test]
+        fn $test_name() {
+            let key = GenericArray::from_iter(0..16);
+            let ours = Ccm::<aes::Aes128, $M, $L>::new(&key);
+            let reference = ccm::Ccm::<aes::Aes128, $M, $N>::new(&key);
+
+            let nonce = GenericArray::from_iter((0..).take(<$N>::USIZE));
+            for &msg_len in LENGTHS {
+                for &ad_len in LENGTHS {
+                    let payload = || Payload {
+                        msg: &[0x5a; 300][..msg_len],
+                        aad: &[0xa5; 300][..ad_len],
+                    };
+                    assert_eq!(
+                        ours.encrypt(&nonce, payload()).unwrap(),
+                        reference.encrypt(&nonce, payload()).unwrap()
+                    );
+                }
+            }
+        }
+    };
+}
+
+ccm_aes_case!(aes_m4_l2, U4, U2, U13);
+ccm_aes_case!(aes_m6_l3, U6, U3, U12);
+ccm_aes_case!(aes_m8_l4, U8, U4, U11);
+ccm_aes_case!(aes_m10_l5, U10, U5, U10);
+ccm_aes_case!(aes_m12_l6, U12, U6, U9);
+ccm_aes_case!(aes_m14_l7, U14, U7, U8);
+ccm_aes_case!(aes_m16_l8, U16, U8, U7);
+ccm_aes_case!(aes_m16_l2, U16, U2, U13);
+ccm_aes_case!(aes_m4_l8, U4, U8, U7);
+
+ccm_case!(rc5_64_m4_l2, RC5_64_16_16, U4, U2, 13);
+ccm_case!(rc5_64_m4_l3, RC5_64_16_16, U4, U3, 12);
+ccm_case!(rc5_64_m4_l4, RC5_64_16_16, U4, U4, 11);
+ccm_case!(rc5_64_m4_l5, RC5_64_16_16, U4, U5, 10);
+ccm_case!(rc5_64_m4_l6, RC5_64_16_16, U4, U6, 9);
+ccm_case!(rc5_64_m4_l7, RC5_64_16_16, U4, U7, 8);
+ccm_case!(rc5_64_m4_l8, RC5_64_16_16, U4, U8, 7);
+ccm_case!(rc5_64_m6_l2, RC5_64_16_16, U6, U2, 13);
+ccm_case!(rc5_64_m6_l3, RC5_64_16_16, U6, U3, 12);
+ccm_case!(rc5_64_m6_l4, RC5_64_16_16, U6, U4, 11);
+ccm_case!(rc5_64_m6_l5, RC5_64_16_16, U6, U5, 10);
+ccm_case!(rc5_64_m6_l6, RC5_64_16_16, U6, U6, 9);
+ccm_case!(rc5_64_m6_l7, RC5_64_16_16, U6, U7, 8);
+ccm_case!(rc5_64_m6_l8, RC5_64_16_16, U6, U8, 7);
+ccm_case!(rc5_64_m8_l2, RC5_64_16_16, U8, U2, 13);
+ccm_case!(rc5_64_m8_l3, RC5_64_16_16, U8, U3, 12);
+ccm_case!(rc5_64_m8_l4, RC5_64_16_16, U8, U4, 11);
+ccm_case!(rc5_64_m8_l5, RC5_64_16_16, U8, U5, 10);
+ccm_case!(rc5_64_m8_l6, RC5_64_16_16, U8, U6, 9);
+ccm_case!(rc5_64_m8_l7, RC5_64_16_16, U8, U7, 8);
+ccm_case!(rc5_64_m8_l8, RC5_64_16_16, U8, U8, 7);
+ccm_case!(rc5_64_m10_l2, RC5_64_16_16, U10, U2, 13);
+ccm_case!(rc5_64_m10_l3, RC5_64_16_16, U10, U3, 12);
+ccm_case!(rc5_64_m10_l4, RC5_64_16_16, U10, U4, 11);
+ccm_case!(rc5_64_m10_l5, RC5_64_16_16, U10, U5, 10);
+ccm_case!(rc5_64_m10_l6, RC5_64_16_16, U10, U6, 9);
+ccm_case!(rc5_64_m10_l7, RC5_64_16_16, U10, U7, 8);
+ccm_case!(rc5_64_m10_l8, RC5_64_16_16, U10, U8, 7);
+ccm_case!(rc5_64_m12_l2, RC5_64_16_16, U12, U2, 13);
+ccm_case!(rc5_64_m12_l3, RC5_64_16_16, U12, U3, 12);
+ccm_case!(rc5_64_m12_l4, RC5_64_16_16, U12, U4, 11);
+ccm_case!(rc5_64_m12_l5, RC5_64_16_16, U12, U5, 10);
+ccm_case!(rc5_64_m12_l6, RC5_64_16_16, U12, U6, 9);
+ccm_case!(rc5_64_m12_l7, RC5_64_16_16, U12, U7, 8);
+ccm_case!(rc5_64_m12_l8, RC5_64_16_16, U12, U8, 7);
+ccm_case!(rc5_64_m14_l2, RC5_64_16_16, U14, U2, 13);
+ccm_case!(rc5_64_m14_l3, RC5_64_16_16, U14, U3, 12);
+ccm_case!(rc5_64_m14_l4, RC5_64_16_16, U14, U4, 11);
+ccm_case!(rc5_64_m14_l5, RC5_64_16_16, U14, U5, 10);
+ccm_case!(rc5_64_m14_l6, RC5_64_16_16, U14, U6, 9);
+ccm_case!(rc5_64_m14_l7, RC5_64_16_16, U14, U7, 8);
+ccm_case!(rc5_64_m14_l8, RC5_64_16_16, U14, U8, 7);
+ccm_case!(rc5_64_m16_l2, RC5_64_16_16, U16, U2, 13);
+ccm_case!(rc5_64_m16_l3, RC5_64_16_16, U16, U3, 12);
+ccm_case!(rc5_64_m16_l4, RC5_64_16_16, U16, U4, 11);
+ccm_case!(rc5_64_m16_l5, RC5_64_16_16, U16, U5, 10);
+ccm_case!(rc5_64_m16_l6, RC5_64_16_16, U16, U6, 9);
+ccm_case!(rc5_64_m16_l7, RC5_64_16_16, U16, U7, 8);
+ccm_case!(rc5_64_m16_l8, RC5_64_16_16, U16, U8, 7);
+
+ccm_case!(rc5_32_m4_l2, RC5_32_12_16, U4, U2, 5);
+ccm_case!(rc5_32_m4_l3, RC5_32_12_16, U4, U3, 4);
+ccm_case!(rc5_32_m4_l4, RC5_32_12_16, U4, U4, 3);
+ccm_case!(rc5_32_m6_l2, RC5_32_12_16, U6, U2, 5);
+ccm_case!(rc5_32_m6_l3, RC5_32_12_16, U6, U3, 4);
+ccm_case!(rc5_32_m6_l4, RC5_32_12_16, U6, U4, 3);
+ccm_case!(rc5_32_m8_l2, RC5_32_12_16, U8, U2, 5);
+ccm_case!(rc5_32_m8_l3, RC5_32_12_16, U8, U3, 4);
+ccm_case!(rc5_32_m8_l4, RC5_32_12_16, U8, U4, 3);
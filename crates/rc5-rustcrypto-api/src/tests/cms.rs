@@ -0,0 +1,154 @@
+use der::{Decode, Encode, ErrorKind, Tag, Tagged};
+
+use crate::asn1::Rc5CbcParameters;
+use crate::cms::{
+    ber_to_der, EncryptedContentInfo, EncryptedData, EnvelopedData, Error, ID_DATA,
+    ID_ENCRYPTED_DATA,
+};
+
+const MESSAGE: &[u8] = include_bytes!("cms/message.txt");
+
+/// `ContentInfo`s holding `MESSAGE` encrypted with `rc5-CBC-PAD`; see `cms/gen.py`.
+const ENCRYPTED_DATA: &[u8] = include_bytes!("cms/encrypted-data.der");
+const ENCRYPTED_DATA_KEY: [u8; 16] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+];
+const ENCRYPTED_DATA_BER: &[u8] = include_bytes!("cms/encrypted-data.ber");
+const ENVELOPED_DATA: &[u8] = include_bytes!("cms/enveloped-data.der");
+const ENVELOPED_DATA_KEY: [u8; 5] = [0x5e, 0xca, 0x1a, 0xb1, 0xe5];
+
+#[test]
+fn decrypt_encrypted_data() {
+    let data = EncryptedData::from_content_info(ENCRYPTED_DATA).unwrap();
+    let info = &data.encrypted_content_info;
+    assert_eq!(data.version(), 0);
+    assert_eq!(info.content_type, ID_DATA);
+    assert_eq!(info.content_encryption_algorithm.rounds(), 16);
+    assert_eq!(info.content_encryption_algorithm.block_size_in_bits(), 64);
+
+    assert_eq!(data.decrypt(&ENCRYPTED_DATA_KEY).unwrap(), MESSAGE);
+    assert_eq!(data.to_content_info().unwrap(), ENCRYPTED_DATA);
+}
+
+#[test]
+fn decrypt_enveloped_data() {
+    let data = EnvelopedData::from_content_info(ENVELOPED_DATA).unwrap();
+    assert_eq!(data.version, 0);
+    assert!(data.originator_info.is_none());
+    assert_eq!(data.recipient_infos.tag(), Tag::Set);
+    assert!(data.unprotected_attrs.is_none());
+    assert_eq!(
+        data.encrypted_content_info
+            .content_encryption_algorithm
+            .rounds(),
+        12
+    );
+
+    assert_eq!(data.decrypt(&ENVELOPED_DATA_KEY).unwrap(), MESSAGE);
+}
+
+#[test]
+fn decrypt_ber() {
+    assert!(EncryptedData::from_content_info(ENCRYPTED_DATA_BER).is_err());
+    let der = ber_to_der(ENCRYPTED_DATA_BER).unwrap();
+    assert_eq!(der, ENCRYPTED_DATA);
+    let data = EncryptedData::from_content_info(&der).unwrap();
+    assert_eq!(data.decrypt(&ENCRYPTED_DATA_KEY).unwrap(), MESSAGE);
+
+    assert_eq!(ber_to_der(ENCRYPTED_DATA).unwrap(), ENCRYPTED_DATA);
+    assert_eq!(ber_to_der(ENVELOPED_DATA).unwrap(), ENVELOPED_DATA);
+
+    // long-form lengths, and an encrypted content split over nested constructed octet strings
+    let ber = hex::decode(
+        concat!(
+            "3080 06092a864886f70d010706 a080",
+            "308114 020100 30810e a080 2480 040101 0000 040102 0000",
+            "0000 0000",
+        )
+        .replace(' ', ""),
+    )
+    .unwrap();
+    let der = hex::decode(
+        concat!(
+            "3018 06092a864886f70d010706 a00b",
+            "3009 020100 3004 80020102",
+        )
+        .replace(' ', ""),
+    )
+    .unwrap();
+    assert_eq!(ber_to_der(&ber).unwrap(), der);
+
+    for bad in [
+        &ENCRYPTED_DATA_BER[..ENCRYPTED_DATA_BER.len() - 1],
+        &[ENCRYPTED_DATA_BER, &[0]].concat(),
+        &[0x30, 0x80, 0x04, 0x80, 0x00, 0x00, 0x00, 0x00],
+        &[0x30, 0x85, 0xff, 0xff, 0xff, 0xff, 0xff],
+        &[0x30, 0x04, 0x24, 0x02, 0x02, 0x00],
+        &[[0x30, 0x80]; 40].concat(),
+    ] {
+        assert!(ber_to_der(bad).is_err(), "{:02x?}", bad);
+    }
+}
+
+#[test]
+fn encrypt_reproduces_fixture() {
+    let data = EncryptedData::from_content_info(ENCRYPTED_DATA).unwrap();
+    let params = data.encrypted_content_info.content_encryption_algorithm;
+    let encrypted = EncryptedData::encrypt(ID_DATA, &params, &ENCRYPTED_DATA_KEY, MESSAGE).unwrap();
+    assert_eq!(encrypted, ENCRYPTED_DATA);
+}
+
+#[test]
+fn round_trip() {
+    let iv = [0x24; 16];
+    for params in [
+        Rc5CbcParameters::new(12, 64, None).unwrap(),
+        Rc5CbcParameters::new(20, 128, Some(&iv)).unwrap(),
+    ] {
+        for len in [0, 1, 16, 100] {
+            let content = vec![0x33; len];
+            let der = EncryptedData::encrypt(ID_DATA, &params, b"a key", &content).unwrap();
+            let data = EncryptedData::from_content_info(&der).unwrap();
+            assert_eq!(
+                data.encrypted_content_info.content_encryption_algorithm,
+                params
+            );
+            assert_eq!(data.decrypt(b"a key").unwrap(), content);
+        }
+    }
+}
+
+#[test]
+fn detached_content() {
+    let info = EncryptedContentInfo {
+        content_type: ID_DATA,
+        content_encryption_algorithm: Rc5CbcParameters::new(12, 64, None).unwrap(),
+        encrypted_content: None,
+    };
+    let der = info.to_der().unwrap();
+    assert_eq!(EncryptedContentInfo::from_der(&der).unwrap(), info);
+    assert_eq!(
+        info.decrypt(&ENCRYPTED_DATA_KEY),
+        Err(Error::MissingContent)
+    );
+}
+
+#[test]
+fn errors() {
+    let data = EncryptedData::from_content_info(ENCRYPTED_DATA).unwrap();
+    assert_eq!(data.decrypt(&[0; 256]), Err(Error::InvalidKeyLength));
+    assert_ne!(
+        data.decrypt(&ENVELOPED_DATA_KEY).ok().as_deref(),
+        Some(MESSAGE)
+    );
+
+    let mut info = data.encrypted_content_info;
+    let ciphertext = info.encrypted_content.unwrap();
+    info.encrypted_content = Some(&ciphertext[..ciphertext.len() - 1]);
+    assert_eq!(info.decrypt(&ENCRYPTED_DATA_KEY), Err(Error::Truncated));
+
+    let error = EncryptedData::from_content_info(ENVELOPED_DATA).unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::OidUnknown { oid } if oid != ID_ENCRYPTED_DATA));
+    assert!(EnvelopedData::from_content_info(ENCRYPTED_DATA).is_err());
+    assert!(EncryptedData::from_content_info(&ENCRYPTED_DATA[..ENCRYPTED_DATA.len() - 1]).is_err());
+}
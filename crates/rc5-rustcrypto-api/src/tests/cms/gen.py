@@ -0,0 +1,94 @@
+#!/usr/bin/env python3
+"""Writes the CMS fixtures next to this script, each a `ContentInfo` holding `message.txt` encrypted
+with `rc5-CBC-PAD`:
+
+- `encrypted-data.der`: `EncryptedData` with RC5-32/16 and a 16-byte key,
+- `enveloped-data.der`: `EnvelopedData` with RC5-32/12 and a 40-bit key, for one `KeyTransRecipientInfo`
+  whose `encryptedKey` is a placeholder: only the content encryption is under test,
+- `encrypted-data.ber`: `encrypted-data.der` in BER as a streaming encoder writes it, with indefinite lengths,
+  and the IV and the encrypted content split over constructed octet strings.
+
+No OpenSSL at hand has RC5, so RC5 is the C reference of `rc5-reference`, by way of `../rc5ref.py`, and the
+DER helpers are shared with `../pbes2/gen.py`; the results print with `openssl cms -cmsout -print -inform DER`.
+"""
+
+import importlib.util
+import os
+import sys
+
+here = os.path.dirname(os.path.abspath(__file__))
+sys.path.insert(0, os.path.join(here, ".."))
+from rc5ref import cbc_pad  # noqa: E402
+
+spec = importlib.util.spec_from_file_location("pbes2", os.path.join(here, "..", "pbes2", "gen.py"))
+pbes2 = importlib.util.module_from_spec(spec)
+spec.loader.exec_module(pbes2)
+from_pbes2 = ("integer", "null", "octets", "oid", "seq", "tlv", "OID_RC5_CBC_PAD")
+integer, null, octets, oid, seq, tlv, OID_RC5_CBC_PAD = (getattr(pbes2, name) for name in from_pbes2)
+
+ENCRYPTED_DATA_KEY = bytes.fromhex("000102030405060708090a0b0c0d0e0f")
+ENCRYPTED_DATA_IV = bytes.fromhex("a0a1a2a3a4a5a6a7")
+ENVELOPED_DATA_KEY = bytes.fromhex("5eca1ab1e5")
+ENVELOPED_DATA_IV = bytes.fromhex("0011223344556677")
+
+ID_DATA = "1.2.840.113549.1.7.1"
+ID_ENVELOPED_DATA = "1.2.840.113549.1.7.3"
+ID_ENCRYPTED_DATA = "1.2.840.113549.1.7.6"
+ID_CN = "2.5.4.3"
+RSA_ENCRYPTION = "1.2.840.113549.1.1.1"
+
+
+def set_of(*items):
+    return tlv(0x31, b"".join(sorted(items)))
+
+
+def indefinite(tag, *items):
+    return bytes([tag, 0x80]) + b"".join(items) + b"\x00\x00"
+
+
+def segments(value, size):
+    return b"".join(octets(value[i:i + size]) for i in range(0, len(value), size))
+
+
+def encrypted_content_info(rounds, key, iv, content):
+    algorithm = seq(oid(OID_RC5_CBC_PAD), seq(integer(16), integer(rounds), integer(64), octets(iv)))
+    return seq(oid(ID_DATA), algorithm, tlv(0x80, cbc_pad(32, rounds, key, iv, content)))
+
+
+def content_info(content_type, content):
+    return seq(oid(content_type), tlv(0xA0, content))
+
+
+def encrypted_data(content):
+    eci = encrypted_content_info(16, ENCRYPTED_DATA_KEY, ENCRYPTED_DATA_IV, content)
+    return content_info(ID_ENCRYPTED_DATA, seq(integer(0), eci))
+
+
+def encrypted_data_ber(content):
+    iv = tlv(0x24, segments(ENCRYPTED_DATA_IV, 3))
+    algorithm = seq(oid(OID_RC5_CBC_PAD), seq(integer(16), integer(16), integer(64), iv))
+    ciphertext = cbc_pad(32, 16, ENCRYPTED_DATA_KEY, ENCRYPTED_DATA_IV, content)
+    eci = indefinite(0x30, oid(ID_DATA), algorithm, indefinite(0xA0, segments(ciphertext, 64)))
+    return indefinite(0x30, oid(ID_ENCRYPTED_DATA), indefinite(0xA0, indefinite(0x30, integer(0), eci)))
+
+
+def enveloped_data(content):
+    issuer = seq(set_of(seq(oid(ID_CN), tlv(0x0C, b"Alice"))))
+    recipient = seq(
+        integer(0),
+        seq(issuer, integer(0x1998)),
+        seq(oid(RSA_ENCRYPTION), null()),
+        octets(bytes(range(64))),
+    )
+    eci = encrypted_content_info(12, ENVELOPED_DATA_KEY, ENVELOPED_DATA_IV, content)
+    return content_info(ID_ENVELOPED_DATA, seq(integer(0), set_of(recipient), eci))
+
+
+with open(os.path.join(here, "message.txt"), "rb") as f:
+    message = f.read()
+with open(os.path.join(here, "encrypted-data.der"), "wb") as f:
+    f.write(encrypted_data(message))
+with open(os.path.join(here, "enveloped-data.der"), "wb") as f:
+    f.write(enveloped_data(message))
+with open(os.path.join(here, "encrypted-data.ber"), "wb") as f:
+    f.write(encrypted_data_ber(message))
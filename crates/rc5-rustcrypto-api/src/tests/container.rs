@@ -0,0 +1,238 @@
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
+use std::io::{self, Cursor, Read, Seek, SeekFrom, Write};
+use std::vec::Vec;
+
+use crate::container::{ByteRepr, Error, Header, Mode, Reader, WordSize, Writer, MAGIC};
+
+const KEY: [u8; 16] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+];
+
+fn header(word_size: WordSize, byte_repr: ByteRepr, chunk_len: u32) -> Header {
+    Header {
+        word_size,
+        rounds: 12,
+        key_len: KEY.len() as u8,
+        mode: Mode::Eax,
+        byte_repr,
+        chunk_len,
+        nonce: (0..Header::nonce_len(Mode::Eax) as u8).collect(),
+        key_id: b"backup-2024".to_vec(),
+    }
+}
+
+fn seal(header: &Header, plaintext: &[u8]) -> Vec<u8> {
+    let mut writer = Writer::new(Vec::new(), header, &KEY).unwrap();
+    writer.write_all(plaintext).unwrap();
+    writer.finish().unwrap()
+}
+
+fn open(container: &[u8]) -> io::Result<Vec<u8>> {
+    let mut plaintext = Vec::new();
+    Reader::open(Cursor::new(container), &KEY)?.read_to_end(&mut plaintext)?;
+    Ok(plaintext)
+}
+
+fn error_of(result: io::Result<Vec<u8>>) -> Error {
+    *result
+        .unwrap_err()
+        .into_inner()
+        .expect("a container error")
+        .downcast::<Error>()
+        .unwrap()
+}
+
+#[test]
+fn round_trip() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut plaintext = vec![0u8; 200];
+    rng.fill_bytes(&mut plaintext);
+
+    for word_size in [WordSize::W32, WordSize::W64] {
+        for byte_repr in [ByteRepr::LittleEndian, ByteRepr::BigEndian] {
+            for chunk_len in [1, 7, 16, 64] {
+                let header = header(word_size, byte_repr, chunk_len);
+                for len in [0, 1, 15, 16, 17, 64, 65, 200] {
+                    let container = seal(&header, &plaintext[..len]);
+                    assert_eq!(open(&container).unwrap(), &plaintext[..len]);
+
+                    let reader = Reader::open(Cursor::new(&container), &KEY).unwrap();
+                    assert_eq!(reader.header(), &header);
+                    assert_eq!(reader.len(), len as u64);
+                    assert_eq!(
+                        reader.chunk_count() as usize,
+                        len.div_ceil(chunk_len as usize).max(1)
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn byte_repr_changes_the_ciphertext() {
+    let plaintext = [0x42; 40];
+    let le_header = header(WordSize::W32, ByteRepr::LittleEndian, 16);
+    let le = seal(&le_header, &plaintext);
+    let be = seal(&header(WordSize::W32, ByteRepr::BigEndian, 16), &plaintext);
+
+    let header_len = le_header.to_bytes().unwrap().len();
+    assert_eq!(le.len(), be.len());
+    assert_ne!(le[header_len..], be[header_len..]);
+}
+
+#[test]
+fn random_access() {
+    let mut rng = StdRng::seed_from_u64(2);
+    let mut plaintext = vec![0u8; 1000];
+    rng.fill_bytes(&mut plaintext);
+    let container = seal(
+        &header(WordSize::W64, ByteRepr::LittleEndian, 64),
+        &plaintext,
+    );
+    let mut reader = Reader::open(Cursor::new(container), &KEY).unwrap();
+
+    for _ in 0..100 {
+        let start = rng.gen_range(0..=plaintext.len());
+        let len = rng.gen_range(0..=plaintext.len() - start);
+        reader.seek(SeekFrom::Start(start as u64)).unwrap();
+        let mut buf = vec![0u8; len];
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, &plaintext[start..start + len]);
+    }
+
+    assert_eq!(reader.read_chunk(3).unwrap(), &plaintext[192..256]);
+    assert_eq!(reader.read_chunk(15).unwrap(), &plaintext[960..]);
+    assert!(reader.read_chunk(16).is_err());
+
+    assert_eq!(reader.seek(SeekFrom::End(-10)).unwrap(), 990);
+    let mut tail = Vec::new();
+    reader.read_to_end(&mut tail).unwrap();
+    assert_eq!(tail, &plaintext[990..]);
+    assert!(reader.seek(SeekFrom::Current(-2000)).is_err());
+}
+
+#[test]
+fn nonce_length() {
+    // Random 64-bit nonces collide after about 2^32 files under one key; shorter ones much sooner.
+    assert!(Header::nonce_len(Mode::Eax) >= 8);
+
+    // Every byte of the nonce reaches EAX, even past RC5-32's 8-byte block.
+    let plaintext = [0x42; 40];
+    let first = header(WordSize::W32, ByteRepr::LittleEndian, 16);
+    let mut second = first.clone();
+    *second.nonce.last_mut().unwrap() ^= 1;
+
+    let header_len = first.to_bytes().unwrap().len();
+    let (a, b) = (seal(&first, &plaintext), seal(&second, &plaintext));
+    assert_ne!(a[header_len..], b[header_len..]);
+    assert_eq!(open(&b).unwrap(), plaintext);
+}
+
+#[test]
+fn header_round_trip() {
+    let header = header(WordSize::W32, ByteRepr::BigEndian, 4096);
+    let bytes = header.to_bytes().unwrap();
+    assert_eq!(bytes[..8], MAGIC);
+    assert_eq!(Header::read_from(&mut &bytes[..]).unwrap(), header);
+}
+
+#[test]
+fn invalid_headers() {
+    let mut bad = header(WordSize::W32, ByteRepr::LittleEndian, 0);
+    assert_eq!(bad.to_bytes(), Err(Error::InvalidHeader));
+
+    bad.chunk_len = 16;
+    bad.nonce.push(0);
+    assert_eq!(bad.to_bytes(), Err(Error::InvalidHeader));
+    assert!(Writer::new(Vec::new(), &bad, &KEY).is_err());
+
+    let good = header(WordSize::W32, ByteRepr::LittleEndian, 16);
+    assert!(Writer::new(Vec::new(), &good, &KEY[..15]).is_err());
+
+    let container = seal(&good, b"data");
+    assert_eq!(error_of(open(b"not a container at all")), Error::BadMagic);
+    let mut newer = container.clone();
+    newer[8] = 2;
+    assert_eq!(error_of(open(&newer)), Error::UnsupportedVersion(2));
+    let mut w16 = container.clone();
+    w16[9] = 16;
+    assert_eq!(error_of(open(&w16)), Error::UnsupportedParams);
+
+    let mut plaintext = Vec::new();
+    let error = Reader::open(Cursor::new(&container), &KEY[..8])
+        .and_then(|mut reader| reader.read_to_end(&mut plaintext));
+    assert!(error.is_err());
+}
+
+#[test]
+fn wrong_key() {
+    let container = seal(
+        &header(WordSize::W32, ByteRepr::LittleEndian, 16),
+        b"secret",
+    );
+    let mut key = KEY;
+    key[0] ^= 1;
+    assert!(Reader::open(Cursor::new(&container), &key).is_err());
+}
+
+#[test]
+fn header_tampering() {
+    let header = header(WordSize::W64, ByteRepr::LittleEndian, 16);
+    let header_len = header.to_bytes().unwrap().len();
+    let container = seal(&header, &[7; 50]);
+
+    for i in 0..header_len {
+        let mut tampered = container.clone();
+        tampered[i] ^= 0x01;
+        assert!(open(&tampered).is_err(), "byte {}", i);
+    }
+}
+
+#[test]
+fn truncation() {
+    let container = seal(
+        &header(WordSize::W32, ByteRepr::LittleEndian, 16),
+        &[9; 100],
+    );
+
+    for len in 0..container.len() {
+        assert!(open(&container[..len]).is_err(), "length {}", len);
+    }
+}
+
+#[test]
+fn truncation_with_forged_trailer() {
+    let header = header(WordSize::W32, ByteRepr::LittleEndian, 16);
+    let container = seal(&header, &[9; 100]);
+    let header_len = header.to_bytes().unwrap().len();
+
+    // drops the last chunks and appends a trailer matching the shorter length
+    let mut forged = container[..header_len + 3 * (16 + 8)].to_vec();
+    forged.extend_from_slice(&3u32.to_be_bytes());
+    forged.extend_from_slice(&48u64.to_be_bytes());
+    forged.extend_from_slice(b"RC5E");
+    assert_eq!(error_of(open(&forged)), Error::TagMismatch);
+
+    let mut longer = container.clone();
+    longer.extend_from_slice(&[0; 24]);
+    assert_eq!(error_of(open(&longer)), Error::Truncated);
+}
+
+#[test]
+fn reordering() {
+    let header = header(WordSize::W32, ByteRepr::LittleEndian, 16);
+    let container = seal(&header, &[5; 100]);
+    let header_len = header.to_bytes().unwrap().len();
+    let stored = 16 + 8;
+
+    let mut swapped = container.clone();
+    let (first, second) = swapped[header_len..].split_at_mut(stored);
+    first.swap_with_slice(&mut second[..stored]);
+    assert_eq!(error_of(open(&swapped)), Error::TagMismatch);
+
+    let mut tampered = container.clone();
+    tampered[header_len + stored + 3] ^= 0x80;
+    assert_eq!(error_of(open(&tampered)), Error::TagMismatch);
+}
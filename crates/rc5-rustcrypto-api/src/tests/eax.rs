@@ -0,0 +1,179 @@
+use aead::{Aead, AeadInPlace, KeyInit, Payload};
+use generic_array::GenericArray;
+use std::vec::Vec;
+use typenum::Unsigned;
+
+use crate::eax::Eax;
+use crate::krovetz18::RC5_64_20_16;
+use crate::rc5::RC5;
+use crate::rivest97::{RC5_32_12_16, RC5_64_16_16};
+
+fn run_eax_paper_case(
+    key_hex: &str,
+    nonce_hex: &str,
+    header_hex: &str,
+    msg_hex: &str,
+    output_hex: &str,
+) {
+    let eax = Eax::<aes::Aes128>::new_from_slice(&hex::decode(key_hex).unwrap()).unwrap();
+    let nonce = hex::decode(nonce_hex).unwrap();
+    let header = hex::decode(header_hex).unwrap();
+    let msg = hex::decode(msg_hex).unwrap();
+
+    let payload = Payload {
+        msg: &msg,
+        aad: &header,
+    };
+    let output = eax
+        .encrypt(GenericArray::from_slice(&nonce), payload)
+        .unwrap();
+    assert_eq!(hex::encode_upper(&output), output_hex);
+
+    let payload = Payload {
+        msg: &output,
+        aad: &header,
+    };
+    assert_eq!(
+        eax.decrypt(GenericArray::from_slice(&nonce), payload)
+            .unwrap(),
+        msg
+    );
+}
+
+#[test]
+fn eax_paper_vector_1() {
+    run_eax_paper_case(
+        "233952DEE4D5ED5F9B9C6D6FF80FF478",
+        "62EC67F9C3A4A407FCB2A8C49031A8B3",
+        "6BFB914FD07EAE6B",
+        "",
+        "E037830E8389F27B025A2D6527E79D01",
+    );
+}
+
+#[test]
+fn eax_paper_vector_2() {
+    run_eax_paper_case(
+        "91945D3F4DCBEE0BF45EF52255F095A4",
+        "BECAF043B0A23D843194BA972C66DEBD",
+        "FA3BFD4806EB53FA",
+        "F7FB",
+        "19DD5C4C9331049D0BDAB0277408F67967E5",
+    );
+}
+
+const LENGTHS: &[usize] = &[0, 1, 7, 8, 9, 15, 16, 17, 31, 32, 33, 100];
+
+/// Cross-checks `Eax` against the `eax` crate, which implements EAX for 128-bit blocks.
+#[test]
+fn matches_eax_crate() {
+    let key = GenericArray::from_iter(0..16);
+    let ours = Eax::<aes::Aes128>::new(&key);
+    let reference = eax::Eax::<aes::Aes128>::new(&key);
+    let nonce = GenericArray::from_iter(100..116);
+
+    for &msg_len in LENGTHS {
+        for &ad_len in LENGTHS {
+            let payload = || Payload {
+                msg: &[0x5a; 100][..msg_len],
+                aad: &[0xa5; 100][..ad_len],
+            };
+            assert_eq!(
+                ours.encrypt(&nonce, payload()).unwrap(),
+                reference.encrypt(&nonce, payload()).unwrap()
+            );
+        }
+    }
+}
+
+/// The counter wraps around the whole block, not just its rightmost bytes.
+#[test]
+fn counter_spans_the_block() {
+    let eax = Eax::<RC5_32_12_16>::new(&GenericArray::default());
+    let nonce = GenericArray::default();
+    let mut buffer = [0u8; 64];
+    eax.encrypt_in_place_detached(&nonce, b"", &mut buffer)
+        .unwrap();
+
+    let blocks = buffer.chunks(8).collect::<Vec<_>>();
+    for (i, block) in blocks.iter().enumerate() {
+        assert!(blocks[i + 1..].iter().all(|other| other != block));
+    }
+}
+
+macro_rules! eax_tests {
+    ($mod_name: ident, $Cipher: ty) => {
+        mod $mod_name {
+            use super::*;
+
+            fn eax() -> Eax<$Cipher> {
+                Eax::new(&GenericArray::from_iter(0..16))
+            }
+
+            #[test]
+            fn round_trip() {
+                let eax = eax();
+                let nonce = GenericArray::from_iter((0..).take(nonce_len()));
+
+                for &len in LENGTHS {
+                    let plaintext = (0..len).map(|i| i as u8).collect::<Vec<_>>();
+                    let mut buffer = plaintext.clone();
+                    let tag = eax
+                        .encrypt_in_place_detached(&nonce, b"header", &mut buffer)
+                        .unwrap();
+                    if len > 0 {
+                        assert_ne!(buffer, plaintext);
+                    }
+
+                    eax.decrypt_in_place_detached(&nonce, b"header", &mut buffer, &tag)
+                        .unwrap();
+                    assert_eq!(buffer, plaintext);
+                }
+            }
+
+            #[test]
+            fn tamper_rejection() {
+                let eax = eax();
+                let nonce = GenericArray::from_iter((0..).take(nonce_len()));
+                let mut buffer = *b"attack at dawn, not at dusk";
+                let tag = eax
+                    .encrypt_in_place_detached(&nonce, b"header", &mut buffer)
+                    .unwrap();
+
+                let mut tampered = buffer;
+                tampered[3] ^= 1;
+                assert!(eax
+                    .decrypt_in_place_detached(&nonce, b"header", &mut tampered, &tag)
+                    .is_err());
+                // the buffer is left as it was on failure
+                assert_eq!(tampered[..3], buffer[..3]);
+
+                let mut copy = buffer;
+                assert!(eax
+                    .decrypt_in_place_detached(&nonce, b"headed", &mut copy, &tag)
+                    .is_err());
+
+                let mut other_nonce = nonce.clone();
+                other_nonce[0] ^= 1;
+                assert!(eax
+                    .decrypt_in_place_detached(&other_nonce, b"header", &mut copy, &tag)
+                    .is_err());
+
+                let mut bad_tag = tag.clone();
+                bad_tag[0] ^= 1;
+                assert!(eax
+                    .decrypt_in_place_detached(&nonce, b"header", &mut copy, &bad_tag)
+                    .is_err());
+            }
+
+            fn nonce_len() -> usize {
+                <Eax<$Cipher> as aead::AeadCore>::NonceSize::USIZE
+            }
+        }
+    };
+}
+
+eax_tests!(rc5_32_12_16, RC5_32_12_16);
+eax_tests!(rc5_64_16_16, RC5_64_16_16);
+eax_tests!(rc5_64_20_16, RC5_64_20_16);
+eax_tests!(rc5_128_12_16, RC5<u128, typenum::U12, typenum::U16>);
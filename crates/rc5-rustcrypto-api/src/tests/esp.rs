@@ -0,0 +1,196 @@
+use cipher::{BlockDecrypt, BlockEncrypt};
+use generic_array::GenericArray;
+use hmac::{Hmac, Mac};
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+use sha1::Sha1;
+use std::vec::Vec;
+use typenum::U12;
+
+use crate::esp::{
+    Cipher, Error, Packet, SecurityAssociation, TruncatedMac, BLOCK_LEN, HEADER_LEN, IV_LEN, ROUNDS,
+};
+
+const SPI: u32 = 0x0000_1001;
+const KEY: [u8; 16] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+];
+const IV: [u8; IV_LEN] = [0xa0, 0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7];
+const AUTH_KEY: &[u8] = b"20 bytes of HMAC key";
+/// IPv4 in the next header: a tunnel-mode packet.
+const IPV4: u8 = 4;
+
+type HmacSha1_96 = TruncatedMac<Hmac<Sha1>, U12>;
+
+fn hmac_sha1_96() -> HmacSha1_96 {
+    TruncatedMac::new(Hmac::new_from_slice(AUTH_KEY).unwrap())
+}
+
+#[test]
+fn packet_layout() {
+    let sa = SecurityAssociation::new(SPI, &KEY).unwrap();
+    let payload = b"0123456789";
+    let packet = sa.encrypt(7, &IV, IPV4, payload);
+
+    assert_eq!(packet[..4], SPI.to_be_bytes());
+    assert_eq!(packet[4..8], 7u32.to_be_bytes());
+    assert_eq!(packet[HEADER_LEN..HEADER_LEN + IV_LEN], IV);
+    // 10 bytes of payload, 4 of padding, pad length and next header
+    assert_eq!(packet.len(), HEADER_LEN + IV_LEN + 16);
+
+    // decrypts the blocks independently of the module: RC5-32/16-CBC
+    let cipher = Cipher::new(&KEY, ROUNDS).unwrap();
+    let mut prev = IV;
+    let mut plaintext = Vec::new();
+    for chunk in packet[HEADER_LEN + IV_LEN..].chunks(BLOCK_LEN) {
+        let mut block = GenericArray::clone_from_slice(chunk);
+        cipher.decrypt_block(&mut block);
+        plaintext.extend(block.iter().zip(prev).map(|(a, b)| a ^ b));
+        prev.copy_from_slice(chunk);
+    }
+    assert_eq!(plaintext, b"0123456789\x01\x02\x03\x04\x04\x04");
+}
+
+#[test]
+fn round_trip() {
+    let mut rng = StdRng::seed_from_u64(37);
+    let mut payload = vec![0u8; 100];
+    rng.fill_bytes(&mut payload);
+
+    let key = [0x5e, 0xca, 0x1a, 0xb1, 0xe5];
+    let plain = SecurityAssociation::new(SPI, &key).unwrap();
+    let authenticated = SecurityAssociation::with_icv(SPI, &key, hmac_sha1_96()).unwrap();
+
+    for len in 0..=payload.len() {
+        let expected = Packet {
+            spi: SPI,
+            sequence_number: len as u32,
+            next_header: IPV4,
+            payload: payload[..len].to_vec(),
+        };
+
+        let packet = plain.encrypt(len as u32, &IV, IPV4, &payload[..len]);
+        assert_eq!((packet.len() - HEADER_LEN - IV_LEN) % BLOCK_LEN, 0);
+        assert_eq!(plain.decrypt(&packet).unwrap(), expected);
+
+        let packet = authenticated.encrypt(len as u32, &IV, IPV4, &payload[..len]);
+        assert_eq!((packet.len() - HEADER_LEN - IV_LEN - 12) % BLOCK_LEN, 0);
+        assert_eq!(authenticated.decrypt(&packet).unwrap(), expected);
+    }
+}
+
+#[test]
+fn icv() {
+    let sa = SecurityAssociation::with_icv(SPI, &KEY, hmac_sha1_96()).unwrap();
+    let packet = sa.encrypt(1, &IV, IPV4, b"payload");
+    let (data, icv) = packet.split_at(packet.len() - 12);
+
+    let mut mac = Hmac::<Sha1>::new_from_slice(AUTH_KEY).unwrap();
+    mac.update(data);
+    assert_eq!(icv, &mac.finalize().into_bytes()[..12]);
+
+    for i in 0..packet.len() {
+        let mut tampered = packet.clone();
+        tampered[i] ^= 0x10;
+        let error = sa.decrypt(&tampered).unwrap_err();
+        if i < 4 {
+            assert_eq!(error, Error::SpiMismatch);
+        } else {
+            assert_eq!(error, Error::IcvMismatch, "byte {}", i);
+        }
+    }
+
+    let other = SecurityAssociation::with_icv(
+        SPI,
+        &KEY,
+        HmacSha1_96::new(Hmac::new_from_slice(b"other").unwrap()),
+    )
+    .unwrap();
+    assert_eq!(other.decrypt(&packet), Err(Error::IcvMismatch));
+}
+
+#[test]
+fn padding() {
+    let sa = SecurityAssociation::new(SPI, &KEY).unwrap();
+    let cipher = Cipher::new(&KEY, ROUNDS).unwrap();
+    // a packet with the given plaintext, encrypted by hand
+    let packet = |plaintext: &[u8]| {
+        let mut packet = [&SPI.to_be_bytes()[..], &[0, 0, 0, 1], &IV].concat();
+        let mut prev = GenericArray::from(IV);
+        for chunk in plaintext.chunks(BLOCK_LEN) {
+            let mut block = GenericArray::clone_from_slice(chunk);
+            block.iter_mut().zip(prev).for_each(|(a, b)| *a ^= b);
+            cipher.encrypt_block(&mut block);
+            packet.extend_from_slice(&block);
+            prev = block;
+        }
+        packet
+    };
+
+    let decrypted = sa
+        .decrypt(&packet(&[0xaa, 1, 2, 3, 4, 5, 5, IPV4]))
+        .unwrap();
+    assert_eq!(decrypted.payload, [0xaa]);
+    assert_eq!(decrypted.next_header, IPV4);
+
+    // padding longer than the minimum, e.g. to hide the payload length, is accepted
+    let long = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 14, IPV4];
+    assert!(sa.decrypt(&packet(&long)).unwrap().payload.is_empty());
+
+    assert_eq!(
+        sa.decrypt(&packet(&[0xaa, 1, 2, 3, 9, 5, 5, IPV4])),
+        Err(Error::InvalidPadding)
+    );
+    assert_eq!(
+        sa.decrypt(&packet(&[1, 2, 3, 4, 5, 6, 7, IPV4])),
+        Err(Error::InvalidPadding)
+    );
+
+    let mut key = KEY;
+    key[0] ^= 1;
+    let wrong = SecurityAssociation::new(SPI, &key).unwrap();
+    let packet = sa.encrypt(1, &IV, IPV4, b"payload");
+    assert_ne!(
+        wrong.decrypt(&packet).ok().map(|packet| packet.payload),
+        Some(b"payload".to_vec())
+    );
+}
+
+#[test]
+fn truncated() {
+    let sa = SecurityAssociation::with_icv(SPI, &KEY, hmac_sha1_96()).unwrap();
+    let packet = sa.encrypt(1, &IV, IPV4, &[0; 30]);
+    for len in 0..packet.len() {
+        assert!(sa.decrypt(&packet[..len]).is_err(), "length {}", len);
+    }
+    assert_eq!(
+        sa.decrypt(&packet[..HEADER_LEN + IV_LEN + 12]),
+        Err(Error::Truncated)
+    );
+    assert_eq!(sa.decrypt(&packet[1..]), Err(Error::Truncated));
+}
+
+#[test]
+fn key_lengths() {
+    assert_eq!(
+        SecurityAssociation::new(SPI, &[0; 4]).err(),
+        Some(Error::InvalidKeyLength)
+    );
+    assert!(SecurityAssociation::new(SPI, &[0; 5]).is_ok());
+    assert!(SecurityAssociation::new(SPI, &[0; 255]).is_ok());
+    assert_eq!(
+        SecurityAssociation::new(SPI, &[0; 256]).err(),
+        Some(Error::InvalidKeyLength)
+    );
+}
+
+#[test]
+fn spi_mismatch() {
+    let sa = SecurityAssociation::new(SPI, &KEY).unwrap();
+    let other = SecurityAssociation::new(SPI + 1, &KEY).unwrap();
+    assert_eq!(other.spi(), SPI + 1);
+    assert_eq!(
+        other.decrypt(&sa.encrypt(1, &IV, IPV4, b"x")),
+        Err(Error::SpiMismatch)
+    );
+}
@@ -0,0 +1,383 @@
+use cipher::KeyInit;
+use generic_array::GenericArray;
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
+use std::cell::Cell;
+use std::io::{self, Read, Write};
+use std::rc::Rc;
+use std::vec::Vec;
+
+use crate::ccm::Ccm;
+use crate::io::{Cbc, Ctr, DecryptReader, Decryptor, Ecb, EncryptWriter, Encryptor, Error, Stream};
+use crate::rivest97::{RC5_32_12_16, RC5_64_16_16};
+use crate::siv::SivAead;
+
+/// Hands out the data in chunks of random length, sometimes empty-handed in between.
+struct ChunkedReader<R> {
+    inner: R,
+    rng: StdRng,
+}
+
+impl<R: Read> Read for ChunkedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.rng.gen_ratio(1, 8) {
+            return Err(io::ErrorKind::Interrupted.into());
+        }
+        let len = self.rng.gen_range(1..=37).min(buf.len());
+        self.inner.read(&mut buf[..len])
+    }
+}
+
+fn encrypt_chunked<E: Encryptor>(mode: E, plaintext: &[u8], rng: &mut StdRng) -> Vec<u8> {
+    let mut writer = EncryptWriter::new(Vec::new(), mode);
+    let mut rest = plaintext;
+    while !rest.is_empty() {
+        let len = rng.gen_range(0..=100).min(rest.len());
+        let written = writer.write(&rest[..len]).unwrap();
+        assert_eq!(written, len);
+        rest = &rest[len..];
+        if rng.gen_ratio(1, 10) {
+            writer.flush().unwrap();
+        }
+    }
+    writer.finish().unwrap()
+}
+
+fn decrypt_chunked<D: Decryptor>(
+    mode: D,
+    ciphertext: &[u8],
+    rng: &mut StdRng,
+) -> io::Result<Vec<u8>> {
+    let inner = ChunkedReader {
+        inner: ciphertext,
+        rng: StdRng::seed_from_u64(rng.next_u64()),
+    };
+    let mut reader = DecryptReader::new(inner, mode);
+    let mut plaintext = Vec::new();
+    loop {
+        let mut buf = [0u8; 64];
+        let len = rng.gen_range(1..=buf.len());
+        match reader.read(&mut buf[..len]) {
+            Ok(0) => return Ok(plaintext),
+            Ok(read) => plaintext.extend_from_slice(&buf[..read]),
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+fn assert_stream_error(result: io::Result<Vec<u8>>, expected: Error) {
+    let err = result.expect_err("corrupted stream accepted");
+    assert_eq!(
+        err.get_ref().and_then(|e| e.downcast_ref::<Error>()),
+        Some(&expected),
+        "{}",
+        err
+    );
+}
+
+const LENGTHS: &[usize] = &[0, 1, 7, 8, 9, 15, 16, 17, 100, 1000, 4096, 10_000];
+
+macro_rules! round_trip_tests {
+    ($test_name: ident, $encryptor: expr, $decryptor: expr) => {
+        #[test]
+        fn $test_name() {
+            let mut rng = StdRng::seed_from_u64(0x5eed);
+
+            for &len in LENGTHS {
+                let mut plaintext = vec![0u8; len];
+                rng.fill_bytes(&mut plaintext);
+
+                let ciphertext = encrypt_chunked($encryptor, &plaintext, &mut rng);
+                if len >= 8 {
+                    assert_ne!(ciphertext[..8], plaintext[..8]);
+                }
+
+                // the chunking must not affect the result
+                assert_eq!(
+                    encrypt_chunked($encryptor, &plaintext, &mut rng),
+                    ciphertext
+                );
+
+                let decrypted = decrypt_chunked($decryptor, &ciphertext, &mut rng).unwrap();
+                assert_eq!(decrypted, plaintext, "length {}", len);
+            }
+        }
+    };
+}
+
+fn rc5_32() -> RC5_32_12_16 {
+    RC5_32_12_16::new(&(0..16).collect())
+}
+
+fn rc5_64() -> RC5_64_16_16 {
+    RC5_64_16_16::new(&(0..16).collect())
+}
+
+fn ccm_64() -> Ccm<RC5_64_16_16, typenum::U16, typenum::U2> {
+    Ccm::from(rc5_64())
+}
+
+fn ccm_32() -> Ccm<RC5_32_12_16, typenum::U8, typenum::U2> {
+    Ccm::from(rc5_32())
+}
+
+fn siv_32() -> SivAead<RC5_32_12_16> {
+    SivAead::new(&(0..32).collect())
+}
+
+round_trip_tests!(ecb_rc5_32, Ecb::new(rc5_32()), Ecb::new(rc5_32()));
+round_trip_tests!(
+    cbc_rc5_32,
+    Cbc::new(rc5_32(), &GenericArray::from([1; 8])),
+    Cbc::new(rc5_32(), &GenericArray::from([1; 8]))
+);
+round_trip_tests!(
+    cbc_rc5_64,
+    Cbc::new(rc5_64(), &GenericArray::from([2; 16])),
+    Cbc::new(rc5_64(), &GenericArray::from([2; 16]))
+);
+round_trip_tests!(
+    ctr_rc5_32,
+    Ctr::new(rc5_32(), &GenericArray::from([3; 8])),
+    Ctr::new(rc5_32(), &GenericArray::from([3; 8]))
+);
+round_trip_tests!(
+    ctr_rc5_64,
+    Ctr::new(rc5_64(), &GenericArray::from([4; 16])),
+    Ctr::new(rc5_64(), &GenericArray::from([4; 16]))
+);
+round_trip_tests!(
+    stream_ccm_rc5_64,
+    Stream::new(ccm_64(), &GenericArray::from([5; 8]), 64),
+    Stream::new(ccm_64(), &GenericArray::from([5; 8]), 64)
+);
+round_trip_tests!(
+    stream_ccm_rc5_32,
+    Stream::new(ccm_32(), &GenericArray::default(), 100),
+    Stream::new(ccm_32(), &GenericArray::default(), 100)
+);
+round_trip_tests!(
+    stream_siv_rc5_32,
+    Stream::new(siv_32(), &GenericArray::from([6; 11]), 1),
+    Stream::new(siv_32(), &GenericArray::from([6; 11]), 1)
+);
+
+/// NIST SP 800-38A, F.2.1 and F.5.1, followed by the padding block or nothing.
+#[test]
+fn sp800_38a_aes_vectors() {
+    let key = hex::decode("2b7e151628aed2a6abf7158809cf4f3c").unwrap();
+    let plaintext = hex::decode(concat!(
+        "6bc1bee22e409f96e93d7e117393172a",
+        "ae2d8a571e03ac9c9eb76fac45af8e51",
+        "30c81c46a35ce411e5fbc1191a0a52ef",
+        "f69f2445df4f9b17ad2b417be66c3710",
+    ))
+    .unwrap();
+    let aes = || aes::Aes128::new_from_slice(&key).unwrap();
+    let mut rng = StdRng::seed_from_u64(0x38a);
+
+    let iv = GenericArray::from_iter(0..16);
+    let cbc = encrypt_chunked(Cbc::new(aes(), &iv), &plaintext, &mut rng);
+    assert_eq!(cbc.len(), plaintext.len() + 16);
+    assert_eq!(
+        hex::encode(&cbc[..plaintext.len()]),
+        concat!(
+            "7649abac8119b246cee98e9b12e9197d",
+            "5086cb9b507219ee95db113a917678b2",
+            "73bed6b8e3c1743b7116e69e22229516",
+            "3ff1caa1681fac09120eca307586e1a7",
+        )
+    );
+
+    let counter = GenericArray::from_iter(0xf0..=0xff);
+    let ctr = encrypt_chunked(Ctr::new(aes(), &counter), &plaintext, &mut rng);
+    assert_eq!(
+        hex::encode(ctr),
+        concat!(
+            "874d6191b620e3261bef6864990db6ce",
+            "9806f66b7970fdff8617187bb9fffdff",
+            "5ae4df3edbd5d35e5b4f09020db03eab",
+            "1e031dda2fbe03d1792170a0f3009cee",
+        )
+    );
+}
+
+#[test]
+fn ecb_blocks_are_independent() {
+    let mut rng = StdRng::seed_from_u64(0xecb);
+    let plaintext = hex::decode("00112233445566770011223344556677").unwrap();
+    let ciphertext = encrypt_chunked(Ecb::new(rc5_32()), &plaintext, &mut rng);
+
+    assert_eq!(ciphertext.len(), 24);
+    assert_eq!(hex::encode(&ciphertext[..8]), "2ddc149bcf088b9e");
+    assert_eq!(ciphertext[..8], ciphertext[8..16]);
+}
+
+#[test]
+fn cbc_truncated_or_corrupted() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let iv = GenericArray::from([7; 8]);
+    let plaintext = [0x42u8; 100];
+    let ciphertext = encrypt_chunked(Cbc::new(rc5_32(), &iv), &plaintext, &mut rng);
+    assert_eq!(ciphertext.len(), 104);
+
+    for len in [0, 1, 7, 9, 103] {
+        let result = decrypt_chunked(Cbc::new(rc5_32(), &iv), &ciphertext[..len], &mut rng);
+        assert_stream_error(result, Error::Truncated);
+    }
+
+    // ends with a block full of 0x42 instead of 4 bytes of 0x04
+    let mut unpadded = Vec::new();
+    let mut writer = EncryptWriter::new(&mut unpadded, Cbc::new(rc5_32(), &iv));
+    writer.write_all(&plaintext[..96]).unwrap();
+    drop(writer);
+    let result = decrypt_chunked(Cbc::new(rc5_32(), &iv), &unpadded, &mut rng);
+    assert_stream_error(result, Error::InvalidPadding);
+}
+
+#[test]
+fn stream_truncated_reordered_or_tampered() {
+    let mut rng = StdRng::seed_from_u64(2);
+    let nonce = GenericArray::from([8; 8]);
+    let mode = || Stream::new(ccm_64(), &nonce, 32);
+    let plaintext = (0..200).map(|i| i as u8).collect::<Vec<_>>();
+    let ciphertext = encrypt_chunked(mode(), &plaintext, &mut rng);
+
+    // 6 full segments and 8 bytes, each with a 16-byte tag
+    let segment = 32 + 16;
+    assert_eq!(ciphertext.len(), 7 * 16 + 200);
+
+    // cut at a segment boundary: the last segment left is not flagged as such
+    for segments in [1, 3, 6] {
+        let result = decrypt_chunked(mode(), &ciphertext[..segments * segment], &mut rng);
+        assert_stream_error(result, Error::TagMismatch);
+    }
+    let result = decrypt_chunked(mode(), &ciphertext[..segment + 15], &mut rng);
+    assert_stream_error(result, Error::Truncated);
+    let result = decrypt_chunked(mode(), &[], &mut rng);
+    assert_stream_error(result, Error::Truncated);
+
+    let mut reordered = ciphertext.clone();
+    reordered[..2 * segment].rotate_left(segment);
+    let result = decrypt_chunked(mode(), &reordered, &mut rng);
+    assert_stream_error(result, Error::TagMismatch);
+
+    for pos in [0, 47, 48, 100, ciphertext.len() - 1] {
+        let mut tampered = ciphertext.clone();
+        tampered[pos] ^= 1;
+        let result = decrypt_chunked(mode(), &tampered, &mut rng);
+        assert_stream_error(result, Error::TagMismatch);
+    }
+
+    let mut appended = ciphertext.clone();
+    appended.extend_from_slice(&ciphertext[..segment]);
+    let result = decrypt_chunked(mode(), &appended, &mut rng);
+    assert_stream_error(result, Error::TagMismatch);
+}
+
+#[test]
+fn stream_releases_only_authenticated_segments() {
+    let nonce = GenericArray::from([9; 8]);
+    let mode = || Stream::new(ccm_64(), &nonce, 16);
+    let mut ciphertext = encrypt_chunked(mode(), &[0; 64], &mut StdRng::seed_from_u64(3));
+    ciphertext[2 * 32] ^= 1;
+
+    let mut reader = DecryptReader::new(&ciphertext[..], mode());
+    let mut plaintext = [0u8; 64];
+    assert_eq!(reader.read(&mut plaintext[..]).unwrap(), 32);
+    let err = reader.read(&mut plaintext[32..]).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+/// Takes part of what it is given, or fails, at random while `flaky` is set.
+struct FlakyWriter {
+    written: Vec<u8>,
+    rng: StdRng,
+    flaky: Rc<Cell<bool>>,
+}
+
+impl Write for FlakyWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.flaky.get() && self.rng.gen_ratio(1, 3) {
+            return Err(io::ErrorKind::Other.into());
+        }
+        let len = self.rng.gen_range(1..=13).min(buf.len());
+        self.written.extend_from_slice(&buf[..len]);
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.flaky.get() && self.rng.gen_ratio(1, 3) {
+            return Err(io::ErrorKind::Other.into());
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn writes_failed_by_the_inner_writer_can_be_retried() {
+    let mut rng = StdRng::seed_from_u64(4);
+    let iv = GenericArray::from([5; 8]);
+    let mut plaintext = [0u8; 1000];
+    rng.fill_bytes(&mut plaintext);
+    let expected = encrypt_chunked(Cbc::new(rc5_32(), &iv), &plaintext, &mut rng);
+
+    let flaky = Rc::new(Cell::new(true));
+    let inner = FlakyWriter {
+        written: Vec::new(),
+        rng: StdRng::seed_from_u64(5),
+        flaky: flaky.clone(),
+    };
+    let mut writer = EncryptWriter::new(inner, Cbc::new(rc5_32(), &iv));
+    let mut rest = &plaintext[..];
+    let mut failures = 0;
+    while !rest.is_empty() {
+        let len = rng.gen_range(0..=30).min(rest.len());
+        match writer.write(&rest[..len]) {
+            Ok(written) => rest = &rest[written..],
+            Err(_) => failures += 1,
+        }
+        if rng.gen_ratio(1, 10) && writer.flush().is_err() {
+            failures += 1;
+        }
+    }
+    assert!(failures > 10);
+
+    flaky.set(false);
+    assert_eq!(writer.finish().unwrap().written, expected);
+}
+
+/// Fails on its second segment.
+struct FailingMode(usize);
+
+impl Encryptor for FailingMode {
+    fn segment_len(&self) -> usize {
+        8
+    }
+
+    fn encrypt_segment(&mut self, segment: &[u8], out: &mut Vec<u8>) -> Result<(), Error> {
+        self.0 += 1;
+        if self.0 == 2 {
+            return Err(Error::TooLong);
+        }
+        out.extend_from_slice(segment);
+        Ok(())
+    }
+
+    fn encrypt_last(self, rest: &[u8], out: &mut Vec<u8>) -> Result<(), Error> {
+        out.extend_from_slice(rest);
+        Ok(())
+    }
+}
+
+#[test]
+fn writes_failed_by_the_mode_fail_for_good() {
+    let mut writer = EncryptWriter::new(Vec::new(), FailingMode(0));
+    assert_eq!(writer.write(&[0; 16]).unwrap(), 16);
+    for _ in 0..2 {
+        let err = writer.write(&[0; 8]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+    assert!(writer.finish().is_err());
+}
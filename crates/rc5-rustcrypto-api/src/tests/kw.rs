@@ -0,0 +1,253 @@
+use cipher::KeyInit;
+use std::vec::Vec;
+
+use crate::kw::{Error, Kek};
+use crate::rivest97::{RC5_32_12_16, RC5_64_16_16};
+
+fn run_rfc3394_case<C>(kek_hex: &str, key_hex: &str, wrapped_hex: &str)
+where
+    Kek<C>: KeyInit,
+    C: cipher::BlockCipher + cipher::BlockEncrypt + cipher::BlockDecrypt,
+{
+    let kek = Kek::<C>::new_from_slice(&hex::decode(kek_hex).unwrap()).unwrap();
+    let key = hex::decode(key_hex).unwrap();
+    let wrapped = hex::decode(wrapped_hex).unwrap();
+
+    let mut buf = [0u8; 64];
+    assert_eq!(kek.wrap(&key, &mut buf).unwrap(), &wrapped[..]);
+    assert_eq!(kek.unwrap(&wrapped, &mut buf).unwrap(), &key[..]);
+}
+
+fn run_rfc5649_case<C>(kek_hex: &str, key_hex: &str, wrapped_hex: &str)
+where
+    Kek<C>: KeyInit,
+    C: cipher::BlockCipher<BlockSize = typenum::U16> + cipher::BlockEncrypt + cipher::BlockDecrypt,
+{
+    let kek = Kek::<C>::new_from_slice(&hex::decode(kek_hex).unwrap()).unwrap();
+    let key = hex::decode(key_hex).unwrap();
+    let wrapped = hex::decode(wrapped_hex).unwrap();
+
+    let mut buf = [0u8; 64];
+    assert_eq!(kek.wrap_with_padding(&key, &mut buf).unwrap(), &wrapped[..]);
+    assert_eq!(
+        kek.unwrap_with_padding(&wrapped, &mut buf).unwrap(),
+        &key[..]
+    );
+}
+
+#[test]
+fn rfc3394_4_1_aes128() {
+    run_rfc3394_case::<aes::Aes128>(
+        "000102030405060708090A0B0C0D0E0F",
+        "00112233445566778899AABBCCDDEEFF",
+        "1FA68B0A8112B447AEF34BD8FB5A7B829D3E862371D2CFE5",
+    );
+}
+
+#[test]
+fn rfc3394_4_6_aes256() {
+    run_rfc3394_case::<aes::Aes256>(
+        "000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F",
+        "00112233445566778899AABBCCDDEEFF000102030405060708090A0B0C0D0E0F",
+        "28C9F404C4B810F4CBCCB35CFB87F8263F5786E2D80ED326CBC7F0E71A99F43BFB988B9B7A02DD21",
+    );
+}
+
+#[test]
+fn rfc5649_20_bytes_aes192() {
+    run_rfc5649_case::<aes::Aes192>(
+        "5840df6e29b02af1ab493b705bf16ea1ae8338f4dcc176a8",
+        "c37b7e6492584340bed12207808941155068f738",
+        "138bdeaa9b8fa7fc61f97742e72248ee5ae6ae5360d1ae6a5f54f373fa543b6a",
+    );
+}
+
+#[test]
+fn rfc5649_7_bytes_aes192() {
+    run_rfc5649_case::<aes::Aes192>(
+        "5840df6e29b02af1ab493b705bf16ea1ae8338f4dcc176a8",
+        "466f7250617369",
+        "afbeb0f07dfbf5419200f2ccb50bb24f",
+    );
+}
+
+fn kek<C: KeyInit>() -> Kek<C> {
+    Kek::new(&(0..).take(C::key_size()).collect())
+}
+
+#[test]
+fn kw_round_trip_rc5_64() {
+    let kek = kek::<RC5_64_16_16>();
+
+    for len in (16..=64).step_by(8) {
+        let key = (0..len).map(|i| i as u8).collect::<Vec<_>>();
+        let mut wrapped = [0u8; 72];
+        let wrapped = kek.wrap(&key, &mut wrapped).unwrap();
+        assert_eq!(wrapped.len(), len + 8);
+
+        let mut unwrapped = [0u8; 72];
+        assert_eq!(kek.unwrap(wrapped, &mut unwrapped).unwrap(), &key[..]);
+    }
+}
+
+#[test]
+fn kw_round_trip_rc5_32() {
+    let kek = kek::<RC5_32_12_16>();
+
+    for len in (8..=64).step_by(4) {
+        let key = (0..len).map(|i| i as u8).collect::<Vec<_>>();
+        let mut wrapped = [0u8; 68];
+        let wrapped = kek.wrap(&key, &mut wrapped).unwrap();
+        assert_eq!(wrapped.len(), len + 4);
+        assert_ne!(&wrapped[4..], &key[..]);
+
+        let mut unwrapped = [0u8; 68];
+        assert_eq!(kek.unwrap(wrapped, &mut unwrapped).unwrap(), &key[..]);
+    }
+}
+
+#[test]
+fn kwp_round_trip_rc5_64() {
+    let kek = kek::<RC5_64_16_16>();
+
+    for len in 1usize..=65 {
+        let key = (0..len).map(|i| i as u8).collect::<Vec<_>>();
+        let mut wrapped = [0u8; 80];
+        let wrapped = kek.wrap_with_padding(&key, &mut wrapped).unwrap();
+        assert_eq!(wrapped.len(), len.div_ceil(8) * 8 + 8);
+
+        let mut unwrapped = [0u8; 80];
+        assert_eq!(
+            kek.unwrap_with_padding(wrapped, &mut unwrapped).unwrap(),
+            &key[..]
+        );
+    }
+}
+
+#[test]
+fn kw_rejects_bad_lengths() {
+    let kek64 = kek::<RC5_64_16_16>();
+    let kek32 = kek::<RC5_32_12_16>();
+    let mut out = [0u8; 64];
+
+    assert_eq!(
+        kek64.wrap(&[0; 8], &mut out),
+        Err(Error::InvalidInputLength)
+    );
+    assert_eq!(
+        kek64.wrap(&[0; 17], &mut out),
+        Err(Error::InvalidInputLength)
+    );
+    assert_eq!(
+        kek64.wrap(&[0; 16], &mut out[..23]),
+        Err(Error::InvalidOutputLength)
+    );
+    assert_eq!(
+        kek64.unwrap(&[0; 16], &mut out),
+        Err(Error::InvalidInputLength)
+    );
+    assert_eq!(
+        kek64.unwrap(&[0; 25], &mut out),
+        Err(Error::InvalidInputLength)
+    );
+    assert_eq!(
+        kek64.unwrap(&[0; 24], &mut out[..15]),
+        Err(Error::InvalidOutputLength)
+    );
+
+    assert_eq!(
+        kek32.wrap(&[0; 4], &mut out),
+        Err(Error::InvalidInputLength)
+    );
+    assert_eq!(
+        kek32.wrap(&[0; 10], &mut out),
+        Err(Error::InvalidInputLength)
+    );
+    assert_eq!(
+        kek32.unwrap(&[0; 8], &mut out),
+        Err(Error::InvalidInputLength)
+    );
+
+    assert_eq!(
+        kek64.wrap_with_padding(&[], &mut out),
+        Err(Error::InvalidInputLength)
+    );
+    assert_eq!(
+        kek64.wrap_with_padding(&[0; 9], &mut out[..23]),
+        Err(Error::InvalidOutputLength)
+    );
+    assert_eq!(
+        kek64.unwrap_with_padding(&[0; 8], &mut out),
+        Err(Error::InvalidInputLength)
+    );
+    assert_eq!(
+        kek64.unwrap_with_padding(&[0; 20], &mut out),
+        Err(Error::InvalidInputLength)
+    );
+}
+
+#[test]
+fn kw_detects_tampering() {
+    let kek64 = kek::<RC5_64_16_16>();
+    let kek32 = kek::<RC5_32_12_16>();
+    let other = Kek::<RC5_64_16_16>::new(&Default::default());
+    let key = [0x42u8; 24];
+    let mut out = [0u8; 64];
+
+    let wrapped = kek64.wrap(&key, &mut [0u8; 32]).unwrap().to_vec();
+    for bit in 0..wrapped.len() * 8 {
+        let mut tampered = wrapped.clone();
+        tampered[bit / 8] ^= 1 << (bit % 8);
+        assert_eq!(
+            kek64.unwrap(&tampered, &mut out),
+            Err(Error::IntegrityCheckFailed)
+        );
+        assert!(out.iter().all(|b| *b == 0));
+    }
+    assert_eq!(
+        other.unwrap(&wrapped, &mut out),
+        Err(Error::IntegrityCheckFailed)
+    );
+
+    let wrapped = kek32.wrap(&key, &mut [0u8; 28]).unwrap().to_vec();
+    for bit in 0..wrapped.len() * 8 {
+        let mut tampered = wrapped.clone();
+        tampered[bit / 8] ^= 1 << (bit % 8);
+        assert_eq!(
+            kek32.unwrap(&tampered, &mut out),
+            Err(Error::IntegrityCheckFailed)
+        );
+    }
+
+    for len in [5, 8, 13] {
+        let wrapped = kek64
+            .wrap_with_padding(&key[..len], &mut [0u8; 32])
+            .unwrap()
+            .to_vec();
+        for bit in 0..wrapped.len() * 8 {
+            let mut tampered = wrapped.clone();
+            tampered[bit / 8] ^= 1 << (bit % 8);
+            assert_eq!(
+                kek64.unwrap_with_padding(&tampered, &mut out),
+                Err(Error::IntegrityCheckFailed)
+            );
+        }
+        assert_eq!(
+            other.unwrap_with_padding(&wrapped, &mut out),
+            Err(Error::IntegrityCheckFailed)
+        );
+    }
+}
+
+#[test]
+fn kwp_rejects_inconsistent_length() {
+    let kek = kek::<RC5_64_16_16>();
+    let mut out = [0u8; 64];
+
+    // A plain KW output is not a valid KWP output: its IV carries no length.
+    let wrapped = kek.wrap(&[0x11; 16], &mut [0u8; 24]).unwrap().to_vec();
+    assert_eq!(
+        kek.unwrap_with_padding(&wrapped, &mut out),
+        Err(Error::IntegrityCheckFailed)
+    );
+}
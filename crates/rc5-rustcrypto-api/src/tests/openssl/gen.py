@@ -0,0 +1,57 @@
+#!/usr/bin/env python3
+"""Writes the fixtures for `openssl enc -rc5-cbc` next to this script.
+
+Each `<name>.bin` is meant to be what the command in CASES writes for `plaintext.txt`, but no OpenSSL at hand
+has RC5 (OpenSSL 3 only ships it in builds configured with `enable-rc5`), so none of them is real `openssl enc`
+output. The key and IV come from the key derivations of `openssl enc` written out here with `hashlib`, which were
+compared with what `openssl enc -des-ede-cbc -P` prints for the same password, salt and KDF options. RC5-32/12/16
+is the C reference of `rc5-reference`, by way of `../rc5ref.py`. The `Salted__` header and CBC with PKCS#7
+padding are written out here.
+"""
+
+import hashlib
+import os
+import sys
+
+sys.path.insert(0, os.path.join(os.path.dirname(os.path.abspath(__file__)), ".."))
+import rc5ref  # noqa: E402
+
+PASSWORD = b"correct horse"
+SALT = bytes.fromhex("0102030405060708")
+
+CASES = [
+    # name, command, kdf
+    ("md5", "openssl enc -rc5-cbc -md md5 -S 0102030405060708 -k 'correct horse'", ("evp", "md5")),
+    ("sha256", "openssl enc -rc5-cbc -md sha256 -S 0102030405060708 -k 'correct horse'", ("evp", "sha256")),
+    ("pbkdf2", "openssl enc -rc5-cbc -pbkdf2 -S 0102030405060708 -k 'correct horse'", ("pbkdf2", "sha256", 10000)),
+    ("pbkdf2-sha1-1000", "openssl enc -rc5-cbc -pbkdf2 -iter 1000 -md sha1 -S 0102030405060708 -k 'correct horse'", ("pbkdf2", "sha1", 1000)),
+]
+
+
+def bytes_to_key(md, password, salt, length):
+    out, d = b"", b""
+    while len(out) < length:
+        d = hashlib.new(md, d + password + salt).digest()
+        out += d
+    return out[:length]
+
+
+def derive(kdf):
+    if kdf[0] == "evp":
+        material = bytes_to_key(kdf[1], PASSWORD, SALT, 24)
+    else:
+        material = hashlib.pbkdf2_hmac(kdf[1], PASSWORD, SALT, kdf[2], 24)
+    return material[:16], material[16:]
+
+
+def encrypt(kdf, plaintext):
+    key, iv = derive(kdf)
+    return b"Salted__" + SALT + rc5ref.cbc_pad(32, 12, key, iv, plaintext)
+
+
+here = os.path.dirname(os.path.abspath(__file__))
+with open(os.path.join(here, "plaintext.txt"), "rb") as f:
+    plaintext = f.read()
+for name, _command, kdf in CASES:
+    with open(os.path.join(here, name + ".bin"), "wb") as f:
+        f.write(encrypt(kdf, plaintext))
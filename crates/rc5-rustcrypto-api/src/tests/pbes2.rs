@@ -0,0 +1,175 @@
+use der::{Decode, Encode, ErrorKind};
+use pkcs5::pbes2::{Pbkdf2Params, Pbkdf2Prf};
+
+use crate::asn1::Rc5CbcParameters;
+use crate::pbes2::{EncryptedPrivateKeyInfo, Error, Parameters, MAX_ITERATION_COUNT};
+
+const PASSWORD: &[u8] = b"correct horse";
+const PRIVATE_KEY: &[u8] = include_bytes!("pbes2/private-key.der");
+
+/// `PRIVATE_KEY` encrypted with PBES2 and `rc5-CBC-PAD` by `pbes2/gen.py`, with RC5 from the C reference of
+/// `rc5-reference`: no OpenSSL at hand has RC5.
+const FIXTURES: &[(u16, u8, Pbkdf2Prf, &[u8])] = &[
+    (
+        64,
+        12,
+        Pbkdf2Prf::HmacWithSha1,
+        include_bytes!("pbes2/rc5-32-12-sha1.der"),
+    ),
+    (
+        128,
+        16,
+        Pbkdf2Prf::HmacWithSha256,
+        include_bytes!("pbes2/rc5-64-16-sha256.der"),
+    ),
+];
+
+fn params<'a>(salt: &'a [u8], iv: Option<&'a [u8]>) -> Parameters<'a> {
+    Parameters {
+        kdf: Pbkdf2Params {
+            salt,
+            iteration_count: 1000,
+            key_length: Some(16),
+            prf: Pbkdf2Prf::HmacWithSha256,
+        },
+        encryption: Rc5CbcParameters::new(12, 64, iv).unwrap(),
+    }
+}
+
+#[test]
+fn decrypt_fixtures() {
+    for &(block_size_in_bits, rounds, prf, der) in FIXTURES {
+        let info = EncryptedPrivateKeyInfo::from_der(der).unwrap();
+        let params = info.encryption_algorithm;
+        assert_eq!(params.encryption.block_size_in_bits(), block_size_in_bits);
+        assert_eq!(params.encryption.rounds(), rounds);
+        assert_eq!(params.kdf.prf, prf);
+
+        assert_eq!(info.decrypt(PASSWORD).unwrap(), PRIVATE_KEY);
+        assert_eq!(info.to_der().unwrap(), der);
+    }
+}
+
+#[test]
+fn encrypt_reproduces_fixtures() {
+    for &(_, _, _, der) in FIXTURES {
+        let info = EncryptedPrivateKeyInfo::from_der(der).unwrap();
+        let encrypted =
+            EncryptedPrivateKeyInfo::encrypt(info.encryption_algorithm, PASSWORD, PRIVATE_KEY)
+                .unwrap();
+        assert_eq!(encrypted, der);
+    }
+}
+
+#[test]
+fn round_trip() {
+    let iv = [7u8; 8];
+    for iv in [Some(&iv[..]), None] {
+        let params = params(b"saltsalt", iv);
+        for len in [0, 1, 7, 8, 9, 100] {
+            let plaintext = vec![0x5a; len];
+            let ciphertext = params.encrypt(PASSWORD, &plaintext).unwrap();
+            assert_eq!(ciphertext.len(), (len / 8 + 1) * 8);
+            assert_eq!(params.decrypt(PASSWORD, &ciphertext).unwrap(), plaintext);
+        }
+    }
+}
+
+#[test]
+fn wrong_password() {
+    let info = EncryptedPrivateKeyInfo::from_der(FIXTURES[0].3).unwrap();
+    assert!(matches!(
+        info.decrypt(b"wrong horse"),
+        Err(Error::InvalidPadding)
+    ));
+}
+
+#[test]
+fn truncated() {
+    let params = params(b"saltsalt", None);
+    let ciphertext = params.encrypt(PASSWORD, b"secret").unwrap();
+    assert_eq!(
+        params.decrypt(PASSWORD, &ciphertext[..7]),
+        Err(Error::Truncated)
+    );
+}
+
+#[test]
+fn key_length() {
+    let mut params = params(b"saltsalt", None);
+    params.kdf.key_length = None;
+    assert_eq!(params.derive_key(PASSWORD), Err(Error::InvalidKeyLength));
+    params.kdf.key_length = Some(0);
+    assert_eq!(params.derive_key(PASSWORD), Err(Error::InvalidKeyLength));
+    params.kdf.key_length = Some(256);
+    assert_eq!(params.derive_key(PASSWORD), Err(Error::InvalidKeyLength));
+    params.kdf.key_length = Some(255);
+    assert_eq!(params.derive_key(PASSWORD).unwrap().len(), 255);
+}
+
+#[test]
+fn iteration_count() {
+    let mut params = params(b"saltsalt", Some(&[0; 8]));
+    params.kdf.iteration_count = 0;
+    assert_eq!(
+        params.derive_key(PASSWORD),
+        Err(Error::InvalidIterationCount)
+    );
+    params.kdf.iteration_count = MAX_ITERATION_COUNT + 1;
+    assert_eq!(
+        params.derive_key(PASSWORD),
+        Err(Error::InvalidIterationCount)
+    );
+    params.kdf.iteration_count = 1;
+    assert_eq!(params.derive_key(PASSWORD).unwrap().len(), 16);
+
+    // rejected as soon as it is decoded, before anything is derived
+    for count in [0, MAX_ITERATION_COUNT + 1, u32::MAX] {
+        params.kdf.iteration_count = count;
+        let info = EncryptedPrivateKeyInfo {
+            encryption_algorithm: params,
+            encrypted_data: &[0; 8],
+        };
+        let error = EncryptedPrivateKeyInfo::from_der(&info.to_der().unwrap()).unwrap_err();
+        assert!(matches!(error.kind(), ErrorKind::Value { .. }), "{}", count);
+    }
+    params.kdf.iteration_count = MAX_ITERATION_COUNT;
+    let info = EncryptedPrivateKeyInfo {
+        encryption_algorithm: params,
+        encrypted_data: &[0; 8],
+    };
+    assert_eq!(
+        EncryptedPrivateKeyInfo::from_der(&info.to_der().unwrap()).unwrap(),
+        info
+    );
+}
+
+#[test]
+fn derive_key() {
+    // RFC 6070, PBKDF2-HMAC-SHA1 with c = 4096
+    let mut params = params(b"salt", None);
+    params.kdf.prf = Pbkdf2Prf::HmacWithSha1;
+    params.kdf.iteration_count = 4096;
+    params.kdf.key_length = Some(20);
+    assert_eq!(
+        params.derive_key(b"password").unwrap(),
+        hex::decode("4b007901b765489abead49d926f721d065a429c1").unwrap()
+    );
+}
+
+#[test]
+fn unsupported_algorithms() {
+    let mut der = FIXTURES[0].3.to_vec();
+    // the last arc of the PBES2 OID: 1.2.840.113549.1.5.13 -> 1.2.840.113549.1.5.3 (PBES1 with MD5 and DES)
+    assert_eq!(der[15], 13);
+    der[15] = 3;
+    let error = EncryptedPrivateKeyInfo::from_der(&der).unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::OidUnknown { .. }));
+
+    let mut der = FIXTURES[0].3.to_vec();
+    // the last arc of rc5-CBC-PAD: 1.2.840.113549.3.9 -> 1.2.840.113549.3.7 (des-EDE3-CBC)
+    assert_eq!(der[61], 9);
+    der[61] = 7;
+    let error = EncryptedPrivateKeyInfo::from_der(&der).unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::OidUnknown { .. }));
+}
@@ -0,0 +1,91 @@
+#!/usr/bin/env python3
+"""Writes the PBES2 `EncryptedPrivateKeyInfo` fixtures next to this script.
+
+`private-key.der` is a P-256 `PrivateKeyInfo` from
+`openssl genpkey -algorithm EC -pkeyopt ec_paramgen_curve:P-256 | openssl pkcs8 -topk8 -nocrypt -outform DER`.
+No OpenSSL at hand has RC5 (OpenSSL 3 only ships it in builds configured with `enable-rc5`), so each
+`<name>.der` is written here: RC5 is the C reference of `rc5-reference`, by way of `../rc5ref.py`, PBKDF2 comes
+from `hashlib`, and CBC with PKCS#5 padding and the DER are written out here. The results parse with
+`openssl asn1parse -inform DER`.
+"""
+
+import hashlib
+import os
+import sys
+
+sys.path.insert(0, os.path.join(os.path.dirname(os.path.abspath(__file__)), ".."))
+from rc5ref import cbc_pad  # noqa: E402
+
+PASSWORD = b"correct horse"
+
+CASES = [
+    # name, word bits, rounds, key length, prf, iterations, salt, iv
+    ("rc5-32-12-sha1", 32, 12, 16, "sha1", 2048,
+     bytes.fromhex("0001020304050607"), bytes.fromhex("08090a0b0c0d0e0f")),
+    ("rc5-64-16-sha256", 64, 16, 32, "sha256", 1000,
+     bytes.fromhex("f0e1d2c3b4a59687f0e1d2c3b4a59687"),
+     bytes.fromhex("00112233445566778899aabbccddeeff")),
+]
+
+OID_PBES2 = "1.2.840.113549.1.5.13"
+OID_PBKDF2 = "1.2.840.113549.1.5.12"
+OID_RC5_CBC_PAD = "1.2.840.113549.3.9"
+OID_HMAC = {"sha1": "1.2.840.113549.2.7", "sha256": "1.2.840.113549.2.9"}
+
+
+def tlv(tag, value):
+    if len(value) < 0x80:
+        length = bytes([len(value)])
+    else:
+        octets = len(value).to_bytes((len(value).bit_length() + 7) // 8, "big")
+        length = bytes([0x80 | len(octets)]) + octets
+    return bytes([tag]) + length + value
+
+
+def seq(*items):
+    return tlv(0x30, b"".join(items))
+
+
+def integer(n):
+    return tlv(0x02, n.to_bytes(n.bit_length() // 8 + 1, "big"))
+
+
+def octets(value):
+    return tlv(0x04, value)
+
+
+def oid(dotted):
+    arcs = [int(arc) for arc in dotted.split(".")]
+    body = bytes([40 * arcs[0] + arcs[1]])
+    for arc in arcs[2:]:
+        chunk = [arc & 0x7F]
+        while arc > 0x7F:
+            arc >>= 7
+            chunk.insert(0, 0x80 | (arc & 0x7F))
+        body += bytes(chunk)
+    return tlv(0x06, body)
+
+
+def null():
+    return tlv(0x05, b"")
+
+
+def encrypted_private_key_info(w, rounds, key_len, prf, iterations, salt, iv, private_key):
+    key = hashlib.pbkdf2_hmac(prf, PASSWORD, salt, iterations, key_len)
+    kdf = [octets(salt), integer(iterations), integer(key_len)]
+    if prf != "sha1":  # DER omits the DEFAULT algid-hmacWithSHA1
+        kdf.append(seq(oid(OID_HMAC[prf]), null()))
+    params = seq(
+        seq(oid(OID_PBKDF2), seq(*kdf)),
+        seq(oid(OID_RC5_CBC_PAD), seq(integer(16), integer(rounds), integer(2 * w), octets(iv))),
+    )
+    return seq(seq(oid(OID_PBES2), params), octets(cbc_pad(w, rounds, key, iv, private_key)))
+
+
+if __name__ == "__main__":
+    here = os.path.dirname(os.path.abspath(__file__))
+    with open(os.path.join(here, "private-key.der"), "rb") as f:
+        private_key = f.read()
+    for name, *case in CASES:
+        with open(os.path.join(here, name + ".der"), "wb") as f:
+            f.write(encrypted_private_key_info(*case, private_key))
@@ -0,0 +1,81 @@
+"""RC5 for the fixture generators, run by the C reference of the `rc5-reference` crate.
+
+Importing this module compiles `crates/rc5-reference/c/rc5ref{16,32,64}.c` (Rivest's code, with the changes
+listed in `rc5ref.c`) with `cc` into a temporary shared library and checks it against the test vectors of Rivest
+and Krovetz. Words are read from and written to blocks little-endian, as in the paper.
+"""
+
+import ctypes
+import os
+import subprocess
+import tempfile
+
+WORDS = {16: ctypes.c_uint16, 32: ctypes.c_uint32, 64: ctypes.c_uint64}
+SOURCES = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "..", "..", "rc5-reference", "c")
+
+
+def _load():
+    out = tempfile.mkdtemp(prefix="rc5ref")
+    library = os.path.join(out, "librc5ref.so")
+    sources = [os.path.join(SOURCES, "rc5ref%d.c" % w) for w in WORDS]
+    subprocess.run(["cc", "-std=c99", "-O2", "-shared", "-fPIC", "-o", library, *sources], check=True)
+    return ctypes.CDLL(library)
+
+
+_lib = _load()
+
+
+class RC5:
+    """RC5-w/r with a key of up to 255 bytes."""
+
+    def __init__(self, w, rounds, key):
+        assert w in WORDS and 0 <= rounds <= 255 and len(key) <= 255
+        self.w, self.rounds, self.n = w, rounds, w // 4
+        self._word = WORDS[w]
+        self._s = (self._word * (2 * rounds + 2))()
+        getattr(_lib, "rc5ref_setup_%d" % w)(bytes(key), len(key), rounds, self._s)
+
+    def _run(self, name, block):
+        assert len(block) == self.n
+        u = self.w // 8
+        words = (self._word * 2)(int.from_bytes(block[:u], "little"), int.from_bytes(block[u:], "little"))
+        out = (self._word * 2)()
+        getattr(_lib, "%s_%d" % (name, self.w))(self._s, self.rounds, words, out)
+        return out[0].to_bytes(u, "little") + out[1].to_bytes(u, "little")
+
+    def encrypt(self, block):
+        return self._run("rc5ref_encrypt", block)
+
+    def decrypt(self, block):
+        return self._run("rc5ref_decrypt", block)
+
+
+def xor(x, y):
+    return bytes(a ^ b for a, b in zip(x, y))
+
+
+def cbc_pad(w, rounds, key, iv, plaintext):
+    """`rc5-CBC-PAD` of RFC 2040: CBC with PKCS#5 padding."""
+    cipher, n = RC5(w, rounds, key), w // 4
+    pad = n - len(plaintext) % n
+    data = plaintext + bytes([pad]) * pad
+    out, prev = b"", iv
+    for i in range(0, len(data), n):
+        prev = cipher.encrypt(xor(data[i:i + n], prev))
+        out += prev
+    return out
+
+
+# Rivest's five RC5-32/12/16 vectors, each encrypting the previous ciphertext, and Krovetz's RC5-64/24/24
+RIVEST97 = [
+    ("00000000000000000000000000000000", "0000000000000000", "21a5dbee154b8f6d"),
+    ("915f4619be41b2516355a50110a9ce91", "21a5dbee154b8f6d", "f7c013ac5b2b8952"),
+    ("783348e75aeb0f2fd7b169bb8dc16787", "f7c013ac5b2b8952", "2f42b3b70369fc92"),
+    ("dc49db1375a5584f6485b413b5f12baf", "2f42b3b70369fc92", "65c178b284d197cc"),
+    ("5269f149d41ba0152497574d7f153125", "65c178b284d197cc", "eb44e415da319824"),
+]
+for key, pt, ct in RIVEST97:
+    cipher = RC5(32, 12, bytes.fromhex(key))
+    assert cipher.encrypt(bytes.fromhex(pt)).hex() == ct
+    assert cipher.decrypt(bytes.fromhex(ct)).hex() == pt
+assert RC5(64, 24, bytes(range(24))).encrypt(bytes(range(16))).hex() == "a46772820edbce0235abea32ae7178da"
@@ -0,0 +1,206 @@
+use aead::{Aead, KeyInit, Payload};
+use generic_array::GenericArray;
+use std::vec::Vec;
+use typenum::Unsigned;
+
+use crate::rivest97::{RC5_32_12_16, RC5_64_16_16};
+use crate::siv::{Siv, SivAead};
+
+fn run_rfc5297_case(key_hex: &str, headers_hex: &[&str], plaintext_hex: &str, output_hex: &str) {
+    let key = hex::decode(key_hex).expect("key hex::decode failed");
+    let headers = headers_hex
+        .iter()
+        .map(|h| hex::decode(h).expect("header hex::decode failed"))
+        .collect::<Vec<_>>();
+    let plaintext = hex::decode(plaintext_hex).expect("plaintext hex::decode failed");
+    let output = hex::decode(output_hex).expect("output hex::decode failed");
+
+    let siv = Siv::<aes::Aes128>::new_from_slice(&key).expect("KeyInit failed");
+
+    let mut buffer = plaintext.clone();
+    siv.encrypt_in_place(&headers, &mut buffer)
+        .expect("encrypt failed");
+    assert_eq!(buffer, output);
+
+    siv.decrypt_in_place(&headers, &mut buffer)
+        .expect("decrypt failed");
+    assert_eq!(buffer, plaintext);
+}
+
+#[test]
+fn rfc5297_a1_deterministic_aes() {
+    run_rfc5297_case(
+        "fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff",
+        &["101112131415161718191a1b1c1d1e1f2021222324252627"],
+        "112233445566778899aabbccddee",
+        "85632d07c6e8f37f950acd320a2ecc9340c02b9690c4dc04daef7f6afe5c",
+    );
+}
+
+#[test]
+fn rfc5297_a2_nonce_based_aes() {
+    run_rfc5297_case(
+        "7f7e7d7c7b7a79787776757473727170404142434445464748494a4b4c4d4e4f",
+        &[
+            "00112233445566778899aabbccddeeffdeaddadadeaddadaffeeddccbbaa99887766554433221100",
+            "102030405060708090a0",
+            "09f911029d74e35bd84156c5635688c0",
+        ],
+        "7468697320697320736f6d6520706c61696e7465787420746f20656e6372797074207573696e67205349562d414553",
+        "7bdb6e3b432667eb06f4d14bff2fbd0fcb900f2fddbe404326601965c889bf17dba77ceb094fa663b7a3f748ba8af829ea64ad544a272e9c485b62a3fd5c0d",
+    );
+}
+
+macro_rules! siv_tests {
+    ($mod: ident, $Cipher: ty) => {
+        mod $mod {
+            use super::*;
+
+            type C = $Cipher;
+
+            #[test]
+            fn round_trip() {
+                let key = GenericArray::from_iter(
+                    (0..).take(<Siv<C> as aead::KeySizeUser>::KeySize::USIZE),
+                );
+                let siv = Siv::<C>::new(&key);
+                let aead = SivAead::<C>::new(&key);
+
+                for len in [0, 1, 7, 8, 9, 15, 16, 17, 31, 32, 33, 100] {
+                    let plaintext = (0..len).map(|i| i as u8).collect::<Vec<_>>();
+                    let headers: &[&[u8]] = &[b"first", b"", b"third"];
+
+                    let mut buffer = plaintext.clone();
+                    siv.encrypt_in_place(headers, &mut buffer)
+                        .expect("encrypt failed");
+                    assert_eq!(
+                        buffer.len(),
+                        len + <C as cipher::BlockSizeUser>::BlockSize::USIZE
+                    );
+                    siv.decrypt_in_place(headers, &mut buffer)
+                        .expect("decrypt failed");
+                    assert_eq!(buffer, plaintext);
+
+                    let nonce = Default::default();
+                    let payload = Payload {
+                        msg: &plaintext,
+                        aad: b"associated data",
+                    };
+                    let ciphertext = aead.encrypt(&nonce, payload).expect("encrypt failed");
+                    let payload = Payload {
+                        msg: &ciphertext,
+                        aad: b"associated data",
+                    };
+                    assert_eq!(
+                        aead.decrypt(&nonce, payload).expect("decrypt failed"),
+                        plaintext
+                    );
+                }
+            }
+
+            #[test]
+            fn determinism() {
+                let key = GenericArray::from_iter(
+                    (0..).take(<Siv<C> as aead::KeySizeUser>::KeySize::USIZE),
+                );
+                let siv = Siv::<C>::new(&key);
+                let aead = SivAead::<C>::new(&key);
+
+                let plaintext = b"the same block stored twice";
+
+                let mut first = plaintext.to_vec();
+                let mut second = plaintext.to_vec();
+                siv.encrypt_in_place([b"ad"], &mut first).unwrap();
+                siv.encrypt_in_place([b"ad"], &mut second).unwrap();
+                assert_eq!(first, second);
+
+                let mut other_ad = plaintext.to_vec();
+                siv.encrypt_in_place([b"AD"], &mut other_ad).unwrap();
+                assert_ne!(first, other_ad);
+
+                let mut split_ad = plaintext.to_vec();
+                siv.encrypt_in_place([&b"a"[..], &b"d"[..]], &mut split_ad)
+                    .unwrap();
+                assert_ne!(first, split_ad);
+
+                let nonce = Default::default();
+                let repeated_nonce = (
+                    aead.encrypt(&nonce, &plaintext[..]).unwrap(),
+                    aead.encrypt(&nonce, &plaintext[..]).unwrap(),
+                );
+                assert_eq!(repeated_nonce.0, repeated_nonce.1);
+
+                let other_nonce = aead
+                    .encrypt(&GenericArray::from([1; 16]), &plaintext[..])
+                    .unwrap();
+                assert_ne!(repeated_nonce.0, other_nonce);
+            }
+
+            #[test]
+            fn tamper_rejection() {
+                let key = GenericArray::from_iter(
+                    (0..).take(<Siv<C> as aead::KeySizeUser>::KeySize::USIZE),
+                );
+                let siv = Siv::<C>::new(&key);
+
+                let plaintext = b"dedup me, but do not let anyone alter me";
+                let mut sealed = plaintext.to_vec();
+                siv.encrypt_in_place([b"header"], &mut sealed).unwrap();
+
+                for bit in 0..sealed.len() * 8 {
+                    let mut tampered = sealed.clone();
+                    tampered[bit / 8] ^= 1 << (bit % 8);
+                    assert!(siv.decrypt_in_place([b"header"], &mut tampered).is_err());
+                }
+
+                let mut wrong_header = sealed.clone();
+                assert!(siv
+                    .decrypt_in_place([b"Header"], &mut wrong_header)
+                    .is_err());
+                assert_eq!(wrong_header, sealed);
+
+                let mut missing_header = sealed.clone();
+                assert!(siv
+                    .decrypt_in_place(core::iter::empty::<&[u8]>(), &mut missing_header)
+                    .is_err());
+
+                let mut truncated =
+                    sealed[..<C as cipher::BlockSizeUser>::BlockSize::USIZE - 1].to_vec();
+                assert!(siv.decrypt_in_place([b"header"], &mut truncated).is_err());
+
+                let mut untouched = sealed;
+                siv.decrypt_in_place([b"header"], &mut untouched).unwrap();
+                assert_eq!(untouched, plaintext);
+            }
+        }
+    };
+}
+
+#[test]
+fn too_many_headers() {
+    assert_eq!(Siv::<aes::Aes128>::MAX_HEADERS, 126);
+    assert_eq!(Siv::<RC5_64_16_16>::MAX_HEADERS, 126);
+    assert_eq!(Siv::<RC5_32_12_16>::MAX_HEADERS, 62);
+
+    let siv = Siv::<RC5_32_12_16>::new(&Default::default());
+    let headers = [b""; 63];
+
+    let mut buffer = Vec::new();
+    assert!(siv.encrypt_in_place(&headers[..62], &mut buffer).is_ok());
+
+    let mut buffer = Vec::new();
+    assert!(siv.encrypt_in_place(headers, &mut buffer).is_err());
+
+    let mut buffer = *b"ciphertext";
+    let tag = siv
+        .encrypt_in_place_detached(&headers[..62], &mut buffer)
+        .unwrap();
+    let ciphertext = buffer;
+    assert!(siv
+        .decrypt_in_place_detached(headers, &mut buffer, &tag)
+        .is_err());
+    assert_eq!(buffer, ciphertext);
+}
+
+siv_tests!(rc5_32_12_16, RC5_32_12_16);
+siv_tests!(rc5_64_16_16, RC5_64_16_16);
@@ -0,0 +1,115 @@
+use cipher::{BlockDecrypt, BlockEncrypt, KeyInit};
+use generic_array::GenericArray;
+use rc5_core::std_words::{LittleEndian, StdArith, StdMagic};
+use rc5_core::traits::{Arithmetics, Magic, WordByteRepr, WordSize};
+use std::vec::Vec;
+use typenum::*;
+
+use crate::rc5::{RC5Var, MAX_KEY_LEN, RC5};
+
+fn run_case<Word>(rounds: u8, key_hex: &str, plaintext_hex: &str, ciphertext_hex: &str)
+where
+    Word: WordSize + Default + Copy,
+    LittleEndian: WordByteRepr<Word>,
+    StdArith: Arithmetics<Word>,
+    StdMagic: Magic<Word>,
+    RC5Var<Word>: BlockEncrypt + BlockDecrypt,
+{
+    let key = hex::decode(key_hex).expect("key hex::decode failed");
+    let plaintext = hex::decode(plaintext_hex).expect("plaintext hex::decode failed");
+    let ciphertext = hex::decode(ciphertext_hex).expect("ciphertext hex::decode failed");
+
+    let cipher = RC5Var::<Word>::new(&key, rounds).expect("RC5Var::new failed");
+    assert_eq!(cipher.rounds(), rounds as usize);
+
+    let mut block = GenericArray::clone_from_slice(&plaintext);
+    cipher.encrypt_block(&mut block);
+    assert_eq!(block.as_slice(), ciphertext);
+
+    cipher.decrypt_block(&mut block);
+    assert_eq!(block.as_slice(), plaintext);
+}
+
+#[test]
+fn krovetz18_vectors() {
+    run_case::<u8>(12, "00010203", "0001", "212A");
+    run_case::<u16>(16, "0001020304050607", "00010203", "23A8D72E");
+    run_case::<u32>(
+        20,
+        "000102030405060708090A0B0C0D0E0F",
+        "0001020304050607",
+        "2A0EDC0E9431FF73",
+    );
+    run_case::<u64>(
+        24,
+        "000102030405060708090A0B0C0D0E0F1011121314151617",
+        "000102030405060708090A0B0C0D0E0F",
+        "A46772820EDBCE0235ABEA32AE7178DA",
+    );
+    run_case::<u128>(
+        28,
+        "000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F",
+        "000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F",
+        "ECA5910921A4F4CFDD7AD7AD20A1FCBA068EC7A7CD752D68FE914B7FE180B440",
+    );
+}
+
+#[test]
+fn rivest97_vector() {
+    run_case::<u32>(
+        12,
+        "915f4619be41b2516355a50110a9ce91",
+        "21a5dbee154b8f6d",
+        "f7c013ac5b2b8952",
+    );
+}
+
+macro_rules! matches_typed {
+    ($test_name: ident, $Word: ty, $R: ty, $B: ty) => {
+        #[test]
+        fn $test_name() {
+            let key = (0..<$B>::USIZE).map(|i| (i * 7) as u8).collect::<Vec<_>>();
+            let typed = RC5::<$Word, $R, $B>::new_from_slice(&key).unwrap();
+            let var = RC5Var::<$Word>::new(&key, <$R>::U8).unwrap();
+
+            for seed in 0..16u8 {
+                let mut expected = GenericArray::default();
+                expected.iter_mut().for_each(|b| *b = seed);
+                let mut block = expected.clone();
+
+                typed.encrypt_block(&mut expected);
+                var.encrypt_block(&mut block);
+                assert_eq!(block, expected);
+            }
+        }
+    };
+}
+
+matches_typed!(no_rounds_empty_key, u32, U0, U0);
+matches_typed!(one_round_odd_key, u16, U1, U5);
+matches_typed!(long_key, u32, U20, U255);
+matches_typed!(many_rounds, u64, U255, U16);
+
+#[test]
+fn rejects_long_keys() {
+    assert!(RC5Var::<u32>::new(&[0; MAX_KEY_LEN], 12).is_ok());
+    assert!(RC5Var::<u32>::new(&[0; MAX_KEY_LEN + 1], 12).is_err());
+}
+
+#[test]
+fn set_key_replaces_the_key() {
+    let mut cipher = RC5Var::<u32>::new(&[0xff; 32], 20).unwrap();
+    cipher
+        .set_key(
+            &hex::decode("915f4619be41b2516355a50110a9ce91").unwrap(),
+            12,
+        )
+        .unwrap();
+    assert_eq!(cipher.rounds(), 12);
+
+    let mut block = GenericArray::clone_from_slice(&hex::decode("21a5dbee154b8f6d").unwrap());
+    cipher.encrypt_block(&mut block);
+    assert_eq!(block.as_slice(), hex::decode("f7c013ac5b2b8952").unwrap());
+
+    assert!(cipher.set_key(&[0; MAX_KEY_LEN + 1], 12).is_err());
+}
@@ -0,0 +1,361 @@
+//! Wycheproof-style test vectors for RC5 in CBC with PKCS#5 padding, CMAC and EAX; see `wycheproof/gen.py`.
+//!
+//! A `valid` test must be accepted with the given output, an `invalid` one rejected, and an `acceptable`
+//! one may go either way, but must give the given output if accepted.
+
+use aead::AeadInPlace;
+use cipher::{Block, BlockCipher, BlockDecrypt, BlockEncrypt, InvalidLength};
+use cmac::digest::crypto_common::InnerInit;
+use cmac::{Cmac, CmacCore, Mac};
+use dbl::Dbl;
+use generic_array::GenericArray;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer};
+use std::collections::{BTreeMap, BTreeSet};
+use std::string::String;
+use std::vec::Vec;
+use typenum::{IsLess, Le, NonZero, Unsigned, U256};
+
+use crate::eax::Eax;
+use crate::io::{decrypt_to_vec, encrypt_to_vec, Cbc};
+use crate::rc5::RC5Var;
+
+type New<C> = fn(&[u8], u8) -> Result<C, InvalidLength>;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct Suite<T> {
+    algorithm: String,
+    number_of_tests: usize,
+    header: Vec<String>,
+    notes: BTreeMap<String, String>,
+    schema: String,
+    test_groups: Vec<Group<T>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct Group<T> {
+    #[serde(rename = "type")]
+    kind: String,
+    word_size: usize,
+    rounds: u8,
+    key_size: usize,
+    iv_size: Option<usize>,
+    tag_size: Option<usize>,
+    tests: Vec<Case<T>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Case<T> {
+    tc_id: usize,
+    comment: String,
+    flags: Vec<String>,
+    result: Outcome,
+    #[serde(flatten)]
+    fields: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Outcome {
+    Valid,
+    Invalid,
+    Acceptable,
+}
+
+#[derive(Deserialize)]
+struct IndCpaTest {
+    #[serde(deserialize_with = "from_hex")]
+    key: Vec<u8>,
+    #[serde(deserialize_with = "from_hex")]
+    iv: Vec<u8>,
+    #[serde(deserialize_with = "from_hex")]
+    msg: Vec<u8>,
+    #[serde(deserialize_with = "from_hex")]
+    ct: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+struct MacTest {
+    #[serde(deserialize_with = "from_hex")]
+    key: Vec<u8>,
+    #[serde(deserialize_with = "from_hex")]
+    msg: Vec<u8>,
+    #[serde(deserialize_with = "from_hex")]
+    tag: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+struct AeadTest {
+    #[serde(deserialize_with = "from_hex")]
+    key: Vec<u8>,
+    #[serde(deserialize_with = "from_hex")]
+    iv: Vec<u8>,
+    #[serde(deserialize_with = "from_hex")]
+    aad: Vec<u8>,
+    #[serde(deserialize_with = "from_hex")]
+    msg: Vec<u8>,
+    #[serde(deserialize_with = "from_hex")]
+    ct: Vec<u8>,
+    #[serde(deserialize_with = "from_hex")]
+    tag: Vec<u8>,
+}
+
+fn from_hex<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    hex::decode(s).map_err(serde::de::Error::custom)
+}
+
+/// Parses a suite and checks its bookkeeping: the test count, unique ids, and a note for every flag.
+fn load<T: DeserializeOwned>(json: &str, algorithm: &str, schema: &str, kind: &str) -> Suite<T> {
+    let suite: Suite<T> = serde_json::from_str(json).unwrap();
+    assert_eq!(suite.algorithm, algorithm);
+    assert_eq!(suite.schema, schema);
+    assert!(!suite.header.is_empty());
+
+    let cases: Vec<&Case<T>> = suite.test_groups.iter().flat_map(|g| &g.tests).collect();
+    assert_eq!(cases.len(), suite.number_of_tests, "{}", algorithm);
+    let ids: BTreeSet<usize> = cases.iter().map(|c| c.tc_id).collect();
+    assert_eq!(ids.len(), cases.len(), "{}: duplicate tcId", algorithm);
+    for case in &cases {
+        for flag in &case.flags {
+            assert!(
+                suite.notes.contains_key(flag),
+                "{}: no note on {}",
+                algorithm,
+                flag
+            );
+        }
+    }
+    for group in &suite.test_groups {
+        assert_eq!(group.kind, kind);
+    }
+    suite
+}
+
+/// Checks what the implementation made of a test: `Some` output if it accepted it, `None` if it rejected it.
+fn expect<T>(case: &Case<T>, actual: Option<Vec<u8>>, expected: &[u8]) {
+    match (case.result, actual) {
+        (Outcome::Valid | Outcome::Acceptable, Some(actual)) => assert_eq!(
+            hex::encode(actual),
+            hex::encode(expected),
+            "tcId {}: {}",
+            case.tc_id,
+            case.comment
+        ),
+        (Outcome::Invalid | Outcome::Acceptable, None) => {}
+        (result, actual) => panic!(
+            "tcId {} ({}): expected {:?}, got {:?}",
+            case.tc_id,
+            case.comment,
+            result,
+            actual.map(hex::encode)
+        ),
+    }
+}
+
+fn run_cbc<C>(group: &Group<IndCpaTest>, new: New<C>)
+where
+    C: BlockCipher + BlockEncrypt + BlockDecrypt,
+{
+    assert_eq!(group.iv_size, Some(8 * C::BlockSize::USIZE));
+    for case in &group.tests {
+        let t = &case.fields;
+        assert_eq!(8 * t.key.len(), group.key_size);
+        let mode = |iv: &[u8]| {
+            (iv.len() == C::BlockSize::USIZE).then(|| {
+                Cbc::new(
+                    new(&t.key, group.rounds).unwrap(),
+                    Block::<C>::from_slice(iv),
+                )
+            })
+        };
+
+        let decrypted = mode(&t.iv).and_then(|cbc| decrypt_to_vec(cbc, &t.ct).ok());
+        expect(case, decrypted, &t.msg);
+        if case.result == Outcome::Valid {
+            let encrypted = encrypt_to_vec(mode(&t.iv).unwrap(), &t.msg).unwrap();
+            expect(case, Some(encrypted), &t.ct);
+        }
+    }
+}
+
+fn run_cmac<C>(group: &Group<MacTest>, new: New<C>)
+where
+    C: BlockCipher + BlockEncrypt + Clone,
+    Block<C>: Dbl,
+    C::BlockSize: IsLess<U256>,
+    Le<C::BlockSize, U256>: NonZero,
+{
+    let tag_len = group.tag_size.unwrap() / 8;
+    assert!(tag_len <= C::BlockSize::USIZE);
+    for case in &group.tests {
+        let t = &case.fields;
+        assert_eq!(8 * t.key.len(), group.key_size);
+        let mac = || {
+            let mut mac =
+                Cmac::<C>::from_core(CmacCore::inner_init(new(&t.key, group.rounds).unwrap()));
+            mac.update(&t.msg);
+            mac
+        };
+
+        let verified = (t.tag.len() == tag_len && mac().verify_truncated_left(&t.tag).is_ok())
+            .then(|| t.tag.clone());
+        expect(case, verified, &t.tag);
+        if case.result != Outcome::Invalid {
+            let tag = mac().finalize().into_bytes();
+            expect(case, Some(tag[..tag_len].to_vec()), &t.tag);
+        }
+    }
+}
+
+fn run_eax<C>(group: &Group<AeadTest>, new: New<C>)
+where
+    C: BlockCipher + BlockEncrypt + Clone,
+    Block<C>: Dbl,
+    C::BlockSize: IsLess<U256>,
+    Le<C::BlockSize, U256>: NonZero,
+{
+    let n = C::BlockSize::USIZE;
+    assert_eq!(group.iv_size, Some(8 * n));
+    assert_eq!(group.tag_size, Some(8 * n));
+    for case in &group.tests {
+        let t = &case.fields;
+        assert_eq!(8 * t.key.len(), group.key_size);
+        let eax = Eax::from(new(&t.key, group.rounds).unwrap());
+
+        let decrypted = (t.iv.len() == n && t.tag.len() == n)
+            .then(|| {
+                let mut buffer = t.ct.clone();
+                eax.decrypt_in_place_detached(
+                    GenericArray::from_slice(&t.iv),
+                    &t.aad,
+                    &mut buffer,
+                    GenericArray::from_slice(&t.tag),
+                )
+                .ok()
+                .map(|()| buffer)
+            })
+            .flatten();
+        expect(case, decrypted, &t.msg);
+        if case.result == Outcome::Valid {
+            let mut buffer = t.msg.clone();
+            let tag = eax
+                .encrypt_in_place_detached(GenericArray::from_slice(&t.iv), &t.aad, &mut buffer)
+                .unwrap();
+            buffer.extend_from_slice(&tag);
+            expect(case, Some(buffer), &[t.ct.as_slice(), &t.tag].concat());
+        }
+    }
+}
+
+fn cbc_suite() -> Suite<IndCpaTest> {
+    load(
+        include_str!("wycheproof/rc5_cbc_pkcs5_test.json"),
+        "RC5-CBC-PKCS5",
+        "ind_cpa_test_schema.json",
+        "IndCpaTest",
+    )
+}
+
+fn cmac_suite() -> Suite<MacTest> {
+    load(
+        include_str!("wycheproof/rc5_cmac_test.json"),
+        "RC5-CMAC",
+        "mac_test_schema.json",
+        "MacTest",
+    )
+}
+
+fn eax_suite() -> Suite<AeadTest> {
+    load(
+        include_str!("wycheproof/rc5_eax_test.json"),
+        "RC5-EAX",
+        "aead_test_schema.json",
+        "AeadTest",
+    )
+}
+
+/// The flags and outcomes of a suite's tests.
+fn coverage<T>(suite: Suite<T>) -> (BTreeSet<String>, BTreeSet<Outcome>) {
+    let cases = || suite.test_groups.iter().flat_map(|g| &g.tests);
+    (
+        cases().flat_map(|c| c.flags.clone()).collect(),
+        cases().map(|c| c.result).collect(),
+    )
+}
+
+#[test]
+fn cbc_pkcs5() {
+    for group in &cbc_suite().test_groups {
+        match group.word_size {
+            32 => run_cbc(group, RC5Var::<u32>::new),
+            64 => run_cbc(group, RC5Var::<u64>::new),
+            w => panic!("{}-bit words", w),
+        }
+    }
+}
+
+#[test]
+fn cmac() {
+    for group in &cmac_suite().test_groups {
+        match group.word_size {
+            32 => run_cmac(group, RC5Var::<u32>::new),
+            64 => run_cmac(group, RC5Var::<u64>::new),
+            w => panic!("{}-bit words", w),
+        }
+    }
+}
+
+#[test]
+fn eax() {
+    for group in &eax_suite().test_groups {
+        match group.word_size {
+            32 => run_eax(group, RC5Var::<u32>::new),
+            64 => run_eax(group, RC5Var::<u64>::new),
+            w => panic!("{}-bit words", w),
+        }
+    }
+}
+
+#[test]
+fn corpus() {
+    let (flags, outcomes) = coverage(cbc_suite());
+    assert!(
+        ["BadPadding", "WrongLength", "InvalidIvSize"]
+            .iter()
+            .all(|f| flags.contains(*f)),
+        "{:?}",
+        flags
+    );
+    assert_eq!(outcomes, [Outcome::Valid, Outcome::Invalid].into());
+
+    let (flags, outcomes) = coverage(cmac_suite());
+    assert!(
+        ["ModifiedTag", "TruncatedTag", "ShortTag"]
+            .iter()
+            .all(|f| flags.contains(*f)),
+        "{:?}",
+        flags
+    );
+    assert_eq!(
+        outcomes,
+        [Outcome::Valid, Outcome::Invalid, Outcome::Acceptable].into()
+    );
+
+    let (flags, outcomes) = coverage(eax_suite());
+    let expected = [
+        "ModifiedTag",
+        "TruncatedTag",
+        "ModifiedNonce",
+        "ModifiedAad",
+        "ModifiedCiphertext",
+        "InvalidIvSize",
+        "LongAad",
+        "CounterWrap",
+    ];
+    assert!(expected.iter().all(|f| flags.contains(*f)), "{:?}", flags);
+    assert_eq!(outcomes, [Outcome::Valid, Outcome::Invalid].into());
+}
@@ -0,0 +1,262 @@
+#!/usr/bin/env python3
+"""Writes the Wycheproof-style test vector files next to this script.
+
+The files follow the schemas of Project Wycheproof (https://github.com/C2SP/wycheproof):
+`ind_cpa_test_schema.json` for CBC with PKCS#5 padding, `mac_test_schema.json` for CMAC and
+`aead_test_schema.json` for EAX, with each test group also naming the RC5 `wordSize` and `rounds`.
+Every test has a `result`: `valid` must be accepted with the given output, `invalid` must be rejected,
+and `acceptable` may go either way, but must give the given output if accepted.
+
+The vectors are computed with RC5 from the C reference of `rc5-reference`, by way of `../rc5ref.py`, and the
+modes written out from their specifications: CBC-PAD from RFC 2040, CMAC from NIST SP 800-38B and EAX from
+Bellare, Rogaway and Wagner.
+"""
+
+import json
+import os
+import sys
+
+sys.path.insert(0, os.path.join(os.path.dirname(os.path.abspath(__file__)), ".."))
+from rc5ref import RC5, xor  # noqa: E402
+
+# the CMAC doubling constants R_b of SP 800-38B, by block size in bytes
+R_B = {8: 0x1B, 16: 0x87}
+
+NOTES = {
+    "BadPadding": "The padding is not PKCS#5 padding; decryption must fail without revealing why.",
+    "WrongLength": "The ciphertext is not a whole, non-zero number of blocks.",
+    "InvalidIvSize": "The IV or nonce is not one block long.",
+    "ModifiedTag": "The tag has been modified.",
+    "TruncatedTag": "The tag is shorter than the tag size of the test group.",
+    "ModifiedNonce": "The nonce has been modified after encryption.",
+    "ModifiedAad": "The associated data has been modified after encryption.",
+    "ModifiedCiphertext": "The ciphertext has been modified after encryption.",
+    "ShortTag": "The tag is half a block: too short for most uses, but a valid truncation.",
+    "LongAad": "The associated data is 2048 bytes long: EAX sets no maximum length, so there is none to test.",
+    "CounterWrap": "The CTR counter of EAX wraps around the whole block.",
+}
+
+
+def pad(n, data):
+    p = n - len(data) % n
+    return data + bytes([p]) * p
+
+
+def cbc_raw(cipher, iv, data):
+    out, prev = b"", iv
+    for i in range(0, len(data), cipher.n):
+        prev = cipher.encrypt(xor(data[i:i + cipher.n], prev))
+        out += prev
+    return out
+
+
+def dbl(block):
+    n = int.from_bytes(block, "big") << 1
+    if n >> (8 * len(block)):
+        n ^= R_B[len(block)]
+    return (n & ((1 << (8 * len(block))) - 1)).to_bytes(len(block), "big")
+
+
+def cmac(cipher, data):
+    n = cipher.n
+    k1 = dbl(cipher.encrypt(bytes(n)))
+    k2 = dbl(k1)
+    if data and len(data) % n == 0:
+        last = xor(data[-n:], k1)
+        data = data[:-n]
+    else:
+        tail = data[len(data) - len(data) % n:] if data else b""
+        last = xor(tail + b"\x80" + bytes(n - len(tail) - 1), k2)
+        data = data[:len(data) - len(tail)]
+    mac = bytes(n)
+    for i in range(0, len(data), n):
+        mac = cipher.encrypt(xor(mac, data[i:i + n]))
+    return cipher.encrypt(xor(mac, last))
+
+
+def omac(cipher, t, data):
+    return cmac(cipher, bytes(cipher.n - 1) + bytes([t]) + data)
+
+
+def ctr(cipher, counter, data):
+    n, out = cipher.n, b""
+    c = int.from_bytes(counter, "big")
+    for i in range(0, len(data), n):
+        keystream = cipher.encrypt(c.to_bytes(n, "big"))
+        out += xor(data[i:i + n], keystream)
+        c = (c + 1) % (1 << (8 * n))
+    return out
+
+
+def eax(cipher, nonce, aad, msg):
+    n = omac(cipher, 0, nonce)
+    h = omac(cipher, 1, aad)
+    ct = ctr(cipher, n, msg)
+    c = omac(cipher, 2, ct)
+    return ct, xor(xor(n, c), h)
+
+
+def flip(data, byte, bit=0):
+    data = bytearray(data)
+    data[byte] ^= 1 << bit
+    return bytes(data)
+
+
+def pattern(length, seed):
+    return bytes((seed + 7 * i) % 256 for i in range(length))
+
+
+class Suite:
+    def __init__(self, algorithm, schema):
+        self.algorithm, self.schema = algorithm, schema
+        self.groups, self.count = [], 0
+
+    def group(self, **fields):
+        group = dict(fields, tests=[])
+        self.groups.append(group)
+        return group
+
+    def test(self, group, comment, result, flags=(), **fields):
+        self.count += 1
+        test = {"tcId": self.count, "comment": comment, "flags": list(flags)}
+        test.update({k: v.hex() for k, v in fields.items()})
+        test["result"] = result
+        group["tests"].append(test)
+
+    def write(self, name):
+        used = sorted({flag for g in self.groups for t in g["tests"] for flag in t["flags"]})
+        suite = {
+            "algorithm": self.algorithm,
+            "numberOfTests": self.count,
+            "header": ["Test vectors of type " + self.schema.split("_test")[0] + ", written by gen.py."],
+            "notes": {flag: NOTES[flag] for flag in used},
+            "schema": self.schema,
+            "testGroups": self.groups,
+        }
+        here = os.path.dirname(os.path.abspath(__file__))
+        with open(os.path.join(here, name), "w") as f:
+            json.dump(suite, f, indent=2)
+            f.write("\n")
+
+
+def cbc_suite():
+    suite = Suite("RC5-CBC-PKCS5", "ind_cpa_test_schema.json")
+    for w, rounds, key_len in [(32, 12, 16), (64, 16, 24)]:
+        n = w // 4
+        group = suite.group(type="IndCpaTest", wordSize=w, rounds=rounds, keySize=8 * key_len, ivSize=8 * n)
+        key, iv = pattern(key_len, w), pattern(n, 0x80)
+        cipher = RC5(w, rounds, key)
+
+        for length in [0, 1, n - 1, n, n + 1, 2 * n, 5 * n + 3]:
+            msg = pattern(length, length)
+            comment = "empty message" if length == 0 else "%d-byte message" % length
+            suite.test(group, comment, "valid", key=key, iv=iv, msg=msg, ct=cbc_raw(cipher, iv, pad(n, msg)))
+
+        # the padding of the last block, written out before encryption
+        body = pattern(n, 1)
+        bad = [
+            ("padding byte 0", bytes(n)),
+            ("padding longer than a block", bytes([n + 1]) * n),
+            ("padding bytes differ", bytes(n - 2) + b"\x01\x02"),
+            ("padding bytes differ from the length", bytes(n - 3) + b"\x02\x03\x03"),
+            ("no padding", pattern(n, 9)[:-1] + b"\x55"),
+            ("padding 0xff", b"\xff" * n),
+        ]
+        for comment, last in bad:
+            msg = body + last
+            suite.test(group, comment, "invalid", ["BadPadding"], key=key, iv=iv, msg=b"",
+                       ct=cbc_raw(cipher, iv, msg))
+
+        ct = cbc_raw(cipher, iv, pad(n, pattern(2 * n, 3)))
+        suite.test(group, "empty ciphertext", "invalid", ["WrongLength"], key=key, iv=iv, msg=b"", ct=b"")
+        suite.test(group, "ciphertext truncated by a byte", "invalid", ["WrongLength"], key=key, iv=iv,
+                   msg=b"", ct=ct[:-1])
+        suite.test(group, "ciphertext extended by a byte", "invalid", ["WrongLength"], key=key, iv=iv,
+                   msg=b"", ct=ct + b"\x00")
+        suite.test(group, "IV a byte short", "invalid", ["InvalidIvSize"], key=key, iv=iv[:-1],
+                   msg=b"", ct=ct)
+        suite.test(group, "empty IV", "invalid", ["InvalidIvSize"], key=key, iv=b"", msg=b"", ct=ct)
+
+        # CBC cannot detect a modified IV: the first block decrypts with the same bits flipped
+        msg = pattern(2 * n, 3)
+        suite.test(group, "modified IV changes the first block", "valid", key=key, iv=flip(iv, 0),
+                   msg=flip(msg, 0), ct=ct)
+    suite.write("rc5_cbc_pkcs5_test.json")
+
+
+def cmac_suite():
+    suite = Suite("RC5-CMAC", "mac_test_schema.json")
+    for w, rounds, key_len, tag_len, short in [(32, 12, 16, 8, False), (32, 12, 16, 4, True),
+                                              (64, 16, 16, 16, False)]:
+        n = w // 4
+        group = suite.group(type="MacTest", wordSize=w, rounds=rounds, keySize=8 * key_len,
+                            tagSize=8 * tag_len)
+        key = pattern(key_len, w + tag_len)
+        cipher = RC5(w, rounds, key)
+        result, flags = ("acceptable", ["ShortTag"]) if short else ("valid", [])
+
+        for length in [0, 1, n - 1, n, n + 1, 2 * n, 64]:
+            msg = pattern(length, 2 * length)
+            tag = cmac(cipher, msg)[:tag_len]
+            comment = "empty message" if length == 0 else "%d-byte message" % length
+            suite.test(group, comment, result, flags, key=key, msg=msg, tag=tag)
+
+        msg = pattern(3 * n, 5)
+        tag = cmac(cipher, msg)[:tag_len]
+        for comment, bad in [
+            ("first tag bit flipped", flip(tag, 0)),
+            ("last tag bit flipped", flip(tag, tag_len - 1, 7)),
+            ("all-zero tag", bytes(tag_len)),
+            ("tag of the message with a bit flipped", cmac(cipher, flip(msg, 0))[:tag_len]),
+        ]:
+            suite.test(group, comment, "invalid", ["ModifiedTag"], key=key, msg=msg, tag=bad)
+        suite.test(group, "tag a byte short", "invalid", ["TruncatedTag"], key=key, msg=msg, tag=tag[:-1])
+        suite.test(group, "empty tag", "invalid", ["TruncatedTag"], key=key, msg=msg, tag=b"")
+    suite.write("rc5_cmac_test.json")
+
+
+def eax_suite():
+    suite = Suite("RC5-EAX", "aead_test_schema.json")
+    for w, rounds, key_len in [(32, 12, 16), (64, 16, 16)]:
+        n = w // 4
+        group = suite.group(type="AeadTest", wordSize=w, rounds=rounds, keySize=8 * key_len, ivSize=8 * n,
+                            tagSize=8 * n)
+        key, nonce = pattern(key_len, w + 1), pattern(n, 0x40)
+        cipher = RC5(w, rounds, key)
+
+        def test(comment, result, flags, nonce, aad, msg, ct=None, tag=None):
+            if ct is None:
+                ct, tag = eax(cipher, nonce, aad, msg)
+            suite.test(group, comment, result, flags, key=key, iv=nonce, aad=aad, msg=msg, ct=ct, tag=tag)
+
+        test("empty message and associated data", "valid", [], nonce, b"", b"")
+        test("empty message", "valid", [], nonce, pattern(n + 3, 1), b"")
+        for length in [1, n - 1, n, n + 1, 3 * n + 5]:
+            test("%d-byte message" % length, "valid", [], nonce, pattern(5, 2), pattern(length, 3))
+        test("2048 bytes of associated data", "valid", ["LongAad"], nonce, pattern(2048, 4), pattern(n, 5))
+        test("counter wraps around", "valid", ["CounterWrap"], b"\xff" * n, b"", pattern(4 * n, 6))
+
+        aad, msg = pattern(7, 7), pattern(2 * n + 1, 8)
+        ct, tag = eax(cipher, nonce, aad, msg)
+        for comment, bad in [
+            ("first tag bit flipped", flip(tag, 0)),
+            ("last tag bit flipped", flip(tag, n - 1, 7)),
+            ("all-zero tag", bytes(n)),
+        ]:
+            test(comment, "invalid", ["ModifiedTag"], nonce, aad, msg, ct, bad)
+        test("tag a byte short", "invalid", ["TruncatedTag"], nonce, aad, msg, ct, tag[:-1])
+        test("tag half a block", "invalid", ["TruncatedTag"], nonce, aad, msg, ct, tag[:n // 2])
+        test("empty tag", "invalid", ["TruncatedTag"], nonce, aad, msg, ct, b"")
+        test("nonce bit flipped", "invalid", ["ModifiedNonce"], flip(nonce, 0), aad, msg, ct, tag)
+        test("associated data bit flipped", "invalid", ["ModifiedAad"], nonce, flip(aad, 3), msg, ct, tag)
+        test("associated data dropped", "invalid", ["ModifiedAad"], nonce, b"", msg, ct, tag)
+        test("ciphertext bit flipped", "invalid", ["ModifiedCiphertext"], nonce, aad, msg, flip(ct, 2), tag)
+        test("ciphertext truncated", "invalid", ["ModifiedCiphertext"], nonce, aad, msg, ct[:-1], tag)
+        test("nonce a byte short", "invalid", ["InvalidIvSize"], nonce[:-1], aad, msg, ct, tag)
+        test("empty nonce", "invalid", ["InvalidIvSize"], b"", aad, msg, ct, tag)
+    suite.write("rc5_eax_test.json")
+
+
+cbc_suite()
+cmac_suite()
+eax_suite()
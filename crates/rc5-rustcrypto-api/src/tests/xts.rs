@@ -0,0 +1,232 @@
+use cipher::KeyInit;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::vec::Vec;
+
+use crate::rivest97::RC5_64_16_16;
+use crate::xts::{Error, Xts};
+
+fn run_ieee1619_case(key_hex: &str, sector: u64, plaintext_hex: &str, ciphertext_hex: &str) {
+    let xts = Xts::<aes::Aes128>::new_from_slice(&hex::decode(key_hex).unwrap()).unwrap();
+    let plaintext = hex::decode(plaintext_hex).unwrap();
+    let ciphertext = hex::decode(ciphertext_hex).unwrap();
+
+    let mut buf = plaintext.clone();
+    xts.encrypt_sector(sector, &mut buf).unwrap();
+    assert_eq!(hex::encode(&buf), hex::encode(&ciphertext));
+
+    xts.decrypt_sector(sector, &mut buf).unwrap();
+    assert_eq!(buf, plaintext);
+}
+
+#[test]
+fn ieee1619_vector_1() {
+    run_ieee1619_case(
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        0,
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "917cf69ebd68b2ec9b9fe9a3eadda692cd43d2f59598ed858c02c2652fbf922e",
+    );
+}
+
+#[test]
+fn ieee1619_vector_2() {
+    run_ieee1619_case(
+        "1111111111111111111111111111111122222222222222222222222222222222",
+        0x3333333333,
+        "4444444444444444444444444444444444444444444444444444444444444444",
+        "c454185e6a16936e39334038acef838bfb186fff7480adc4289382ecd6d394f0",
+    );
+}
+
+#[test]
+fn ieee1619_vector_15_stealing() {
+    run_ieee1619_case(
+        "fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0bfbebdbcbbbab9b8b7b6b5b4b3b2b1b0",
+        0x123456789a,
+        "000102030405060708090a0b0c0d0e0f10",
+        "6c1625db4671522d3d7599601de7ca09ed",
+    );
+}
+
+#[test]
+fn ieee1619_vector_16_stealing() {
+    run_ieee1619_case(
+        "fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0bfbebdbcbbbab9b8b7b6b5b4b3b2b1b0",
+        0x123456789a,
+        "000102030405060708090a0b0c0d0e0f1011",
+        "d069444b7a7e0cab09e24447d24deb1fedbf",
+    );
+}
+
+#[test]
+fn ieee1619_vector_17_stealing() {
+    run_ieee1619_case(
+        "fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0bfbebdbcbbbab9b8b7b6b5b4b3b2b1b0",
+        0x123456789a,
+        "000102030405060708090a0b0c0d0e0f101112",
+        "e5df1351c0544ba1350b3363cd8ef4beedbf9d",
+    );
+}
+
+fn xts() -> Xts<RC5_64_16_16> {
+    Xts::new(&(0..32).collect())
+}
+
+#[test]
+fn round_trip_all_lengths() {
+    let xts = xts();
+
+    for len in 16..=80 {
+        let plaintext = (0..len).map(|i| i as u8).collect::<Vec<_>>();
+        let mut buf = plaintext.clone();
+
+        xts.encrypt_sector(7, &mut buf).unwrap();
+        assert_ne!(buf, plaintext);
+        xts.decrypt_sector(7, &mut buf).unwrap();
+        assert_eq!(buf, plaintext);
+    }
+}
+
+#[test]
+fn sector_number_is_the_tweak() {
+    let xts = xts();
+    let plaintext = [0x5au8; 48];
+
+    let mut first = plaintext;
+    let mut second = plaintext;
+    xts.encrypt_sector(1, &mut first).unwrap();
+    xts.encrypt_sector(2, &mut second).unwrap();
+    assert_ne!(first, second);
+
+    // identical blocks within a sector encrypt differently, too
+    assert_ne!(first[..16], first[16..32]);
+
+    let mut wrong_sector = first;
+    xts.decrypt_sector(2, &mut wrong_sector).unwrap();
+    assert_ne!(wrong_sector, plaintext);
+}
+
+#[test]
+fn rejects_bad_lengths() {
+    let xts = xts();
+
+    assert_eq!(
+        xts.encrypt_sector(0, &mut [0; 15]),
+        Err(Error::SectorTooShort)
+    );
+    assert_eq!(xts.decrypt_sector(0, &mut []), Err(Error::SectorTooShort));
+    assert_eq!(
+        xts.encrypt_sectors(0, 8, &mut [0; 64]),
+        Err(Error::SectorTooShort)
+    );
+    assert_eq!(
+        xts.encrypt_sectors(0, 512, &mut [0; 1000]),
+        Err(Error::PartialSector)
+    );
+}
+
+const SECTOR_SIZE: usize = 520;
+const SECTORS: u64 = 64;
+
+struct Image {
+    path: std::path::PathBuf,
+    file: std::fs::File,
+}
+
+impl Image {
+    fn create(name: &str) -> Self {
+        let path =
+            std::env::temp_dir().join(format!("rc5-xts-{}-{}.img", name, std::process::id()));
+        let file = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&path)
+            .expect("create image");
+        Self { path, file }
+    }
+
+    fn read_sectors(&mut self, first: u64, count: u64) -> Vec<u8> {
+        let mut buf = vec![0u8; count as usize * SECTOR_SIZE];
+        self.file
+            .seek(SeekFrom::Start(first * SECTOR_SIZE as u64))
+            .unwrap();
+        self.file.read_exact(&mut buf).unwrap();
+        buf
+    }
+
+    fn write_sectors(&mut self, first: u64, buf: &[u8]) {
+        self.file
+            .seek(SeekFrom::Start(first * SECTOR_SIZE as u64))
+            .unwrap();
+        self.file.write_all(buf).unwrap();
+    }
+
+    fn transform(&mut self, first: u64, count: u64, f: impl FnOnce(u64, &mut [u8])) {
+        let mut buf = self.read_sectors(first, count);
+        f(first, &mut buf);
+        self.write_sectors(first, &buf);
+    }
+}
+
+impl Drop for Image {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+#[test]
+fn image_file_sector_ranges() {
+    let xts = xts();
+    let rekeyed = Xts::<RC5_64_16_16>::new(&(100..132).collect());
+
+    let plaintext = (0..SECTORS as usize * SECTOR_SIZE)
+        .map(|i| (i / SECTOR_SIZE) as u8 ^ (i % 7) as u8)
+        .collect::<Vec<_>>();
+
+    let mut image = Image::create("ranges");
+    image.write_sectors(0, &plaintext);
+
+    image.transform(0, SECTORS, |first, buf| {
+        xts.encrypt_sectors(first, SECTOR_SIZE, buf).unwrap()
+    });
+    let encrypted = image.read_sectors(0, SECTORS);
+    assert_eq!(encrypted.len(), plaintext.len());
+    for (sector, (enc, plain)) in encrypted
+        .chunks(SECTOR_SIZE)
+        .zip(plaintext.chunks(SECTOR_SIZE))
+        .enumerate()
+    {
+        assert_ne!(enc, plain, "sector #{} left unencrypted", sector);
+    }
+
+    // decrypting a range in the middle touches only that range
+    image.transform(10, 5, |first, buf| {
+        xts.decrypt_sectors(first, SECTOR_SIZE, buf).unwrap()
+    });
+    let partially_decrypted = image.read_sectors(0, SECTORS);
+    let range = 10 * SECTOR_SIZE..15 * SECTOR_SIZE;
+    assert_eq!(partially_decrypted[range.clone()], plaintext[range.clone()]);
+    assert_eq!(partially_decrypted[..range.start], encrypted[..range.start]);
+    assert_eq!(partially_decrypted[range.end..], encrypted[range.end..]);
+    image.transform(10, 5, |first, buf| {
+        xts.encrypt_sectors(first, SECTOR_SIZE, buf).unwrap()
+    });
+    assert_eq!(image.read_sectors(0, SECTORS), encrypted);
+
+    // re-encrypt arbitrary ranges in place under another key, one sector or many at a time
+    for (first, count) in [(0, 1), (1, 20), (21, 1), (22, 41), (63, 1)] {
+        image.transform(first, count, |first, buf| {
+            xts.decrypt_sectors(first, SECTOR_SIZE, buf).unwrap();
+            rekeyed.encrypt_sectors(first, SECTOR_SIZE, buf).unwrap();
+        });
+    }
+
+    for sector in (0..SECTORS).rev() {
+        image.transform(sector, 1, |sector, buf| {
+            rekeyed.decrypt_sector(sector, buf).unwrap()
+        });
+    }
+    assert_eq!(image.read_sectors(0, SECTORS), plaintext);
+}
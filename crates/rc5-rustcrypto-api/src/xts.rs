@@ -0,0 +1,203 @@
+//! XTS ([IEEE 1619](https://doi.org/10.1109/IEEESTD.2008.4493450)): tweakable sector encryption
+//! for 128-bit block ciphers, with ciphertext stealing for sectors that are not a whole number of blocks.
+//!
+//! The tweak of a sector is its number, encrypted under the second key. Each block of the sector uses
+//! that tweak multiplied by `α^j` in GF(2^128), `j` being the block's index within the sector.
+
+use cipher::{Block, BlockCipher, BlockDecrypt, BlockEncrypt, Key, KeyInit, KeySizeUser};
+use core::ops::Mul;
+use generic_array::{ArrayLength, GenericArray};
+use typenum::{Prod, Unsigned, U16};
+
+const BLOCK: usize = U16::USIZE;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// A sector must be at least one block (16 bytes) long.
+    SectorTooShort,
+    /// The buffer is not a whole number of sectors.
+    PartialSector,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::SectorTooShort => write!(f, "sector is shorter than a block"),
+            Self::PartialSector => write!(f, "buffer is not a whole number of sectors"),
+        }
+    }
+}
+
+/// XTS over a 128-bit block cipher `C` (e.g. `RC5<u64, ...>`).
+///
+/// The key is twice the size of the cipher's key: the left half encrypts data, the right half tweaks.
+#[derive(Clone)]
+pub struct Xts<C> {
+    data: C,
+    tweak: C,
+}
+
+impl<C> KeySizeUser for Xts<C>
+where
+    C: KeySizeUser,
+    C::KeySize: Mul<typenum::U2>,
+    Prod<C::KeySize, typenum::U2>: ArrayLength<u8>,
+{
+    type KeySize = Prod<C::KeySize, typenum::U2>;
+}
+
+impl<C> KeyInit for Xts<C>
+where
+    C: KeyInit,
+    C::KeySize: Mul<typenum::U2>,
+    Prod<C::KeySize, typenum::U2>: ArrayLength<u8>,
+{
+    fn new(key: &Key<Self>) -> Self {
+        let (data_key, tweak_key) = key.split_at(C::KeySize::USIZE);
+        Self::from_ciphers(
+            C::new(Key::<C>::from_slice(data_key)),
+            C::new(Key::<C>::from_slice(tweak_key)),
+        )
+    }
+}
+
+impl<C> Xts<C> {
+    /// Builds XTS from two independently keyed ciphers.
+    pub fn from_ciphers(data: C, tweak: C) -> Self {
+        Self { data, tweak }
+    }
+}
+
+impl<C> Xts<C>
+where
+    C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+{
+    /// Encrypts one sector in place. The sector may be any length of at least 16 bytes.
+    pub fn encrypt_sector(&self, sector: u64, buf: &mut [u8]) -> Result<(), Error> {
+        let blocks = Self::full_blocks(buf)?;
+        let tail = buf.len() % BLOCK;
+        let mut tweak = self.initial_tweak(sector);
+
+        for block in buf.chunks_exact_mut(BLOCK).take(blocks - 1) {
+            xex(block, &tweak, |b| self.data.encrypt_block(b));
+            tweak = mul_alpha(tweak);
+        }
+
+        let (last_full, tail_bytes) = buf[(blocks - 1) * BLOCK..].split_at_mut(BLOCK);
+        xex(last_full, &tweak, |b| self.data.encrypt_block(b));
+
+        if tail > 0 {
+            tweak = mul_alpha(tweak);
+
+            let mut stolen = [0u8; BLOCK];
+            stolen[..tail].copy_from_slice(tail_bytes);
+            stolen[tail..].copy_from_slice(&last_full[tail..]);
+            tail_bytes.copy_from_slice(&last_full[..tail]);
+
+            xex(&mut stolen, &tweak, |b| self.data.encrypt_block(b));
+            last_full.copy_from_slice(&stolen);
+        }
+
+        Ok(())
+    }
+
+    /// Decrypts one sector in place.
+    pub fn decrypt_sector(&self, sector: u64, buf: &mut [u8]) -> Result<(), Error> {
+        let blocks = Self::full_blocks(buf)?;
+        let tail = buf.len() % BLOCK;
+        let mut tweak = self.initial_tweak(sector);
+
+        for block in buf.chunks_exact_mut(BLOCK).take(blocks - 1) {
+            xex(block, &tweak, |b| self.data.decrypt_block(b));
+            tweak = mul_alpha(tweak);
+        }
+
+        let (last_full, tail_bytes) = buf[(blocks - 1) * BLOCK..].split_at_mut(BLOCK);
+
+        if tail > 0 {
+            xex(last_full, &mul_alpha(tweak), |b| self.data.decrypt_block(b));
+
+            let mut stolen = [0u8; BLOCK];
+            stolen[..tail].copy_from_slice(tail_bytes);
+            stolen[tail..].copy_from_slice(&last_full[tail..]);
+            tail_bytes.copy_from_slice(&last_full[..tail]);
+
+            last_full.copy_from_slice(&stolen);
+        }
+
+        xex(last_full, &tweak, |b| self.data.decrypt_block(b));
+
+        Ok(())
+    }
+
+    /// Encrypts consecutive sectors of `sector_size` bytes in place, the first of them being `first_sector`.
+    pub fn encrypt_sectors(
+        &self,
+        first_sector: u64,
+        sector_size: usize,
+        buf: &mut [u8],
+    ) -> Result<(), Error> {
+        Self::for_each_sector(first_sector, sector_size, buf, |sector, buf| {
+            self.encrypt_sector(sector, buf)
+        })
+    }
+
+    /// Decrypts consecutive sectors of `sector_size` bytes in place, the first of them being `first_sector`.
+    pub fn decrypt_sectors(
+        &self,
+        first_sector: u64,
+        sector_size: usize,
+        buf: &mut [u8],
+    ) -> Result<(), Error> {
+        Self::for_each_sector(first_sector, sector_size, buf, |sector, buf| {
+            self.decrypt_sector(sector, buf)
+        })
+    }
+
+    fn for_each_sector(
+        first_sector: u64,
+        sector_size: usize,
+        buf: &mut [u8],
+        mut f: impl FnMut(u64, &mut [u8]) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        if sector_size < BLOCK {
+            return Err(Error::SectorTooShort);
+        }
+        if !buf.len().is_multiple_of(sector_size) {
+            return Err(Error::PartialSector);
+        }
+
+        buf.chunks_exact_mut(sector_size)
+            .zip(first_sector..)
+            .try_for_each(|(buf, sector)| f(sector, buf))
+    }
+
+    fn full_blocks(buf: &[u8]) -> Result<usize, Error> {
+        match buf.len() / BLOCK {
+            0 => Err(Error::SectorTooShort),
+            blocks => Ok(blocks),
+        }
+    }
+
+    fn initial_tweak(&self, sector: u64) -> u128 {
+        let mut tweak = Block::<C>::from((sector as u128).to_le_bytes());
+        self.tweak.encrypt_block(&mut tweak);
+        u128::from_le_bytes(tweak.into())
+    }
+}
+
+/// `E(P ^ T) ^ T` (or `D(C ^ T) ^ T`) on a single block.
+fn xex(block: &mut [u8], tweak: &u128, f: impl FnOnce(&mut GenericArray<u8, U16>)) {
+    let tweak = tweak.to_le_bytes();
+    let block = GenericArray::<u8, U16>::from_mut_slice(block);
+
+    block.iter_mut().zip(&tweak).for_each(|(b, t)| *b ^= t);
+    f(block);
+    block.iter_mut().zip(&tweak).for_each(|(b, t)| *b ^= t);
+}
+
+/// Multiplication by the primitive element `α` of GF(2^128), modulo `x^128 + x^7 + x^2 + x + 1`,
+/// in the little-endian convention of IEEE 1619.
+fn mul_alpha(tweak: u128) -> u128 {
+    (tweak << 1) ^ if tweak >> 127 == 1 { 0x87 } else { 0 }
+}
@@ -0,0 +1,121 @@
+//! Properties over random keys, round counts and blocks; proptest shrinks any failing case to a minimal one.
+
+use cipher::{Block, BlockDecrypt, BlockEncrypt, BlockSizeUser, KeyInit, KeySizeUser};
+use proptest::collection::vec;
+use proptest::prelude::*;
+use rc5_core::strange_words::{
+    StrangeArithmetics, StrangeByteRepr, StrangeMagic, W24bit, W32bit, W80bit,
+};
+use rc5_rustcrypto_api::krovetz18::*;
+use rc5_rustcrypto_api::rc5::RC5Var;
+use rc5_rustcrypto_api::rivest97::*;
+
+use super::*;
+
+type Strange<Word> = RC5Var<Word, StrangeByteRepr, StrangeArithmetics, StrangeMagic>;
+
+fn key() -> impl Strategy<Value = Vec<u8>> {
+    vec(any::<u8>(), 0..=64)
+}
+
+fn block<C: BlockSizeUser>() -> impl Strategy<Value = Vec<u8>> {
+    vec(any::<u8>(), C::block_size())
+}
+
+fn encrypt<C: BlockEncrypt>(cipher: &C, plaintext: &[u8]) -> Vec<u8> {
+    let mut block = Block::<C>::clone_from_slice(plaintext);
+    cipher.encrypt_block(&mut block);
+    block.to_vec()
+}
+
+fn decrypt<C: BlockDecrypt>(cipher: &C, ciphertext: &[u8]) -> Vec<u8> {
+    let mut block = Block::<C>::clone_from_slice(ciphertext);
+    cipher.decrypt_block(&mut block);
+    block.to_vec()
+}
+
+/// decrypt ∘ encrypt = id, for every word type.
+macro_rules! round_trip {
+    ($($name:ident: $cipher:ty,)*) => {
+        proptest! {
+            $(
+                #[test]
+                fn $name(key in key(), rounds in 0..=40u8, plaintext in block::<$cipher>()) {
+                    let cipher = <$cipher>::new(&key, rounds).unwrap();
+                    let ciphertext = encrypt(&cipher, &plaintext);
+                    prop_assert_eq!(decrypt(&cipher, &ciphertext), plaintext);
+                }
+            )*
+        }
+    };
+}
+
+round_trip! {
+    round_trip_u8: RC5Var<u8>,
+    round_trip_u16: RC5Var<u16>,
+    round_trip_w24: Strange<W24bit>,
+    round_trip_u32: RC5Var<u32>,
+    round_trip_w32: Strange<W32bit>,
+    round_trip_u64: RC5Var<u64>,
+    round_trip_w80: Strange<W80bit>,
+    round_trip_u128: RC5Var<u128>,
+}
+
+/// Each fixed-parameter alias is [`RC5Var`] with its word size and round count, for keys of its length.
+macro_rules! alias {
+    ($($name:ident: $alias:ty = $word:ty, $rounds:literal,)*) => {
+        proptest! {
+            $(
+                #[test]
+                fn $name(
+                    key in vec(any::<u8>(), <$alias as KeySizeUser>::key_size()),
+                    plaintext in block::<$alias>(),
+                ) {
+                    let fixed = <$alias as KeyInit>::new_from_slice(&key).unwrap();
+                    let var = RC5Var::<$word>::new(&key, $rounds).unwrap();
+                    let ciphertext = encrypt(&fixed, &plaintext);
+                    prop_assert_eq!(&ciphertext, &encrypt(&var, &plaintext));
+                    prop_assert_eq!(decrypt(&fixed, &ciphertext), plaintext);
+                }
+            )*
+        }
+    };
+}
+
+alias! {
+    rivest97_32_12_16: RC5_32_12_16 = u32, 12,
+    rivest97_32_12_24: RC5_32_12_24 = u32, 12,
+    rivest97_32_12_32: RC5_32_12_32 = u32, 12,
+    rivest97_64_16_16: RC5_64_16_16 = u64, 16,
+    rivest97_64_16_24: RC5_64_16_24 = u64, 16,
+    rivest97_64_16_32: RC5_64_16_32 = u64, 16,
+    krovetz18_32_16_16: RC5_32_16_16 = u32, 16,
+    krovetz18_32_16_24: RC5_32_16_24 = u32, 16,
+    krovetz18_32_16_32: RC5_32_16_32 = u32, 16,
+    krovetz18_64_20_16: RC5_64_20_16 = u64, 20,
+    krovetz18_64_20_24: RC5_64_20_24 = u64, 20,
+    krovetz18_64_20_32: RC5_64_20_32 = u64, 20,
+}
+
+proptest! {
+    /// `W32bit` keeps a 32-bit word in a `u64`; its arithmetic must match native `u32`.
+    #[test]
+    fn strange_w32_is_u32(key in key(), rounds in 0..=40u8, plaintext in block::<RC5Var<u32>>()) {
+        let strange = Strange::<W32bit>::new(&key, rounds).unwrap();
+        let native = RC5Var::<u32>::new(&key, rounds).unwrap();
+        let ciphertext = encrypt(&native, &plaintext);
+        prop_assert_eq!(&encrypt(&strange, &plaintext), &ciphertext);
+        prop_assert_eq!(decrypt(&strange, &ciphertext), plaintext);
+    }
+
+    #[test]
+    fn encode_is_rc5_32_12_16(
+        key in vec(any::<u8>(), 16),
+        plaintext in block::<RC5_32_12_16>(),
+    ) {
+        let cipher = RC5_32_12_16::new_from_slice(&key).unwrap();
+        let ciphertext = encode(key.clone(), plaintext.clone());
+        prop_assert_eq!(&ciphertext, &encrypt(&cipher, &plaintext));
+        prop_assert_eq!(decode(key, ciphertext.clone()), decrypt(&cipher, &ciphertext));
+    }
+}